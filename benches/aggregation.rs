@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use finance_news_aggregator_rs::aggregation::{dedup_articles, sort_by_date_desc};
+use finance_news_aggregator_rs::http::HttpClient;
+use finance_news_aggregator_rs::news_source::{FeedSource, NewsSource};
+use finance_news_aggregator_rs::parser::NewsParser;
+use finance_news_aggregator_rs::{NewsArticle, Result};
+use futures::future::join_all;
+use reqwest::Client;
+use std::collections::HashMap;
+use std::hint::black_box;
+
+const SMALL: &str = include_str!("fixtures/small.xml");
+
+/// Source that serves the small fixture from memory instead of the network
+struct MockSource {
+    url_map: HashMap<String, String>,
+    client: Client,
+    parser: NewsParser,
+}
+
+impl MockSource {
+    fn new() -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://mock.example.com/{topic}".to_string(),
+        );
+        Self {
+            url_map,
+            client: Client::new(),
+            parser: NewsParser::new("nytimes"),
+        }
+    }
+}
+
+#[async_trait]
+impl NewsSource for MockSource {
+    fn name(&self) -> &'static str {
+        "Mock"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec!["news"]
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for MockSource {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        &self.client
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    async fn fetch_body(&self, _url: &str) -> Result<String> {
+        Ok(SMALL.to_string())
+    }
+}
+
+/// Fetch every source concurrently, then merge, deduplicate, and sort
+async fn aggregate(sources: &[MockSource]) -> usize {
+    let results = join_all(sources.iter().map(|source| source.fetch_topic("news"))).await;
+    let mut articles = dedup_articles(results.into_iter().flatten().flatten().collect());
+    sort_by_date_desc(&mut articles);
+    articles.len()
+}
+
+fn bench_aggregation(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("Failed to build runtime");
+
+    let mut group = c.benchmark_group("aggregate");
+    for count in [1, 8, 32] {
+        let sources: Vec<MockSource> = (0..count).map(|_| MockSource::new()).collect();
+        group.bench_with_input(
+            BenchmarkId::new("sources", count),
+            &sources,
+            |b, sources| b.iter(|| runtime.block_on(aggregate(black_box(sources)))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_aggregation);
+criterion_main!(benches);
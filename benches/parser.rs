@@ -0,0 +1,45 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use finance_news_aggregator_rs::parser::NewsParser;
+use std::hint::black_box;
+
+const SMALL: &str = include_str!("fixtures/small.xml");
+const CDATA_HEAVY: &str = include_str!("fixtures/cdata_heavy.xml");
+const ENTITY_HEAVY: &str = include_str!("fixtures/entity_heavy.xml");
+
+/// Build a large feed by repeating the items of the small fixture
+fn large_feed(copies: usize) -> String {
+    let start = SMALL.find("<item>").expect("fixture has items");
+    let end = SMALL.rfind("</item>").expect("fixture has items") + "</item>".len();
+    let items = &SMALL[start..end];
+
+    let mut feed = String::with_capacity(SMALL.len() + items.len() * copies);
+    feed.push_str(&SMALL[..start]);
+    for _ in 0..copies {
+        feed.push_str(items);
+    }
+    feed.push_str(&SMALL[end..]);
+    feed
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let parser = NewsParser::new("nytimes");
+    let large = large_feed(50);
+    let fixtures = [
+        ("small", SMALL),
+        ("large", large.as_str()),
+        ("cdata_heavy", CDATA_HEAVY),
+        ("entity_heavy", ENTITY_HEAVY),
+    ];
+
+    let mut group = c.benchmark_group("parse_response");
+    for (name, feed) in fixtures {
+        group.throughput(Throughput::Bytes(feed.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), feed, |b, feed| {
+            b.iter(|| parser.parse_response(black_box(feed)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
@@ -18,9 +18,10 @@ async fn main() -> Result<()> {
             println!("WSJ Opinions: {} articles", articles.len());
             for (i, article) in articles.iter().enumerate() {
                 println!(
-                    "  {}: {}. Link: {}",
+                    "  {}: {} ({}). Link: {}",
                     i + 1,
                     article.title.as_deref().unwrap_or("No title"),
+                    article.relative_time().as_deref().unwrap_or("undated"),
                     article.link.as_deref().unwrap_or("No link")
                 );
             }
@@ -1,5 +1,5 @@
 use finance_news_aggregator_rs::NewsClient;
-use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::FeedSource;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -0,0 +1,46 @@
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::types::{HttpVersion, SourceConfig};
+use finance_news_aggregator_rs::{NewsClient, Result};
+use futures::future::join_all;
+use std::time::Instant;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Initialize logger
+    env_logger::init();
+
+    println!("Finance News Aggregator - HTTP Version Example\n");
+    println!("Fetches every WSJ topic concurrently from the same host under each protocol");
+    println!("setting. Run it a few times; the first round also pays DNS and TLS setup.\n");
+
+    for version in [
+        HttpVersion::Http1Only,
+        HttpVersion::Auto,
+        HttpVersion::Http2PriorKnowledge,
+    ] {
+        let config = SourceConfig::default().with_http_version(version);
+        let mut client = NewsClient::with_config(config);
+        let wsj = client.wsj();
+        let topics = wsj.available_topics();
+
+        let start = Instant::now();
+        let results = join_all(topics.iter().map(|topic| wsj.fetch_topic(topic))).await;
+        let elapsed = start.elapsed();
+
+        let succeeded = results.iter().filter(|r| r.is_ok()).count();
+        let articles: usize = results.iter().flatten().map(Vec::len).sum();
+        println!(
+            "{:<20} {:>3}/{} feeds, {:>4} articles in {:?}",
+            format!("{:?}", version),
+            succeeded,
+            topics.len(),
+            articles,
+            elapsed
+        );
+        if let Some(Err(e)) = results.iter().find(|r| r.is_err()) {
+            println!("{:<20} first error: {}", "", e);
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::{FeedSource, NewsSource};
 use finance_news_aggregator_rs::{NewsClient, Result};
 
 /// Example demonstrating the new topic-based and URL-based fetching API
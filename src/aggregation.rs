@@ -0,0 +1,493 @@
+use crate::types::{MatchField, NewsArticle, TermMatch};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// Remove duplicate articles from a merged list
+///
+/// Two articles are considered duplicates when they share the same link (ignoring
+/// fragments and trailing slashes), the same GUID, or the same title (ignoring case
+/// and whitespace). The first occurrence is kept and the original order preserved.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::aggregation::dedup_articles;
+/// use finance_news_aggregator_rs::NewsArticle;
+///
+/// let mut a = NewsArticle::new();
+/// a.link = Some("https://example.com/story/".to_string());
+/// let mut b = NewsArticle::new();
+/// b.link = Some("https://example.com/story#comments".to_string());
+///
+/// assert_eq!(dedup_articles(vec![a, b]).len(), 1);
+/// ```
+pub fn dedup_articles(articles: Vec<NewsArticle>) -> Vec<NewsArticle> {
+    let mut seen_links = HashSet::new();
+    let mut seen_guids = HashSet::new();
+    let mut seen_titles = HashSet::new();
+
+    articles
+        .into_iter()
+        .filter(|article| {
+            let link = article.link.as_deref().map(normalize_link);
+            let guid = article.guid.as_deref().map(str::trim).map(str::to_string);
+            let title = article.title.as_deref().map(normalize_title);
+
+            let duplicate = link.as_ref().is_some_and(|l| seen_links.contains(l))
+                || guid.as_ref().is_some_and(|g| seen_guids.contains(g))
+                || title.as_ref().is_some_and(|t| seen_titles.contains(t));
+
+            if !duplicate {
+                seen_links.extend(link);
+                seen_guids.extend(guid);
+                seen_titles.extend(title.filter(|t| !t.is_empty()));
+            }
+            !duplicate
+        })
+        .collect()
+}
+
+/// Sort articles newest first
+///
+/// Articles without a parseable publication date are placed last, keeping their
+/// relative order.
+pub fn sort_by_date_desc(articles: &mut [NewsArticle]) {
+    articles.sort_by_key(|article| std::cmp::Reverse(article.published_at()));
+}
+
+/// Check whether an article's title or description mentions any keyword
+///
+/// Matching is case-insensitive and keywords must appear as whole words, so
+/// `"ipo"` matches "Reddit IPO priced" but not "hippo".
+pub fn mentions_any(article: &NewsArticle, keywords: &[&str]) -> bool {
+    !find_keywords(article, keywords).is_empty()
+}
+
+/// Find every mention of the keywords in an article's title and description
+///
+/// Matched as in [`mentions_any`]. Matches are ordered by field, then position.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::aggregation::find_keywords;
+/// use finance_news_aggregator_rs::NewsArticle;
+///
+/// let mut article = NewsArticle::new();
+/// article.title = Some("Fed signals a rate cut".to_string());
+///
+/// let matches = find_keywords(&article, &["rate cut"]);
+/// assert_eq!(&article.title.as_deref().unwrap()[matches[0].start..matches[0].end], "rate cut");
+/// ```
+pub fn find_keywords(article: &NewsArticle, keywords: &[&str]) -> Vec<TermMatch> {
+    let keywords: Vec<(&str, Vec<String>)> = keywords
+        .iter()
+        .map(|keyword| {
+            let words = keyword.split_whitespace().map(str::to_lowercase).collect();
+            (*keyword, words)
+        })
+        .filter(|(_, words): &(&str, Vec<String>)| !words.is_empty())
+        .collect();
+
+    let mut matches = Vec::new();
+    for field in [MatchField::Title, MatchField::Description] {
+        let Some(text) = field.text(article) else {
+            continue;
+        };
+        let spans = word_spans(text, char::is_alphanumeric);
+        let words: Vec<String> = spans.iter().map(|(_, word)| word.to_lowercase()).collect();
+        for (term, keyword) in &keywords {
+            for (i, window) in words.windows(keyword.len()).enumerate() {
+                if window == keyword.as_slice() {
+                    let (last_start, last) = spans[i + keyword.len() - 1];
+                    matches.push(TermMatch {
+                        term: term.to_string(),
+                        field,
+                        start: spans[i].0,
+                        end: last_start + last.len(),
+                    });
+                }
+            }
+        }
+    }
+    matches.sort_by_key(|m| (m.field, m.start, m.end));
+    matches
+}
+
+/// Record the mentions of the keywords in `article.matches`
+///
+/// Returns whether any keyword was found, so it can drive a filter:
+/// `articles.retain_mut(|article| highlight_keywords(article, keywords))`.
+pub fn highlight_keywords(article: &mut NewsArticle, keywords: &[&str]) -> bool {
+    let found = find_keywords(article, keywords);
+    let matched = !found.is_empty();
+    add_matches(article, found);
+    matched
+}
+
+/// Add matches to `article.matches`, skipping those already recorded
+pub(crate) fn add_matches(article: &mut NewsArticle, found: Vec<TermMatch>) {
+    for found in found {
+        if !article.matches.contains(&found) {
+            article.matches.push(found);
+        }
+    }
+}
+
+/// Split text into runs of word characters, with the byte offset of each run
+pub(crate) fn word_spans(text: &str, is_word: impl Fn(char) -> bool) -> Vec<(usize, &str)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (is_word(c), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                spans.push((s, &text[s..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, &text[s..]));
+    }
+    spans
+}
+
+/// Per-source weights and quotas applied to merged articles
+///
+/// Keeps one prolific feed from drowning out the others: a quota caps how many
+/// articles a source contributes, and weights interleave the sources so that a source
+/// of weight 2 gets two places for every place of a source of the default weight 1.
+/// Articles keep their order within each source.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::aggregation::MergePolicy;
+/// use finance_news_aggregator_rs::NewsArticle;
+///
+/// let from = |source: &'static str, count: usize| {
+///     (0..count).map(move |i| NewsArticle {
+///         title: Some(format!("{} {}", source, i)),
+///         source: Some(source.to_string()),
+///         ..NewsArticle::new()
+///     })
+/// };
+/// let articles = from("Seeking Alpha", 20).chain(from("WSJ", 4)).collect();
+///
+/// let policy = MergePolicy::new()
+///     .with_quota("Seeking Alpha", 2)
+///     .with_weight("WSJ", 2.0);
+/// let titles: Vec<_> = policy
+///     .apply(articles)
+///     .into_iter()
+///     .filter_map(|article| article.title)
+///     .collect();
+/// assert_eq!(titles, ["WSJ 0", "WSJ 1", "Seeking Alpha 0", "WSJ 2", "WSJ 3", "Seeking Alpha 1"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MergePolicy {
+    /// Relative share of the merged list by source name; 1 when unset
+    pub weights: HashMap<String, f64>,
+    /// Most articles kept by source name; unlimited when unset
+    pub quotas: HashMap<String, usize>,
+}
+
+impl MergePolicy {
+    /// Create a policy keeping every article in its original order
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the weight of a source; weights below zero count as zero
+    pub fn with_weight(mut self, source: &str, weight: f64) -> Self {
+        self.weights.insert(source.to_string(), weight.max(0.0));
+        self
+    }
+
+    /// Keep at most `quota` articles from a source
+    pub fn with_quota(mut self, source: &str, quota: usize) -> Self {
+        self.quotas.insert(source.to_string(), quota);
+        self
+    }
+
+    /// Whether the policy leaves articles as they are
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty() && self.quotas.is_empty()
+    }
+
+    /// Apply the quotas, then interleave the sources by weight
+    ///
+    /// Sources are matched on `NewsArticle::source`. On a tie the heavier source goes
+    /// first, then the source that appeared first; sources of weight 0 come after all
+    /// others.
+    pub fn apply(&self, articles: Vec<NewsArticle>) -> Vec<NewsArticle> {
+        if self.is_empty() {
+            return articles;
+        }
+
+        // Articles of each source in order, sources in order of first appearance
+        let mut queues: Vec<(String, Vec<NewsArticle>)> = Vec::new();
+        for article in articles {
+            let source = article.source.clone().unwrap_or_default();
+            let index = match queues.iter().position(|(name, _)| *name == source) {
+                Some(index) => index,
+                None => {
+                    queues.push((source, Vec::new()));
+                    queues.len() - 1
+                }
+            };
+            queues[index].1.push(article);
+        }
+
+        let mut queues: Vec<(f64, usize, std::vec::IntoIter<NewsArticle>)> = queues
+            .into_iter()
+            .map(|(source, mut articles)| {
+                if let Some(&quota) = self.quotas.get(&source) {
+                    articles.truncate(quota);
+                }
+                let weight = self.weights.get(&source).copied().unwrap_or(1.0);
+                (weight, 0, articles.into_iter())
+            })
+            .collect();
+
+        // Smooth weighted round robin: the next place goes to the source whose next
+        // article is due earliest, at (taken + 1) / weight
+        let due = |weight: f64, taken: usize| {
+            if weight > 0.0 {
+                (taken + 1) as f64 / weight
+            } else {
+                f64::INFINITY
+            }
+        };
+        let mut merged = Vec::new();
+        loop {
+            let next = queues
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, _, articles))| !articles.as_slice().is_empty())
+                .min_by(|(i, (wa, ta, _)), (j, (wb, tb, _))| {
+                    due(*wa, *ta)
+                        .total_cmp(&due(*wb, *tb))
+                        .then(wb.total_cmp(wa))
+                        .then(i.cmp(j))
+                })
+                .map(|(index, _)| index);
+            let Some(index) = next else {
+                break;
+            };
+            let (_, taken, articles) = &mut queues[index];
+            merged.extend(articles.next());
+            *taken += 1;
+        }
+        merged
+    }
+}
+
+/// Limits on a merged fetch, for callers that must answer within a latency budget
+///
+/// When a limit is reached, the articles gathered so far are returned and the sources
+/// left incomplete are listed in [`BudgetedArticles::truncated`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AggregationBudget {
+    /// Stop once this many articles are gathered
+    pub max_articles: Option<usize>,
+    /// Stop waiting for feeds this long after the fetch starts
+    pub deadline: Option<Duration>,
+    /// Keep at most this many articles from each source
+    pub per_source_quota: Option<usize>,
+}
+
+impl AggregationBudget {
+    /// Create a budget without limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop once `max_articles` articles are gathered
+    pub fn with_max_articles(mut self, max_articles: usize) -> Self {
+        self.max_articles = Some(max_articles);
+        self
+    }
+
+    /// Stop waiting for feeds after `deadline`
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Keep at most `quota` articles from each source
+    pub fn with_per_source_quota(mut self, quota: usize) -> Self {
+        self.per_source_quota = Some(quota);
+        self
+    }
+}
+
+/// Articles gathered within an [`AggregationBudget`]
+#[derive(Debug, Clone, Default)]
+pub struct BudgetedArticles {
+    pub articles: Vec<NewsArticle>,
+    /// Sources, in name order, that had articles left out or feeds still in flight
+    /// when the budget ran out
+    pub truncated: Vec<String>,
+}
+
+impl BudgetedArticles {
+    /// Whether every source was gathered in full
+    pub fn is_complete(&self) -> bool {
+        self.truncated.is_empty()
+    }
+}
+
+/// Normalize a link for duplicate detection
+fn normalize_link(link: &str) -> String {
+    let link = link.trim();
+    let link = link.split('#').next().unwrap_or(link);
+    link.trim_end_matches('/').to_string()
+}
+
+/// Normalize a title for duplicate detection
+fn normalize_title(title: &str) -> String {
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(title: &str, link: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some(title.to_string());
+        article.link = Some(link.to_string());
+        article
+    }
+
+    #[test]
+    fn test_dedup_by_link() {
+        let articles = vec![
+            article("Bitcoin rallies", "https://a.com/btc/"),
+            article("BTC jumps 5%", "https://a.com/btc"),
+        ];
+        let deduped = dedup_articles(articles);
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(deduped[0].title.as_deref(), Some("Bitcoin rallies"));
+    }
+
+    #[test]
+    fn test_dedup_by_title_across_sources() {
+        let articles = vec![
+            article("Bitcoin  Rallies", "https://a.com/1"),
+            article("bitcoin rallies", "https://b.com/2"),
+            article("Ether slips", "https://b.com/3"),
+        ];
+        assert_eq!(dedup_articles(articles).len(), 2);
+    }
+
+    #[test]
+    fn test_mentions_any_whole_words() {
+        let ipo = article("Reddit prices IPO above range", "https://a.com/1");
+        let debut = article("Chipmaker set for Nasdaq market debut", "https://a.com/2");
+        let hippo = article("Hippo Holdings beats estimates", "https://a.com/3");
+
+        let keywords = &["ipo", "market debut"];
+        assert!(mentions_any(&ipo, keywords));
+        assert!(mentions_any(&debut, keywords));
+        assert!(!mentions_any(&hippo, keywords));
+    }
+
+    #[test]
+    fn test_find_keywords_offsets() {
+        let mut fed = article("Fed's Powell: rate cut «likely» — RATE  CUT odds rise", "");
+        fed.description = Some("Traders price a rate cut".to_string());
+        let title = fed.title.clone().unwrap();
+
+        let matches = find_keywords(&fed, &["rate cut", "powell", "ecb"]);
+        let found: Vec<(MatchField, &str, &str)> = matches
+            .iter()
+            .map(|m| {
+                let text = m.field.text(&fed).unwrap();
+                (m.field, m.term.as_str(), &text[m.start..m.end])
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (MatchField::Title, "powell", "Powell"),
+                (MatchField::Title, "rate cut", "rate cut"),
+                (MatchField::Title, "rate cut", "RATE  CUT"),
+                (MatchField::Description, "rate cut", "rate cut"),
+            ]
+        );
+        // Offsets are in bytes, past the multi-byte quotes and dash
+        assert_eq!(&title[matches[2].start..], "RATE  CUT odds rise");
+
+        assert!(highlight_keywords(&mut fed, &["powell"]));
+        assert!(highlight_keywords(&mut fed, &["powell", "ecb"]));
+        assert_eq!(fed.matches.len(), 1);
+        assert!(!highlight_keywords(&mut fed, &["ecb"]));
+    }
+
+    #[test]
+    fn test_sort_by_date_desc() {
+        let mut old = article("Old", "https://a.com/1");
+        old.pub_date = Some("Wed, 01 May 2024 10:00:00 GMT".to_string());
+        let mut new = article("New", "https://a.com/2");
+        new.pub_date = Some("2024-05-02T10:00:00Z".to_string());
+        let undated = article("Undated", "https://a.com/3");
+
+        let mut articles = vec![undated, old, new];
+        sort_by_date_desc(&mut articles);
+        let titles: Vec<_> = articles
+            .iter()
+            .map(|a| a.title.as_deref().unwrap())
+            .collect();
+        assert_eq!(titles, vec!["New", "Old", "Undated"]);
+    }
+
+    #[test]
+    fn test_dedup_by_guid() {
+        let mut first = article("One", "https://a.com/1");
+        first.guid = Some("abc".to_string());
+        let mut second = article("Two", "https://a.com/2");
+        second.guid = Some("abc".to_string());
+        assert_eq!(dedup_articles(vec![first, second]).len(), 1);
+    }
+
+    #[test]
+    fn test_merge_policy() {
+        let from = |source: &str, count: usize| -> Vec<NewsArticle> {
+            (0..count)
+                .map(|i| {
+                    let mut article = article(&format!("{} {}", source, i), "");
+                    article.source = Some(source.to_string());
+                    article
+                })
+                .collect()
+        };
+        let titles = |articles: Vec<NewsArticle>| -> Vec<String> {
+            articles.into_iter().filter_map(|a| a.title).collect()
+        };
+        let articles: Vec<NewsArticle> = [from("A", 3), from("B", 2), from("C", 2)].concat();
+
+        assert_eq!(MergePolicy::new().apply(articles.clone()).len(), 7);
+        // Equal weights alternate between sources
+        let policy = MergePolicy::new().with_quota("A", 2);
+        assert_eq!(
+            titles(policy.apply(articles.clone())),
+            ["A 0", "B 0", "C 0", "A 1", "B 1", "C 1"]
+        );
+        // Weight 0 sources come last; unweighted sources keep weight 1
+        let policy = MergePolicy::new()
+            .with_weight("A", 0.0)
+            .with_weight("C", 2.0);
+        assert_eq!(
+            titles(policy.apply(articles)),
+            ["C 0", "C 1", "B 0", "B 1", "A 0", "A 1", "A 2"]
+        );
+    }
+}
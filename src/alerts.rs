@@ -0,0 +1,348 @@
+//! Named alert rules routing matching articles to notifiers
+//!
+//! An [`AlertRule`] pairs a [`Query`] with the notifier that hears about its
+//! matches, such as a "macro-alerts" rule posting FOMC news to one webhook and a
+//! "my-portfolio" rule posting holdings news to another. Rules are plain data and
+//! (de)serialize with their query as a string, so rule sets live in config files:
+//!
+//! ```json
+//! [
+//!   { "name": "macro-alerts", "query": "(FOMC OR CPI) AND NOT crypto", "notifier": "macro" },
+//!   { "name": "my-portfolio", "query": "ticker:AAPL OR ticker:NVDA",
+//!     "sources": ["CNBC", "Seeking Alpha"], "notifier": "portfolio" }
+//! ]
+//! ```
+//!
+//! Notifiers are registered by name with an [`AlertRouter`], or directly with
+//! `Poller::with_notifier()` when the rules are in the `alerts` of a `PollerConfig`.
+
+use crate::breaking::Priority;
+use crate::error::Result;
+use crate::filter::Query;
+use crate::health::WebhookNotifier;
+use crate::poller::{ArticleSink, NewArticles};
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// A saved query whose matches go to a named notifier
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertRule {
+    pub name: String,
+    pub query: Query,
+    /// Sources the rule applies to, ignoring case, or every source when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<String>,
+    /// Name of the notifier channel receiving the matches
+    pub notifier: String,
+}
+
+impl AlertRule {
+    /// Send articles of any source matching `query` to `notifier`
+    pub fn new(name: impl Into<String>, query: Query, notifier: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            query,
+            sources: Vec::new(),
+            notifier: notifier.into(),
+        }
+    }
+
+    /// Apply the rule only to articles of these sources
+    pub fn with_sources<I, S>(mut self, sources: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.sources = sources.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether the rule applies to articles of `source`
+    pub fn covers(&self, source: &str) -> bool {
+        self.sources.is_empty()
+            || self
+                .sources
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(source))
+    }
+
+    /// The alert for the matching articles of a batch, if any match
+    pub fn check(&self, batch: &NewArticles) -> Option<Alert> {
+        if !self.covers(&batch.source) {
+            return None;
+        }
+        let articles = self.query.filter(batch.articles.clone());
+        (!articles.is_empty()).then(|| Alert {
+            rule: self.name.clone(),
+            source: batch.source.clone(),
+            topic: batch.topic.clone(),
+            articles,
+        })
+    }
+}
+
+/// Articles of one feed that matched an alert rule
+#[derive(Debug, Clone, Serialize)]
+pub struct Alert {
+    /// Name of the rule
+    pub rule: String,
+    pub source: String,
+    pub topic: String,
+    /// The matching articles, with their matches recorded in `NewsArticle::matches`
+    pub articles: Vec<NewsArticle>,
+}
+
+impl Alert {
+    /// Highest priority among the articles, for escalating breaking news
+    pub fn priority(&self) -> Priority {
+        self.articles
+            .iter()
+            .map(|article| article.priority)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// Channel that alerts are sent to
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn send(&self, alert: &Alert) -> Result<()>;
+}
+
+/// Posts each alert to the webhook as JSON
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn send(&self, alert: &Alert) -> Result<()> {
+        self.post(alert).await
+    }
+}
+
+/// Queues alerts for a task in the same process; a dropped receiver is not an error
+#[async_trait]
+impl Notifier for mpsc::UnboundedSender<Alert> {
+    async fn send(&self, alert: &Alert) -> Result<()> {
+        if mpsc::UnboundedSender::send(self, alert.clone()).is_err() {
+            debug!("Receiver gone, dropping alert '{}'", alert.rule);
+        }
+        Ok(())
+    }
+}
+
+/// Checks new articles against alert rules and sends each match to its notifier
+///
+/// Also an `ArticleSink`, for use with a `Poller` whose rules are not in its config.
+#[derive(Clone, Default)]
+pub struct AlertRouter {
+    rules: Vec<AlertRule>,
+    notifiers: HashMap<String, Arc<dyn Notifier>>,
+    dry_run: bool,
+}
+
+impl AlertRouter {
+    /// Route the matches of `rules`
+    pub fn new(rules: Vec<AlertRule>) -> Self {
+        Self {
+            rules,
+            notifiers: HashMap::new(),
+            dry_run: false,
+        }
+    }
+
+    /// Register the notifier that rules name `name`
+    pub fn with_notifier(
+        mut self,
+        name: impl Into<String>,
+        notifier: impl Notifier + 'static,
+    ) -> Self {
+        self.notifiers.insert(name.into(), Arc::new(notifier));
+        self
+    }
+
+    /// Log the alerts that would be sent instead of sending them, to try out rules
+    /// against live feeds
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn rules(&self) -> &[AlertRule] {
+        &self.rules
+    }
+
+    /// Replace the rules, keeping the registered notifiers
+    pub fn set_rules(&mut self, rules: Vec<AlertRule>) {
+        self.rules = rules;
+    }
+
+    pub(crate) fn set_dry_run(&mut self, dry_run: bool) {
+        self.dry_run = dry_run;
+    }
+
+    pub(crate) fn add_notifier(&mut self, name: String, notifier: Arc<dyn Notifier>) {
+        self.notifiers.insert(name, notifier);
+    }
+
+    /// Send the alerts of every rule matching articles of a batch
+    ///
+    /// Rules naming a notifier that is not registered are skipped with a warning.
+    /// Every matching rule is notified even if one fails; the first failure is
+    /// returned.
+    pub async fn route(&self, batch: &NewArticles) -> Result<()> {
+        let mut result = Ok(());
+        for rule in &self.rules {
+            let Some(alert) = rule.check(batch) else {
+                continue;
+            };
+            let Some(notifier) = self.notifiers.get(&rule.notifier) else {
+                warn!(
+                    "Alert rule '{}' names unknown notifier '{}'",
+                    rule.name, rule.notifier
+                );
+                continue;
+            };
+            if self.dry_run {
+                info!(
+                    "Dry run: alert '{}' would send {} articles of {} topic '{}' to '{}'",
+                    rule.name,
+                    alert.articles.len(),
+                    alert.source,
+                    alert.topic,
+                    rule.notifier
+                );
+                continue;
+            }
+            if let Err(e) = notifier.send(&alert).await {
+                warn!("Failed to send alert '{}': {}", rule.name, e);
+                result = result.and(Err(e));
+            }
+        }
+        result
+    }
+}
+
+impl fmt::Debug for AlertRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut notifiers: Vec<&str> = self.notifiers.keys().map(String::as_str).collect();
+        notifiers.sort_unstable();
+        f.debug_struct("AlertRouter")
+            .field("rules", &self.rules)
+            .field("notifiers", &notifiers)
+            .field("dry_run", &self.dry_run)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl ArticleSink for AlertRouter {
+    async fn deliver(&self, batch: &NewArticles) -> Result<()> {
+        self.route(batch).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::FanError;
+
+    fn batch(source: &str, titles: &[&str]) -> NewArticles {
+        let articles = titles
+            .iter()
+            .map(|title| {
+                let mut article = NewsArticle::new();
+                article.title = Some(title.to_string());
+                article
+            })
+            .collect();
+        NewArticles {
+            source: source.to_string(),
+            topic: "news".to_string(),
+            articles,
+        }
+    }
+
+    struct Failing;
+
+    #[async_trait]
+    impl Notifier for Failing {
+        async fn send(&self, _alert: &Alert) -> Result<()> {
+            Err(FanError::Unknown("down".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_rule_serde() {
+        let json = r#"{"name": "my-portfolio", "query": "ticker:AAPL OR ticker:NVDA",
+            "sources": ["CNBC"], "notifier": "portfolio"}"#;
+        let rule: AlertRule = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            rule,
+            AlertRule::new(
+                "my-portfolio",
+                Query::parse("ticker:AAPL OR ticker:NVDA").unwrap(),
+                "portfolio"
+            )
+            .with_sources(["CNBC"])
+        );
+        let value = serde_json::to_value(&rule).unwrap();
+        assert_eq!(value["query"], "ticker:AAPL OR ticker:NVDA");
+        assert_eq!(serde_json::from_value::<AlertRule>(value).unwrap(), rule);
+
+        let invalid = r#"{"name": "x", "query": "(FOMC", "notifier": "macro"}"#;
+        assert!(serde_json::from_str::<AlertRule>(invalid).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_route_by_rule() {
+        let (macro_tx, mut macro_rx) = mpsc::unbounded_channel();
+        let (portfolio_tx, mut portfolio_rx) = mpsc::unbounded_channel();
+        let router = AlertRouter::new(vec![
+            AlertRule::new(
+                "macro-alerts",
+                Query::parse("FOMC OR CPI").unwrap(),
+                "macro",
+            ),
+            AlertRule::new(
+                "my-portfolio",
+                Query::parse("ticker:NVDA").unwrap(),
+                "portfolio",
+            )
+            .with_sources(["cnbc"]),
+            AlertRule::new("orphan", Query::parse("FOMC").unwrap(), "missing"),
+        ])
+        .with_notifier("macro", macro_tx)
+        .with_notifier("portfolio", portfolio_tx);
+
+        let news = batch("CNBC", &["FOMC holds", "$NVDA beats", "Oil slips"]);
+        router.deliver(&news).await.unwrap();
+        let alert = macro_rx.try_recv().unwrap();
+        assert_eq!(alert.rule, "macro-alerts");
+        assert_eq!(alert.articles.len(), 1);
+        assert_eq!(alert.articles[0].matches[0].term, "FOMC");
+        let alert = portfolio_rx.try_recv().unwrap();
+        assert_eq!(alert.articles[0].title.as_deref(), Some("$NVDA beats"));
+
+        // The portfolio rule only covers CNBC
+        router
+            .route(&batch("WSJ", &["$NVDA and CPI"]))
+            .await
+            .unwrap();
+        assert_eq!(macro_rx.try_recv().unwrap().source, "WSJ");
+        assert!(portfolio_rx.try_recv().is_err());
+
+        // A failing notifier does not stop the others
+        let router = router.with_notifier("portfolio", Failing);
+        assert!(router.route(&news).await.is_err());
+        assert!(macro_rx.try_recv().is_ok());
+
+        // A dry run sends nothing, failing notifiers included
+        let router = router.with_dry_run(true);
+        router.route(&news).await.unwrap();
+        assert!(macro_rx.try_recv().is_err());
+    }
+}
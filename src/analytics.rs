@@ -0,0 +1,511 @@
+//! Activity summaries of article lists
+//!
+//! [`bucket_by_hour()`] and [`bucket_by_day()`] count articles per UTC hour or day,
+//! with the keywords and tickers mentioned most in each, for activity charts without
+//! external tooling. Buckets serialize with serde:
+//!
+//! ```json
+//! {
+//!   "start": "2024-05-02T20:00:00Z",
+//!   "end": "2024-05-02T21:00:00Z",
+//!   "articles": 14,
+//!   "keywords": [{ "term": "earnings", "count": 6 }, { "term": "iphone", "count": 3 }],
+//!   "tickers": [{ "term": "AAPL", "count": 5 }]
+//! }
+//! ```
+//!
+//! [`align_with_prices()`] is a building block for event studies: it groups articles
+//! into bursts of coverage and annotates each with the returns of a price series over
+//! the horizons after the burst started.
+
+use crate::export::tickers;
+use crate::types::NewsArticle;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::Duration as StdDuration;
+
+/// Keywords and tickers listed per bucket
+pub const TOP_TERMS: usize = 10;
+
+/// Longest gap between articles of one burst, unless set with
+/// `PriceAlignment::with_burst_gap()`
+pub const DEFAULT_BURST_GAP: StdDuration = StdDuration::from_secs(30 * 60);
+
+/// Horizons returns are measured over, unless set with
+/// `PriceAlignment::with_horizons()`: 15 minutes, 1 hour, and 1 day
+pub const DEFAULT_HORIZONS: [StdDuration; 3] = [
+    StdDuration::from_secs(15 * 60),
+    StdDuration::from_secs(60 * 60),
+    StdDuration::from_secs(24 * 60 * 60),
+];
+
+/// Title words that are not keywords
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "with", "from", "its", "his", "her", "their", "after", "over", "into",
+    "amid", "says", "said", "are", "was", "were", "has", "have", "had", "will", "this", "that",
+    "what", "why", "how", "who", "when", "than", "but", "not", "you", "your", "our", "new", "more",
+    "most", "about", "could", "would", "should", "can", "may", "just", "now", "here", "there",
+    "they", "them", "out", "off", "all", "one", "two", "per", "via", "set", "get", "gets", "top",
+    "week", "today", "year", "years", "day", "days",
+];
+
+/// A term and the number of articles mentioning it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TermCount {
+    pub term: String,
+    pub count: usize,
+}
+
+/// Articles published in one period, oldest bound inclusive
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bucket {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub articles: usize,
+    /// Title words mentioned most, by number of articles
+    pub keywords: Vec<TermCount>,
+    /// Tickers mentioned most, by number of articles
+    pub tickers: Vec<TermCount>,
+}
+
+/// Summarize articles per UTC hour
+///
+/// See [`bucket_by()`].
+pub fn bucket_by_hour(articles: &[NewsArticle]) -> Vec<Bucket> {
+    bucket_by(articles, Duration::hours(1))
+}
+
+/// Summarize articles per UTC day
+///
+/// See [`bucket_by()`].
+pub fn bucket_by_day(articles: &[NewsArticle]) -> Vec<Bucket> {
+    bucket_by(articles, Duration::days(1))
+}
+
+/// Summarize articles per period of `width`, counted from the Unix epoch
+///
+/// Buckets run oldest first from the first article's to the last article's, empty
+/// ones included so a chart's gaps show. Articles without a publication date are
+/// left out. Keywords are title words of at least three letters that are not
+/// stopwords, numbers, or the article's tickers; tickers come from
+/// `extra_fields["symbols"]` and `$CASHTAGS`. Each term counts once per article,
+/// and ties are listed alphabetically.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::NewsArticle;
+/// use finance_news_aggregator_rs::analytics::bucket_by_hour;
+///
+/// let article = |title: &str, date: &str| NewsArticle {
+///     title: Some(title.to_string()),
+///     pub_date: Some(date.to_string()),
+///     ..NewsArticle::new()
+/// };
+/// let articles = [
+///     article("$AAPL earnings beat estimates", "2024-05-02T20:30:00Z"),
+///     article("Apple earnings lift futures", "2024-05-02T20:45:00Z"),
+///     article("Oil slips", "2024-05-02T22:10:00Z"),
+/// ];
+///
+/// let buckets = bucket_by_hour(&articles);
+/// assert_eq!(buckets.len(), 3);
+/// assert_eq!(buckets[0].articles, 2);
+/// assert_eq!(buckets[0].keywords[0].term, "earnings");
+/// assert_eq!(buckets[0].tickers[0].term, "AAPL");
+/// assert_eq!(buckets[1].articles, 0);
+/// ```
+pub fn bucket_by(articles: &[NewsArticle], width: Duration) -> Vec<Bucket> {
+    let width = width.max(Duration::seconds(1));
+    let mut dated: BTreeMap<DateTime<Utc>, Vec<&NewsArticle>> = BTreeMap::new();
+    for article in articles {
+        if let Some(published) = article.published_at() {
+            dated
+                .entry(bucket_start(published, width))
+                .or_default()
+                .push(article);
+        }
+    }
+    let (Some(first), Some(last)) = (dated.keys().next(), dated.keys().next_back()) else {
+        return Vec::new();
+    };
+
+    let mut buckets = Vec::new();
+    let mut start = *first;
+    while start <= *last {
+        let articles = dated.get(&start).map(Vec::as_slice).unwrap_or_default();
+        buckets.push(summarize(start, start + width, articles));
+        start += width;
+    }
+    buckets
+}
+
+fn summarize(start: DateTime<Utc>, end: DateTime<Utc>, articles: &[&NewsArticle]) -> Bucket {
+    let mut keywords: HashMap<String, usize> = HashMap::new();
+    let mut tickers_mentioned: HashMap<String, usize> = HashMap::new();
+    for article in articles {
+        let tickers = tickers(article);
+        let title = article.title.as_deref().unwrap_or_default().to_lowercase();
+        let words: BTreeSet<&str> = title
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| word.chars().count() >= 3)
+            .filter(|word| !word.chars().all(|c| c.is_ascii_digit()))
+            .filter(|word| !STOPWORDS.contains(word))
+            .filter(|word| !tickers.contains(&word.to_uppercase()))
+            .collect();
+        for word in words {
+            *keywords.entry(word.to_string()).or_default() += 1;
+        }
+        for ticker in tickers {
+            *tickers_mentioned.entry(ticker).or_default() += 1;
+        }
+    }
+    Bucket {
+        start,
+        end,
+        articles: articles.len(),
+        keywords: top_terms(keywords),
+        tickers: top_terms(tickers_mentioned),
+    }
+}
+
+/// The most counted terms, most first and ties alphabetically
+fn top_terms(counts: HashMap<String, usize>) -> Vec<TermCount> {
+    let mut terms: Vec<TermCount> = counts
+        .into_iter()
+        .map(|(term, count)| TermCount { term, count })
+        .collect();
+    terms.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.term.cmp(&b.term)));
+    terms.truncate(TOP_TERMS);
+    terms
+}
+
+/// Start of the period of `width` since the Unix epoch that `time` falls in
+fn bucket_start(time: DateTime<Utc>, width: Duration) -> DateTime<Utc> {
+    let width = width.num_seconds();
+    let seconds = time.timestamp().div_euclid(width) * width;
+    DateTime::from_timestamp(seconds, 0).unwrap_or(time)
+}
+
+/// A price of a series, with the volume traded since the point before it if known
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PricePoint {
+    pub time: DateTime<Utc>,
+    pub price: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume: Option<f64>,
+}
+
+impl PricePoint {
+    pub fn new(time: DateTime<Utc>, price: f64) -> Self {
+        Self {
+            time,
+            price,
+            volume: None,
+        }
+    }
+
+    /// Set the volume traded since the point before
+    pub fn with_volume(mut self, volume: f64) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+}
+
+/// Price change over one horizon after a burst started
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HorizonReturn {
+    pub horizon_seconds: u64,
+    /// Price at the end of the horizon over the price at the start, minus one, or
+    /// `None` when the series ends before the horizon does
+    #[serde(rename = "return")]
+    pub change: Option<f64>,
+    /// Volume of the points within the horizon, or `None` when they carry none
+    pub volume: Option<f64>,
+}
+
+/// Articles published in quick succession, with the returns that followed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewsBurst {
+    /// Publication of the first article
+    pub start: DateTime<Utc>,
+    /// Publication of the last article
+    pub end: DateTime<Utc>,
+    /// Indexes of the articles in the list aligned, oldest first
+    pub articles: Vec<usize>,
+    /// Latest price at or before `start`, or `None` when the series starts later
+    pub price: Option<f64>,
+    /// One return per horizon, in the order of the horizons
+    pub returns: Vec<HorizonReturn>,
+}
+
+/// Groups articles into bursts and measures the returns after each
+///
+/// A burst is a run of articles each published within the burst gap of the one
+/// before. Prices are taken as of a time, the latest point at or before it, so a
+/// return over a horizon compares the last prices known at the start and at the end.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceAlignment {
+    burst_gap: StdDuration,
+    horizons: Vec<StdDuration>,
+}
+
+impl Default for PriceAlignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PriceAlignment {
+    /// Align with the default burst gap and horizons
+    pub fn new() -> Self {
+        Self {
+            burst_gap: DEFAULT_BURST_GAP,
+            horizons: DEFAULT_HORIZONS.to_vec(),
+        }
+    }
+
+    /// Start a new burst after this long without articles
+    pub fn with_burst_gap(mut self, gap: StdDuration) -> Self {
+        self.burst_gap = gap;
+        self
+    }
+
+    /// Measure returns over these horizons
+    pub fn with_horizons(mut self, horizons: impl IntoIterator<Item = StdDuration>) -> Self {
+        self.horizons = horizons.into_iter().collect();
+        self
+    }
+
+    /// The bursts of `articles` annotated from `series`, oldest first
+    ///
+    /// Articles without a publication date are left out; the series may be in any
+    /// order.
+    pub fn align(&self, articles: &[NewsArticle], series: &[PricePoint]) -> Vec<NewsBurst> {
+        let mut series = series.to_vec();
+        series.sort_by_key(|point| point.time);
+        let gap = to_chrono(self.burst_gap);
+
+        let mut dated: Vec<(DateTime<Utc>, usize)> = articles
+            .iter()
+            .enumerate()
+            .filter_map(|(index, article)| Some((article.published_at()?, index)))
+            .collect();
+        dated.sort();
+
+        let mut bursts: Vec<NewsBurst> = Vec::new();
+        for (published, index) in dated {
+            match bursts.last_mut() {
+                Some(burst) if published - burst.end <= gap => {
+                    burst.end = published;
+                    burst.articles.push(index);
+                }
+                _ => bursts.push(NewsBurst {
+                    start: published,
+                    end: published,
+                    articles: vec![index],
+                    price: None,
+                    returns: Vec::new(),
+                }),
+            }
+        }
+        for burst in &mut bursts {
+            burst.price = price_at(&series, burst.start);
+            burst.returns = self
+                .horizons
+                .iter()
+                .map(|horizon| horizon_return(&series, burst.start, burst.price, *horizon))
+                .collect();
+        }
+        bursts
+    }
+}
+
+/// Group articles into bursts and annotate each with the returns of `series` over
+/// the default horizons
+///
+/// See [`PriceAlignment`] for the burst gap and horizons.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{DateTime, Duration, Utc};
+/// use finance_news_aggregator_rs::NewsArticle;
+/// use finance_news_aggregator_rs::analytics::{PricePoint, align_with_prices};
+///
+/// let at = |time: &str| DateTime::parse_from_rfc3339(time).unwrap().with_timezone(&Utc);
+/// let article = NewsArticle {
+///     title: Some("Nvidia beats".to_string()),
+///     pub_date: Some("2024-05-22T20:21:00Z".to_string()),
+///     ..NewsArticle::new()
+/// };
+/// let series = [
+///     PricePoint::new(at("2024-05-22T20:00:00Z"), 950.0),
+///     PricePoint::new(at("2024-05-22T20:30:00Z"), 1000.0),
+///     PricePoint::new(at("2024-05-22T21:30:00Z"), 1045.0),
+/// ];
+///
+/// let bursts = align_with_prices(&[article], &series);
+/// assert_eq!(bursts[0].price, Some(950.0));
+/// // 15 minutes later, the price was 1000
+/// let change = bursts[0].returns[0].change.unwrap();
+/// assert!((change - 50.0 / 950.0).abs() < 1e-9);
+/// // The series ends before the day is up
+/// assert_eq!(bursts[0].returns[2].change, None);
+/// ```
+pub fn align_with_prices(articles: &[NewsArticle], series: &[PricePoint]) -> Vec<NewsBurst> {
+    PriceAlignment::new().align(articles, series)
+}
+
+/// The latest price of a sorted series at or before `time`
+fn price_at(series: &[PricePoint], time: DateTime<Utc>) -> Option<f64> {
+    let after = series.partition_point(|point| point.time <= time);
+    after.checked_sub(1).map(|index| series[index].price)
+}
+
+fn horizon_return(
+    series: &[PricePoint],
+    start: DateTime<Utc>,
+    price: Option<f64>,
+    horizon: StdDuration,
+) -> HorizonReturn {
+    // `None` for a horizon past the last representable date, which no series reaches
+    let end = Duration::from_std(horizon)
+        .ok()
+        .and_then(|horizon| start.checked_add_signed(horizon));
+    let reaches_end = end.is_some_and(|end| series.last().is_some_and(|point| point.time >= end));
+    let change = match (price, end.and_then(|end| price_at(series, end))) {
+        (Some(start_price), Some(end_price)) if reaches_end && start_price != 0.0 => {
+            Some(end_price / start_price - 1.0)
+        }
+        _ => None,
+    };
+    let within = series
+        .iter()
+        .filter(|point| point.time > start && end.is_none_or(|end| point.time <= end));
+    let volume = within
+        .filter_map(|point| point.volume)
+        .fold(None, |total, volume| Some(total.unwrap_or(0.0) + volume));
+    HorizonReturn {
+        horizon_seconds: horizon.as_secs(),
+        change,
+        volume,
+    }
+}
+
+fn to_chrono(duration: StdDuration) -> Duration {
+    Duration::from_std(duration).unwrap_or(Duration::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(title: &str, date: &str) -> NewsArticle {
+        NewsArticle {
+            title: Some(title.to_string()),
+            pub_date: Some(date.to_string()),
+            ..NewsArticle::new()
+        }
+    }
+
+    #[test]
+    fn test_bucket_by_day() {
+        let mut nvidia = article("Nvidia beats, stock jumps", "Thu, 02 May 2024 23:30:00 GMT");
+        nvidia
+            .extra_fields
+            .insert("symbols".to_string(), "NVDA".into());
+        let articles = [
+            article(
+                "Stock futures rise ahead of jobs report",
+                "2024-05-01T09:00:00Z",
+            ),
+            nvidia,
+            article("Undated stock story", ""),
+            article("Stock rally fades", "2024-05-02T00:00:00Z"),
+        ];
+
+        let buckets = bucket_by_day(&articles);
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].start.to_rfc3339(), "2024-05-01T00:00:00+00:00");
+        assert_eq!(buckets[0].end, buckets[1].start);
+        assert_eq!(buckets[0].articles, 1);
+        assert_eq!(buckets[1].articles, 2);
+        assert_eq!(
+            buckets[1].keywords[0],
+            TermCount {
+                term: "stock".to_string(),
+                count: 2
+            }
+        );
+        assert_eq!(buckets[1].tickers.len(), 1);
+        assert!(bucket_by_day(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_top_terms() {
+        let counts = (0..15)
+            .map(|i| (format!("term{:02}", i), i % 3))
+            .collect::<HashMap<_, _>>();
+        let top = top_terms(counts);
+        assert_eq!(top.len(), TOP_TERMS);
+        assert_eq!(top[0].term, "term02");
+        assert_eq!(top[0].count, 2);
+        assert!(top.windows(2).all(|pair| pair[0].count >= pair[1].count));
+    }
+
+    fn at(minute: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_714_680_000, 0).unwrap() + Duration::minutes(minute)
+    }
+
+    #[test]
+    fn test_align_with_prices() {
+        let dated = |minute: i64| article("Apple earnings", &at(minute).to_rfc3339());
+        let articles = [dated(32), dated(0), dated(15), article("Undated", "")];
+        let series = [
+            PricePoint::new(at(90), 110.0).with_volume(500.0),
+            PricePoint::new(at(-5), 100.0),
+            PricePoint::new(at(30), 102.0).with_volume(1000.0),
+        ];
+        let alignment = PriceAlignment::new()
+            .with_burst_gap(StdDuration::from_secs(20 * 60))
+            .with_horizons([
+                StdDuration::from_secs(30 * 60),
+                StdDuration::from_secs(3 * 60 * 60),
+            ]);
+
+        let bursts = alignment.align(&articles, &series);
+        assert_eq!(bursts.len(), 1);
+        let burst = &bursts[0];
+        assert_eq!((burst.start, burst.end), (at(0), at(32)));
+        assert_eq!(burst.articles, [1, 2, 0]);
+        assert_eq!(burst.price, Some(100.0));
+        assert_eq!(burst.returns[0].horizon_seconds, 1800);
+        assert!((burst.returns[0].change.unwrap() - 0.02).abs() < 1e-9);
+        assert_eq!(burst.returns[0].volume, Some(1000.0));
+        assert_eq!(burst.returns[1].change, None);
+        assert_eq!(burst.returns[1].volume, Some(1500.0));
+
+        // A gap narrower than the articles' splits them
+        let split = alignment.with_burst_gap(StdDuration::from_secs(10 * 60));
+        let bursts = split.align(&articles, &series);
+        assert_eq!(bursts.len(), 3);
+        assert_eq!(bursts[2].price, Some(102.0));
+
+        // A horizon beyond any date has no return, only the volume after the burst
+        let endless = PriceAlignment::new().with_horizons([StdDuration::MAX]);
+        let returns = &endless.align(&articles, &series)[0].returns;
+        assert_eq!(returns[0].change, None);
+        assert_eq!(returns[0].volume, Some(1500.0));
+    }
+
+    #[test]
+    fn test_bucket_serializes() {
+        let buckets = bucket_by_hour(&[article("$AAPL earnings", "2024-05-02T20:30:00Z")]);
+        let json = serde_json::to_value(&buckets[0]).unwrap();
+        assert_eq!(json["start"], "2024-05-02T20:00:00Z");
+        assert_eq!(json["tickers"][0]["term"], "AAPL");
+        assert_eq!(json["keywords"][0]["term"], "earnings");
+    }
+}
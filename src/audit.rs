@@ -0,0 +1,318 @@
+//! Audit log of fetch decisions
+//!
+//! An [`AuditLog`] writes one JSON object per line for every request a
+//! `NewsClient` sends and every feed a `Poller` polls, so an operator can show
+//! afterwards what was fetched, when, and what became of it. Request entries
+//! carry the URL, the conditional headers sent, the status, and the bytes read;
+//! poll entries carry the articles parsed, those dropped as already seen, and
+//! those that passed the keyword filter.
+//!
+//! ```json
+//! {"event":"request","time":"2024-05-22T20:21:00Z","url":"https://www.cnbc.com/id/100003114/device/rss/rss.html","if_none_match":"\"5f2a\"","status":304,"bytes":0,"elapsed_ms":84}
+//! {"event":"poll","time":"2024-05-22T20:21:00Z","source":"CNBC","topic":"top_news","articles":30,"duplicates":28,"matched":1}
+//! ```
+//!
+//! Entries are written and flushed as they happen; a failed write is logged and
+//! does not fail the fetch.
+
+use crate::error::Result;
+use crate::http::{HttpClient, HttpRequest, HttpResponse};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::StreamExt;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// One line of the audit log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum AuditEntry {
+    Request(RequestAudit),
+    Poll(PollAudit),
+}
+
+/// An HTTP request and its response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequestAudit {
+    /// When the request was sent
+    pub time: DateTime<Utc>,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub if_none_match: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub if_modified_since: Option<String>,
+    /// Status of the response, `None` when there was none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Body bytes read; a body that was not read in full counts what was
+    pub bytes: u64,
+    /// From sending the request to the end of the body
+    pub elapsed_ms: u64,
+    /// Why the request or the body failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A poll of one feed and what became of its articles
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PollAudit {
+    pub time: DateTime<Utc>,
+    pub source: String,
+    pub topic: String,
+    /// Articles the feed returned
+    pub articles: usize,
+    /// Articles dropped as delivered by an earlier poll
+    pub duplicates: usize,
+    /// New articles that passed the keyword filter and were delivered
+    pub matched: usize,
+    /// Why the fetch failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Writes audit entries as JSON lines
+///
+/// Clones write to the same destination.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use finance_news_aggregator_rs::NewsClient;
+/// use finance_news_aggregator_rs::audit::AuditLog;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let log = AuditLog::to_file("fetches.jsonl")?;
+/// let mut client = NewsClient::new().with_audit_log(log);
+/// let poller = client.poller(&[("CNBC", "top_news")])?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AuditLog {
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl std::fmt::Debug for AuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditLog").finish_non_exhaustive()
+    }
+}
+
+impl AuditLog {
+    /// Append entries to a file, creating it if needed
+    pub fn to_file(path: impl AsRef<Path>) -> Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self::to_writer(BufWriter::new(file)))
+    }
+
+    /// Write entries to `writer`, such as stderr or a pipe to a log shipper
+    pub fn to_writer(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(Box::new(writer))),
+        }
+    }
+
+    /// Write an entry and flush it
+    pub fn record(&self, entry: &AuditEntry) {
+        let mut line = match serde_json::to_vec(entry) {
+            Ok(line) => line,
+            Err(e) => {
+                warn!("Failed to serialize audit entry: {}", e);
+                return;
+            }
+        };
+        line.push(b'\n');
+        let mut writer = self.writer.lock().unwrap();
+        if let Err(e) = writer.write_all(&line).and_then(|_| writer.flush()) {
+            warn!("Failed to write audit entry: {}", e);
+        }
+    }
+
+    /// Wrap an HTTP client so that its requests are recorded
+    pub fn client(&self, inner: Arc<dyn HttpClient>) -> AuditedClient {
+        AuditedClient {
+            inner,
+            log: self.clone(),
+        }
+    }
+}
+
+/// HTTP client recording its requests in an [`AuditLog`]
+///
+/// A request is recorded once its body has been read or dropped, so the entry
+/// holds the bytes actually read.
+#[derive(Clone)]
+pub struct AuditedClient {
+    inner: Arc<dyn HttpClient>,
+    log: AuditLog,
+}
+
+#[async_trait]
+impl HttpClient for AuditedClient {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let mut pending = PendingRequest {
+            log: self.log.clone(),
+            started: Instant::now(),
+            entry: RequestAudit {
+                time: Utc::now(),
+                url: request.url.clone(),
+                if_none_match: request.header("If-None-Match").map(str::to_string),
+                if_modified_since: request.header("If-Modified-Since").map(str::to_string),
+                status: None,
+                bytes: 0,
+                elapsed_ms: 0,
+                error: None,
+            },
+        };
+        let response = match self.inner.send(request).await {
+            Ok(response) => response,
+            Err(e) => {
+                pending.entry.error = Some(e.to_string());
+                return Err(e);
+            }
+        };
+        pending.entry.status = Some(response.status());
+        Ok(response.map_body(|body| {
+            body.map(move |chunk| {
+                pending.read(&chunk);
+                chunk
+            })
+            .boxed()
+        }))
+    }
+}
+
+/// A request entry, recorded when dropped along with the response body
+struct PendingRequest {
+    log: AuditLog,
+    started: Instant,
+    entry: RequestAudit,
+}
+
+impl PendingRequest {
+    /// Count a chunk of the body, or note why it failed
+    fn read(&mut self, chunk: &std::io::Result<bytes::Bytes>) {
+        match chunk {
+            Ok(bytes) => self.entry.bytes += bytes.len() as u64,
+            Err(e) => self.entry.error = Some(e.to_string()),
+        }
+    }
+}
+
+impl Drop for PendingRequest {
+    fn drop(&mut self) {
+        self.entry.elapsed_ms = self.started.elapsed().as_millis() as u64;
+        self.log.record(&AuditEntry::Request(self.entry.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::FanError;
+
+    /// Collects what an `AuditLog` writes
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(data);
+            Ok(data.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn entries(&self) -> Vec<AuditEntry> {
+            let text = String::from_utf8(self.0.lock().unwrap().clone()).unwrap();
+            text.lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect()
+        }
+    }
+
+    /// Answers `/feed` with a short body, `/down` with no response, and 304 otherwise
+    struct Feeds;
+
+    #[async_trait]
+    impl HttpClient for Feeds {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+            match request.url.as_str() {
+                "https://example.com/feed" => Ok(HttpResponse::new(200, "<rss></rss>")),
+                "https://example.com/down" => Err(FanError::Timeout("no answer".to_string())),
+                _ => Ok(HttpResponse::new(304, "")),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_recorded() {
+        let buffer = Buffer::default();
+        let client = AuditLog::to_writer(buffer.clone()).client(Arc::new(Feeds));
+
+        let body = client
+            .send(HttpRequest::get("https://example.com/feed"))
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert_eq!(body, "<rss></rss>");
+        let request = HttpRequest::get("https://example.com/cached")
+            .with_header("If-None-Match", "\"5f2a\"")
+            .with_header("If-Modified-Since", "Wed, 22 May 2024 20:21:00 GMT");
+        assert_eq!(client.send(request).await.unwrap().status(), 304);
+        assert!(
+            client
+                .send(HttpRequest::get("https://example.com/down"))
+                .await
+                .is_err()
+        );
+
+        let requests: Vec<RequestAudit> = buffer
+            .entries()
+            .into_iter()
+            .map(|entry| match entry {
+                AuditEntry::Request(request) => request,
+                other => panic!("unexpected entry {:?}", other),
+            })
+            .collect();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].status, Some(200));
+        assert_eq!(requests[0].bytes, 11);
+        assert_eq!(requests[1].status, Some(304));
+        assert_eq!(requests[1].if_none_match.as_deref(), Some("\"5f2a\""));
+        assert!(requests[1].if_modified_since.is_some());
+        assert_eq!(requests[2].status, None);
+        assert!(requests[2].error.as_deref().unwrap().contains("no answer"));
+    }
+
+    #[test]
+    fn test_entry_format() {
+        let buffer = Buffer::default();
+        AuditLog::to_writer(buffer.clone()).record(&AuditEntry::Poll(PollAudit {
+            time: DateTime::from_timestamp(1_716_409_260, 0).unwrap(),
+            source: "CNBC".to_string(),
+            topic: "top_news".to_string(),
+            articles: 30,
+            duplicates: 28,
+            matched: 1,
+            error: None,
+        }));
+        let text = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            text,
+            "{\"event\":\"poll\",\"time\":\"2024-05-22T20:21:00Z\",\"source\":\"CNBC\",\
+             \"topic\":\"top_news\",\"articles\":30,\"duplicates\":28,\"matched\":1}\n"
+        );
+    }
+}
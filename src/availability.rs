@@ -0,0 +1,228 @@
+//! Which source topics currently work
+//!
+//! Publishers retire feeds without notice; MarketWatch and NASDAQ have both dropped
+//! topics this crate still lists. `NewsClient::check_topics()` fetches every topic
+//! once and records an [`AvailableTopics`] snapshot, which `fetch_group()` and the
+//! other aggregation calls consult to skip topics known to be dead. Overrides pin the
+//! status of a topic regardless of what a check finds.
+
+use crate::error::{FanError, Result};
+use crate::types::NewsArticle;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Availability of one source topic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TopicStatus {
+    /// Fetched with articles
+    Working,
+    /// Fetched without articles, or failed in a way that may pass
+    Degraded,
+    /// Gone: not found, forbidden, or no longer a feed
+    Deprecated,
+}
+
+impl TopicStatus {
+    /// Status a health check assigns to the result of fetching a topic
+    ///
+    /// Returns `None` when the result says nothing about the topic, such as a source
+    /// without an API key.
+    pub fn of(result: &Result<Vec<NewsArticle>>) -> Option<Self> {
+        match result {
+            Ok(articles) if articles.is_empty() => Some(TopicStatus::Degraded),
+            Ok(_) => Some(TopicStatus::Working),
+            Err(FanError::MissingApiKey(_)) => None,
+            Err(FanError::HttpStatus {
+                status: 403 | 404 | 410,
+                ..
+            })
+            | Err(FanError::NotAFeed { .. }) => Some(TopicStatus::Deprecated),
+            Err(_) => Some(TopicStatus::Degraded),
+        }
+    }
+}
+
+/// Snapshot of the status of every checked topic, by source and topic
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::availability::{AvailableTopics, TopicStatus};
+///
+/// let mut topics = AvailableTopics::new();
+/// topics.record("MarketWatch", "topstories", TopicStatus::Deprecated);
+/// assert!(!topics.is_available("MarketWatch", "topstories"));
+///
+/// // The feed is back, but the next check has not run yet
+/// topics.set_override("MarketWatch", "topstories", TopicStatus::Working);
+/// assert!(topics.is_available("MarketWatch", "topstories"));
+///
+/// // Topics never checked are assumed to work
+/// assert!(topics.is_available("CNBC", "investing"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvailableTopics {
+    /// When the snapshot was last checked
+    pub checked_at: DateTime<Utc>,
+    /// Status found by the last check
+    pub topics: BTreeMap<String, BTreeMap<String, TopicStatus>>,
+    /// Manually set statuses, which win over `topics`
+    #[serde(default)]
+    pub overrides: BTreeMap<String, BTreeMap<String, TopicStatus>>,
+}
+
+impl Default for AvailableTopics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AvailableTopics {
+    /// Create an empty snapshot
+    pub fn new() -> Self {
+        Self {
+            checked_at: Utc::now(),
+            topics: BTreeMap::new(),
+            overrides: BTreeMap::new(),
+        }
+    }
+
+    /// Record the status a check found for a topic
+    pub fn record(&mut self, source: &str, topic: &str, status: TopicStatus) {
+        self.topics
+            .entry(source.to_string())
+            .or_default()
+            .insert(topic.to_string(), status);
+    }
+
+    /// Pin the status of a topic, whatever later checks find
+    pub fn set_override(&mut self, source: &str, topic: &str, status: TopicStatus) {
+        self.overrides
+            .entry(source.to_string())
+            .or_default()
+            .insert(topic.to_string(), status);
+    }
+
+    /// Remove the override of a topic, returning to the checked status
+    pub fn clear_override(&mut self, source: &str, topic: &str) {
+        if let Some(topics) = self.overrides.get_mut(source) {
+            topics.remove(topic);
+            if topics.is_empty() {
+                self.overrides.remove(source);
+            }
+        }
+    }
+
+    /// Status of a topic, from its override or the last check
+    pub fn status(&self, source: &str, topic: &str) -> Option<TopicStatus> {
+        let lookup = |map: &BTreeMap<String, BTreeMap<String, TopicStatus>>| {
+            map.get(source)
+                .and_then(|topics| topics.get(topic))
+                .copied()
+        };
+        lookup(&self.overrides).or_else(|| lookup(&self.topics))
+    }
+
+    /// Whether a topic is worth fetching: anything but deprecated, including topics
+    /// never checked
+    pub fn is_available(&self, source: &str, topic: &str) -> bool {
+        self.status(source, topic) != Some(TopicStatus::Deprecated)
+    }
+
+    /// Topics of a source with a status, overrides included, in name order
+    pub fn with_status(&self, source: &str, status: TopicStatus) -> Vec<String> {
+        let mut topics: Vec<String> = self
+            .topics
+            .get(source)
+            .into_iter()
+            .chain(self.overrides.get(source))
+            .flat_map(|topics| topics.keys())
+            .filter(|topic| self.status(source, topic) == Some(status))
+            .cloned()
+            .collect();
+        topics.sort();
+        topics.dedup();
+        topics
+    }
+
+    /// Read a snapshot saved with `save()`
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+
+    /// Write the snapshot as JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_of_results() {
+        let not_found = FanError::HttpStatus {
+            status: 404,
+            url: "https://feeds.example.com/gone".to_string(),
+            snippet: String::new(),
+        };
+        let unavailable = FanError::HttpStatus {
+            status: 503,
+            url: "https://feeds.example.com/busy".to_string(),
+            snippet: String::new(),
+        };
+        assert_eq!(
+            TopicStatus::of(&Ok(vec![NewsArticle::new()])),
+            Some(TopicStatus::Working)
+        );
+        assert_eq!(TopicStatus::of(&Ok(vec![])), Some(TopicStatus::Degraded));
+        assert_eq!(
+            TopicStatus::of(&Err(not_found)),
+            Some(TopicStatus::Deprecated)
+        );
+        assert_eq!(
+            TopicStatus::of(&Err(unavailable)),
+            Some(TopicStatus::Degraded)
+        );
+        assert_eq!(
+            TopicStatus::of(&Err(FanError::MissingApiKey("Polygon".to_string()))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_overrides_win_until_cleared() {
+        let mut topics = AvailableTopics::new();
+        topics.record("NASDAQ", "Stocks", TopicStatus::Working);
+        topics.record("NASDAQ", "Retired", TopicStatus::Deprecated);
+        topics.set_override("NASDAQ", "Stocks", TopicStatus::Deprecated);
+
+        assert!(!topics.is_available("NASDAQ", "Stocks"));
+        assert_eq!(
+            topics.with_status("NASDAQ", TopicStatus::Deprecated),
+            ["Retired", "Stocks"]
+        );
+
+        topics.clear_override("NASDAQ", "Stocks");
+        assert!(topics.is_available("NASDAQ", "Stocks"));
+        assert!(topics.overrides.is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("fan-topics-{}.json", std::process::id()));
+        let mut topics = AvailableTopics::new();
+        topics.record("CNBC", "investing", TopicStatus::Working);
+        topics.set_override("CNBC", "retired", TopicStatus::Deprecated);
+        topics.save(&path).unwrap();
+
+        assert_eq!(AvailableTopics::load(&path).unwrap(), topics);
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,325 @@
+use crate::error::{FanError, Result};
+use crate::parser::NewsParser;
+use crate::types::NewsArticle;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use futures::future;
+use futures::stream::{self, BoxStream, StreamExt};
+use log::{debug, warn};
+use reqwest::Client;
+use std::collections::HashSet;
+
+/// Internet Archive CDX search endpoint
+const CDX_URL: &str = "https://web.archive.org/cdx/search/cdx";
+
+/// Wayback Machine endpoint serving archived captures
+const WAYBACK_URL: &str = "https://web.archive.org/web";
+
+/// An archived capture of a feed URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// When the Wayback Machine captured the feed
+    pub captured_at: DateTime<Utc>,
+    /// The feed URL as it was captured
+    pub original_url: String,
+    /// Content digest of the capture
+    pub digest: String,
+}
+
+impl Snapshot {
+    /// URL of the raw archived body, without the Wayback Machine toolbar or rewriting
+    pub fn raw_url(&self) -> String {
+        format!(
+            "{}/{}id_/{}",
+            WAYBACK_URL,
+            self.captured_at.format("%Y%m%d%H%M%S"),
+            self.original_url
+        )
+    }
+}
+
+/// Historical articles for a feed from Wayback Machine snapshots
+///
+/// Live feeds only expose their latest 20-50 items. This queries the Internet
+/// Archive's CDX API for captures of a feed URL, parses each archived body, and
+/// yields the articles it has not seen in a newer capture, keeping their original
+/// publication dates. Useful for building research datasets.
+///
+/// # Example
+/// ```rust,no_run
+/// use finance_news_aggregator_rs::backfill::WaybackBackfill;
+/// use chrono::NaiveDate;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let backfill = WaybackBackfill::new(reqwest::Client::new())
+///         .with_date_range(
+///             NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+///             NaiveDate::from_ymd_opt(2023, 3, 31).unwrap(),
+///         )
+///         .with_limit(20);
+///     let articles = backfill
+///         .fetch_articles("https://feeds.content.dowjones.io/public/rss/RSSMarketsMain")
+///         .await?;
+///     println!("Recovered {} archived articles", articles.len());
+///     Ok(())
+/// }
+/// ```
+pub struct WaybackBackfill {
+    client: Client,
+    parser: NewsParser,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    limit: Option<usize>,
+}
+
+impl WaybackBackfill {
+    /// Create a backfill client using the given HTTP client
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            parser: NewsParser::new("generic"),
+            from: None,
+            to: None,
+            limit: None,
+        }
+    }
+
+    /// Use a source-specific parser, e.g. `NewsParser::new("wsj")` for WSJ feeds
+    pub fn with_parser(mut self, parser: NewsParser) -> Self {
+        self.parser = parser;
+        self
+    }
+
+    /// Only use captures taken between `from` and `to` (inclusive)
+    ///
+    /// Bounds given in reverse order are swapped.
+    pub fn with_date_range(mut self, from: NaiveDate, to: NaiveDate) -> Self {
+        self.from = Some(from.min(to));
+        self.to = Some(from.max(to));
+        self
+    }
+
+    /// Use at most `limit` captures
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// List the distinct archived captures of a feed URL, newest first
+    ///
+    /// Captures with identical content are collapsed into one.
+    pub async fn snapshots(&self, feed_url: &str) -> Result<Vec<Snapshot>> {
+        let mut query = vec![
+            ("url", feed_url.to_string()),
+            ("output", "json".to_string()),
+            ("fl", "timestamp,original,digest".to_string()),
+            ("filter", "statuscode:200".to_string()),
+            ("collapse", "digest".to_string()),
+        ];
+        if let Some(from) = self.from {
+            query.push(("from", from.format("%Y%m%d").to_string()));
+        }
+        if let Some(to) = self.to {
+            query.push(("to", to.format("%Y%m%d").to_string()));
+        }
+        if let Some(limit) = self.limit {
+            // Negative limits return the most recent captures
+            query.push(("limit", format!("-{}", limit)));
+        }
+
+        debug!("Querying Wayback CDX for {}", feed_url);
+        let body = self
+            .client
+            .get(CDX_URL)
+            .query(&query)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        let mut snapshots = parse_cdx_rows(&body)?;
+        snapshots.sort_by_key(|snapshot| std::cmp::Reverse(snapshot.captured_at));
+        debug!("Found {} captures of {}", snapshots.len(), feed_url);
+        Ok(snapshots)
+    }
+
+    /// Fetch and parse one archived capture
+    ///
+    /// Articles without their own publication date are dated with the capture time,
+    /// and every article records the capture in `extra_fields["archived_at"]`.
+    pub async fn fetch_snapshot(&self, snapshot: &Snapshot) -> Result<Vec<NewsArticle>> {
+        let body = self
+            .client
+            .get(snapshot.raw_url())
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        let mut articles = self.parser.parse_response(&body)?;
+        for article in &mut articles {
+            annotate_archived(article, snapshot);
+        }
+        Ok(articles)
+    }
+
+    /// Stream historical articles for a feed URL, newest capture first
+    ///
+    /// Each article is yielded once, from the newest capture containing it. Captures
+    /// that cannot be fetched or parsed are logged and skipped; only a failed CDX
+    /// query ends the stream with an error.
+    pub fn stream_articles<'a>(&'a self, feed_url: &'a str) -> BoxStream<'a, Result<NewsArticle>> {
+        let mut seen = HashSet::new();
+        stream::once(self.snapshots(feed_url))
+            .flat_map(move |snapshots| match snapshots {
+                Ok(snapshots) => stream::iter(snapshots)
+                    .then(move |snapshot| async move {
+                        match self.fetch_snapshot(&snapshot).await {
+                            Ok(articles) => articles,
+                            Err(e) => {
+                                warn!("Skipping capture {}: {}", snapshot.raw_url(), e);
+                                Vec::new()
+                            }
+                        }
+                    })
+                    .flat_map(|articles| stream::iter(articles.into_iter().map(Ok)))
+                    .boxed(),
+                Err(e) => stream::once(future::ready(Err(e))).boxed(),
+            })
+            .filter(move |article| {
+                let new = match article {
+                    Ok(article) => article_key(article).is_none_or(|key| seen.insert(key)),
+                    Err(_) => true,
+                };
+                future::ready(new)
+            })
+            .boxed()
+    }
+
+    /// Collect historical articles for a feed URL
+    ///
+    /// See [`stream_articles`](Self::stream_articles).
+    pub async fn fetch_articles(&self, feed_url: &str) -> Result<Vec<NewsArticle>> {
+        let mut stream = self.stream_articles(feed_url);
+        let mut articles = Vec::new();
+        while let Some(article) = stream.next().await {
+            articles.push(article?);
+        }
+        Ok(articles)
+    }
+}
+
+/// Parse the JSON output of a CDX query with `fl=timestamp,original,digest`
+///
+/// The first row holds the column names.
+fn parse_cdx_rows(body: &str) -> Result<Vec<Snapshot>> {
+    // An empty result is an empty body rather than an empty array
+    if body.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rows: Vec<Vec<String>> = serde_json::from_str(body)?;
+    rows.into_iter()
+        .skip(1)
+        .map(|row| match row.as_slice() {
+            [timestamp, original, digest, ..] => {
+                let captured_at = NaiveDateTime::parse_from_str(timestamp, "%Y%m%d%H%M%S")
+                    .map_err(|e| {
+                        FanError::FeedParsing(format!("Invalid CDX timestamp {}: {}", timestamp, e))
+                    })?
+                    .and_utc();
+                Ok(Snapshot {
+                    captured_at,
+                    original_url: original.clone(),
+                    digest: digest.clone(),
+                })
+            }
+            _ => Err(FanError::FeedParsing(format!("Invalid CDX row: {:?}", row))),
+        })
+        .collect()
+}
+
+/// Record the capture an article was recovered from
+fn annotate_archived(article: &mut NewsArticle, snapshot: &Snapshot) {
+    if article.pub_date.is_none() {
+        article.pub_date = Some(snapshot.captured_at.to_rfc2822());
+    }
+    article.extra_fields.insert(
+        "archived_at".to_string(),
+        snapshot.captured_at.to_rfc3339().into(),
+    );
+}
+
+/// Identity of an article across captures: its GUID, link, or title
+fn article_key(article: &NewsArticle) -> Option<String> {
+    article
+        .guid
+        .as_ref()
+        .or(article.link.as_ref())
+        .or(article.title.as_ref())
+        .map(|key| key.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cdx_rows() {
+        let body = r#"[["timestamp","original","digest"],
+            ["20230105120000","https://example.com/rss","ABC"],
+            ["20230210083000","https://example.com/rss","DEF"]]"#;
+
+        let snapshots = parse_cdx_rows(body).unwrap();
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[1].digest, "DEF");
+        assert_eq!(
+            snapshots[0].raw_url(),
+            "https://web.archive.org/web/20230105120000id_/https://example.com/rss"
+        );
+
+        assert!(parse_cdx_rows("").unwrap().is_empty());
+        assert!(parse_cdx_rows(r#"[["timestamp"],["bad"]]"#).is_err());
+    }
+
+    #[test]
+    fn test_date_range_in_reverse_order() {
+        let (earlier, later) = (
+            NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+        );
+        let backfill = WaybackBackfill::new(Client::new()).with_date_range(later, earlier);
+        assert_eq!((backfill.from, backfill.to), (Some(earlier), Some(later)));
+    }
+
+    #[test]
+    fn test_annotate_archived_keeps_original_date() {
+        let snapshot = Snapshot {
+            captured_at: NaiveDate::from_ymd_opt(2023, 1, 5)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+                .and_utc(),
+            original_url: "https://example.com/rss".to_string(),
+            digest: "ABC".to_string(),
+        };
+
+        let mut dated = NewsArticle::new();
+        dated.pub_date = Some("Mon, 02 Jan 2023 09:30:00 GMT".to_string());
+        annotate_archived(&mut dated, &snapshot);
+        assert_eq!(
+            dated.pub_date.as_deref(),
+            Some("Mon, 02 Jan 2023 09:30:00 GMT")
+        );
+        assert_eq!(
+            dated.extra_fields["archived_at"],
+            "2023-01-05T12:00:00+00:00"
+        );
+
+        let mut undated = NewsArticle::new();
+        annotate_archived(&mut undated, &snapshot);
+        assert_eq!(undated.published_at(), Some(snapshot.captured_at));
+    }
+}
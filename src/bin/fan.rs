@@ -0,0 +1,493 @@
+//! `fan`, the command-line tool for finance-news-aggregator-rs
+//!
+//! Built with the `cli` feature: `cargo install finance-news-aggregator-rs --features cli`.
+
+use anyhow::{Context, bail};
+use chrono::{DateTime, Utc};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use finance_news_aggregator_rs::aggregation::dedup_articles;
+use finance_news_aggregator_rs::availability::TopicStatus;
+use finance_news_aggregator_rs::dates::parse_date;
+use finance_news_aggregator_rs::deprecation::{DeprecationHistory, ErrorClass};
+use finance_news_aggregator_rs::filter::{Query, parse_age};
+use finance_news_aggregator_rs::import::{self, FieldMapping};
+use finance_news_aggregator_rs::news_source::{NewsSource, NewsSourceExt};
+use finance_news_aggregator_rs::output::{ArticleTemplate, select_field};
+use finance_news_aggregator_rs::store::{SearchFilter, SqliteStore};
+use finance_news_aggregator_rs::{NewsArticle, NewsClient, TopicGroup};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+#[derive(Parser)]
+#[command(name = "fan", version, about = "Finance news aggregator")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Line printed for each article unless `--template` or `--field` is given
+const DEFAULT_TEMPLATE: &str = "{source} | {published} | {title} | {link}";
+
+/// Titles longer than this are cut short in `fan query --format table`
+const TABLE_TITLE_WIDTH: usize = 90;
+
+#[derive(Subcommand)]
+enum Command {
+    /// Fetch a topic of a source and print its articles
+    Fetch {
+        /// Source name, such as CNBC or MarketWatch
+        source: String,
+        topic: String,
+        /// Line printed for each article, with {field} placeholders such as {title}
+        #[arg(long, conflicts_with_all = ["field", "json"])]
+        template: Option<String>,
+        /// Print these fields, tab-separated; a path such as enclosures.0.url
+        #[arg(long, conflicts_with = "json")]
+        field: Vec<String>,
+        /// Print the articles as a JSON array
+        #[arg(long)]
+        json: bool,
+        /// Keep only articles matching a query, such as '("rate cut" OR FOMC) since:6h'
+        #[arg(long)]
+        query: Option<String>,
+    },
+    /// Search the local article store instead of fetching
+    Query {
+        /// Full-text query, such as 'nvidia AND earn*'; every article if left out
+        text: Option<String>,
+        /// SQLite article store to read, as written by store::SqliteStore
+        #[arg(long, default_value = "news.db")]
+        db: PathBuf,
+        /// Only articles published since an age such as 24h, or a date such as 2024-06-01
+        #[arg(long)]
+        since: Option<String>,
+        /// Only articles published before an age or a date
+        #[arg(long)]
+        until: Option<String>,
+        /// Only articles of this source, such as CNBC
+        #[arg(long)]
+        source: Option<String>,
+        /// Only articles mentioning this ticker, such as NVDA
+        #[arg(long)]
+        ticker: Option<String>,
+        /// Print at most this many articles
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Add the articles of a CSV or NDJSON dataset to the local article store
+    Import {
+        /// Dataset file; a .csv file is read as CSV, anything else as JSON lines
+        file: PathBuf,
+        /// SQLite article store to add to, created if missing
+        #[arg(long, default_value = "news.db")]
+        db: PathBuf,
+        /// Source of articles the dataset names none for
+        #[arg(long)]
+        source: Option<String>,
+        /// Read an article field from a column, such as title=headline_text
+        #[arg(long, value_name = "FIELD=COLUMN")]
+        map: Vec<String>,
+    },
+    /// Check every topic of every source and report which ones work
+    Health {
+        /// Write the topic availability snapshot to this JSON file
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Deprecation history to update, reporting what changed since the last run
+        #[arg(long)]
+        history: Option<PathBuf>,
+    },
+    /// List the sources
+    Sources {
+        /// Print the sources with their topics and topic groups as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// List the topics of a source
+    Topics {
+        /// Source name, such as CNBC or MarketWatch
+        source: String,
+        /// Fetch each topic and flag the ones that are deprecated
+        #[arg(long)]
+        probe: bool,
+        /// Print the topics, or the probe results, as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print a shell completion script
+    Completions { shell: Shell },
+}
+
+/// How `fan query` prints the articles
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Aligned columns of publication time, source, and title
+    Table,
+    /// One line per article, as `fan fetch` prints them
+    Lines,
+    /// A JSON array
+    Json,
+}
+
+/// A source as `fan sources --json` prints it
+#[derive(Serialize)]
+struct SourceListing {
+    name: &'static str,
+    topics: Vec<&'static str>,
+    /// Topics of each topic group the source serves
+    groups: BTreeMap<&'static str, Vec<&'static str>>,
+}
+
+/// A probed topic as `fan topics --probe --json` prints it
+#[derive(Serialize)]
+struct TopicProbe {
+    topic: &'static str,
+    status: Option<TopicStatus>,
+    articles: Option<usize>,
+    error_class: Option<ErrorClass>,
+    error: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::init();
+    match Cli::parse().command {
+        Command::Fetch {
+            source,
+            topic,
+            template,
+            field,
+            json,
+            query,
+        } => fetch(&source, &topic, template, field, json, query.as_deref()).await,
+        Command::Query {
+            text,
+            db,
+            since,
+            until,
+            source,
+            ticker,
+            limit,
+            format,
+        } => {
+            let mut filter = SearchFilter::new().with_limit(limit);
+            if let Some(since) = since {
+                filter = filter.with_since(parse_time(&since)?);
+            }
+            if let Some(until) = until {
+                filter = filter.with_until(parse_time(&until)?);
+            }
+            if let Some(source) = source {
+                filter = filter.with_source(&source);
+            }
+            if let Some(ticker) = ticker {
+                filter = filter.with_ticker(&ticker);
+            }
+            query(&db, text.as_deref().unwrap_or(""), &filter, format)
+        }
+        Command::Import {
+            file,
+            db,
+            source,
+            map,
+        } => {
+            let mut mapping = FieldMapping::new();
+            if let Some(source) = source {
+                mapping = mapping.with_source(&source);
+            }
+            for entry in map {
+                let Some((field, column)) = entry.split_once('=') else {
+                    bail!("--map takes FIELD=COLUMN, not '{}'", entry);
+                };
+                mapping = mapping.with_field(field.trim(), column.trim());
+            }
+            import(&file, &db, &mapping)
+        }
+        Command::Health { output, history } => health(output, history).await,
+        Command::Sources { json } => sources(json),
+        Command::Topics {
+            source,
+            probe,
+            json,
+        } => topics(&source, probe, json).await,
+        Command::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "fan", &mut io::stdout());
+            Ok(())
+        }
+    }
+}
+
+async fn fetch(
+    name: &str,
+    topic: &str,
+    template: Option<String>,
+    fields: Vec<String>,
+    json: bool,
+    query: Option<&str>,
+) -> anyhow::Result<()> {
+    let query = query.map(Query::parse).transpose()?;
+    let mut client = NewsClient::new();
+    let source = find_source(&mut client, name)?;
+    let mut articles = source.fetch_topic(topic).await?;
+    if let Some(query) = &query {
+        articles = query.filter(articles);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&articles)?);
+        return Ok(());
+    }
+    if !fields.is_empty() {
+        for article in &articles {
+            let values: Vec<String> = fields
+                .iter()
+                .map(|field| select_field(article, field).unwrap_or_default())
+                .collect();
+            println!("{}", values.join("\t"));
+        }
+        return Ok(());
+    }
+    let template = ArticleTemplate::new(template.as_deref().unwrap_or(DEFAULT_TEMPLATE));
+    for article in &articles {
+        println!("{}", template.render(article));
+    }
+    Ok(())
+}
+
+fn query(db: &Path, text: &str, filter: &SearchFilter, format: Format) -> anyhow::Result<()> {
+    if !db.exists() {
+        bail!("no article store at {}", db.display());
+    }
+    let store = SqliteStore::open(db).with_context(|| format!("opening {}", db.display()))?;
+    let articles = store.search(text, filter)?;
+    match format {
+        Format::Table => print_table(&articles),
+        Format::Lines => {
+            let template = ArticleTemplate::new(DEFAULT_TEMPLATE);
+            for article in &articles {
+                println!("{}", template.render(article));
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&articles)?),
+    }
+    Ok(())
+}
+
+fn import(file: &Path, db: &Path, mapping: &FieldMapping) -> anyhow::Result<()> {
+    let is_csv = file
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+    let articles = if is_csv {
+        import::from_csv(file, mapping)
+    } else {
+        import::from_ndjson(file, mapping)
+    }
+    .with_context(|| format!("reading {}", file.display()))?;
+    let articles = dedup_articles(articles);
+    let store = SqliteStore::open(db).with_context(|| format!("opening {}", db.display()))?;
+    let added = store.insert(&articles)?;
+    println!(
+        "{} articles read, {} new in {}",
+        articles.len(),
+        added,
+        db.display()
+    );
+    Ok(())
+}
+
+/// Print articles in columns, newest first as the store returns them
+fn print_table(articles: &[NewsArticle]) {
+    let source_width = articles
+        .iter()
+        .filter_map(|article| article.source.as_deref())
+        .map(|source| source.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("SOURCE".len());
+    println!("{:<16}  {:<source_width$}  TITLE", "PUBLISHED", "SOURCE");
+    for article in articles {
+        let published = article
+            .published_at()
+            .map(|published| published.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+        let mut title = article.title.clone().unwrap_or_default();
+        if title.chars().count() > TABLE_TITLE_WIDTH {
+            title = title.chars().take(TABLE_TITLE_WIDTH - 1).collect();
+            title.push('…');
+        }
+        println!(
+            "{:<16}  {:<source_width$}  {}",
+            published,
+            article.source.as_deref().unwrap_or_default(),
+            title
+        );
+    }
+}
+
+/// A `--since` or `--until` bound: an age such as 24h before now, or a date
+fn parse_time(value: &str) -> anyhow::Result<DateTime<Utc>> {
+    if let Some(age) = parse_age(value) {
+        return chrono::Duration::from_std(age)
+            .ok()
+            .and_then(|age| Utc::now().checked_sub_signed(age))
+            .with_context(|| format!("age '{}' reaches back too far", value));
+    }
+    match parse_date(value) {
+        Some(date) => Ok(date),
+        None => bail!(
+            "expected an age such as 24h or a date such as 2024-06-01, got '{}'",
+            value
+        ),
+    }
+}
+
+async fn health(output: Option<PathBuf>, history: Option<PathBuf>) -> anyhow::Result<()> {
+    let mut client = NewsClient::new();
+    let previous = match &history {
+        Some(path) => Some(
+            DeprecationHistory::load_or_new(path)
+                .with_context(|| format!("reading {}", path.display()))?,
+        ),
+        None => None,
+    };
+    if let Some(previous) = &previous {
+        client = client.with_deprecation_history(previous.clone());
+    }
+
+    let topics = client.check_topics().await;
+    for (source, statuses) in &topics.topics {
+        let count = |status| statuses.values().filter(|s| **s == status).count();
+        println!(
+            "{:<24} {:>3} working {:>3} degraded {:>3} deprecated",
+            source,
+            count(TopicStatus::Working),
+            count(TopicStatus::Degraded),
+            count(TopicStatus::Deprecated)
+        );
+        for topic in topics.with_status(source, TopicStatus::Deprecated) {
+            println!("    deprecated: {}", topic);
+        }
+    }
+    if let Some(path) = &output {
+        topics
+            .save(path)
+            .with_context(|| format!("writing {}", path.display()))?;
+    }
+
+    if let (Some(path), Some(previous)) = (&history, &previous) {
+        let current = client
+            .deprecation_history()
+            .expect("client was given a history");
+        print!("{}", current.diff(previous));
+        current
+            .save(path)
+            .with_context(|| format!("writing {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn sources(json: bool) -> anyhow::Result<()> {
+    let mut client = NewsClient::new();
+    let mut names = client.source_names();
+    names.sort_unstable();
+    if !json {
+        for name in names {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    let mut listings = Vec::new();
+    for name in names {
+        let source = find_source(&mut client, name)?;
+        let mut topics = source.available_topics();
+        topics.sort_unstable();
+        let groups = TopicGroup::all()
+            .into_iter()
+            .map(|group| (group.as_str(), source.group_topics(group)))
+            .filter(|(_, topics)| !topics.is_empty())
+            .collect();
+        listings.push(SourceListing {
+            name,
+            topics,
+            groups,
+        });
+    }
+    println!("{}", serde_json::to_string_pretty(&listings)?);
+    Ok(())
+}
+
+async fn topics(name: &str, probe: bool, json: bool) -> anyhow::Result<()> {
+    let mut client = NewsClient::new();
+    let source = find_source(&mut client, name)?;
+
+    let mut topics = source.available_topics();
+    topics.sort_unstable();
+    if !probe {
+        if json {
+            println!("{}", serde_json::to_string_pretty(&topics)?);
+        } else {
+            for topic in topics {
+                println!("{}", topic);
+            }
+        }
+        return Ok(());
+    }
+
+    let results = source.fetch_all_topics().await;
+    if json {
+        let probes: Vec<TopicProbe> = topics
+            .into_iter()
+            .filter_map(|topic| {
+                let result = results.get(topic)?;
+                Some(TopicProbe {
+                    topic,
+                    status: TopicStatus::of(result),
+                    articles: result.as_ref().ok().map(Vec::len),
+                    error_class: result.as_ref().err().and_then(ErrorClass::of),
+                    error: result.as_ref().err().map(ToString::to_string),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&probes)?);
+        return Ok(());
+    }
+    for topic in topics {
+        let Some(result) = results.get(topic) else {
+            continue;
+        };
+        let status = match TopicStatus::of(result) {
+            Some(TopicStatus::Working) => "ok",
+            Some(TopicStatus::Degraded) => "degraded",
+            Some(TopicStatus::Deprecated) => "DEPRECATED",
+            None => "skipped",
+        };
+        match result {
+            Ok(articles) => println!("{:<10} {:<32} {} articles", status, topic, articles.len()),
+            Err(e) => {
+                let class = ErrorClass::of(e)
+                    .map(|class| format!("{:?}", class))
+                    .unwrap_or_default();
+                println!("{:<10} {:<32} {} {}", status, topic, class, e)
+            }
+        }
+    }
+    Ok(())
+}
+
+fn find_source(client: &mut NewsClient, name: &str) -> anyhow::Result<Arc<dyn NewsSource>> {
+    match client.source(name) {
+        Some(source) => Ok(source),
+        None => bail!(
+            "unknown source '{}', expected one of: {}",
+            name,
+            client.source_names().join(", ")
+        ),
+    }
+}
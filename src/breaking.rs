@@ -0,0 +1,397 @@
+//! Breaking-news detection and article priority
+//!
+//! A [`BreakingDetector`] sets `NewsArticle::priority` from three signals:
+//!
+//! | Signal | Articles |
+//! |--------|----------|
+//! | Marker | with a title starting "BREAKING", "URGENT", "JUST IN", "FLASH", or "ALERT" |
+//! | Real-time feed | of MarketWatch real-time headlines, Market Pulse, and bulletins, and Seeking Alpha market news |
+//! | Coverage | whose story at least 3 sources have headlined within 15 minutes |
+//!
+//! A marker or coverage makes an article [`Priority::High`], and any two signals
+//! make it [`Priority::Breaking`]; a real-time feed alone is routine, since those
+//! feeds carry everything. Stories are matched by the words of their titles, so
+//! "Fed cuts rates by half a point" and "Fed cuts interest rates half point" are
+//! one story. The article that brings a story to the coverage threshold is the first
+//! to get the raised priority; the ones before it keep theirs.
+//!
+//! Alert rules can escalate on the priority with `priority:breaking`, and notifiers
+//! read it from the articles, or from `Alert::priority()`.
+
+use crate::error::FanError;
+use crate::types::NewsArticle;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Time within which headlines of other sources count as coverage, unless set with
+/// `BreakingDetector::with_window()`
+pub const DEFAULT_COVERAGE_WINDOW: Duration = Duration::from_secs(15 * 60);
+
+/// Sources that make a story covered, unless set with
+/// `BreakingDetector::with_min_sources()`
+pub const DEFAULT_MIN_SOURCES: usize = 3;
+
+/// Title prefixes marking breaking news, lowercase
+const MARKERS: &[&str] = &["breaking", "urgent", "just in", "flash", "alert"];
+
+/// Feeds of real-time headlines: source and topic
+const REALTIME_FEEDS: &[(&str, &str)] = &[
+    ("MarketWatch", "real_time_headlines"),
+    ("MarketWatch", "market_pulse"),
+    ("MarketWatch", "bulletins"),
+    ("Seeking Alpha", "market-news"),
+];
+
+/// Share of title words two headlines of one story have in common
+const SIMILARITY: f64 = 0.5;
+
+/// Headlines remembered for coverage, whatever their age
+const MAX_HEADLINES: usize = 5000;
+
+/// Words left out when comparing titles
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "with", "from", "its", "his", "her", "their", "after", "over", "into",
+    "amid", "says", "said", "are", "was", "has", "have", "will", "this", "that",
+];
+
+/// How urgently an article should reach readers
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    /// Regular coverage
+    #[default]
+    Routine,
+    /// Marked as breaking, or covered by several sources
+    High,
+    /// Breaking news by more than one signal
+    Breaking,
+}
+
+impl Priority {
+    pub fn all() -> Vec<Priority> {
+        vec![Priority::Routine, Priority::High, Priority::Breaking]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::Routine => "routine",
+            Priority::High => "high",
+            Priority::Breaking => "breaking",
+        }
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Priority {
+    type Err = FanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Priority::all()
+            .into_iter()
+            .find(|priority| priority.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| FanError::Unknown(format!("Unknown priority: {}", s)))
+    }
+}
+
+/// A recent headline, for coverage by other sources
+#[derive(Debug)]
+struct Headline {
+    source: String,
+    words: HashSet<String>,
+    at: DateTime<Utc>,
+}
+
+/// Sets the priority of articles; see the [module docs](self) for the signals
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::NewsArticle;
+/// use finance_news_aggregator_rs::breaking::{BreakingDetector, Priority};
+///
+/// let detector = BreakingDetector::new();
+/// let mut article = NewsArticle::new();
+/// article.title = Some("BREAKING: Fed cuts rates by half a point".to_string());
+///
+/// assert_eq!(detector.assess("CNBC", Some("top_news"), &article), Priority::High);
+/// assert_eq!(
+///     detector.assess("MarketWatch", Some("bulletins"), &article),
+///     Priority::Breaking
+/// );
+/// ```
+#[derive(Debug)]
+pub struct BreakingDetector {
+    window: Duration,
+    min_sources: usize,
+    /// Real-time feeds: source and topic
+    realtime_feeds: Vec<(String, String)>,
+    headlines: Mutex<Vec<Headline>>,
+}
+
+impl Default for BreakingDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BreakingDetector {
+    /// Detect with the built-in real-time feeds and default thresholds
+    pub fn new() -> Self {
+        Self {
+            window: DEFAULT_COVERAGE_WINDOW,
+            min_sources: DEFAULT_MIN_SOURCES,
+            realtime_feeds: REALTIME_FEEDS
+                .iter()
+                .map(|(source, topic)| (source.to_string(), topic.to_string()))
+                .collect(),
+            headlines: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Count headlines of other sources within this time of each other as coverage
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Count a story as covered once this many sources, the article's included,
+    /// headline it
+    pub fn with_min_sources(mut self, sources: usize) -> Self {
+        self.min_sources = sources.max(2);
+        self
+    }
+
+    /// Treat a topic of a source as a real-time headline feed
+    pub fn with_realtime_feed(mut self, source: &str, topic: &str) -> Self {
+        self.realtime_feeds
+            .push((source.to_string(), topic.to_string()));
+        self
+    }
+
+    /// The priority of an article fetched from `topic` of `source`, remembering its
+    /// headline for the coverage of later articles
+    pub fn assess(&self, source: &str, topic: Option<&str>, article: &NewsArticle) -> Priority {
+        self.assess_at(source, topic, article, Utc::now())
+    }
+
+    /// The priority of an article seen at `now`
+    ///
+    /// Coverage is measured between publication dates, or the time an article is seen
+    /// when it has none, so replayed articles are judged as they were published.
+    pub fn assess_at(
+        &self,
+        source: &str,
+        topic: Option<&str>,
+        article: &NewsArticle,
+        now: DateTime<Utc>,
+    ) -> Priority {
+        let title = article.title.as_deref().unwrap_or_default();
+        let signals = [
+            has_marker(title),
+            topic.is_some_and(|topic| self.is_realtime(source, topic)),
+            self.covered(source, title, article.published_at().unwrap_or(now)),
+        ];
+        let (marker, covered) = (signals[0], signals[2]);
+        match signals.iter().filter(|signal| **signal).count() {
+            2.. => Priority::Breaking,
+            1 if marker || covered => Priority::High,
+            _ => Priority::Routine,
+        }
+    }
+
+    /// Set the priority of every article of a list fetched from `topic`, keeping
+    /// higher priorities set before
+    pub fn prioritize_all(&self, source: &str, topic: Option<&str>, articles: &mut [NewsArticle]) {
+        let now = Utc::now();
+        for article in articles {
+            let priority = self.assess_at(source, topic, article, now);
+            article.priority = article.priority.max(priority);
+        }
+    }
+
+    fn is_realtime(&self, source: &str, topic: &str) -> bool {
+        self.realtime_feeds.iter().any(|(feed_source, feed_topic)| {
+            feed_source.eq_ignore_ascii_case(source) && feed_topic == topic
+        })
+    }
+
+    /// Whether enough sources headlined the story of `title` around `at`, recording
+    /// the headline
+    fn covered(&self, source: &str, title: &str, at: DateTime<Utc>) -> bool {
+        let words = title_words(title);
+        if words.is_empty() {
+            return false;
+        }
+        let window = chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::MAX);
+        let mut headlines = self.headlines.lock().unwrap();
+        let newest = headlines
+            .iter()
+            .map(|headline| headline.at)
+            .max()
+            .map_or(at, |newest| newest.max(at));
+        // A window reaching before the earliest date keeps every headline
+        if let Some(oldest) = newest.checked_sub_signed(window) {
+            headlines.retain(|headline| headline.at >= oldest);
+        }
+        if headlines.len() >= MAX_HEADLINES {
+            headlines.remove(0);
+        }
+
+        let mut sources: HashSet<String> = headlines
+            .iter()
+            .filter(|headline| (headline.at - at).abs() <= window)
+            .filter(|headline| similarity(&headline.words, &words) >= SIMILARITY)
+            .map(|headline| headline.source.to_lowercase())
+            .collect();
+        sources.insert(source.to_lowercase());
+        headlines.push(Headline {
+            source: source.to_string(),
+            words,
+            at,
+        });
+        sources.len() >= self.min_sources
+    }
+}
+
+/// Whether a title starts with a breaking-news marker, after any brackets or emoji
+fn has_marker(title: &str) -> bool {
+    let title = title
+        .trim_start_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase();
+    MARKERS.iter().any(|marker| {
+        title
+            .strip_prefix(marker)
+            .is_some_and(|rest| rest.chars().next().is_none_or(|c| !c.is_alphanumeric()))
+    })
+}
+
+/// Lowercase words of a title that tell stories apart, markers left out
+fn title_words(title: &str) -> HashSet<String> {
+    title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 3 || word.chars().all(|c| c.is_ascii_digit()))
+        .filter(|word| !word.is_empty())
+        .filter(|word| !STOPWORDS.contains(word) && !MARKERS.contains(word))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Words two titles share, over the words of the shorter one
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let shorter = a.len().min(b.len());
+    if shorter == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / shorter as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(title: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some(title.to_string());
+        article
+    }
+
+    #[test]
+    fn test_has_marker() {
+        assert!(has_marker("BREAKING: Fed cuts rates"));
+        assert!(has_marker("🚨 Breaking - Fed cuts rates"));
+        assert!(has_marker("[URGENT] Trading halted"));
+        assert!(has_marker("Just in: Nvidia beats"));
+        assert!(!has_marker("Breakingviews: The Fed's dilemma"));
+        assert!(!has_marker("Fed cuts rates, breaking with tradition"));
+    }
+
+    #[test]
+    fn test_coverage() {
+        let detector = BreakingDetector::new();
+        let start = Utc::now();
+        let at = |minutes: i64| start + chrono::Duration::minutes(minutes);
+        let assess = |source: &str, title: &str, minutes: i64| {
+            detector.assess_at(source, Some("top_news"), &article(title), at(minutes))
+        };
+
+        assert_eq!(
+            assess("CNBC", "Fed cuts rates by half a point", 0),
+            Priority::Routine
+        );
+        assert_eq!(
+            assess("CNBC", "Fed cuts rates by half a point, stocks jump", 1),
+            Priority::Routine
+        );
+        assert_eq!(
+            assess("WSJ", "Fed Cuts Interest Rates by Half Point", 3),
+            Priority::Routine
+        );
+        assert_eq!(
+            assess("Yahoo Finance", "Oil slips on demand worries", 4),
+            Priority::Routine
+        );
+        // A third source within the window
+        assert_eq!(
+            assess("Bloomberg", "Fed cuts rates half point in surprise move", 9),
+            Priority::High
+        );
+        assert_eq!(
+            assess("Reuters", "BREAKING: Fed cuts rates by half point", 10),
+            Priority::Breaking
+        );
+        // Too long after the others
+        assert_eq!(
+            assess("MarketWatch", "Fed cuts rates by half a point", 60),
+            Priority::Routine
+        );
+
+        let endless = BreakingDetector::new().with_window(std::time::Duration::MAX);
+        for source in ["CNBC", "WSJ"] {
+            assert_eq!(
+                endless.assess_at(source, None, &article("Oil slips"), at(0)),
+                Priority::Routine
+            );
+        }
+    }
+
+    #[test]
+    fn test_realtime_feed() {
+        let detector = BreakingDetector::new().with_realtime_feed("CNBC", "top_news");
+        let fed = article("Fed holds rates steady");
+        assert_eq!(
+            detector.assess("MarketWatch", Some("bulletins"), &fed),
+            Priority::Routine
+        );
+        assert_eq!(
+            detector.assess("CNBC", Some("top_news"), &article("Flash: Fed holds")),
+            Priority::Breaking
+        );
+
+        let mut articles = vec![fed.clone(), fed];
+        articles[1].priority = Priority::Breaking;
+        detector.prioritize_all("CNBC", None, &mut articles);
+        assert_eq!(articles[0].priority, Priority::Routine);
+        assert_eq!(articles[1].priority, Priority::Breaking);
+    }
+
+    #[test]
+    fn test_priority_from_str() {
+        assert_eq!("BREAKING".parse::<Priority>().unwrap(), Priority::Breaking);
+        assert!(Priority::High > Priority::Routine);
+        assert!("urgent".parse::<Priority>().is_err());
+    }
+}
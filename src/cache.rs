@@ -0,0 +1,203 @@
+use crate::types::NewsArticle;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long expired articles stay available to `get_any()` by default
+const DEFAULT_MAX_STALE: Duration = Duration::from_secs(60 * 60);
+
+/// Longest TTL an entry is stored for; longer ones are cut to it
+const MAX_TTL: Duration = Duration::from_secs(365 * 24 * 3600);
+
+/// In-memory cache of parsed articles keyed by (source name, topic)
+///
+/// Used by `NewsClient` when caching is enabled with `SourceConfig::with_cache()`,
+/// so repeated fetches within a topic's TTL are answered without a network request.
+#[derive(Debug)]
+pub struct TopicCache {
+    entries: HashMap<(String, String), CachedTopic>,
+    max_stale: Duration,
+}
+
+impl Default for TopicCache {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            max_stale: DEFAULT_MAX_STALE,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CachedTopic {
+    articles: Vec<NewsArticle>,
+    expires_at: Instant,
+}
+
+impl TopicCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how long past its TTL an entry is still served by `get_any()`
+    /// before it is evicted (1 hour by default)
+    pub fn with_max_stale(mut self, max_stale: Duration) -> Self {
+        self.max_stale = max_stale;
+        self
+    }
+
+    /// Get the cached articles for a topic if they have not expired
+    pub fn get(&self, source: &str, topic: &str) -> Option<Vec<NewsArticle>> {
+        self.entries
+            .get(&(source.to_string(), topic.to_string()))
+            .filter(|entry| entry.expires_at > Instant::now())
+            .map(|entry| entry.articles.clone())
+    }
+
+    /// Get the last cached articles for a topic, even if they have expired
+    ///
+    /// Used to answer stale-while-revalidate fetches immediately. Entries expired
+    /// for longer than the max stale age are not returned.
+    pub fn get_any(&self, source: &str, topic: &str) -> Option<Vec<NewsArticle>> {
+        let now = Instant::now();
+        self.entries
+            .get(&(source.to_string(), topic.to_string()))
+            .filter(|entry| !entry.is_evictable(now, self.max_stale))
+            .map(|entry| entry.articles.clone())
+    }
+
+    /// Store the articles for a topic for `ttl`, at most a year
+    ///
+    /// Expired entries are kept for the max stale age so `get_any()` can still
+    /// return them; entries expired for longer are dropped at the same time.
+    pub fn insert(&mut self, source: &str, topic: &str, articles: Vec<NewsArticle>, ttl: Duration) {
+        let now = Instant::now();
+        let max_stale = self.max_stale;
+        self.entries
+            .retain(|_, entry| !entry.is_evictable(now, max_stale));
+        let Some(expires_at) = now.checked_add(ttl.min(MAX_TTL)) else {
+            return;
+        };
+        self.entries.insert(
+            (source.to_string(), topic.to_string()),
+            CachedTopic {
+                articles,
+                expires_at,
+            },
+        );
+    }
+
+    /// Drop the cached articles for a topic
+    pub fn invalidate(&mut self, source: &str, topic: &str) {
+        self.entries
+            .remove(&(source.to_string(), topic.to_string()));
+    }
+
+    /// Drop every cached topic
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Number of topics currently cached, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache holds no topics
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl CachedTopic {
+    /// Whether the entry expired more than `max_stale` before `now`
+    fn is_evictable(&self, now: Instant, max_stale: Duration) -> bool {
+        now.saturating_duration_since(self.expires_at) > max_stale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SourceConfig;
+
+    fn article(title: &str) -> NewsArticle {
+        NewsArticle {
+            title: Some(title.to_string()),
+            ..NewsArticle::new()
+        }
+    }
+
+    #[test]
+    fn test_cache_expiry_and_invalidation() {
+        let mut cache = TopicCache::new();
+        cache.insert(
+            "CNBC",
+            "markets",
+            vec![article("One")],
+            Duration::from_secs(60),
+        );
+        cache.insert("CNBC", "energy", vec![article("Two")], Duration::ZERO);
+
+        let cached = cache.get("CNBC", "markets").unwrap();
+        assert_eq!(cached[0].title.as_deref(), Some("One"));
+        assert!(cache.get("CNBC", "energy").is_none());
+        assert!(cache.get_any("CNBC", "energy").is_some());
+        assert!(cache.get("NASDAQ", "markets").is_none());
+
+        cache.invalidate("CNBC", "markets");
+        assert!(cache.get("CNBC", "markets").is_none());
+    }
+
+    #[test]
+    fn test_stale_entries_are_evicted() {
+        let mut cache = TopicCache::new().with_max_stale(Duration::ZERO);
+        cache.insert("CNBC", "energy", vec![article("Two")], Duration::ZERO);
+        std::thread::sleep(Duration::from_millis(5));
+
+        assert!(cache.get_any("CNBC", "energy").is_none());
+        cache.insert(
+            "CNBC",
+            "markets",
+            vec![article("One")],
+            Duration::from_secs(60),
+        );
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get_any("CNBC", "markets").is_some());
+    }
+
+    #[test]
+    fn test_cache_ttl_resolution() {
+        let config = SourceConfig::default();
+        assert_eq!(config.cache_ttl_for("CNBC", "markets", None), None);
+
+        let config = SourceConfig::default()
+            .with_cache(300)
+            .with_topic_ttl("CNBC", "markets", 60);
+        let feed_ttl = Some(Duration::from_secs(900));
+
+        // A configured topic TTL wins over the feed's, which wins over the default
+        assert_eq!(
+            config.cache_ttl_for("CNBC", "markets", feed_ttl),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(config.cache_ttl_for("CNBC", "energy", feed_ttl), feed_ttl);
+        assert_eq!(
+            config.cache_ttl_for("CNBC", "energy", None),
+            Some(Duration::from_secs(300))
+        );
+        // A feed cannot keep itself cached for longer than a day
+        let huge = Some(Duration::from_secs(200_000_000_000_000_000 * 60));
+        assert_eq!(
+            config.cache_ttl_for("CNBC", "energy", huge),
+            Some(crate::types::MAX_FEED_TTL)
+        );
+    }
+
+    #[test]
+    fn test_huge_ttl_does_not_overflow() {
+        let mut cache = TopicCache::new();
+        cache.insert("CNBC", "markets", vec![article("One")], Duration::MAX);
+        assert!(cache.get("CNBC", "markets").is_some());
+    }
+}
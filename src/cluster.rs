@@ -0,0 +1,276 @@
+//! Clustering of syndicated wire copies
+//!
+//! Outlets republish AP and Reuters wire stories word for word under their own
+//! links and often their own headlines, so link, GUID, and title matching in
+//! `aggregation::dedup_articles()` let the copies through. [`SyndicationDedup`]
+//! compares article bodies instead: each description is cut into overlapping
+//! runs of words (shingles), summarized by a MinHash [`Signature`], and two
+//! articles of different sources whose signatures estimate a Jaccard similarity
+//! of at least the threshold are copies of one story.
+//!
+//! Articles of one source are never collapsed, since a feed's own boilerplate
+//! descriptions would look like copies, and descriptions shorter than
+//! [`DEFAULT_MIN_WORDS`] words are left to the title matching.
+
+use crate::disk_cache::fnv1a_64;
+use crate::types::NewsArticle;
+
+/// Estimated share of shingles two bodies have in common for them to be copies,
+/// unless set with `SyndicationDedup::with_threshold()`
+pub const DEFAULT_SYNDICATION_THRESHOLD: f64 = 0.8;
+
+/// Fewest words a description needs to be compared, unless set with
+/// `SyndicationDedup::with_min_words()`
+pub const DEFAULT_MIN_WORDS: usize = 20;
+
+/// Words in a shingle
+const SHINGLE_WORDS: usize = 4;
+
+/// Hash functions in a signature; the similarity estimate is off by about
+/// 1 / sqrt(SIGNATURE_HASHES)
+const SIGNATURE_HASHES: usize = 128;
+
+/// MinHash summary of the shingles of a text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    minimums: [u64; SIGNATURE_HASHES],
+}
+
+impl Signature {
+    /// Sign the words of `text`, skipping HTML tags, or `None` when it has fewer
+    /// than `min_words` words
+    pub fn of(text: &str, min_words: usize) -> Option<Self> {
+        let words = words(text);
+        if words.len() < min_words.max(SHINGLE_WORDS) {
+            return None;
+        }
+        let mut minimums = [u64::MAX; SIGNATURE_HASHES];
+        for shingle in words.windows(SHINGLE_WORDS) {
+            let hash = fnv1a_64(shingle.join(" ").as_bytes());
+            for (i, minimum) in minimums.iter_mut().enumerate() {
+                *minimum = (*minimum).min(mix(hash ^ seed(i)));
+            }
+        }
+        Some(Self { minimums })
+    }
+
+    /// Estimated Jaccard similarity of the shingles of two texts, from 0 to 1
+    pub fn similarity(&self, other: &Signature) -> f64 {
+        let equal = self
+            .minimums
+            .iter()
+            .zip(&other.minimums)
+            .filter(|(a, b)| a == b)
+            .count();
+        equal as f64 / SIGNATURE_HASHES as f64
+    }
+}
+
+/// Collapses syndicated copies of a story into its first article
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::NewsArticle;
+/// use finance_news_aggregator_rs::cluster::SyndicationDedup;
+///
+/// let wire = "The Federal Reserve held its benchmark rate steady on Wednesday, \
+///     signaling that policymakers want more evidence that inflation is cooling \
+///     before they begin cutting borrowing costs later this year.";
+/// let copy = |source: &str, title: &str| NewsArticle {
+///     title: Some(title.to_string()),
+///     description: Some(wire.to_string()),
+///     source: Some(source.to_string()),
+///     ..NewsArticle::new()
+/// };
+///
+/// let articles = vec![
+///     copy("Yahoo Finance", "Fed holds rates steady"),
+///     copy("CNBC", "Fed leaves rates unchanged, eyes cuts later this year"),
+/// ];
+/// assert_eq!(SyndicationDedup::new().dedup(articles).len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SyndicationDedup {
+    threshold: f64,
+    min_words: usize,
+}
+
+impl Default for SyndicationDedup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SyndicationDedup {
+    /// Collapse with the default threshold and minimum length
+    pub fn new() -> Self {
+        Self {
+            threshold: DEFAULT_SYNDICATION_THRESHOLD,
+            min_words: DEFAULT_MIN_WORDS,
+        }
+    }
+
+    /// Count articles as copies from this estimated similarity, between 0 and 1;
+    /// lower collapses lightly edited copies too
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Only compare descriptions of at least this many words
+    pub fn with_min_words(mut self, min_words: usize) -> Self {
+        self.min_words = min_words;
+        self
+    }
+
+    /// The signature of an article's description, if it is long enough to compare
+    pub fn signature(&self, article: &NewsArticle) -> Option<Signature> {
+        Signature::of(article.description.as_deref()?, self.min_words)
+    }
+
+    /// Group the indexes of articles that are copies of one story, each group in
+    /// list order and the groups by their first article
+    ///
+    /// An article joins the first group holding a copy of it from another source,
+    /// and articles without a signature stay alone.
+    pub fn clusters(&self, articles: &[NewsArticle]) -> Vec<Vec<usize>> {
+        let mut clusters: Vec<Vec<usize>> = Vec::new();
+        // Signed articles: cluster, source, and signature
+        let mut signed: Vec<(usize, Option<&str>, Signature)> = Vec::new();
+        for (index, article) in articles.iter().enumerate() {
+            let source = article.source.as_deref();
+            let Some(signature) = self.signature(article) else {
+                clusters.push(vec![index]);
+                continue;
+            };
+            let copy_of = signed.iter().find(|(_, other_source, other)| {
+                *other_source != source && signature.similarity(other) >= self.threshold
+            });
+            let cluster = match copy_of {
+                Some((cluster, _, _)) => *cluster,
+                None => {
+                    clusters.push(Vec::new());
+                    clusters.len() - 1
+                }
+            };
+            clusters[cluster].push(index);
+            signed.push((cluster, source, signature));
+        }
+        clusters
+    }
+
+    /// Keep the first article of each story, preserving the order
+    pub fn dedup(&self, articles: Vec<NewsArticle>) -> Vec<NewsArticle> {
+        let mut keep = vec![false; articles.len()];
+        for cluster in self.clusters(&articles) {
+            keep[cluster[0]] = true;
+        }
+        articles
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(article, keep)| keep.then_some(article))
+            .collect()
+    }
+}
+
+/// Lowercase words of a text, outside HTML tags
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag && c.is_alphanumeric() => word.extend(c.to_lowercase()),
+            _ => {}
+        }
+        if !c.is_alphanumeric() && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// Seed of the `i`th hash function
+fn seed(i: usize) -> u64 {
+    (i as u64 + 1).wrapping_mul(0x9e3779b97f4a7c15)
+}
+
+/// SplitMix64 finalizer, turning one shingle hash into independent-looking ones
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIRE: &str = "NEW YORK (AP) - Stocks fell on Wall Street Tuesday after a \
+        hotter than expected report on inflation dashed hopes that the Federal Reserve \
+        would cut interest rates soon. The S&P 500 dropped 1.4%, its worst day in \
+        two months, while Treasury yields climbed to their highest level since November.";
+
+    fn article(source: &str, description: &str) -> NewsArticle {
+        NewsArticle {
+            description: Some(description.to_string()),
+            source: Some(source.to_string()),
+            ..NewsArticle::new()
+        }
+    }
+
+    #[test]
+    fn test_words_skip_tags() {
+        assert_eq!(
+            words("<p>Stocks <b>fell</b> on Wall&nbsp;Street</p>"),
+            ["stocks", "fell", "on", "wall", "nbsp", "street"]
+        );
+    }
+
+    #[test]
+    fn test_similarity() {
+        let signature = |text: &str| Signature::of(text, DEFAULT_MIN_WORDS).unwrap();
+        let wire = signature(WIRE);
+        assert_eq!(wire.similarity(&wire), 1.0);
+
+        // A copy with a credit line appended is still a copy
+        let credited = signature(&format!(
+            "<p>{}</p> Copyright 2024 The Associated Press.",
+            WIRE
+        ));
+        assert!(wire.similarity(&credited) >= DEFAULT_SYNDICATION_THRESHOLD);
+
+        let other = signature(
+            "Nvidia reported record quarterly revenue on Wednesday as demand for its \
+             data center chips kept climbing, and the company forecast sales above what \
+             analysts had expected for the current quarter.",
+        );
+        assert!(wire.similarity(&other) < 0.2);
+
+        assert!(Signature::of("Stocks fell on Wall Street", DEFAULT_MIN_WORDS).is_none());
+    }
+
+    #[test]
+    fn test_dedup() {
+        let edited = WIRE.replace("NEW YORK (AP) - ", "");
+        let articles = vec![
+            article("Yahoo Finance", WIRE),
+            article("CNBC", "Stocks fell on Wall Street"),
+            article("Yahoo Finance", WIRE),
+            article("MarketWatch", &edited),
+        ];
+        let dedup = SyndicationDedup::new();
+        // Copies within one source are left to the feed
+        assert_eq!(dedup.clusters(&articles), [vec![0, 3], vec![1], vec![2]]);
+        assert_eq!(dedup.dedup(articles.clone()).len(), 3);
+
+        let strict = SyndicationDedup::new().with_threshold(1.0);
+        assert_eq!(strict.clusters(&articles).len(), 4);
+    }
+}
@@ -0,0 +1,343 @@
+//! Columnar articles for Arrow and analytics tools (features "arrow", "parquet", and "duckdb")
+//!
+//! [`to_arrow()`] converts articles into an Arrow `RecordBatch`, to hand them to
+//! polars, DataFusion, or any other Arrow-based pipeline without going through JSON.
+//! The `arrow_array` and `arrow_schema` crates are re-exported so the batch can be
+//! used without matching their versions.
+//!
+//! With the "parquet" feature, [`write_parquet()`] writes the same batch to a Parquet
+//! file, which DuckDB queries in place or loads into a table, next to price data:
+//!
+//! ```sql
+//! CREATE TABLE news AS SELECT * FROM 'news.parquet';
+//! SELECT source, count(*) FROM news WHERE list_contains(symbols, 'NVDA') GROUP BY source;
+//! ```
+//!
+//! With the "duckdb" feature, [`write_duckdb()`] appends the batch to a table of a
+//! DuckDB database file instead, and [`append_duckdb()`] to a table of an open
+//! connection, such as one that already holds the prices. The `duckdb` crate is
+//! re-exported.
+//!
+//! Each article is one row with the columns of [`schema()`]:
+//!
+//! | Column | Type | From |
+//! |--------|------|------|
+//! | `title`, `link`, `description`, `guid`, `category`, `author`, `source` | string | the article fields |
+//! | `pub_date` | string | the date as the feed wrote it |
+//! | `published_at` | timestamp (ms, UTC) | `NewsArticle::published_at()` |
+//! | `symbols` | list of strings | `extra_fields["symbols"]` |
+//! | `extra_fields` | string | the remaining extra fields as a JSON object |
+//! | `stale`, `updated` | boolean | the article flags |
+
+#[cfg(any(feature = "parquet", feature = "duckdb"))]
+use crate::error::Result;
+use crate::types::NewsArticle;
+use arrow_array::builder::{ListBuilder, StringBuilder};
+use arrow_array::{ArrayRef, BooleanArray, RecordBatch, StringArray, TimestampMillisecondArray};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+#[cfg(feature = "parquet")]
+use parquet::arrow::ArrowWriter;
+#[cfg(feature = "parquet")]
+use parquet::basic::Compression;
+#[cfg(feature = "parquet")]
+use parquet::file::properties::WriterProperties;
+#[cfg(feature = "parquet")]
+use std::fs::File;
+#[cfg(feature = "parquet")]
+use std::io::Write;
+#[cfg(any(feature = "parquet", feature = "duckdb"))]
+use std::path::Path;
+use std::sync::Arc;
+
+pub use arrow_array;
+pub use arrow_schema;
+#[cfg(feature = "duckdb")]
+pub use duckdb;
+
+/// Key of the tickers in `NewsArticle::extra_fields`, a comma-separated list
+const SYMBOLS_FIELD: &str = "symbols";
+
+/// Write articles to a Parquet file, replacing it if it exists
+///
+/// # Returns
+/// The number of rows written
+#[cfg(feature = "parquet")]
+pub fn write_parquet(articles: &[NewsArticle], path: impl AsRef<Path>) -> Result<usize> {
+    write_parquet_to(articles, File::create(path)?)
+}
+
+/// Write articles as Parquet to any writer, such as a buffer to upload
+#[cfg(feature = "parquet")]
+pub(crate) fn write_parquet_to(
+    articles: &[NewsArticle],
+    writer: impl Write + Send,
+) -> Result<usize> {
+    let batch = to_arrow(articles);
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), Some(properties))?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(batch.num_rows())
+}
+
+/// Columns of the DuckDB tables, matching [`schema()`]
+#[cfg(feature = "duckdb")]
+const DUCKDB_COLUMNS: &str = "title VARCHAR, link VARCHAR, description VARCHAR, \
+    pub_date VARCHAR, published_at TIMESTAMPTZ, guid VARCHAR, category VARCHAR, \
+    author VARCHAR, source VARCHAR, symbols VARCHAR[] NOT NULL, \
+    extra_fields VARCHAR NOT NULL, stale BOOLEAN NOT NULL, updated BOOLEAN NOT NULL";
+
+/// Append articles to a table of a DuckDB database file, creating the file and
+/// the table if needed
+///
+/// # Returns
+/// The number of rows appended
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use finance_news_aggregator_rs::columnar::write_duckdb;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let articles = Vec::new();
+/// write_duckdb(&articles, "market.duckdb", "news")?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "duckdb")]
+pub fn write_duckdb(
+    articles: &[NewsArticle],
+    path: impl AsRef<Path>,
+    table: &str,
+) -> Result<usize> {
+    append_duckdb(articles, &duckdb::Connection::open(path)?, table)
+}
+
+/// Append articles to a table of an open DuckDB connection, creating the table
+/// if needed
+///
+/// Rows go through DuckDB's appender as one Arrow batch, not one insert each.
+#[cfg(feature = "duckdb")]
+pub fn append_duckdb(
+    articles: &[NewsArticle],
+    connection: &duckdb::Connection,
+    table: &str,
+) -> Result<usize> {
+    connection.execute_batch(&format!(
+        "CREATE TABLE IF NOT EXISTS \"{}\" ({});",
+        table.replace('"', "\"\""),
+        DUCKDB_COLUMNS
+    ))?;
+    if articles.is_empty() {
+        return Ok(0);
+    }
+    let mut appender = connection.appender(table)?;
+    appender.append_record_batch(to_arrow(articles))?;
+    appender.flush()?;
+    Ok(articles.len())
+}
+
+/// Arrow schema of the batches made by [`to_arrow()`]; see the
+/// [module docs](self) for where each column comes from
+pub fn schema() -> Schema {
+    let text = |name: &str| Field::new(name, DataType::Utf8, true);
+    Schema::new(vec![
+        text("title"),
+        text("link"),
+        text("description"),
+        text("pub_date"),
+        Field::new(
+            "published_at",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            true,
+        ),
+        text("guid"),
+        text("category"),
+        text("author"),
+        text("source"),
+        Field::new(
+            SYMBOLS_FIELD,
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            false,
+        ),
+        Field::new("extra_fields", DataType::Utf8, false),
+        Field::new("stale", DataType::Boolean, false),
+        Field::new("updated", DataType::Boolean, false),
+    ])
+}
+
+/// Convert articles into a record batch, one row per article
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::NewsArticle;
+/// use finance_news_aggregator_rs::columnar::to_arrow;
+///
+/// let mut article = NewsArticle::new();
+/// article.title = Some("Fed holds rates".to_string());
+/// article.pub_date = Some("Wed, 01 May 2024 18:00:00 GMT".to_string());
+///
+/// let batch = to_arrow(&[article]);
+/// assert_eq!(batch.num_rows(), 1);
+/// assert!(batch.column_by_name("published_at").is_some());
+/// ```
+pub fn to_arrow(articles: &[NewsArticle]) -> RecordBatch {
+    let text = |field: fn(&NewsArticle) -> &Option<String>| -> ArrayRef {
+        Arc::new(StringArray::from_iter(
+            articles.iter().map(|article| field(article).as_deref()),
+        ))
+    };
+
+    let mut symbols = ListBuilder::new(StringBuilder::new());
+    let mut extra_fields = StringBuilder::new();
+    for article in articles {
+        if let Some(values) = article.extra_fields.get(SYMBOLS_FIELD) {
+            values
+                .values()
+                .iter()
+                .flat_map(|value| value.split(','))
+                .map(str::trim)
+                .filter(|symbol| !symbol.is_empty())
+                .for_each(|symbol| symbols.values().append_value(symbol));
+        }
+        symbols.append(true);
+
+        let mut extras = article.extra_fields.clone();
+        extras.remove(SYMBOLS_FIELD);
+        // A map of strings always serializes
+        extra_fields.append_value(serde_json::to_string(&extras).unwrap_or_default());
+    }
+
+    let published_at = TimestampMillisecondArray::from_iter(articles.iter().map(|article| {
+        article
+            .published_at()
+            .map(|published| published.timestamp_millis())
+    }))
+    .with_timezone("UTC");
+    let columns: Vec<ArrayRef> = vec![
+        text(|article| &article.title),
+        text(|article| &article.link),
+        text(|article| &article.description),
+        text(|article| &article.pub_date),
+        Arc::new(published_at),
+        text(|article| &article.guid),
+        text(|article| &article.category),
+        text(|article| &article.author),
+        text(|article| &article.source),
+        Arc::new(symbols.finish()),
+        Arc::new(extra_fields.finish()),
+        Arc::new(BooleanArray::from_iter(
+            articles.iter().map(|article| Some(article.stale)),
+        )),
+        Arc::new(BooleanArray::from_iter(
+            articles.iter().map(|article| Some(article.updated)),
+        )),
+    ];
+    RecordBatch::try_new(Arc::new(schema()), columns).expect("columns match the schema")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "parquet")]
+    use arrow_array::{Array, ListArray};
+    #[cfg(feature = "parquet")]
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    #[test]
+    fn test_to_arrow() {
+        let batch = to_arrow(&[]);
+        assert_eq!(batch.num_rows(), 0);
+        assert_eq!(batch.schema().as_ref(), &schema());
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_write_parquet() {
+        let mut nvidia = NewsArticle::new();
+        nvidia.title = Some("Nvidia earnings beat".to_string());
+        nvidia.source = Some("Polygon".to_string());
+        nvidia.pub_date = Some("2024-05-22T21:00:00Z".to_string());
+        nvidia
+            .extra_fields
+            .insert("symbols".to_string(), "NVDA, AMD".into());
+        nvidia
+            .extra_fields
+            .insert("outlet".to_string(), "Benzinga".into());
+        let mut undated = NewsArticle::new();
+        undated.title = Some("Oil slips".to_string());
+
+        let path =
+            std::env::temp_dir().join(format!("fan-articles-{}.parquet", std::process::id()));
+        assert_eq!(write_parquet(&[nvidia, undated], &path).unwrap(), 2);
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<RecordBatch> = reader.map(|batch| batch.unwrap()).collect();
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().as_ref(), &schema());
+
+        let column = |name: &str| Arc::clone(batch.column_by_name(name).unwrap());
+        let published_at = column("published_at");
+        let published_at = published_at
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(published_at.value(0), 1_716_411_600_000);
+        assert!(published_at.is_null(1));
+        let extra_fields = column("extra_fields");
+        let extra_fields = extra_fields.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(extra_fields.value(0), r#"{"outlet":"Benzinga"}"#);
+        let symbols = column("symbols");
+        let symbols = symbols.as_any().downcast_ref::<ListArray>().unwrap();
+        let first = symbols.value(0);
+        let first = first.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(
+            first.iter().collect::<Vec<_>>(),
+            vec![Some("NVDA"), Some("AMD")]
+        );
+        assert_eq!(symbols.value(1).len(), 0);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "duckdb")]
+    #[test]
+    fn test_append_duckdb() {
+        let mut nvidia = NewsArticle::new();
+        nvidia.title = Some("Nvidia earnings beat".to_string());
+        nvidia.pub_date = Some("2024-05-22T21:00:00Z".to_string());
+        nvidia
+            .extra_fields
+            .insert("symbols".to_string(), "NVDA, AMD".into());
+        let mut undated = NewsArticle::new();
+        undated.title = Some("Oil slips".to_string());
+
+        let connection = duckdb::Connection::open_in_memory().unwrap();
+        let articles = [nvidia, undated];
+        assert_eq!(append_duckdb(&articles, &connection, "news").unwrap(), 2);
+        // The second call appends to the existing table
+        assert_eq!(append_duckdb(&articles, &connection, "news").unwrap(), 2);
+        assert_eq!(append_duckdb(&[], &connection, "news").unwrap(), 0);
+
+        let count: i64 = connection
+            .query_row("SELECT count(*) FROM news", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 4);
+        let (symbol, published_ms): (String, i64) = connection
+            .query_row(
+                "SELECT symbols[2], epoch_ms(published_at) FROM news \
+                 WHERE list_contains(symbols, 'NVDA') LIMIT 1",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(symbol, "AMD");
+        assert_eq!(published_ms, 1_716_411_600_000);
+    }
+}
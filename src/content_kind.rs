@@ -0,0 +1,331 @@
+//! Labeling articles as news, opinion, analysis, press releases, or transcripts
+//!
+//! A [`ContentClassifier`] labels an article with a [`ContentKind`], stored in
+//! `extra_fields["content_kind"]` and read back with `NewsArticle::content_kind()`,
+//! so opinion pieces can be left out of alerts with `NOT kind:opinion`.
+//!
+//! [`RuleClassifier`] decides from the feed an article came from, then its title
+//! and link:
+//!
+//! | Kind | Feeds | Titles and links |
+//! |------|-------|------------------|
+//! | `Transcript` | Seeking Alpha transcripts | "... Earnings Call Transcript" |
+//! | `Opinion` | WSJ opinion, CNBC commentary | "Opinion: ...", "Column: ...", `/opinion/` links |
+//! | `Analysis` | Seeking Alpha long and short ideas, IPO analysis | "Analysis: ...", Seeking Alpha `/article/` links |
+//! | `PressRelease` | PR Newswire, GlobeNewswire, Business Wire | links to the wires |
+//!
+//! Anything else is `News`. A model-backed classifier is any type implementing
+//! `ContentClassifier`, including a closure.
+
+use crate::error::FanError;
+use crate::types::NewsArticle;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Key of the label in `NewsArticle::extra_fields`
+pub const CONTENT_KIND_FIELD: &str = "content_kind";
+
+/// Title prefixes of opinion pieces, lowercase
+const OPINION_PREFIXES: &[&str] = &[
+    "opinion:",
+    "op-ed:",
+    "commentary:",
+    "column:",
+    "editorial:",
+    "letters:",
+];
+/// Link path segments of opinion pieces
+const OPINION_PATHS: &[&str] = &["/opinion/", "/opinions/", "/commentary/", "/op-ed/"];
+/// Title prefixes of analysis pieces, lowercase
+const ANALYSIS_PREFIXES: &[&str] = &["analysis:", "analysis-", "news analysis:"];
+/// Hosts of press-release wires
+const WIRE_HOSTS: &[&str] = &[
+    "prnewswire.com",
+    "globenewswire.com",
+    "businesswire.com",
+    "accesswire.com",
+    "newsfilecorp.com",
+];
+
+/// What kind of piece an article is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ContentKind {
+    /// Reporting
+    News,
+    /// Columns, editorials, and commentary
+    Opinion,
+    /// Research and investment ideas
+    Analysis,
+    /// Company announcements distributed by a wire
+    PressRelease,
+    /// Earnings call and conference transcripts
+    Transcript,
+}
+
+impl ContentKind {
+    /// All kinds
+    pub fn all() -> Vec<ContentKind> {
+        vec![
+            ContentKind::News,
+            ContentKind::Opinion,
+            ContentKind::Analysis,
+            ContentKind::PressRelease,
+            ContentKind::Transcript,
+        ]
+    }
+
+    /// Stable identifier for the kind
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentKind::News => "news",
+            ContentKind::Opinion => "opinion",
+            ContentKind::Analysis => "analysis",
+            ContentKind::PressRelease => "press_release",
+            ContentKind::Transcript => "transcript",
+        }
+    }
+}
+
+impl fmt::Display for ContentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ContentKind {
+    type Err = FanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ContentKind::all()
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| FanError::Unknown(format!("Unknown content kind: {}", s)))
+    }
+}
+
+/// Decides the kind of an article
+pub trait ContentClassifier: Send + Sync {
+    /// The kind of an article, fetched from `topic` of its source when known
+    fn classify(&self, article: &NewsArticle, topic: Option<&str>) -> ContentKind;
+
+    /// Classify an article and record the kind in its `extra_fields`
+    fn label(&self, article: &mut NewsArticle, topic: Option<&str>) -> ContentKind {
+        let kind = self.classify(article, topic);
+        article
+            .extra_fields
+            .insert(CONTENT_KIND_FIELD.to_string(), kind.as_str().into());
+        kind
+    }
+
+    /// Label every article of a list fetched from `topic`
+    fn label_all(&self, articles: &mut [NewsArticle], topic: Option<&str>) {
+        for article in articles {
+            self.label(article, topic);
+        }
+    }
+}
+
+impl<F> ContentClassifier for F
+where
+    F: Fn(&NewsArticle, Option<&str>) -> ContentKind + Send + Sync,
+{
+    fn classify(&self, article: &NewsArticle, topic: Option<&str>) -> ContentKind {
+        self(article, topic)
+    }
+}
+
+/// Rule-based classifier; see the [module docs](self) for the rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleClassifier {
+    /// Source, topic (every topic when `None`), and the kind of its articles
+    feeds: Vec<(String, Option<String>, ContentKind)>,
+}
+
+impl Default for RuleClassifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuleClassifier {
+    /// The built-in rules
+    pub fn new() -> Self {
+        Self { feeds: Vec::new() }
+            .with_topic("Seeking Alpha", "transcripts", ContentKind::Transcript)
+            .with_topic("Seeking Alpha", "long-ideas", ContentKind::Analysis)
+            .with_topic("Seeking Alpha", "short-ideas", ContentKind::Analysis)
+            .with_topic("Seeking Alpha", "ipo-analysis", ContentKind::Analysis)
+            .with_topic("Wall Street Journal", "RSSOpinion", ContentKind::Opinion)
+            .with_topic("CNBC", "commentary", ContentKind::Opinion)
+            .with_source("PR Newswire", ContentKind::PressRelease)
+            .with_source("GlobeNewswire", ContentKind::PressRelease)
+            .with_source("Business Wire", ContentKind::PressRelease)
+    }
+
+    /// Label every article of a source's topic as `kind`
+    pub fn with_topic(mut self, source: &str, topic: &str, kind: ContentKind) -> Self {
+        self.feeds
+            .push((source.to_string(), Some(topic.to_string()), kind));
+        self
+    }
+
+    /// Label every article of a source as `kind`
+    pub fn with_source(mut self, source: &str, kind: ContentKind) -> Self {
+        self.feeds.push((source.to_string(), None, kind));
+        self
+    }
+
+    /// The kind of the feed an article came from, if a rule names it
+    fn feed_kind(&self, article: &NewsArticle, topic: Option<&str>) -> Option<ContentKind> {
+        let source = article.source.as_deref()?;
+        self.feeds
+            .iter()
+            .find(|(rule_source, rule_topic, _)| {
+                rule_source.eq_ignore_ascii_case(source)
+                    && rule_topic
+                        .as_deref()
+                        .is_none_or(|rule_topic| Some(rule_topic) == topic)
+            })
+            .map(|(_, _, kind)| *kind)
+    }
+}
+
+impl ContentClassifier for RuleClassifier {
+    fn classify(&self, article: &NewsArticle, topic: Option<&str>) -> ContentKind {
+        if let Some(kind) = self.feed_kind(article, topic) {
+            return kind;
+        }
+        let title = article.title.as_deref().unwrap_or_default().to_lowercase();
+        let link = article.link.as_deref().unwrap_or_default().to_lowercase();
+        let title_starts = |prefixes: &[&str]| prefixes.iter().any(|p| title.starts_with(p));
+
+        if title.trim_end().ends_with("transcript") || title.contains("call transcript") {
+            ContentKind::Transcript
+        } else if title_starts(OPINION_PREFIXES) || OPINION_PATHS.iter().any(|p| link.contains(p)) {
+            ContentKind::Opinion
+        } else if title_starts(ANALYSIS_PREFIXES) || link.contains("seekingalpha.com/article/") {
+            ContentKind::Analysis
+        } else if link_host(&link).is_some_and(|host| {
+            WIRE_HOSTS
+                .iter()
+                .any(|wire| host == *wire || host.ends_with(&format!(".{}", wire)))
+        }) {
+            ContentKind::PressRelease
+        } else {
+            ContentKind::News
+        }
+    }
+}
+
+/// Host of a link, without the port
+fn link_host(link: &str) -> Option<&str> {
+    let rest = link.split_once("://")?.1;
+    let host = rest.split(['/', '?', '#']).next()?;
+    Some(host.split(':').next().unwrap_or(host))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(source: &str, title: &str, link: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.source = Some(source.to_string());
+        article.title = Some(title.to_string());
+        article.link = Some(link.to_string());
+        article
+    }
+
+    #[test]
+    fn test_feed_rules() {
+        let classifier = RuleClassifier::new();
+        let idea = article("Seeking Alpha", "Nvidia: Still A Buy", "");
+        assert_eq!(
+            classifier.classify(&idea, Some("long-ideas")),
+            ContentKind::Analysis
+        );
+        let wsj = article("Wall Street Journal", "The Fed's Mistake", "");
+        assert_eq!(
+            classifier.classify(&wsj, Some("RSSOpinion")),
+            ContentKind::Opinion
+        );
+        assert_eq!(
+            classifier.classify(&wsj, Some("RSSWSJD")),
+            ContentKind::News
+        );
+        let wire = article("PR Newswire", "Acme Reports Record Quarter", "");
+        assert_eq!(classifier.classify(&wire, None), ContentKind::PressRelease);
+
+        let custom = RuleClassifier::new().with_topic("CNBC", "earnings", ContentKind::Analysis);
+        let cnbc = article("CNBC", "Earnings recap", "");
+        assert_eq!(
+            custom.classify(&cnbc, Some("earnings")),
+            ContentKind::Analysis
+        );
+    }
+
+    #[test]
+    fn test_title_and_link_rules() {
+        let classifier = RuleClassifier::new();
+        for (title, link, kind) in [
+            (
+                "Apple Inc. (AAPL) Q2 2024 Earnings Call Transcript",
+                "https://seekingalpha.com/article/4690000-apple-aapl-q2-2024-earnings-call-transcript",
+                ContentKind::Transcript,
+            ),
+            (
+                "Opinion: The soft landing is a myth",
+                "",
+                ContentKind::Opinion,
+            ),
+            (
+                "Why rates must fall",
+                "https://www.example.com/opinion/2024/05/rates",
+                ContentKind::Opinion,
+            ),
+            (
+                "ANALYSIS-Chip stocks face a reckoning",
+                "",
+                ContentKind::Analysis,
+            ),
+            (
+                "Acme to present at conference",
+                "https://www.globenewswire.com/news-release/2024/05/01/acme",
+                ContentKind::PressRelease,
+            ),
+            (
+                "Stocks rise as yields ease",
+                "https://www.cnbc.com/2024/05/01/stocks.html",
+                ContentKind::News,
+            ),
+        ] {
+            let piece = article("Yahoo Finance", title, link);
+            assert_eq!(classifier.classify(&piece, None), kind, "{}", title);
+        }
+    }
+
+    #[test]
+    fn test_label() {
+        let mut articles = vec![
+            article("CNBC", "Fed holds rates", ""),
+            article("CNBC", "Column: Buy the dip", ""),
+        ];
+        RuleClassifier::new().label_all(&mut articles, Some("economy"));
+        assert_eq!(articles[0].content_kind(), Some(ContentKind::News));
+        assert_eq!(articles[1].extra_fields[CONTENT_KIND_FIELD], "opinion");
+
+        // A closure stands in for a model
+        let model = |_: &NewsArticle, _: Option<&str>| ContentKind::Analysis;
+        assert_eq!(model.label(&mut articles[0], None), ContentKind::Analysis);
+        assert_eq!(articles[0].content_kind(), Some(ContentKind::Analysis));
+
+        assert_eq!(
+            "Press_Release".parse::<ContentKind>().unwrap(),
+            ContentKind::PressRelease
+        );
+        assert!("rumor".parse::<ContentKind>().is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use crate::error::{FanError, Result};
+#[cfg(feature = "alpha-vantage")]
+use crate::news_source::alpha_vantage;
+#[cfg(feature = "finnhub-news")]
+use crate::news_source::finnhub_news;
+#[cfg(feature = "news-api-org")]
+use crate::news_source::news_api_org;
+#[cfg(feature = "polygon")]
+use crate::news_source::polygon;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Key-based sources and the environment variables holding their API keys
+///
+/// Only sources whose features are enabled are listed.
+pub const API_KEY_VARS: &[(&str, &str)] = &[
+    #[cfg(feature = "news-api-org")]
+    ("NewsAPI", news_api_org::API_KEY_ENV),
+    #[cfg(feature = "finnhub-news")]
+    ("Finnhub", finnhub_news::API_KEY_ENV),
+    #[cfg(feature = "alpha-vantage")]
+    ("AlphaVantage", alpha_vantage::API_KEY_ENV),
+    #[cfg(feature = "polygon")]
+    ("Polygon", polygon::API_KEY_ENV),
+];
+
+/// API keys for key-based sources, keyed by source name
+///
+/// `NewsClient` hands each key-based source its key from this store; sources without
+/// a key stay registered but skip topic groups and fail direct fetches with
+/// `FanError::MissingApiKey`. Keys can come from the environment, a dotenv-style
+/// file, or code, and later additions replace earlier ones:
+///
+/// ```rust,no_run
+/// use finance_news_aggregator_rs::NewsClient;
+/// use finance_news_aggregator_rs::credentials::Credentials;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let credentials = Credentials::from_env()
+///     .with_file(".env")?
+///     .with_key("Polygon", "your-api-key");
+/// let client = NewsClient::new().with_credentials(credentials);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct Credentials {
+    keys: HashMap<String, String>,
+}
+
+impl Credentials {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the keys of every key-based source from its environment variable
+    ///
+    /// See [`API_KEY_VARS`] for the variable names.
+    pub fn from_env() -> Self {
+        let mut credentials = Self::new();
+        for (source, var) in API_KEY_VARS {
+            if let Ok(key) = std::env::var(var) {
+                credentials.insert(source, &key);
+            }
+        }
+        credentials
+    }
+
+    /// Read keys from a dotenv-style file
+    ///
+    /// See [`with_file`](Self::with_file) for the format.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new().with_file(path)
+    }
+
+    /// Add the keys from a dotenv-style file
+    ///
+    /// Each `NAME=key` line names either a source (`Polygon=...`) or its environment
+    /// variable (`POLYGON_API_KEY=...`). Blank lines, `#` comments, an `export ` prefix,
+    /// and quotes around the key are allowed.
+    pub fn with_file(mut self, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+
+        for (number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (name, key) = line.split_once('=').ok_or_else(|| {
+                FanError::Unknown(format!(
+                    "Invalid credentials line {} in {:?}: expected NAME=key",
+                    number + 1,
+                    path
+                ))
+            })?;
+
+            let name = name.trim();
+            let source = API_KEY_VARS
+                .iter()
+                .find(|(_, var)| *var == name)
+                .map_or(name, |(source, _)| *source);
+            self.insert(source, key.trim().trim_matches(|c| c == '"' || c == '\''));
+        }
+        Ok(self)
+    }
+
+    /// Set the key for a source
+    ///
+    /// # Arguments
+    /// * `source` - Source name as returned by `NewsSource::name()` (e.g., "NewsAPI")
+    /// * `api_key` - The key issued by the provider
+    pub fn with_key(mut self, source: &str, api_key: &str) -> Self {
+        self.insert(source, api_key);
+        self
+    }
+
+    /// Add the keys of `other`, replacing those of the same sources
+    pub fn with_credentials(mut self, other: &Credentials) -> Self {
+        self.keys.extend(
+            other
+                .keys
+                .iter()
+                .map(|(source, key)| (source.clone(), key.clone())),
+        );
+        self
+    }
+
+    /// Get the key for a source
+    pub fn get(&self, source: &str) -> Option<&str> {
+        self.keys.get(source).map(String::as_str)
+    }
+
+    /// Get the key for a source, or an error explaining how to provide it
+    pub fn require(&self, source: &str) -> Result<&str> {
+        require_key(source, self.get(source))
+    }
+
+    /// Names of the sources with a key, in no particular order
+    pub fn sources(&self) -> impl Iterator<Item = &str> {
+        self.keys.keys().map(String::as_str)
+    }
+
+    fn insert(&mut self, source: &str, api_key: &str) {
+        // An empty variable or file entry counts as unset
+        if !api_key.is_empty() {
+            self.keys.insert(source.to_string(), api_key.to_string());
+        }
+    }
+}
+
+// Keys are secrets: only show which sources have one
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sources: Vec<&str> = self.sources().collect();
+        sources.sort_unstable();
+        f.debug_struct("Credentials")
+            .field("sources", &sources)
+            .finish()
+    }
+}
+
+/// Unwrap a source's API key, or explain how to provide it
+pub(crate) fn require_key<'a>(source: &str, api_key: Option<&'a str>) -> Result<&'a str> {
+    api_key.ok_or_else(|| {
+        let hint = match API_KEY_VARS.iter().find(|(name, _)| *name == source) {
+            Some((_, var)) => format!("set {} or ", var),
+            None => String::new(),
+        };
+        FanError::MissingApiKey(format!(
+            "{} ({}use Credentials::with_key(\"{}\", ..))",
+            source, hint, source
+        ))
+    })
+}
+
+// The tests read keys through the NewsAPI and Polygon variables
+#[cfg(all(test, feature = "news-api-org", feature = "polygon"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_and_programmatic_keys() {
+        let path = std::env::temp_dir().join(format!("fan-credentials-{}", std::process::id()));
+        fs::write(
+            &path,
+            "# provider keys\nexport NEWSAPI_KEY=\"news-key\"\n\nFinnhub = finn-key\nPOLYGON_API_KEY=\n",
+        )
+        .unwrap();
+
+        let credentials = Credentials::from_file(&path)
+            .unwrap()
+            .with_key("Finnhub", "override");
+        assert_eq!(credentials.get("NewsAPI"), Some("news-key"));
+        assert_eq!(credentials.get("Finnhub"), Some("override"));
+        assert_eq!(credentials.get("Polygon"), None);
+        assert!(!format!("{:?}", credentials).contains("news-key"));
+
+        fs::write(&path, "not a key line\n").unwrap();
+        assert!(Credentials::from_file(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_require_explains_missing_key() {
+        let credentials = Credentials::new();
+        match credentials.require("Polygon") {
+            Err(FanError::MissingApiKey(message)) => {
+                assert!(message.contains("POLYGON_API_KEY"));
+            }
+            other => panic!("expected MissingApiKey, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Polars data frames of articles (feature "polars")
+//!
+//! [`ToDataFrame::to_dataframe()`] turns a slice of articles into a polars
+//! `DataFrame`, ready to group and aggregate headlines by source, time, or ticker:
+//!
+//! ```rust
+//! use finance_news_aggregator_rs::NewsArticle;
+//! use finance_news_aggregator_rs::dataframe::ToDataFrame;
+//!
+//! let mut article = NewsArticle::new();
+//! article.source = Some("CNBC".to_string());
+//! article.extra_fields.insert("symbols".to_string(), "NVDA,AMD".into());
+//!
+//! let df = vec![article].to_dataframe()?;
+//! let per_source = df.group_by(["source"])?.select(["title"]).count()?;
+//! let per_ticker = df.explode(["symbols"])?;
+//! assert_eq!(per_source.height(), 1);
+//! assert_eq!(per_ticker.height(), 2);
+//! # Ok::<(), finance_news_aggregator_rs::FanError>(())
+//! ```
+//!
+//! The columns are those of `columnar::schema()` with the "arrow" feature: the
+//! article fields as strings, `published_at` as a UTC datetime in milliseconds,
+//! `symbols` as a list of strings, the remaining `extra_fields` as a JSON string,
+//! and the `stale` and `updated` flags. Hourly buckets and other date parts come
+//! from polars' own temporal functions on `published_at`.
+
+use crate::error::Result;
+use crate::types::NewsArticle;
+use polars::prelude::*;
+
+pub use polars;
+
+/// Key of the tickers in `NewsArticle::extra_fields`, a comma-separated list
+const SYMBOLS_FIELD: &str = "symbols";
+
+/// Conversion of articles into a polars `DataFrame`, one row per article
+pub trait ToDataFrame {
+    /// Build a data frame of the articles; see the [module docs](self) for the columns
+    fn to_dataframe(&self) -> Result<DataFrame>;
+}
+
+impl ToDataFrame for [NewsArticle] {
+    fn to_dataframe(&self) -> Result<DataFrame> {
+        let text = |name: &str, field: fn(&NewsArticle) -> &Option<String>| -> Column {
+            StringChunked::from_iter_options(
+                name.into(),
+                self.iter().map(|article| field(article).as_deref()),
+            )
+            .into_column()
+        };
+
+        let mut symbols = ListStringChunkedBuilder::new(SYMBOLS_FIELD.into(), self.len(), 0);
+        let mut extra_fields = Vec::with_capacity(self.len());
+        for article in self {
+            let values = article
+                .extra_fields
+                .get(SYMBOLS_FIELD)
+                .map(|values| values.values())
+                .unwrap_or_default();
+            symbols.append_values_iter(
+                values
+                    .iter()
+                    .flat_map(|value| value.split(','))
+                    .map(str::trim)
+                    .filter(|symbol| !symbol.is_empty()),
+            );
+
+            let mut extras = article.extra_fields.clone();
+            extras.remove(SYMBOLS_FIELD);
+            // A map of strings always serializes
+            extra_fields.push(serde_json::to_string(&extras).unwrap_or_default());
+        }
+
+        let published_at = Int64Chunked::from_iter_options(
+            "published_at".into(),
+            self.iter().map(|article| {
+                article
+                    .published_at()
+                    .map(|published| published.timestamp_millis())
+            }),
+        )
+        .into_datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC));
+        let flag = |name: &str, field: fn(&NewsArticle) -> bool| -> Column {
+            BooleanChunked::from_iter_values(name.into(), self.iter().map(field)).into_column()
+        };
+
+        let columns = vec![
+            text("title", |article| &article.title),
+            text("link", |article| &article.link),
+            text("description", |article| &article.description),
+            text("pub_date", |article| &article.pub_date),
+            published_at.into_column(),
+            text("guid", |article| &article.guid),
+            text("category", |article| &article.category),
+            text("author", |article| &article.author),
+            text("source", |article| &article.source),
+            symbols.finish().into_column(),
+            Column::new("extra_fields".into(), extra_fields),
+            flag("stale", |article| article.stale),
+            flag("updated", |article| article.updated),
+        ];
+        Ok(DataFrame::new(columns)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_dataframe() {
+        let mut nvidia = NewsArticle::new();
+        nvidia.title = Some("Nvidia earnings beat".to_string());
+        nvidia.source = Some("Polygon".to_string());
+        nvidia.pub_date = Some("2024-05-22T21:00:00Z".to_string());
+        nvidia
+            .extra_fields
+            .insert("symbols".to_string(), "NVDA, AMD".into());
+        nvidia
+            .extra_fields
+            .insert("outlet".to_string(), "Benzinga".into());
+        let mut oil = NewsArticle::new();
+        oil.title = Some("Oil slips".to_string());
+        oil.source = Some("Polygon".to_string());
+        oil.stale = true;
+
+        let df = [nvidia, oil].to_dataframe().unwrap();
+        assert_eq!(df.shape(), (2, 13));
+        assert_eq!(
+            df.column("published_at").unwrap().dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+        );
+        let published_at = df.column("published_at").unwrap();
+        assert_eq!(
+            published_at.get(0).unwrap(),
+            AnyValue::Datetime(
+                1_716_411_600_000,
+                TimeUnit::Milliseconds,
+                Some(&TimeZone::UTC)
+            )
+        );
+        assert!(published_at.get(1).unwrap().is_null());
+        assert_eq!(
+            df.column("extra_fields").unwrap().str().unwrap().get(0),
+            Some(r#"{"outlet":"Benzinga"}"#)
+        );
+        assert_eq!(
+            df.column("stale").unwrap().bool().unwrap().get(1),
+            Some(true)
+        );
+
+        let tickers = df.explode(["symbols"]).unwrap();
+        let tickers: Vec<_> = tickers
+            .column("symbols")
+            .unwrap()
+            .str()
+            .unwrap()
+            .iter()
+            .collect();
+        assert_eq!(tickers, vec![Some("NVDA"), Some("AMD"), None]);
+
+        let per_source = df
+            .group_by(["source"])
+            .unwrap()
+            .select(["title"])
+            .count()
+            .unwrap();
+        assert_eq!(per_source.height(), 1);
+    }
+
+    #[test]
+    fn test_to_dataframe_empty() {
+        let df = Vec::<NewsArticle>::new().to_dataframe().unwrap();
+        assert_eq!(df.shape(), (0, 13));
+    }
+}
@@ -0,0 +1,246 @@
+//! Publish date parsing
+//!
+//! Feeds are supposed to date items in RFC 2822 (RSS) or RFC 3339 (Atom), but in
+//! practice they mix in North American zone names ("EST", "ET"), zone-less
+//! timestamps, and ISO dates with a space instead of a `T`. `parse_date()` accepts
+//! all of these and normalizes them to UTC; the original string stays in
+//! `NewsArticle::pub_date`.
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+
+/// Formats of zone-less timestamps, tried in order after the zone has been split off
+const NAIVE_FORMATS: [&str; 12] = [
+    "%a, %d %b %Y %H:%M:%S",
+    "%a, %d %b %Y %H:%M",
+    "%d %b %Y %H:%M:%S",
+    "%d %b %Y %H:%M",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+    "%Y%m%dT%H%M%S",
+    "%B %d, %Y %I:%M %p",
+    "%b %d, %Y %I:%M %p",
+    "%m/%d/%Y %I:%M:%S %p",
+];
+
+/// Zone names with a fixed UTC offset in hours
+const FIXED_ZONES: [(&str, i32); 21] = [
+    ("Z", 0),
+    ("UT", 0),
+    ("UTC", 0),
+    ("GMT", 0),
+    ("EST", -5),
+    ("EDT", -4),
+    ("CST", -6),
+    ("CDT", -5),
+    ("MST", -7),
+    ("MDT", -6),
+    ("PST", -8),
+    ("PDT", -7),
+    ("BST", 1),
+    ("CET", 1),
+    ("CEST", 2),
+    ("HKT", 8),
+    ("SGT", 8),
+    ("JST", 9),
+    ("KST", 9),
+    ("AEST", 10),
+    ("AEDT", 11),
+];
+
+/// Generic North American zone names, with their standard-time offset in hours;
+/// daylight saving time adds one hour
+const US_ZONES: [(&str, i32); 4] = [("ET", -5), ("CT", -6), ("MT", -7), ("PT", -8)];
+
+/// Parse a feed date into UTC
+///
+/// Accepts RFC 2822 and RFC 3339 dates, the formats in `NAIVE_FORMATS`, and plain
+/// dates ("2024-05-01"). Zone names such as "EST" or "ET" (Eastern time, with US
+/// daylight saving rules) and numeric offsets are honored; timestamps without a
+/// zone are taken as UTC.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::dates::parse_date;
+///
+/// let date = parse_date("May 1, 2024 2:30 PM ET").unwrap();
+/// assert_eq!(date.to_rfc3339(), "2024-05-01T18:30:00+00:00");
+/// ```
+pub fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(date) = DateTime::parse_from_rfc2822(value) {
+        return Some(date.with_timezone(&Utc));
+    }
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(date.with_timezone(&Utc));
+    }
+
+    let (local, zone) = split_zone(value);
+    let naive = parse_naive(local)?;
+    let offset_seconds = match zone {
+        Some(zone) => zone_offset(zone, naive)?,
+        None => 0,
+    };
+    let utc = naive - chrono::Duration::seconds(i64::from(offset_seconds));
+    Some(Utc.from_utc_datetime(&utc))
+}
+
+/// Split a trailing zone name or numeric offset off a timestamp
+fn split_zone(value: &str) -> (&str, Option<&str>) {
+    if let Some(local) = value.strip_suffix('Z')
+        && local.ends_with(|c: char| c.is_ascii_digit())
+    {
+        return (local, Some("Z"));
+    }
+    match value.rsplit_once(' ') {
+        Some((local, zone)) if is_zone(zone) => (local.trim_end(), Some(zone)),
+        _ => (value, None),
+    }
+}
+
+/// Whether a token is a zone name or a numeric offset rather than part of the time
+fn is_zone(token: &str) -> bool {
+    let upper = token.to_ascii_uppercase();
+    FIXED_ZONES.iter().any(|(name, _)| *name == upper)
+        || US_ZONES.iter().any(|(name, _)| *name == upper)
+        || parse_offset(token).is_some()
+}
+
+/// Parse a zone-less timestamp with the first matching format
+fn parse_naive(value: &str) -> Option<NaiveDateTime> {
+    NAIVE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+}
+
+/// UTC offset in seconds of a zone at a local time
+fn zone_offset(zone: &str, local: NaiveDateTime) -> Option<i32> {
+    let upper = zone.to_ascii_uppercase();
+    if let Some((_, hours)) = FIXED_ZONES.iter().find(|(name, _)| *name == upper) {
+        return Some(hours * 3600);
+    }
+    if let Some((_, hours)) = US_ZONES.iter().find(|(name, _)| *name == upper) {
+        let dst = if is_us_daylight_time(local) { 1 } else { 0 };
+        return Some((hours + dst) * 3600);
+    }
+    parse_offset(zone)
+}
+
+/// Parse a numeric offset such as "+0530", "-05:00", or "-5" into seconds
+fn parse_offset(token: &str) -> Option<i32> {
+    let sign = match token.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let digits: String = token[1..].chars().filter(|c| *c != ':').collect();
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (digits[..2].parse().ok()?, digits[2..].parse().ok()?),
+        _ => return None,
+    };
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Convert a UTC time to US Eastern local time
+pub(crate) fn to_us_eastern(utc: DateTime<Utc>) -> NaiveDateTime {
+    let standard = utc.naive_utc() - chrono::Duration::hours(5);
+    // DST starts at 2:00 standard time and ends at 2:00 daylight time, which is 1:00
+    // standard time
+    let hour = chrono::Duration::hours(1);
+    if is_us_daylight_time(standard) && is_us_daylight_time(standard + hour) {
+        standard + hour
+    } else {
+        standard
+    }
+}
+
+/// Whether US daylight saving time is in effect at a local time
+///
+/// DST runs from 2:00 on the second Sunday of March to 2:00 on the first Sunday of
+/// November.
+fn is_us_daylight_time(local: NaiveDateTime) -> bool {
+    let year = local.year();
+    let starts = NaiveDate::from_weekday_of_month_opt(year, 3, Weekday::Sun, 2)
+        .and_then(|date| date.and_hms_opt(2, 0, 0));
+    let ends = NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Sun, 1)
+        .and_then(|date| date.and_hms_opt(2, 0, 0));
+    match (starts, ends) {
+        (Some(starts), Some(ends)) => local >= starts && local < ends,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(value: &str) -> String {
+        parse_date(value)
+            .map(|date| date.to_rfc3339())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_zone_names_and_offsets() {
+        assert_eq!(
+            utc("Wed, 01 May 2024 14:30:00 EST"),
+            "2024-05-01T19:30:00+00:00"
+        );
+        assert_eq!(
+            utc("Wed, 01 May 2024 14:30:00 UTC"),
+            "2024-05-01T14:30:00+00:00"
+        );
+        assert_eq!(
+            utc("2024-05-01 14:30:00 -04:00"),
+            "2024-05-01T18:30:00+00:00"
+        );
+        // Eastern time follows daylight saving
+        assert_eq!(utc("Jan 15, 2024 09:00 AM ET"), "2024-01-15T14:00:00+00:00");
+        assert_eq!(utc("Jul 15, 2024 09:00 AM ET"), "2024-07-15T13:00:00+00:00");
+    }
+
+    #[test]
+    fn test_zone_less_dates_are_utc() {
+        assert_eq!(utc("2024-05-01T14:30:00"), "2024-05-01T14:30:00+00:00");
+        assert_eq!(utc("2024-05-01 14:30"), "2024-05-01T14:30:00+00:00");
+        assert_eq!(utc("20240501T143000Z"), "2024-05-01T14:30:00+00:00");
+        assert_eq!(utc("2024-05-01"), "2024-05-01T00:00:00+00:00");
+        assert!(parse_date("last Tuesday").is_none());
+    }
+
+    #[test]
+    fn test_to_us_eastern() {
+        let eastern = |value: &str| {
+            let utc = DateTime::parse_from_rfc3339(value)
+                .unwrap()
+                .with_timezone(&Utc);
+            to_us_eastern(utc).format("%Y-%m-%d %H:%M").to_string()
+        };
+        assert_eq!(eastern("2024-01-15T14:30:00Z"), "2024-01-15 09:30");
+        assert_eq!(eastern("2024-07-15T13:30:00Z"), "2024-07-15 09:30");
+        assert_eq!(eastern("2024-03-10T06:59:00Z"), "2024-03-10 01:59");
+        assert_eq!(eastern("2024-03-10T07:00:00Z"), "2024-03-10 03:00");
+        assert_eq!(eastern("2024-11-03T05:59:00Z"), "2024-11-03 01:59");
+        assert_eq!(eastern("2024-11-03T06:00:00Z"), "2024-11-03 01:00");
+    }
+
+    #[test]
+    fn test_us_daylight_time_boundaries() {
+        let at = |value: &str| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap();
+        assert!(!is_us_daylight_time(at("2024-03-10 01:59")));
+        assert!(is_us_daylight_time(at("2024-03-10 02:00")));
+        assert!(is_us_daylight_time(at("2024-11-03 01:59")));
+        assert!(!is_us_daylight_time(at("2024-11-03 02:00")));
+    }
+}
@@ -0,0 +1,500 @@
+//! Deprecation history of source topics across runs
+//!
+//! One health check shows which topics fail today, not whether they worked last week.
+//! A [`DeprecationHistory`] keeps, for every source topic, when it first failed, when
+//! it last worked, and how often it failed with each [`ErrorClass`]. Saved as JSON
+//! between runs, two histories can be compared with `diff()` so a nightly job reports
+//! only the topics that broke or came back since the previous run.
+
+use crate::error::{FanError, Result};
+use crate::types::NewsArticle;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Kind of failure, coarse enough to count across runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorClass {
+    /// HTTP 404 or 410
+    NotFound,
+    /// HTTP 403
+    Forbidden,
+    /// A web page served where the feed used to be
+    NotAFeed,
+    /// HTTP 429 or a source's own rate limit
+    RateLimited,
+    /// HTTP 5xx
+    ServerError,
+    /// Any other HTTP status
+    HttpStatus,
+    Timeout,
+    /// Connection or DNS failure
+    Connection,
+    /// A response that could not be parsed
+    Parse,
+    Other,
+}
+
+impl ErrorClass {
+    /// Class of an error, or `None` for errors that say nothing about the topic,
+    /// such as a missing API key
+    pub fn of(error: &FanError) -> Option<Self> {
+        let class = match error {
+            FanError::HttpStatus {
+                status: 404 | 410, ..
+            } => ErrorClass::NotFound,
+            FanError::HttpStatus { status: 403, .. } => ErrorClass::Forbidden,
+            FanError::HttpStatus { status: 429, .. } | FanError::RateLimited(_) => {
+                ErrorClass::RateLimited
+            }
+            FanError::HttpStatus {
+                status: 500..=599, ..
+            } => ErrorClass::ServerError,
+            FanError::HttpStatus { .. } => ErrorClass::HttpStatus,
+            FanError::NotAFeed { .. } => ErrorClass::NotAFeed,
+            FanError::Timeout(_) => ErrorClass::Timeout,
+            #[cfg(feature = "fetch")]
+            FanError::Http(e) if e.is_timeout() => ErrorClass::Timeout,
+            #[cfg(feature = "fetch")]
+            FanError::Http(e) if e.is_connect() => ErrorClass::Connection,
+            FanError::XmlParsing(_) | FanError::JsonSerialization(_) | FanError::FeedParsing(_) => {
+                ErrorClass::Parse
+            }
+            #[cfg(feature = "proto")]
+            FanError::ProtoDecoding(_) => ErrorClass::Parse,
+            FanError::MissingApiKey(_) => return None,
+            _ => ErrorClass::Other,
+        };
+        Some(class)
+    }
+
+    /// Whether the class means the topic is gone rather than failing for a while
+    pub fn is_deprecation(self) -> bool {
+        matches!(
+            self,
+            ErrorClass::NotFound | ErrorClass::Forbidden | ErrorClass::NotAFeed
+        )
+    }
+}
+
+/// What is known about one source topic over every recorded run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EndpointHistory {
+    /// URL of the latest failed request, when the error names one
+    pub url: Option<String>,
+    /// Time of the first failure ever recorded
+    pub first_failure: Option<DateTime<Utc>>,
+    /// Time of the first failure since the topic last worked, while it is failing
+    pub failing_since: Option<DateTime<Utc>>,
+    pub last_failure: Option<DateTime<Utc>>,
+    pub last_success: Option<DateTime<Utc>>,
+    /// Class of the latest failure
+    pub last_class: Option<ErrorClass>,
+    /// Message of the latest failure
+    pub last_error: Option<String>,
+    pub successes: u32,
+    /// Failures by class
+    pub errors: BTreeMap<ErrorClass, u32>,
+}
+
+impl EndpointHistory {
+    /// Whether the latest recorded fetch failed
+    pub fn is_failing(&self) -> bool {
+        self.failing_since.is_some()
+    }
+
+    /// Whether the topic is failing with an error that means it is gone
+    pub fn is_deprecated(&self) -> bool {
+        self.is_failing() && self.last_class.is_some_and(ErrorClass::is_deprecation)
+    }
+
+    /// Failures recorded, of any class
+    pub fn failures(&self) -> u32 {
+        self.errors.values().sum()
+    }
+}
+
+/// Fetch outcomes of every source topic, kept across runs
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::deprecation::{DeprecationHistory, ErrorClass};
+/// use finance_news_aggregator_rs::FanError;
+///
+/// let previous = DeprecationHistory::new();
+/// let mut history = previous.clone();
+/// history.record(
+///     "MarketWatch",
+///     "topstories",
+///     &Err(FanError::HttpStatus {
+///         status: 404,
+///         url: "https://feeds.marketwatch.com/marketwatch/topstories/".to_string(),
+///         snippet: String::new(),
+///     }),
+/// );
+///
+/// let endpoint = history.endpoint("MarketWatch", "topstories").unwrap();
+/// assert!(endpoint.is_deprecated());
+/// assert_eq!(endpoint.errors[&ErrorClass::NotFound], 1);
+///
+/// let diff = history.diff(&previous);
+/// assert_eq!(diff.newly_failing, [("MarketWatch".to_string(), "topstories".to_string())]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DeprecationHistory {
+    /// When a fetch was last recorded
+    pub updated_at: DateTime<Utc>,
+    /// History of each topic, by source and topic
+    pub endpoints: BTreeMap<String, BTreeMap<String, EndpointHistory>>,
+}
+
+impl Default for DeprecationHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeprecationHistory {
+    /// Create an empty history
+    pub fn new() -> Self {
+        Self {
+            updated_at: Utc::now(),
+            endpoints: BTreeMap::new(),
+        }
+    }
+
+    /// Record the result of fetching a topic
+    ///
+    /// Any successful fetch counts as the topic working, even without articles.
+    /// Errors that say nothing about the topic, such as a missing API key, are not
+    /// recorded.
+    pub fn record(&mut self, source: &str, topic: &str, result: &Result<Vec<NewsArticle>>) {
+        self.record_at(source, topic, result, Utc::now());
+    }
+
+    fn record_at(
+        &mut self,
+        source: &str,
+        topic: &str,
+        result: &Result<Vec<NewsArticle>>,
+        at: DateTime<Utc>,
+    ) {
+        let class = match result {
+            Ok(_) => None,
+            Err(error) => match ErrorClass::of(error) {
+                Some(class) => Some((class, error)),
+                None => return,
+            },
+        };
+        let endpoint = self
+            .endpoints
+            .entry(source.to_string())
+            .or_default()
+            .entry(topic.to_string())
+            .or_default();
+        self.updated_at = at;
+
+        let Some((class, error)) = class else {
+            endpoint.successes += 1;
+            endpoint.last_success = Some(at);
+            endpoint.failing_since = None;
+            return;
+        };
+        endpoint.first_failure.get_or_insert(at);
+        endpoint.failing_since.get_or_insert(at);
+        endpoint.last_failure = Some(at);
+        endpoint.last_class = Some(class);
+        endpoint.last_error = Some(error.to_string());
+        if let FanError::HttpStatus { url, .. } | FanError::NotAFeed { url, .. } = error {
+            endpoint.url = Some(url.clone());
+        }
+        *endpoint.errors.entry(class).or_insert(0) += 1;
+    }
+
+    /// History of one topic, if it was ever recorded
+    pub fn endpoint(&self, source: &str, topic: &str) -> Option<&EndpointHistory> {
+        self.endpoints
+            .get(source)
+            .and_then(|topics| topics.get(topic))
+    }
+
+    /// `(source, topic)` pairs failing with an error that means they are gone
+    pub fn deprecated(&self) -> Vec<(String, String)> {
+        self.matching(EndpointHistory::is_deprecated)
+    }
+
+    /// `(source, topic)` pairs whose latest fetch failed
+    pub fn failing(&self) -> Vec<(String, String)> {
+        self.matching(EndpointHistory::is_failing)
+    }
+
+    /// Failures of every topic by class
+    pub fn error_summary(&self) -> BTreeMap<ErrorClass, u32> {
+        let mut summary = BTreeMap::new();
+        for endpoint in self.endpoints.values().flat_map(BTreeMap::values) {
+            for (class, count) in &endpoint.errors {
+                *summary.entry(*class).or_insert(0) += count;
+            }
+        }
+        summary
+    }
+
+    /// Changes from an earlier history, such as the one saved by the previous run
+    pub fn diff(&self, previous: &DeprecationHistory) -> HistoryDiff {
+        let was = |source: &str, topic: &str, check: fn(&EndpointHistory) -> bool| {
+            previous.endpoint(source, topic).is_some_and(check)
+        };
+        let mut diff = HistoryDiff::default();
+        for (source, topics) in &self.endpoints {
+            for (topic, endpoint) in topics {
+                let key = (source.clone(), topic.clone());
+                if endpoint.is_failing() {
+                    if was(source, topic, EndpointHistory::is_failing) {
+                        diff.still_failing.push(key.clone());
+                    } else {
+                        diff.newly_failing.push(key.clone());
+                    }
+                    if endpoint.is_deprecated()
+                        && !was(source, topic, EndpointHistory::is_deprecated)
+                    {
+                        diff.newly_deprecated.push(key);
+                    }
+                } else if was(source, topic, EndpointHistory::is_failing) {
+                    diff.recovered.push(key);
+                }
+            }
+        }
+        diff
+    }
+
+    /// Read a history saved with `save()`
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+
+    /// Read a saved history, or start an empty one if there is no file yet
+    pub fn load_or_new(path: &Path) -> Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Write the history as JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn matching(&self, check: fn(&EndpointHistory) -> bool) -> Vec<(String, String)> {
+        self.endpoints
+            .iter()
+            .flat_map(|(source, topics)| {
+                topics
+                    .iter()
+                    .filter(|(_, endpoint)| check(endpoint))
+                    .map(move |(topic, _)| (source.clone(), topic.clone()))
+            })
+            .collect()
+    }
+}
+
+/// Topics whose state changed between two histories, as `(source, topic)` pairs in
+/// name order
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HistoryDiff {
+    /// Failing now, but not before
+    pub newly_failing: Vec<(String, String)>,
+    /// Failing now and before
+    pub still_failing: Vec<(String, String)>,
+    /// Failing with an error that means they are gone, which they were not before
+    pub newly_deprecated: Vec<(String, String)>,
+    /// Working now after failing before
+    pub recovered: Vec<(String, String)>,
+}
+
+impl HistoryDiff {
+    /// Whether no topic started failing or recovered
+    pub fn is_empty(&self) -> bool {
+        self.newly_failing.is_empty() && self.recovered.is_empty()
+    }
+}
+
+impl fmt::Display for HistoryDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sections = [
+            ("Newly failing", &self.newly_failing),
+            ("Newly deprecated", &self.newly_deprecated),
+            ("Recovered", &self.recovered),
+            ("Still failing", &self.still_failing),
+        ];
+        for (title, topics) in sections {
+            if topics.is_empty() {
+                continue;
+            }
+            writeln!(f, "{} ({}):", title, topics.len())?;
+            for (source, topic) in topics {
+                writeln!(f, "  {}::{}", source, topic)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn not_found() -> Result<Vec<NewsArticle>> {
+        Err(FanError::HttpStatus {
+            status: 404,
+            url: "https://feeds.example.com/gone".to_string(),
+            snippet: String::new(),
+        })
+    }
+
+    fn timeout() -> Result<Vec<NewsArticle>> {
+        Err(FanError::Timeout("markets".to_string()))
+    }
+
+    fn day(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, day, 6, 0, 0).unwrap()
+    }
+
+    fn pair(source: &str, topic: &str) -> (String, String) {
+        (source.to_string(), topic.to_string())
+    }
+
+    #[test]
+    fn test_error_classes() {
+        let class = |error: FanError| ErrorClass::of(&error);
+        assert_eq!(class(not_found().unwrap_err()), Some(ErrorClass::NotFound));
+        assert_eq!(
+            class(FanError::NotAFeed {
+                url: "https://example.com/feed.xml".to_string(),
+                content_type: "text/html".to_string(),
+            }),
+            Some(ErrorClass::NotAFeed)
+        );
+        assert_eq!(
+            class(FanError::HttpStatus {
+                status: 503,
+                url: String::new(),
+                snippet: String::new(),
+            }),
+            Some(ErrorClass::ServerError)
+        );
+        assert_eq!(
+            class(FanError::FeedParsing("bad".to_string())),
+            Some(ErrorClass::Parse)
+        );
+        assert_eq!(class(FanError::MissingApiKey("Polygon".to_string())), None);
+        assert!(ErrorClass::Forbidden.is_deprecation());
+        assert!(!ErrorClass::Timeout.is_deprecation());
+    }
+
+    #[test]
+    fn test_history_tracks_first_failure_and_last_success() {
+        let mut history = DeprecationHistory::new();
+        history.record_at("CNBC", "markets", &Ok(vec![]), day(1));
+        history.record_at("CNBC", "markets", &timeout(), day(2));
+        history.record_at("CNBC", "markets", &not_found(), day(3));
+
+        let endpoint = history.endpoint("CNBC", "markets").unwrap();
+        assert_eq!(endpoint.last_success, Some(day(1)));
+        assert_eq!(endpoint.first_failure, Some(day(2)));
+        assert_eq!(endpoint.failing_since, Some(day(2)));
+        assert_eq!(endpoint.last_failure, Some(day(3)));
+        assert_eq!(endpoint.failures(), 2);
+        assert_eq!(endpoint.errors[&ErrorClass::Timeout], 1);
+        assert_eq!(
+            endpoint.url.as_deref(),
+            Some("https://feeds.example.com/gone")
+        );
+        assert!(endpoint.is_deprecated());
+
+        history.record_at("CNBC", "markets", &Ok(vec![]), day(4));
+        let endpoint = history.endpoint("CNBC", "markets").unwrap();
+        assert!(!endpoint.is_failing());
+        assert_eq!(endpoint.first_failure, Some(day(2)));
+        assert_eq!(history.error_summary()[&ErrorClass::NotFound], 1);
+
+        // A missing API key says nothing about the topic
+        history.record_at(
+            "Polygon",
+            "news",
+            &Err(FanError::MissingApiKey("Polygon".to_string())),
+            day(4),
+        );
+        assert!(history.endpoint("Polygon", "news").is_none());
+    }
+
+    #[test]
+    fn test_diff_across_runs() {
+        let mut previous = DeprecationHistory::new();
+        previous.record_at("NASDAQ", "Stocks", &timeout(), day(1));
+        previous.record_at("NASDAQ", "Markets", &timeout(), day(1));
+        previous.record_at("NASDAQ", "Earnings", &Ok(vec![]), day(1));
+
+        let mut current = previous.clone();
+        current.record_at("NASDAQ", "Stocks", &Ok(vec![]), day(2));
+        current.record_at("NASDAQ", "Markets", &not_found(), day(2));
+        current.record_at("NASDAQ", "Earnings", &timeout(), day(2));
+
+        let diff = current.diff(&previous);
+        assert_eq!(diff.newly_failing, [pair("NASDAQ", "Earnings")]);
+        assert_eq!(diff.still_failing, [pair("NASDAQ", "Markets")]);
+        assert_eq!(diff.newly_deprecated, [pair("NASDAQ", "Markets")]);
+        assert_eq!(diff.recovered, [pair("NASDAQ", "Stocks")]);
+        assert!(
+            diff.to_string()
+                .contains("Recovered (1):\n  NASDAQ::Stocks")
+        );
+        assert!(current.diff(&current).is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path =
+            std::env::temp_dir().join(format!("fan-deprecation-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(
+            DeprecationHistory::load_or_new(&path).unwrap().endpoints,
+            BTreeMap::new()
+        );
+
+        let mut history = DeprecationHistory::new();
+        history.record_at("CNBC", "markets", &not_found(), day(1));
+        history.save(&path).unwrap();
+
+        let json = fs::read_to_string(&path).unwrap();
+        assert!(json.contains(r#""not_found": 1"#));
+        assert_eq!(DeprecationHistory::load(&path).unwrap(), history);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_history_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(DeprecationHistory)).unwrap();
+
+        assert_eq!(schema["properties"]["updated_at"]["format"], "date-time");
+        let endpoint = &schema["$defs"]["EndpointHistory"]["properties"];
+        assert!(endpoint["errors"]["additionalProperties"].is_object());
+        assert!(schema["$defs"]["ErrorClass"].is_object());
+
+        let diff = serde_json::to_value(schemars::schema_for!(HistoryDiff)).unwrap();
+        assert!(diff["properties"]["newly_failing"]["items"].is_object());
+    }
+}
@@ -0,0 +1,312 @@
+use crate::error::Result;
+use chrono::{DateTime, Utc};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Age past which a temporary file is taken for a leftover of an interrupted write
+/// rather than a `store()` in progress
+const TMP_GRACE: Duration = Duration::from_secs(60);
+
+/// Last successful response body for a feed URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedBody {
+    pub url: String,
+    pub body: String,
+    /// `ETag` response header, sent back as `If-None-Match`
+    pub etag: Option<String>,
+    /// `Last-Modified` response header, sent back as `If-Modified-Since`
+    pub last_modified: Option<String>,
+    /// When the body was last fetched or confirmed unchanged by the server
+    pub fetched_at: DateTime<Utc>,
+    /// Name of the source the feed belongs to, used to group entries for retention
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+impl CachedBody {
+    /// Create a cache entry for a body fetched just now
+    pub fn new(
+        url: &str,
+        body: String,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    ) -> Self {
+        Self {
+            url: url.to_string(),
+            body,
+            etag,
+            last_modified,
+            fetched_at: Utc::now(),
+            source: None,
+        }
+    }
+
+    /// Attribute the entry to a source
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.source = Some(source.to_string());
+        self
+    }
+
+    /// Key entries are grouped by when enforcing `RetentionPolicy::max_entries_per_source`
+    fn retention_group(&self) -> &str {
+        self.source.as_deref().unwrap_or(&self.url)
+    }
+}
+
+/// Limits on how much a persistent cache keeps
+///
+/// Applied by `DiskCache::prune()` and, with the "sqlite" feature,
+/// `SqliteStore::prune()`. Both limits are off by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Entries not fetched or revalidated for longer than this are removed
+    pub max_age: Option<Duration>,
+    /// Only the most recently fetched entries of each source are kept
+    pub max_entries_per_source: Option<usize>,
+}
+
+impl RetentionPolicy {
+    /// Create a policy that keeps everything
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove entries older than `max_age`
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Keep at most `max_entries` entries per source
+    pub fn with_max_entries_per_source(mut self, max_entries: usize) -> Self {
+        self.max_entries_per_source = Some(max_entries);
+        self
+    }
+}
+
+/// Persistent cache of raw feed bodies, one JSON file per URL
+///
+/// Used by `NewsClient` when enabled with `SourceConfig::with_disk_cache()`. Stored
+/// validators make refreshes conditional GETs, and the last body is served (with
+/// `NewsArticle::stale` set) when a feed cannot be fetched.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// Open a cache in `dir`, creating the directory if needed
+    pub fn new(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Directory holding the cache files
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Load the cached body for a URL
+    ///
+    /// Missing, unreadable, or corrupt entries are treated as absent.
+    pub fn load(&self, url: &str) -> Option<CachedBody> {
+        let path = self.path_for(url);
+        let content = fs::read_to_string(&path).ok()?;
+        match serde_json::from_str::<CachedBody>(&content) {
+            Ok(entry) if entry.url == url => Some(entry),
+            Ok(_) => None,
+            Err(e) => {
+                warn!("Ignoring corrupt cache file {:?}: {}", path, e);
+                None
+            }
+        }
+    }
+
+    /// Store the body for a URL, replacing any previous entry
+    pub fn store(&self, entry: &CachedBody) -> Result<()> {
+        let path = self.path_for(&entry.url);
+        // Write to a temporary file first so readers never see a partial entry
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec(entry)?)?;
+        fs::rename(&tmp_path, &path)?;
+
+        debug!(
+            "Cached {} bytes for {} at {:?}",
+            entry.body.len(),
+            entry.url,
+            path
+        );
+        Ok(())
+    }
+
+    /// Remove the entries that fall outside `policy`
+    ///
+    /// Corrupt cache files and leftovers from interrupted writes are removed as well;
+    /// temporary files younger than a minute are left to the writes in progress.
+    /// A `Poller` prunes its client's cache hourly; other long-running aggregators
+    /// should call this periodically so the cache does not grow without bound.
+    ///
+    /// # Returns
+    /// The number of files removed
+    pub fn prune(&self, policy: &RetentionPolicy) -> Result<usize> {
+        let mut removed = 0;
+        let mut groups: HashMap<String, Vec<(DateTime<Utc>, PathBuf)>> = HashMap::new();
+        let max_age = policy
+            .max_age
+            .and_then(|max_age| chrono::Duration::from_std(max_age).ok());
+
+        for dir_entry in fs::read_dir(&self.dir)? {
+            let path = dir_entry?.path();
+            let entry = match path.extension().and_then(|ext| ext.to_str()) {
+                Some("json") => fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<CachedBody>(&content).ok()),
+                Some("tmp") if is_recent(&path) => continue,
+                Some("tmp") => None,
+                _ => continue,
+            };
+
+            let Some(entry) = entry else {
+                removed += usize::from(remove_if_present(&path)?);
+                continue;
+            };
+            if max_age.is_some_and(|max_age| Utc::now() - entry.fetched_at > max_age) {
+                removed += usize::from(remove_if_present(&path)?);
+                continue;
+            }
+            groups
+                .entry(entry.retention_group().to_string())
+                .or_default()
+                .push((entry.fetched_at, path));
+        }
+
+        if let Some(max_entries) = policy.max_entries_per_source {
+            for entries in groups.values_mut() {
+                entries.sort_by_key(|(fetched_at, _)| std::cmp::Reverse(*fetched_at));
+                for (_, path) in entries.iter().skip(max_entries) {
+                    removed += usize::from(remove_if_present(path)?);
+                }
+            }
+        }
+
+        debug!("Pruned {} entries from disk cache {:?}", removed, self.dir);
+        Ok(removed)
+    }
+
+    /// Path of the cache file for a URL
+    fn path_for(&self, url: &str) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}.json", fnv1a_64(url.as_bytes())))
+    }
+}
+
+/// FNV-1a hash, stable across platforms and Rust releases so cache file names are too
+pub(crate) fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Remove a file, returning `false` when another process already removed it
+fn remove_if_present(path: &Path) -> std::io::Result<bool> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Whether a file was modified within `TMP_GRACE`
+fn is_recent(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        // A modification time in the future counts as recent
+        .is_ok_and(|modified| modified.elapsed().unwrap_or_default() < TMP_GRACE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    #[test]
+    fn test_store_and_load() {
+        let dir = std::env::temp_dir().join(format!("fan-disk-cache-{}", std::process::id()));
+        let cache = DiskCache::new(&dir).unwrap();
+        let url = "https://example.com/rss/markets";
+
+        assert!(cache.load(url).is_none());
+
+        let entry = CachedBody::new(
+            url,
+            "<rss></rss>".to_string(),
+            Some("\"abc\"".to_string()),
+            None,
+        );
+        cache.store(&entry).unwrap();
+
+        let loaded = cache.load(url).unwrap();
+        assert_eq!(loaded.body, "<rss></rss>");
+        assert_eq!(loaded.etag.as_deref(), Some("\"abc\""));
+        assert!(cache.load("https://example.com/rss/other").is_none());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_prune() {
+        let dir = std::env::temp_dir().join(format!("fan-disk-prune-{}", std::process::id()));
+        let cache = DiskCache::new(&dir).unwrap();
+        let store = |url: &str, source: &str, age_hours: i64| {
+            let mut entry = CachedBody::new(url, String::new(), None, None).with_source(source);
+            entry.fetched_at = Utc::now() - chrono::Duration::hours(age_hours);
+            cache.store(&entry).unwrap();
+        };
+        store("https://a.example.com/1", "A", 1);
+        store("https://a.example.com/2", "A", 2);
+        store("https://a.example.com/3", "A", 3);
+        store("https://b.example.com/1", "B", 48);
+        fs::write(dir.join("corrupt.json"), "not json").unwrap();
+        // A write in progress is left alone, one interrupted long ago is not
+        fs::write(dir.join("in-flight.tmp"), "{").unwrap();
+        fs::File::create(dir.join("interrupted.tmp"))
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+
+        let policy = RetentionPolicy::new()
+            .with_max_age(Duration::from_secs(24 * 3600))
+            .with_max_entries_per_source(2);
+        assert_eq!(cache.prune(&policy).unwrap(), 4);
+        assert!(dir.join("in-flight.tmp").exists());
+        assert!(!dir.join("interrupted.tmp").exists());
+
+        assert!(cache.load("https://a.example.com/1").is_some());
+        assert!(cache.load("https://a.example.com/2").is_some());
+        assert!(cache.load("https://a.example.com/3").is_none());
+        assert!(cache.load("https://b.example.com/1").is_none());
+        assert_eq!(cache.prune(&RetentionPolicy::new()).unwrap(), 0);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_remove_if_present() {
+        let path = std::env::temp_dir().join(format!("fan-disk-remove-{}", std::process::id()));
+        fs::write(&path, "{}").unwrap();
+        assert!(remove_if_present(&path).unwrap());
+        // Already removed, as by a concurrent prune
+        assert!(!remove_if_present(&path).unwrap());
+    }
+
+    #[test]
+    fn test_fnv1a_64() {
+        assert_eq!(fnv1a_64(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63dc4c8601ec8c);
+    }
+}
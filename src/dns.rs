@@ -0,0 +1,116 @@
+use log::debug;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// In-process DNS cache for the HTTP client
+///
+/// Resolves host names with the system resolver and keeps the addresses for `ttl`,
+/// so tight polling loops against the same feed hosts skip repeated lookups.
+/// Enabled through `SourceConfig::with_dns_cache()`.
+#[derive(Debug, Clone)]
+pub struct CachingResolver {
+    ttl: Duration,
+    cache: Arc<Mutex<HashMap<String, CacheEntry>>>,
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    addrs: Vec<SocketAddr>,
+    /// `None` when the TTL reaches past what an `Instant` can hold
+    expires_at: Option<Instant>,
+}
+
+impl CacheEntry {
+    fn is_live(&self, now: Instant) -> bool {
+        self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+}
+
+impl CachingResolver {
+    /// Create a resolver that caches lookups for `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Get cached addresses for a host if they have not expired
+    fn cached(&self, host: &str) -> Option<Vec<SocketAddr>> {
+        let cache = self.cache.lock().unwrap();
+        cache
+            .get(host)
+            .filter(|entry| entry.is_live(Instant::now()))
+            .map(|entry| entry.addrs.clone())
+    }
+
+    /// Store freshly resolved addresses for a host
+    fn store(&self, host: &str, addrs: Vec<SocketAddr>) {
+        let mut cache = self.cache.lock().unwrap();
+        let now = Instant::now();
+        cache.retain(|_, entry| entry.is_live(now));
+        cache.insert(
+            host.to_string(),
+            CacheEntry {
+                addrs,
+                expires_at: now.checked_add(self.ttl),
+            },
+        );
+    }
+}
+
+impl Resolve for CachingResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let resolver = self.clone();
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            if let Some(addrs) = resolver.cached(&host) {
+                debug!("DNS cache hit for {}", host);
+                return Ok(Box::new(addrs.into_iter()) as Addrs);
+            }
+
+            // Port 0 is a placeholder; the HTTP client fills in the real port
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            debug!("Resolved {} to {} addresses", host, addrs.len());
+            resolver.store(&host, addrs.clone());
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_expiry() {
+        let resolver = CachingResolver::new(Duration::from_secs(60));
+        let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+
+        resolver.store("feeds.example.com", vec![addr]);
+        assert_eq!(resolver.cached("feeds.example.com"), Some(vec![addr]));
+        assert_eq!(resolver.cached("other.example.com"), None);
+
+        let expired = CachingResolver::new(Duration::ZERO);
+        expired.store("feeds.example.com", vec![addr]);
+        assert_eq!(expired.cached("feeds.example.com"), None);
+
+        let forever = CachingResolver::new(Duration::from_secs(u64::MAX));
+        forever.store("feeds.example.com", vec![addr]);
+        assert_eq!(forever.cached("feeds.example.com"), Some(vec![addr]));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_localhost() {
+        let resolver = CachingResolver::new(Duration::from_secs(60));
+        let name: Name = "localhost".parse().unwrap();
+
+        let addrs: Vec<SocketAddr> = resolver.resolve(name).await.unwrap().collect();
+        assert!(!addrs.is_empty());
+        assert!(resolver.cached("localhost").is_some());
+    }
+}
@@ -0,0 +1,263 @@
+use crate::aggregation::{add_matches, sort_by_date_desc, word_spans};
+use crate::types::{MatchField, NewsArticle, TermMatch};
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Parameters for an earnings news lookup
+///
+/// An empty symbol list matches every article; a date window keeps only articles
+/// published within it (inclusive, UTC). Articles without a parseable date are
+/// dropped when a window is set.
+#[derive(Debug, Clone, Default)]
+pub struct EarningsQuery {
+    pub symbols: Vec<String>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+impl EarningsQuery {
+    /// Create a query matching all earnings news
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a query for a list of ticker symbols
+    pub fn for_symbols<I, S>(symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::new().with_symbols(symbols)
+    }
+
+    /// Create a query for a single day
+    pub fn on(date: NaiveDate) -> Self {
+        Self::new().with_date_range(date, date)
+    }
+
+    /// Restrict results to these ticker symbols
+    pub fn with_symbols<I, S>(mut self, symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.symbols = symbols
+            .into_iter()
+            .map(|s| s.as_ref().trim().trim_start_matches('$').to_uppercase())
+            .filter(|s| !s.is_empty())
+            .collect();
+        self
+    }
+
+    /// Restrict results to articles published between `from` and `to` (inclusive)
+    ///
+    /// Bounds given in reverse order are swapped.
+    pub fn with_date_range(mut self, from: NaiveDate, to: NaiveDate) -> Self {
+        self.from = Some(from.min(to));
+        self.to = Some(from.max(to));
+        self
+    }
+
+    /// Check whether an article falls within the date window
+    pub fn matches_date(&self, article: &NewsArticle) -> bool {
+        if self.from.is_none() && self.to.is_none() {
+            return true;
+        }
+
+        match article.published_at() {
+            Some(published) => {
+                let day = published.date_naive();
+                self.from.is_none_or(|from| day >= from) && self.to.is_none_or(|to| day <= to)
+            }
+            None => false,
+        }
+    }
+
+    /// Get the requested symbols mentioned by an article
+    ///
+    /// Symbols are matched as whole uppercase words in the title and description
+    /// (so `$AAPL` and `(NASDAQ: AAPL)` both match), and against the
+    /// `extra_fields["symbols"]` list populated by some sources.
+    pub fn matched_symbols(&self, article: &NewsArticle) -> Vec<String> {
+        let mut tokens: Vec<&str> = [article.title.as_deref(), article.description.as_deref()]
+            .into_iter()
+            .flatten()
+            .flat_map(|text| text.split(|c: char| !(c.is_ascii_alphanumeric() || c == '.')))
+            .map(|token| token.trim_end_matches('.'))
+            .collect();
+        if let Some(symbols) = article.extra_fields.get("symbols") {
+            tokens.extend(symbols.as_str().split(',').map(str::trim));
+        }
+
+        self.symbols
+            .iter()
+            .filter(|symbol| tokens.contains(&symbol.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    /// Find the requested symbols in an article's title and description
+    ///
+    /// Matched as in `matched_symbols()`, except that symbols only listed in
+    /// `extra_fields["symbols"]` have no position in the text and are left out.
+    pub fn find_symbols(&self, article: &NewsArticle) -> Vec<TermMatch> {
+        let mut matches = Vec::new();
+        for field in [MatchField::Title, MatchField::Description] {
+            let Some(text) = field.text(article) else {
+                continue;
+            };
+            for (start, token) in word_spans(text, |c| c.is_ascii_alphanumeric() || c == '.') {
+                let token = token.trim_end_matches('.');
+                if self.symbols.iter().any(|symbol| symbol == token) {
+                    matches.push(TermMatch {
+                        term: token.to_string(),
+                        field,
+                        start,
+                        end: start + token.len(),
+                    });
+                }
+            }
+        }
+        matches
+    }
+}
+
+/// Earnings news grouped for an event-driven workflow
+///
+/// When the query names symbols, `groups` is keyed by ticker and an article that
+/// mentions several requested tickers appears under each of them, with the
+/// positions of the tickers in `NewsArticle::matches`. Otherwise
+/// `groups` is keyed by publication date (`YYYY-MM-DD`). Articles within a group
+/// are ordered newest first.
+#[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EarningsReport {
+    pub groups: BTreeMap<String, Vec<NewsArticle>>,
+}
+
+impl EarningsReport {
+    /// Filter and group articles according to a query
+    pub fn build(query: &EarningsQuery, articles: Vec<NewsArticle>) -> Self {
+        let mut groups: BTreeMap<String, Vec<NewsArticle>> = BTreeMap::new();
+
+        for article in articles.into_iter().filter(|a| query.matches_date(a)) {
+            if query.symbols.is_empty() {
+                let key = article
+                    .published_at()
+                    .map(|date| date.date_naive().to_string())
+                    .unwrap_or_else(|| "undated".to_string());
+                groups.entry(key).or_default().push(article);
+            } else {
+                let found = query.find_symbols(&article);
+                let mut article = article;
+                add_matches(&mut article, found);
+                for symbol in query.matched_symbols(&article) {
+                    groups.entry(symbol).or_default().push(article.clone());
+                }
+            }
+        }
+
+        for articles in groups.values_mut() {
+            sort_by_date_desc(articles);
+        }
+
+        Self { groups }
+    }
+
+    /// Total number of articles across all groups
+    pub fn total_articles(&self) -> usize {
+        self.groups.values().map(Vec::len).sum()
+    }
+
+    /// Check whether the report has no articles
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(title: &str, pub_date: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some(title.to_string());
+        article.pub_date = Some(pub_date.to_string());
+        article
+    }
+
+    #[test]
+    fn test_matched_symbols() {
+        let query = EarningsQuery::for_symbols(["aapl", "$MSFT", "NVDA"]);
+        let a = article(
+            "Apple Inc. (NASDAQ: AAPL) and MSFT report Q2 results",
+            "Thu, 02 May 2024 20:30:00 GMT",
+        );
+        assert_eq!(query.matched_symbols(&a), vec!["AAPL", "MSFT"]);
+
+        // Lowercase words and substrings do not match
+        let b = article("Snapple and msft chatter", "Thu, 02 May 2024 20:30:00 GMT");
+        assert!(query.matched_symbols(&b).is_empty());
+    }
+
+    #[test]
+    fn test_report_grouped_by_symbol_within_window() {
+        let query = EarningsQuery::for_symbols(["AAPL", "AMZN"]).with_date_range(
+            NaiveDate::from_ymd_opt(2024, 5, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 5, 3).unwrap(),
+        );
+        let articles = vec![
+            article("AAPL beats estimates", "Thu, 02 May 2024 20:30:00 GMT"),
+            article("AAPL guidance in focus", "2024-05-03T12:00:00Z"),
+            article("AAPL preview", "Mon, 29 Apr 2024 09:00:00 GMT"),
+            article(
+                "AMZN and AAPL lead after hours",
+                "Thu, 02 May 2024 21:00:00 GMT",
+            ),
+        ];
+
+        let report = EarningsReport::build(&query, articles);
+        assert_eq!(report.groups["AAPL"].len(), 3);
+        assert_eq!(
+            report.groups["AAPL"][0].title.as_deref(),
+            Some("AAPL guidance in focus")
+        );
+        assert_eq!(report.groups["AMZN"].len(), 1);
+        assert_eq!(report.total_articles(), 4);
+
+        let both = &report.groups["AMZN"][0];
+        let found: Vec<(&str, usize)> = both
+            .matches
+            .iter()
+            .map(|m| (m.term.as_str(), m.start))
+            .collect();
+        assert_eq!(found, [("AMZN", 0), ("AAPL", 9)]);
+    }
+
+    #[test]
+    fn test_date_range_in_reverse_order() {
+        let query = EarningsQuery::new().with_date_range(
+            NaiveDate::from_ymd_opt(2024, 5, 3).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 5, 2).unwrap(),
+        );
+        assert!(query.matches_date(&article("In", "Thu, 02 May 2024 20:30:00 GMT")));
+        assert!(!query.matches_date(&article("Out", "Mon, 29 Apr 2024 09:00:00 GMT")));
+    }
+
+    #[test]
+    fn test_report_grouped_by_date_without_symbols() {
+        let query = EarningsQuery::new();
+        let mut undated = NewsArticle::new();
+        undated.title = Some("Earnings season recap".to_string());
+        let articles = vec![
+            article("One", "Thu, 02 May 2024 20:30:00 GMT"),
+            article("Two", "Fri, 03 May 2024 08:00:00 GMT"),
+            undated,
+        ];
+
+        let report = EarningsReport::build(&query, articles);
+        let keys: Vec<&str> = report.groups.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["2024-05-02", "2024-05-03", "undated"]);
+    }
+}
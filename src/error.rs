@@ -6,6 +6,7 @@ pub type Result<T> = std::result::Result<T, FanError>;
 /// Error types for the FAN library
 #[derive(Error, Debug)]
 pub enum FanError {
+    #[cfg(feature = "fetch")]
     #[error("HTTP request failed: {0}")]
     Http(#[from] reqwest::Error),
 
@@ -15,15 +16,92 @@ pub enum FanError {
     #[error("JSON serialization failed: {0}")]
     JsonSerialization(#[from] serde_json::Error),
 
+    #[cfg(feature = "proto")]
+    #[error("Protobuf decoding failed: {0}")]
+    ProtoDecoding(#[from] prost::DecodeError),
+
+    #[cfg(feature = "sqlite")]
+    #[error("SQLite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+
+    #[cfg(feature = "parquet")]
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] parquet::errors::ParquetError),
+
+    #[cfg(feature = "duckdb")]
+    #[error("DuckDB error: {0}")]
+    DuckDb(#[from] duckdb::Error),
+
+    #[cfg(feature = "polars")]
+    #[error("Polars error: {0}")]
+    Polars(#[from] polars::prelude::PolarsError),
+
+    #[cfg(feature = "mqtt")]
+    #[error("MQTT publish failed: {0}")]
+    Mqtt(#[from] rumqttc::ClientError),
+
+    #[cfg(feature = "nats")]
+    #[error("NATS error: {0}")]
+    Nats(String),
+
+    #[cfg(feature = "object-store")]
+    #[error("Object store error: {0}")]
+    ObjectStore(#[from] object_store::Error),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    #[cfg(feature = "csv")]
+    #[error("CSV parsing failed: {0}")]
+    CsvParsing(#[from] csv::Error),
+
+    #[error("HTTP {status} from {url}: {snippet}")]
+    HttpStatus {
+        status: u16,
+        url: String,
+        /// Start of the response body, e.g. the text of an HTML error page
+        snippet: String,
+    },
+
+    #[error("Not a feed: {url} returned {content_type}")]
+    NotAFeed { url: String, content_type: String },
+
+    #[error("Not an image: {url} returned {content_type}")]
+    NotAnImage { url: String, content_type: String },
+
+    #[error("Too large: {url} is over {limit} bytes")]
+    TooLarge { url: String, limit: u64 },
+
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
 
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+
+    #[error("Invalid symbol: {0}")]
+    InvalidSymbol(String),
+
+    #[error("Invalid watchlist: {0}")]
+    InvalidWatchlist(String),
+
+    #[error("Invalid import: {0}")]
+    InvalidImport(String),
+
+    #[error("Unsupported schema version {found}: this release reads up to {supported}")]
+    UnsupportedSchema { found: u32, supported: u32 },
+
     #[error("Feed parsing error: {0}")]
     FeedParsing(String),
 
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    #[error("Missing API key: {0}")]
+    MissingApiKey(String),
+
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
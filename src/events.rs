@@ -0,0 +1,280 @@
+//! Typed events from the polling subsystem
+//!
+//! An [`EventBus`] broadcasts every [`Event`] to all of its subscribers, so a
+//! notifier, an article store, and a metrics exporter can each follow a `Poller`
+//! without knowing about one another. Attach a bus with `Poller::with_event_bus()`.
+//!
+//! Subscribers that fall more than the bus capacity behind miss the oldest events
+//! rather than slowing the poller down; `EventSubscriber::missed()` counts them.
+
+use crate::poller::ArticleVersion;
+use crate::types::NewsArticle;
+use crate::volume::AnomalyKind;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, Stream};
+use serde::Serialize;
+use tokio::sync::broadcast::{self, error::RecvError, error::TryRecvError};
+
+/// Events kept for slow subscribers unless set with `EventBus::with_capacity()`
+pub const DEFAULT_EVENT_CAPACITY: usize = 1024;
+
+/// Something a `Poller` observed
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    NewArticle(Box<NewArticle>),
+    ArticleUpdated(Box<ArticleUpdated>),
+    FeedDegraded(FeedDegraded),
+    FeedRecovered(FeedRecovered),
+    VolumeAnomaly(VolumeAnomaly),
+}
+
+impl Event {
+    /// Source the event is about
+    pub fn source(&self) -> &str {
+        match self {
+            Event::NewArticle(event) => &event.source,
+            Event::ArticleUpdated(event) => &event.source,
+            Event::FeedDegraded(event) => &event.source,
+            Event::FeedRecovered(event) => &event.source,
+            Event::VolumeAnomaly(event) => &event.source,
+        }
+    }
+
+    /// Topic the event is about
+    pub fn topic(&self) -> &str {
+        match self {
+            Event::NewArticle(event) => &event.topic,
+            Event::ArticleUpdated(event) => &event.topic,
+            Event::FeedDegraded(event) => &event.topic,
+            Event::FeedRecovered(event) => &event.topic,
+            Event::VolumeAnomaly(event) => &event.topic,
+        }
+    }
+}
+
+/// An article no earlier poll delivered
+#[derive(Debug, Clone, Serialize)]
+pub struct NewArticle {
+    pub source: String,
+    pub topic: String,
+    pub article: NewsArticle,
+}
+
+/// A delivered article its feed re-emitted with a changed title or description
+#[derive(Debug, Clone, Serialize)]
+pub struct ArticleUpdated {
+    pub source: String,
+    pub topic: String,
+    /// The article as the feed has it now, with `updated` set
+    pub article: NewsArticle,
+    /// Title and description the article had before
+    pub previous: ArticleVersion,
+}
+
+/// A feed that has started failing
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedDegraded {
+    pub source: String,
+    pub topic: String,
+    /// Failed polls in a row
+    pub consecutive_failures: u32,
+    /// Share of recent polls that succeeded
+    pub success_rate: f64,
+    /// Error of the latest failed poll
+    pub error: String,
+    /// Time of the first failed poll in the run
+    pub failing_since: DateTime<Utc>,
+}
+
+/// A degraded feed that is healthy again
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedRecovered {
+    pub source: String,
+    pub topic: String,
+    /// Time the feed was degraded
+    pub degraded_since: DateTime<Utc>,
+    /// Share of recent polls that succeeded
+    pub success_rate: f64,
+}
+
+/// A feed whose number of new articles jumped or dried up; see `volume::VolumePolicy`
+#[derive(Debug, Clone, Serialize)]
+pub struct VolumeAnomaly {
+    pub source: String,
+    pub topic: String,
+    pub kind: AnomalyKind,
+    /// New articles in the latest finished interval
+    pub articles: usize,
+    /// Mean new articles per interval before the anomaly
+    pub baseline: f64,
+    /// Start of the interval of a spike, or of the first empty interval of a silence
+    pub since: DateTime<Utc>,
+}
+
+/// Broadcasts events to any number of subscribers
+///
+/// Cloning a bus gives another handle to the same bus.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::events::{Event, EventBus, NewArticle};
+/// use finance_news_aggregator_rs::NewsArticle;
+///
+/// let bus = EventBus::new();
+/// let mut notifier = bus.subscribe();
+/// let mut store = bus.subscribe();
+///
+/// bus.publish(Event::NewArticle(Box::new(NewArticle {
+///     source: "CNBC".to_string(),
+///     topic: "earnings".to_string(),
+///     article: NewsArticle::new(),
+/// })));
+/// assert_eq!(notifier.try_recv().unwrap().source(), "CNBC");
+/// assert_eq!(store.try_recv().unwrap().topic(), "earnings");
+/// ```
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<Event>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    /// Create a bus keeping `DEFAULT_EVENT_CAPACITY` events for slow subscribers
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_EVENT_CAPACITY)
+    }
+
+    /// Create a bus keeping `capacity` events for slow subscribers
+    pub fn with_capacity(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self { sender }
+    }
+
+    /// Subscribe to the events published from now on
+    pub fn subscribe(&self) -> EventSubscriber {
+        EventSubscriber {
+            receiver: self.sender.subscribe(),
+            missed: 0,
+        }
+    }
+
+    /// Publish an event to every subscriber, returning how many there are
+    ///
+    /// An event published without subscribers is dropped.
+    pub fn publish(&self, event: Event) -> usize {
+        self.sender.send(event).unwrap_or(0)
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+/// Receiving end of an `EventBus` subscription
+#[derive(Debug)]
+pub struct EventSubscriber {
+    receiver: broadcast::Receiver<Event>,
+    missed: u64,
+}
+
+impl EventSubscriber {
+    /// Wait for the next event
+    ///
+    /// Returns `None` once every handle to the bus is dropped and the remaining
+    /// events are received.
+    pub async fn recv(&mut self) -> Option<Event> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(event) => return Some(event),
+                Err(RecvError::Lagged(missed)) => self.missed += missed,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Take the next event if one is waiting
+    pub fn try_recv(&mut self) -> Option<Event> {
+        loop {
+            match self.receiver.try_recv() {
+                Ok(event) => return Some(event),
+                Err(TryRecvError::Lagged(missed)) => self.missed += missed,
+                Err(TryRecvError::Empty | TryRecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Number of events this subscriber missed by falling behind
+    pub fn missed(&self) -> u64 {
+        self.missed
+    }
+
+    /// Receive the events as a stream
+    pub fn into_stream(self) -> impl Stream<Item = Event> {
+        stream::unfold(self, |mut subscriber| async move {
+            subscriber.recv().await.map(|event| (event, subscriber))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn degraded(topic: &str) -> Event {
+        Event::FeedDegraded(FeedDegraded {
+            source: "MarketWatch".to_string(),
+            topic: topic.to_string(),
+            consecutive_failures: 3,
+            success_rate: 0.8,
+            error: "HTTP 404".to_string(),
+            failing_since: Utc::now(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_every_subscriber_gets_every_event() {
+        let bus = EventBus::new();
+        let mut first = bus.subscribe();
+        let mut second = bus.subscribe();
+        assert_eq!(bus.publish(degraded("topstories")), 2);
+
+        assert_eq!(first.recv().await.unwrap().topic(), "topstories");
+        assert_eq!(second.recv().await.unwrap().topic(), "topstories");
+        assert!(first.try_recv().is_none());
+
+        drop(bus);
+        assert!(first.recv().await.is_none());
+    }
+
+    #[test]
+    fn test_slow_subscribers_miss_the_oldest_events() {
+        let bus = EventBus::with_capacity(2);
+        let mut subscriber = bus.subscribe();
+        for topic in ["a", "b", "c"] {
+            bus.publish(degraded(topic));
+        }
+
+        assert_eq!(subscriber.try_recv().unwrap().topic(), "b");
+        assert_eq!(subscriber.try_recv().unwrap().topic(), "c");
+        assert_eq!(subscriber.missed(), 1);
+    }
+
+    #[test]
+    fn test_events_serialize_with_their_type() {
+        let json = serde_json::to_value(degraded("topstories")).unwrap();
+        assert_eq!(json["type"], "feed_degraded");
+        assert_eq!(json["consecutive_failures"], 3);
+    }
+
+    #[test]
+    fn test_publish_without_subscribers() {
+        assert_eq!(EventBus::new().publish(degraded("topstories")), 0);
+    }
+}
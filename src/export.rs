@@ -0,0 +1,261 @@
+//! Exporting articles as files for note-taking tools
+//!
+//! [`write_markdown()`] writes one Markdown note per article into a directory, such
+//! as an Obsidian vault, with YAML front matter for the source, tickers, and date:
+//!
+//! ```markdown
+//! ---
+//! title: "Apple beats on iPhone sales"
+//! source: "CNBC"
+//! date: 2024-05-02T20:30:00+00:00
+//! link: "https://www.cnbc.com/2024/05/02/apple-earnings.html"
+//! tickers: [AAPL]
+//! ---
+//!
+//! # Apple beats on iPhone sales
+//!
+//! Apple reported fiscal second-quarter results...
+//!
+//! [Read on CNBC](https://www.cnbc.com/2024/05/02/apple-earnings.html)
+//! ```
+//!
+//! Notes are named after the publication date and title, so exporting the same
+//! articles again overwrites their notes instead of adding copies.
+
+use crate::error::Result;
+use crate::types::NewsArticle;
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Longest title part of a note's file name, in characters
+const MAX_FILE_TITLE_CHARS: usize = 100;
+
+/// Write one Markdown note per article into `dir`, creating the directory if needed
+///
+/// Articles with the same link are written once.
+///
+/// # Returns
+/// Paths of the notes written, in article order
+pub fn write_markdown(articles: &[NewsArticle], dir: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir)?;
+
+    let mut links = HashSet::new();
+    let mut names = HashSet::new();
+    let mut paths = Vec::new();
+    for article in articles {
+        if article
+            .link
+            .as_ref()
+            .is_some_and(|link| !links.insert(link.as_str()))
+        {
+            continue;
+        }
+        // Different articles with the same date and title get numbered notes
+        let stem = note_name(article);
+        let name = (1..)
+            .map(|n| match n {
+                1 => format!("{}.md", stem),
+                n => format!("{} ({}).md", stem, n),
+            })
+            .find(|name| !names.contains(name))
+            .unwrap_or_default();
+        names.insert(name.clone());
+
+        let path = dir.join(name);
+        fs::write(&path, to_markdown(article))?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+/// Render an article as a Markdown note with YAML front matter
+pub fn to_markdown(article: &NewsArticle) -> String {
+    let title = article.title.as_deref().unwrap_or("Untitled").trim();
+    let mut note = String::from("---\n");
+    let mut field = |name: &str, value: Option<String>| {
+        if let Some(value) = value {
+            let _ = writeln!(note, "{}: {}", name, value);
+        }
+    };
+    field("title", Some(yaml_string(title)));
+    field("source", article.source.as_deref().map(yaml_string));
+    field("author", article.author.as_deref().map(yaml_string));
+    field(
+        "date",
+        article
+            .published_at()
+            .map(|published| published.to_rfc3339())
+            .or_else(|| article.pub_date.as_deref().map(yaml_string)),
+    );
+    field("link", article.link.as_deref().map(yaml_string));
+    field("category", article.category.as_deref().map(yaml_string));
+    let tickers = tickers(article);
+    field(
+        "tickers",
+        (!tickers.is_empty()).then(|| {
+            let tickers: Vec<&str> = tickers.iter().map(String::as_str).collect();
+            format!("[{}]", tickers.join(", "))
+        }),
+    );
+    note.push_str("---\n\n");
+
+    let _ = writeln!(note, "# {}\n", title);
+    if let Some(description) = article
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|description| !description.is_empty())
+    {
+        let _ = writeln!(note, "{}\n", description);
+    }
+    if let Some(content) = article.extra("content", "encoded") {
+        let _ = writeln!(note, "{}\n", content.as_str().trim());
+    }
+    if let Some(link) = &article.link {
+        let source = article.source.as_deref().unwrap_or("the web");
+        let _ = writeln!(note, "[Read on {}]({})", source, link);
+    }
+    note
+}
+
+/// Tickers an article is about: `extra_fields["symbols"]` and `$CASHTAGS` in its text
+pub(crate) fn tickers(article: &NewsArticle) -> BTreeSet<String> {
+    let mut tickers: BTreeSet<String> = article
+        .extra_fields
+        .get("symbols")
+        .map(|symbols| symbols.values().iter().flat_map(|value| value.split(',')))
+        .into_iter()
+        .flatten()
+        .map(|symbol| symbol.trim().to_uppercase())
+        .filter(|symbol| !symbol.is_empty())
+        .collect();
+    let text = [article.title.as_deref(), article.description.as_deref()];
+    for text in text.into_iter().flatten() {
+        for tag in text.split('$').skip(1) {
+            let symbol: String = tag
+                .chars()
+                .take_while(|c| c.is_ascii_uppercase() || *c == '.')
+                .collect();
+            let symbol = symbol.trim_end_matches('.');
+            if (1..=6).contains(&symbol.len()) {
+                tickers.insert(symbol.to_string());
+            }
+        }
+    }
+    tickers
+}
+
+/// File name of an article's note, without the extension
+///
+/// The publication date and the title, with characters that are not allowed in file
+/// names or that Obsidian treats as link syntax replaced.
+fn note_name(article: &NewsArticle) -> String {
+    let title: String = article
+        .title
+        .as_deref()
+        .unwrap_or("Untitled")
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']' => '-',
+            c if c.is_control() => ' ',
+            c => c,
+        })
+        .take(MAX_FILE_TITLE_CHARS)
+        .collect();
+    let title = title.trim().trim_matches('.');
+    let title = if title.is_empty() { "Untitled" } else { title };
+    match article.published_at() {
+        Some(published) => format!("{} {}", published.format("%Y-%m-%d"), title),
+        None => title.to_string(),
+    }
+}
+
+/// A double-quoted YAML string
+fn yaml_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' | '\t' => quoted.push(' '),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(title: &str, link: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some(title.to_string());
+        article.link = Some(link.to_string());
+        article.source = Some("CNBC".to_string());
+        article.pub_date = Some("Thu, 02 May 2024 20:30:00 GMT".to_string());
+        article
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let mut apple = article(
+            "Apple \"beats\" on iPhone sales",
+            "https://www.cnbc.com/apple",
+        );
+        apple.description = Some("Shares of $AAPL rose; $MSFT. fell".to_string());
+        apple
+            .extra_fields
+            .insert("symbols".to_string(), "AAPL,NVDA".into());
+
+        assert_eq!(
+            to_markdown(&apple),
+            "---\n\
+             title: \"Apple \\\"beats\\\" on iPhone sales\"\n\
+             source: \"CNBC\"\n\
+             date: 2024-05-02T20:30:00+00:00\n\
+             link: \"https://www.cnbc.com/apple\"\n\
+             tickers: [AAPL, MSFT, NVDA]\n\
+             ---\n\n\
+             # Apple \"beats\" on iPhone sales\n\n\
+             Shares of $AAPL rose; $MSFT. fell\n\n\
+             [Read on CNBC](https://www.cnbc.com/apple)\n"
+        );
+    }
+
+    #[test]
+    fn test_write_markdown() {
+        let dir = std::env::temp_dir().join(format!("fan-markdown-{}", std::process::id()));
+        let articles = vec![
+            article("Fed: rates on hold?", "https://example.com/1"),
+            article("Fed: rates on hold?", "https://example.com/1"),
+            article("Fed: rates on hold?", "https://example.com/2"),
+        ];
+
+        let paths = write_markdown(&articles, &dir).unwrap();
+        let names: Vec<_> = paths
+            .iter()
+            .map(|path| path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "2024-05-02 Fed- rates on hold-.md",
+                "2024-05-02 Fed- rates on hold- (2).md",
+            ]
+        );
+        assert!(
+            fs::read_to_string(&paths[1])
+                .unwrap()
+                .contains("link: \"https://example.com/2\"")
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
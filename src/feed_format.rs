@@ -0,0 +1,526 @@
+use crate::error::{FanError, Result};
+use crate::parser::{LimitGuard, NewsParser, ParserLimits};
+use crate::types::{Enclosure, NewsArticle};
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use serde::Deserialize;
+
+/// Syndication formats understood by `GenericSource`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    /// RSS 2.0 (`<rss>` root)
+    Rss2,
+    /// RSS 1.0 / RDF Site Summary (`<rdf:RDF>` root)
+    Rss1,
+    /// Atom 1.0 (`<feed>` root)
+    Atom,
+    /// JSON Feed 1.x
+    JsonFeed,
+}
+
+impl FeedFormat {
+    /// Work out the format of a feed body
+    ///
+    /// The body decides: JSON bodies are JSON Feeds and XML bodies are identified by
+    /// their root element. The `Content-Type` header is only used when the root element
+    /// is not a known feed root, since many servers label feeds `text/html` or
+    /// `text/xml`.
+    ///
+    /// # Arguments
+    /// * `content_type` - The response's `Content-Type` header, if any
+    /// * `body` - The response body
+    ///
+    /// # Returns
+    /// The detected format, or `None` when the body is not a recognizable feed
+    pub fn detect(content_type: Option<&str>, body: &str) -> Option<Self> {
+        let body = body.trim_start_matches('\u{feff}').trim_start();
+        if body.starts_with('{') {
+            return Some(Self::JsonFeed);
+        }
+
+        let root = root_element(body);
+        match root.as_deref() {
+            Some("rss") => Some(Self::Rss2),
+            Some("RDF") => Some(Self::Rss1),
+            Some("feed") => Some(Self::Atom),
+            // Not XML at all, e.g. an HTML error page that failed to parse
+            None => None,
+            Some(_) => Self::from_content_type(content_type?),
+        }
+    }
+
+    /// Map a `Content-Type` header to the format it announces
+    fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next()?.trim();
+        match mime.to_ascii_lowercase().as_str() {
+            "application/rss+xml" => Some(Self::Rss2),
+            "application/rdf+xml" => Some(Self::Rss1),
+            "application/atom+xml" => Some(Self::Atom),
+            "application/feed+json" | "application/json" => Some(Self::JsonFeed),
+            _ => None,
+        }
+    }
+
+    /// Parse a feed body in this format
+    ///
+    /// RSS bodies are handed to `parser`; Atom and JSON Feed entries are mapped onto the
+    /// same `NewsArticle` fields RSS items use.
+    pub fn parse(self, parser: &NewsParser, body: &str) -> Result<Vec<NewsArticle>> {
+        match self {
+            Self::Rss2 => parser.parse_response(body),
+            Self::Rss1 => {
+                let mut articles = parser.parse_response(body)?;
+                // RSS 1.0 items are dated with Dublin Core instead of <pubDate>
+                for article in &mut articles {
+                    if article.pub_date.is_none() {
+                        article.pub_date = article
+                            .extra_fields
+                            .remove("dc:date")
+                            .map(|date| date.as_str().to_string());
+                    }
+                }
+                Ok(articles)
+            }
+            Self::Atom => parse_atom(body, parser.limits()),
+            Self::JsonFeed => parse_json_feed(body),
+        }
+    }
+
+    /// Link to the next page of a paginated feed in this format
+    ///
+    /// JSON Feeds announce it in `next_url`; RSS and Atom feeds in a `rel="next"`
+    /// link. The link is returned as written and may be relative.
+    pub fn next_page_link(self, parser: &NewsParser, body: &str) -> Option<String> {
+        match self {
+            Self::JsonFeed => serde_json::from_str::<JsonFeed>(body).ok()?.next_url,
+            Self::Rss2 | Self::Rss1 | Self::Atom => parser.parse_next_link(body),
+        }
+    }
+}
+
+/// Local name of the first element in an XML document
+fn root_element(body: &str) -> Option<String> {
+    let mut reader = Reader::from_str(body);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) => {
+                let name = e.local_name();
+                return std::str::from_utf8(name.as_ref()).ok().map(str::to_string);
+            }
+            Ok(Event::Decl(_))
+            | Ok(Event::Comment(_))
+            | Ok(Event::PI(_))
+            | Ok(Event::DocType(_)) => {}
+            Ok(Event::Text(e)) if e.iter().all(u8::is_ascii_whitespace) => {}
+            _ => return None,
+        }
+    }
+}
+
+/// An Atom entry while it is being read
+#[derive(Default)]
+struct AtomEntry {
+    article: NewsArticle,
+    summary: Option<String>,
+    content: Option<String>,
+    published: Option<String>,
+    updated: Option<String>,
+}
+
+impl AtomEntry {
+    /// Apply the text of an element inside the entry
+    fn push_text(&mut self, tag: &str, in_author: bool, text: &str) {
+        let field = match (tag, in_author) {
+            ("name", true) => &mut self.article.author,
+            ("title", false) => &mut self.article.title,
+            ("id", false) => &mut self.article.guid,
+            ("summary", false) => &mut self.summary,
+            ("content", false) => &mut self.content,
+            ("published", false) => &mut self.published,
+            ("updated", false) => &mut self.updated,
+            _ => return,
+        };
+        append(field, text);
+    }
+
+    /// Finish the entry
+    ///
+    /// The summary is preferred over full content, and the publication date over the
+    /// last update (which Atom requires, unlike `published`).
+    fn into_article(self) -> NewsArticle {
+        let mut article = self.article;
+        article.description = self.summary.or(self.content);
+        article.pub_date = self.published.or(self.updated);
+        for field in [
+            &mut article.title,
+            &mut article.guid,
+            &mut article.author,
+            &mut article.description,
+            &mut article.pub_date,
+        ] {
+            *field = field.take().map(|value| value.trim().to_string());
+        }
+        article
+    }
+}
+
+/// Parse the `<entry>` elements of an Atom feed
+fn parse_atom(body: &str, limits: ParserLimits) -> Result<Vec<NewsArticle>> {
+    // Text is not trimmed while reading so that entity references keep their spacing
+    let mut reader = Reader::from_str(body);
+
+    let mut articles = Vec::new();
+    let mut entry: Option<AtomEntry> = None;
+    let mut tag = String::new();
+    let mut in_author = false;
+    let mut guard = LimitGuard::new(limits);
+
+    loop {
+        let event = reader.read_event().map_err(FanError::XmlParsing)?;
+        guard.check(&event)?;
+        match event {
+            Event::Eof => break,
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                tag = local_name(e);
+                match (tag.as_str(), entry.as_mut()) {
+                    ("entry", _) => entry = Some(AtomEntry::default()),
+                    ("author", Some(_)) => in_author = !is_empty,
+                    ("link", Some(entry)) => set_atom_link(&mut entry.article, e),
+                    ("category", Some(entry)) if entry.article.category.is_none() => {
+                        entry.article.category = attribute(e, b"term");
+                    }
+                    _ => {}
+                }
+                if is_empty {
+                    tag.clear();
+                }
+            }
+            Event::Text(e) => {
+                if let Some(entry) = entry.as_mut() {
+                    let text = e.decode().map_err(quick_xml::Error::from)?;
+                    entry.push_text(&tag, in_author, &text);
+                }
+            }
+            Event::CData(e) => {
+                if let Some(entry) = entry.as_mut() {
+                    let text = e.decode().map_err(quick_xml::Error::from)?;
+                    entry.push_text(&tag, in_author, &text);
+                }
+            }
+            Event::GeneralRef(e) => {
+                if let Some(entry) = entry.as_mut() {
+                    let name = e.decode().map_err(quick_xml::Error::from)?;
+                    let resolved = match e.resolve_char_ref()? {
+                        Some(ch) => ch.to_string(),
+                        None => resolve_predefined_entity(&name).unwrap_or("").to_string(),
+                    };
+                    entry.push_text(&tag, in_author, &resolved);
+                }
+            }
+            Event::End(ref e) => {
+                match e.local_name().as_ref() {
+                    b"entry" => articles.extend(entry.take().map(AtomEntry::into_article)),
+                    b"author" => in_author = false,
+                    _ => {}
+                }
+                tag.clear();
+            }
+            _ => {}
+        }
+    }
+
+    Ok(articles)
+}
+
+/// Record an entry's `<link>`, preferring `rel="alternate"` links
+///
+/// `rel="enclosure"` links are kept as enclosures.
+fn set_atom_link(article: &mut NewsArticle, element: &BytesStart) {
+    let rel = attribute(element, b"rel");
+    match rel.as_deref() {
+        Some("enclosure") => {
+            if let Some(url) = attribute(element, b"href") {
+                article.enclosures.push(Enclosure {
+                    mime_type: attribute(element, b"type"),
+                    length: attribute(element, b"length").and_then(|length| length.parse().ok()),
+                    ..Enclosure::new(&url)
+                });
+            }
+        }
+        None | Some("alternate") if article.link.is_none() => {
+            article.link = attribute(element, b"href");
+        }
+        _ => {}
+    }
+}
+
+fn local_name(element: &BytesStart) -> String {
+    String::from_utf8_lossy(element.local_name().as_ref()).into_owned()
+}
+
+fn attribute(element: &BytesStart, key: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.local_name().as_ref() == key)
+        .and_then(|attr| attr.unescape_value().ok())
+        .map(|value| value.into_owned())
+}
+
+fn append(field: &mut Option<String>, text: &str) {
+    match field {
+        Some(existing) => existing.push_str(text),
+        None => *field = Some(text.to_string()),
+    }
+}
+
+/// Top level of a JSON Feed document
+#[derive(Debug, Deserialize)]
+struct JsonFeed {
+    #[serde(default)]
+    items: Vec<JsonFeedItem>,
+    next_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonFeedItem {
+    id: Option<serde_json::Value>,
+    url: Option<String>,
+    external_url: Option<String>,
+    title: Option<String>,
+    summary: Option<String>,
+    content_text: Option<String>,
+    content_html: Option<String>,
+    image: Option<String>,
+    date_published: Option<String>,
+    date_modified: Option<String>,
+    /// JSON Feed 1.1
+    #[serde(default)]
+    authors: Vec<JsonFeedAuthor>,
+    /// JSON Feed 1.0
+    author: Option<JsonFeedAuthor>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    attachments: Vec<JsonFeedAttachment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonFeedAttachment {
+    url: String,
+    mime_type: Option<String>,
+    size_in_bytes: Option<u64>,
+    duration_in_seconds: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonFeedAuthor {
+    name: Option<String>,
+}
+
+/// Parse the items of a JSON Feed
+fn parse_json_feed(body: &str) -> Result<Vec<NewsArticle>> {
+    let feed: JsonFeed = serde_json::from_str(body)?;
+
+    Ok(feed
+        .items
+        .into_iter()
+        .map(|item| {
+            let mut article = NewsArticle::new();
+            // Ids are strings in the spec, but some publishers emit numbers
+            article.guid = item.id.map(|id| match id {
+                serde_json::Value::String(id) => id,
+                other => other.to_string(),
+            });
+            article.link = item.url.or(item.external_url);
+            article.title = item.title;
+            article.description = item.summary.or(item.content_text).or(item.content_html);
+            article.pub_date = item.date_published.or(item.date_modified);
+            article.author = item
+                .authors
+                .into_iter()
+                .chain(item.author)
+                .find_map(|author| author.name);
+            article.category = item.tags.into_iter().next();
+            if let Some(image) = item.image {
+                article
+                    .extra_fields
+                    .insert("thumbnail".to_string(), image.into());
+            }
+            article.enclosures = item
+                .attachments
+                .into_iter()
+                .map(|attachment| Enclosure {
+                    mime_type: attachment.mime_type,
+                    length: attachment.size_in_bytes,
+                    duration_seconds: attachment.duration_in_seconds.map(|s| s as u64),
+                    ..Enclosure::new(&attachment.url)
+                })
+                .collect();
+            article
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        let rss = r#"<?xml version="1.0"?><rss version="2.0"><channel></channel></rss>"#;
+        let rdf = r#"<?xml version="1.0"?>
+            <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"></rdf:RDF>"#;
+        let atom = r#"<!-- generated --><feed xmlns="http://www.w3.org/2005/Atom"></feed>"#;
+
+        assert_eq!(FeedFormat::detect(None, rss), Some(FeedFormat::Rss2));
+        assert_eq!(FeedFormat::detect(None, rdf), Some(FeedFormat::Rss1));
+        // The root element wins over a wrong header
+        assert_eq!(
+            FeedFormat::detect(Some("text/html; charset=utf-8"), atom),
+            Some(FeedFormat::Atom)
+        );
+        assert_eq!(
+            FeedFormat::detect(
+                None,
+                "\u{feff} {\"version\": \"https://jsonfeed.org/version/1.1\"}"
+            ),
+            Some(FeedFormat::JsonFeed)
+        );
+        assert_eq!(
+            FeedFormat::detect(Some("application/rss+xml"), "<channel></channel>"),
+            Some(FeedFormat::Rss2)
+        );
+        assert_eq!(FeedFormat::detect(None, "<html><body></body></html>"), None);
+        assert_eq!(
+            FeedFormat::detect(Some("application/rss+xml"), "Not found"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_atom() {
+        let atom = r#"<?xml version="1.0" encoding="utf-8"?>
+            <feed xmlns="http://www.w3.org/2005/Atom">
+              <title>Example</title>
+              <entry>
+                <title>Bonds &amp; stocks rally</title>
+                <link rel="self" href="https://example.com/self"/>
+                <link href="https://example.com/bonds"/>
+                <link rel="enclosure" type="audio/mpeg" length="1000" href="https://example.com/bonds.mp3"/>
+                <id>urn:uuid:1225c695</id>
+                <updated>2024-05-01T15:00:00Z</updated>
+                <published>2024-05-01T14:30:00Z</published>
+                <author><name>Jane Reporter</name></author>
+                <category term="markets"/>
+                <content type="html">&lt;p&gt;Full text&lt;/p&gt;</content>
+                <summary>Short summary</summary>
+              </entry>
+              <entry>
+                <title>Second</title>
+                <updated>2024-05-01T12:00:00Z</updated>
+                <content type="html"><![CDATA[<p>Only content</p>]]></content>
+              </entry>
+            </feed>"#;
+
+        let articles = FeedFormat::Atom
+            .parse(&NewsParser::new("generic"), atom)
+            .unwrap();
+        assert_eq!(articles.len(), 2);
+
+        let first = &articles[0];
+        assert_eq!(first.title.as_deref(), Some("Bonds & stocks rally"));
+        assert_eq!(first.link.as_deref(), Some("https://example.com/bonds"));
+        assert_eq!(first.guid.as_deref(), Some("urn:uuid:1225c695"));
+        assert_eq!(first.pub_date.as_deref(), Some("2024-05-01T14:30:00Z"));
+        assert_eq!(first.author.as_deref(), Some("Jane Reporter"));
+        assert_eq!(first.category.as_deref(), Some("markets"));
+        assert_eq!(first.description.as_deref(), Some("Short summary"));
+        assert_eq!(first.audio().unwrap().length, Some(1000));
+
+        let second = &articles[1];
+        assert_eq!(second.pub_date.as_deref(), Some("2024-05-01T12:00:00Z"));
+        assert_eq!(second.description.as_deref(), Some("<p>Only content</p>"));
+        assert!(second.author.is_none());
+    }
+
+    #[test]
+    fn test_parse_json_feed() {
+        let feed = r#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Example",
+            "next_url": "https://example.com/feed.json?page=2",
+            "items": [
+                {
+                    "id": "https://example.com/1",
+                    "url": "https://example.com/1",
+                    "title": "Fed holds rates",
+                    "content_text": "The Fed...",
+                    "image": "https://example.com/1.jpg",
+                    "date_published": "2024-05-01T14:30:00Z",
+                    "authors": [{"name": "Jane Reporter"}],
+                    "tags": ["macro", "fed"]
+                },
+                {
+                    "id": 2,
+                    "external_url": "https://other.example.com/2",
+                    "content_html": "<p>Hi</p>",
+                    "attachments": [{"url": "https://example.com/2.m4a", "mime_type": "audio/x-m4a", "duration_in_seconds": 61.5}]
+                }
+            ]
+        }"#;
+
+        let articles = FeedFormat::JsonFeed
+            .parse(&NewsParser::new("generic"), feed)
+            .unwrap();
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0].author.as_deref(), Some("Jane Reporter"));
+        assert_eq!(articles[0].category.as_deref(), Some("macro"));
+        assert_eq!(
+            articles[0].extra_fields["thumbnail"],
+            "https://example.com/1.jpg"
+        );
+        assert_eq!(articles[1].guid.as_deref(), Some("2"));
+        assert_eq!(
+            articles[1].link.as_deref(),
+            Some("https://other.example.com/2")
+        );
+        assert_eq!(articles[1].description.as_deref(), Some("<p>Hi</p>"));
+        assert_eq!(articles[1].audio().unwrap().duration_seconds, Some(61));
+
+        let parser = NewsParser::new("generic");
+        assert_eq!(
+            FeedFormat::JsonFeed
+                .next_page_link(&parser, feed)
+                .as_deref(),
+            Some("https://example.com/feed.json?page=2")
+        );
+    }
+
+    #[test]
+    fn test_parse_rss1_uses_dublin_core_date() {
+        let rdf = r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+                xmlns="http://purl.org/rss/1.0/" xmlns:dc="http://purl.org/dc/elements/1.1/">
+              <channel rdf:about="https://example.com/"><title>Example</title></channel>
+              <item rdf:about="https://example.com/1">
+                <title>Oil slips</title>
+                <link>https://example.com/1</link>
+                <dc:date>2024-05-01T14:30:00Z</dc:date>
+                <dc:creator>Jane Reporter</dc:creator>
+              </item>
+            </rdf:RDF>"#;
+
+        let articles = FeedFormat::Rss1
+            .parse(&NewsParser::new("generic"), rdf)
+            .unwrap();
+        assert_eq!(articles.len(), 1);
+        assert_eq!(
+            articles[0].pub_date.as_deref(),
+            Some("2024-05-01T14:30:00Z")
+        );
+        assert_eq!(articles[0].author.as_deref(), Some("Jane Reporter"));
+        assert!(articles[0].published_at().is_some());
+    }
+}
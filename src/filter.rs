@@ -0,0 +1,629 @@
+//! Boolean query language for filtering articles
+//!
+//! A [`Query`] combines keywords, phrases and field terms with `AND`, `OR`, `NOT`
+//! and parentheses:
+//!
+//! ```text
+//! ("rate cut" OR FOMC) AND NOT crypto ticker:NVDA since:6h
+//! ```
+//!
+//! | Term | Matches articles |
+//! |------|------------------|
+//! | `fed`, `"rate cut"` | mentioning the word or phrase in the title or description, as in `aggregation::mentions_any()` |
+//! | `ticker:NVDA` | mentioning the ticker, as in `EarningsQuery::matched_symbols()` |
+//! | `source:CNBC`, `source:"Seeking Alpha"` | from the source, ignoring case |
+//! | `event:FOMC` | tagged with the macro event by `macro_events::MacroCalendar` |
+//! | `kind:opinion` | labeled with the kind by a `content_kind::ContentClassifier` |
+//! | `priority:high` | of the priority or above, as set by a `breaking::BreakingDetector` |
+//! | `since:6h` | published within the last 30s, 15m, 6h, 2d or 1w |
+//!
+//! Operators are uppercase; terms written next to each other must all match. `NOT`
+//! binds tightest, then `AND`, then `OR`.
+//!
+//! A `Query` displays and serializes as its query string, so it can be kept in config
+//! files such as the alert rules of `alerts::AlertRule`.
+
+use crate::aggregation::{add_matches, find_keywords};
+use crate::breaking::Priority;
+use crate::content_kind::ContentKind;
+use crate::earnings::EarningsQuery;
+use crate::error::{FanError, Result};
+use crate::macro_events::MACRO_EVENT_FIELD;
+use crate::types::NewsArticle;
+use chrono::{DateTime, Utc};
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::iter::Peekable;
+use std::str::FromStr;
+use std::time::Duration;
+use std::vec::IntoIter;
+
+/// A parsed filter query
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    /// A word or phrase in the title or description
+    Keyword(String),
+    /// A ticker symbol mentioned by the article
+    Ticker(String),
+    /// The article's source name
+    Source(String),
+    /// A macro event the article is tagged with
+    Event(String),
+    /// The kind of piece the article is labeled as
+    Kind(ContentKind),
+    /// Of the priority or a higher one
+    Priority(Priority),
+    /// Published at most this long ago
+    Since(Duration),
+    Not(Box<Query>),
+    And(Vec<Query>),
+    Or(Vec<Query>),
+}
+
+impl Query {
+    /// Parse a query
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finance_news_aggregator_rs::filter::Query;
+    /// use finance_news_aggregator_rs::NewsArticle;
+    ///
+    /// let query = Query::parse(r#"("rate cut" OR FOMC) AND NOT crypto"#).unwrap();
+    ///
+    /// let mut article = NewsArticle::new();
+    /// article.title = Some("FOMC minutes point to a rate cut".to_string());
+    /// assert!(query.matches(&article));
+    /// ```
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut tokens = tokenize(input)?.into_iter().peekable();
+        if tokens.peek().is_none() {
+            return Err(FanError::InvalidQuery("empty query".to_string()));
+        }
+        let query = parse_or(&mut tokens)?;
+        match tokens.next() {
+            None => Ok(query),
+            Some(token) => Err(FanError::InvalidQuery(format!("unexpected {}", token))),
+        }
+    }
+
+    /// Whether an article matches the query now
+    pub fn matches(&self, article: &NewsArticle) -> bool {
+        self.matches_at(article, Utc::now())
+    }
+
+    /// Whether an article matches the query, with `since:` terms measured from `now`
+    pub fn matches_at(&self, article: &NewsArticle, now: DateTime<Utc>) -> bool {
+        match self {
+            Self::Keyword(keyword) => !find_keywords(article, &[keyword]).is_empty(),
+            Self::Ticker(symbol) => !EarningsQuery::for_symbols([symbol])
+                .matched_symbols(article)
+                .is_empty(),
+            Self::Source(source) => article
+                .source
+                .as_deref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(source)),
+            Self::Event(event) => article
+                .extra_fields
+                .get(MACRO_EVENT_FIELD)
+                .is_some_and(|tags| {
+                    tags.values()
+                        .iter()
+                        .any(|tag| tag.eq_ignore_ascii_case(event))
+                }),
+            Self::Kind(kind) => article.content_kind() == Some(*kind),
+            Self::Priority(priority) => article.priority >= *priority,
+            Self::Since(age) => article
+                .age_at(now)
+                .is_some_and(|article_age| article_age <= *age),
+            Self::Not(query) => !query.matches_at(article, now),
+            Self::And(queries) => queries.iter().all(|query| query.matches_at(article, now)),
+            Self::Or(queries) => queries.iter().any(|query| query.matches_at(article, now)),
+        }
+    }
+
+    /// Keep the matching articles, recording their keyword and ticker matches
+    ///
+    /// Terms under a `NOT` are not recorded in `NewsArticle::matches`.
+    pub fn filter(&self, articles: Vec<NewsArticle>) -> Vec<NewsArticle> {
+        let now = Utc::now();
+        articles
+            .into_iter()
+            .filter(|article| self.matches_at(article, now))
+            .map(|mut article| {
+                self.highlight(&mut article);
+                article
+            })
+            .collect()
+    }
+
+    /// Record where the query's keywords and tickers appear in an article
+    fn highlight(&self, article: &mut NewsArticle) {
+        match self {
+            Self::Keyword(keyword) => add_matches(article, find_keywords(article, &[keyword])),
+            Self::Ticker(symbol) => {
+                let found = EarningsQuery::for_symbols([symbol]).find_symbols(article);
+                add_matches(article, found);
+            }
+            Self::And(queries) | Self::Or(queries) => {
+                for query in queries {
+                    query.highlight(article);
+                }
+            }
+            Self::Source(_)
+            | Self::Event(_)
+            | Self::Kind(_)
+            | Self::Priority(_)
+            | Self::Since(_)
+            | Self::Not(_) => {}
+        }
+    }
+}
+
+impl FromStr for Query {
+    type Err = FanError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+}
+
+/// Writes the query in the syntax `parse()` reads back
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Keyword(keyword) => write_text(f, keyword),
+            Self::Ticker(symbol) => {
+                write!(f, "ticker:")?;
+                write_text(f, symbol)
+            }
+            Self::Source(source) => {
+                write!(f, "source:")?;
+                write_text(f, source)
+            }
+            Self::Event(event) => {
+                write!(f, "event:")?;
+                write_text(f, event)
+            }
+            Self::Kind(kind) => write!(f, "kind:{}", kind),
+            Self::Priority(priority) => write!(f, "priority:{}", priority),
+            Self::Since(age) => write_age(f, *age),
+            Self::Not(query) => {
+                write!(f, "NOT ")?;
+                write_operand(f, query)
+            }
+            Self::And(queries) | Self::Or(queries) => {
+                let operator = if matches!(self, Self::And(_)) {
+                    " AND "
+                } else {
+                    " OR "
+                };
+                for (i, query) in queries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "{}", operator)?;
+                    }
+                    match (self, query) {
+                        (Self::And(_), _) => write_operand(f, query)?,
+                        (_, Self::Or(_)) => write!(f, "({})", query)?,
+                        _ => write!(f, "{}", query)?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Serialized as its query string
+impl Serialize for Query {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Query {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        Self::parse(&input).map_err(de::Error::custom)
+    }
+}
+
+/// Write a word bare, or quoted when it would not read back as the same word
+fn write_text(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    let bare = !text.is_empty()
+        && !matches!(text, "AND" | "OR" | "NOT")
+        && !text.contains(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | '"' | ':'));
+    if bare {
+        write!(f, "{}", text)
+    } else {
+        write!(f, "\"{}\"", text)
+    }
+}
+
+/// Write an operand of `AND` or `NOT`, in parentheses when it is a list
+fn write_operand(f: &mut fmt::Formatter<'_>, query: &Query) -> fmt::Result {
+    match query {
+        Query::And(_) | Query::Or(_) => write!(f, "({})", query),
+        _ => write!(f, "{}", query),
+    }
+}
+
+/// Write an age in its largest whole unit
+fn write_age(f: &mut fmt::Formatter<'_>, age: Duration) -> fmt::Result {
+    let seconds = age.as_secs();
+    let (count, unit) = [(7 * 86_400, "w"), (86_400, "d"), (3600, "h"), (60, "m")]
+        .into_iter()
+        .find(|(unit, _)| seconds > 0 && seconds.is_multiple_of(*unit))
+        .map_or((seconds, "s"), |(size, unit)| (seconds / size, unit));
+    write!(f, "since:{}{}", count, unit)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    /// A bare word, or a quoted phrase
+    Text(String),
+    /// `name:value`
+    Field(String, String),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Open => write!(f, "'('"),
+            Self::Close => write!(f, "')'"),
+            Self::And => write!(f, "AND"),
+            Self::Or => write!(f, "OR"),
+            Self::Not => write!(f, "NOT"),
+            Self::Text(text) => write!(f, "'{}'", text),
+            Self::Field(name, value) => write!(f, "'{}:{}'", name, value),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '"' => {
+                chars.next();
+                tokens.push(Token::Text(quoted(&mut chars)?));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    chars.next();
+                    if c == ':' {
+                        let value = match chars.peek() {
+                            Some('"') => {
+                                chars.next();
+                                quoted(&mut chars)?
+                            }
+                            _ => bare(&mut chars),
+                        };
+                        if value.is_empty() {
+                            let message = format!("{}: needs a value", word);
+                            return Err(FanError::InvalidQuery(message));
+                        }
+                        tokens.push(Token::Field(std::mem::take(&mut word), value));
+                        break;
+                    }
+                    word.push(c);
+                }
+                match word.as_str() {
+                    "" => {}
+                    "AND" => tokens.push(Token::And),
+                    "OR" => tokens.push(Token::Or),
+                    "NOT" => tokens.push(Token::Not),
+                    _ => tokens.push(Token::Text(word)),
+                }
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Read up to the closing quote, the opening one already consumed
+fn quoted(chars: &mut Peekable<std::str::Chars<'_>>) -> Result<String> {
+    let mut text = String::new();
+    for c in chars.by_ref() {
+        if c == '"' {
+            return Ok(text);
+        }
+        text.push(c);
+    }
+    Err(FanError::InvalidQuery(format!(
+        "unclosed quote before '{}'",
+        text
+    )))
+}
+
+/// Read an unquoted value
+fn bare(chars: &mut Peekable<std::str::Chars<'_>>) -> String {
+    let mut text = String::new();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() || c == '(' || c == ')' {
+            break;
+        }
+        text.push(c);
+        chars.next();
+    }
+    text
+}
+
+type Tokens = Peekable<IntoIter<Token>>;
+
+fn parse_or(tokens: &mut Tokens) -> Result<Query> {
+    let mut queries = vec![parse_and(tokens)?];
+    while tokens.next_if_eq(&Token::Or).is_some() {
+        queries.push(parse_and(tokens)?);
+    }
+    Ok(flatten(queries, Query::Or))
+}
+
+fn parse_and(tokens: &mut Tokens) -> Result<Query> {
+    let mut queries = vec![parse_not(tokens)?];
+    loop {
+        match tokens.peek() {
+            Some(Token::And) => {
+                tokens.next();
+                queries.push(parse_not(tokens)?);
+            }
+            // Juxtaposed terms
+            Some(Token::Open | Token::Not | Token::Text(_) | Token::Field(..)) => {
+                queries.push(parse_not(tokens)?);
+            }
+            _ => return Ok(flatten(queries, Query::And)),
+        }
+    }
+}
+
+fn parse_not(tokens: &mut Tokens) -> Result<Query> {
+    if tokens.next_if_eq(&Token::Not).is_some() {
+        return Ok(Query::Not(Box::new(parse_not(tokens)?)));
+    }
+    match tokens.next() {
+        Some(Token::Open) => {
+            let query = parse_or(tokens)?;
+            match tokens.next() {
+                Some(Token::Close) => Ok(query),
+                _ => Err(FanError::InvalidQuery("missing ')'".to_string())),
+            }
+        }
+        Some(Token::Text(text)) => Ok(Query::Keyword(text)),
+        Some(Token::Field(name, value)) => field(&name, &value),
+        Some(token) => Err(FanError::InvalidQuery(format!("unexpected {}", token))),
+        None => Err(FanError::InvalidQuery("query ends early".to_string())),
+    }
+}
+
+fn field(name: &str, value: &str) -> Result<Query> {
+    match name.to_ascii_lowercase().as_str() {
+        "ticker" => Ok(Query::Ticker(value.to_string())),
+        "source" => Ok(Query::Source(value.to_string())),
+        "event" => Ok(Query::Event(value.to_string())),
+        "kind" => value
+            .parse()
+            .map(Query::Kind)
+            .map_err(|_| FanError::InvalidQuery(format!("unknown kind '{}'", value))),
+        "priority" => value
+            .parse()
+            .map(Query::Priority)
+            .map_err(|_| FanError::InvalidQuery(format!("unknown priority '{}'", value))),
+        "since" => parse_age(value)
+            .map(Query::Since)
+            .ok_or_else(|| FanError::InvalidQuery(format!("invalid age '{}'", value))),
+        _ => Err(FanError::InvalidQuery(format!("unknown field '{}'", name))),
+    }
+}
+
+/// Parse an age such as `30s`, `15m`, `6h`, `2d` or `1w`
+///
+/// Returns `None` for ages too large to represent in seconds.
+pub fn parse_age(value: &str) -> Option<Duration> {
+    let split = value.find(|c: char| !c.is_ascii_digit())?;
+    let (count, unit) = value.split_at(split);
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86_400,
+        "w" => 7 * 86_400,
+        _ => return None,
+    };
+    Some(Duration::from_secs(
+        count.parse::<u64>().ok()?.checked_mul(seconds)?,
+    ))
+}
+
+/// Collapse a single-element list into its element
+fn flatten(mut queries: Vec<Query>, combine: fn(Vec<Query>) -> Query) -> Query {
+    if queries.len() == 1 {
+        queries.remove(0)
+    } else {
+        combine(queries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content_kind::CONTENT_KIND_FIELD;
+
+    fn article(title: &str, source: &str, pub_date: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some(title.to_string());
+        article.source = Some(source.to_string());
+        article.pub_date = Some(pub_date.to_string());
+        article
+    }
+
+    fn keyword(text: &str) -> Query {
+        Query::Keyword(text.to_string())
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        let query =
+            Query::parse(r#"("rate cut" OR FOMC) AND NOT crypto ticker:NVDA since:6h"#).unwrap();
+        assert_eq!(
+            query,
+            Query::And(vec![
+                Query::Or(vec![keyword("rate cut"), keyword("FOMC")]),
+                Query::Not(Box::new(keyword("crypto"))),
+                Query::Ticker("NVDA".to_string()),
+                Query::Since(Duration::from_secs(6 * 3600)),
+            ])
+        );
+
+        // AND binds tighter than OR, and lowercase operators are words
+        assert_eq!(
+            Query::parse("fed rates OR ecb or boe").unwrap(),
+            Query::Or(vec![
+                Query::And(vec![keyword("fed"), keyword("rates")]),
+                Query::And(vec![keyword("ecb"), keyword("or"), keyword("boe")]),
+            ])
+        );
+        assert_eq!(
+            "source:\"Seeking Alpha\"".parse::<Query>().unwrap(),
+            Query::Source("Seeking Alpha".to_string())
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for input in [
+            r#"("rate cut" OR FOMC) AND NOT crypto AND ticker:NVDA AND since:6h"#,
+            r#"fed AND rates OR source:"Seeking Alpha" OR NOT (ecb OR boe)"#,
+            "event:FOMC AND NOT event:CPI AND NOT kind:opinion",
+            "priority:breaking OR ticker:NVDA AND priority:high",
+            r#""AND" AND "ticker:NVDA" AND since:90s AND since:2w"#,
+        ] {
+            let query = Query::parse(input).unwrap();
+            assert_eq!(query.to_string(), input);
+            assert_eq!(Query::parse(&query.to_string()).unwrap(), query);
+        }
+        assert_eq!(
+            Query::parse("fed (rates OR ecb)").unwrap().to_string(),
+            "fed AND (rates OR ecb)"
+        );
+
+        let json = serde_json::to_string(&Query::parse("NOT crypto since:1d").unwrap()).unwrap();
+        assert_eq!(json, r#""NOT crypto AND since:1d""#);
+        assert!(serde_json::from_str::<Query>(r#""(fed""#).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for input in [
+            "",
+            "(fed",
+            "fed)",
+            "fed OR",
+            "NOT",
+            "\"rate cut",
+            "since:6x",
+            "since:",
+            "since:99999999999999999999w",
+            "since:3000000000000000000w",
+            "author:someone",
+            "kind:rumor",
+            "priority:urgent",
+        ] {
+            assert!(
+                matches!(Query::parse(input), Err(FanError::InvalidQuery(_))),
+                "{:?} should not parse",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let now = DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let query = Query::parse(r#"("rate cut" OR FOMC) AND NOT crypto since:6h"#).unwrap();
+
+        let fomc = article("FOMC holds steady", "CNBC", "2024-05-01T09:00:00Z");
+        let old = article("FOMC holds steady", "CNBC", "2024-04-30T09:00:00Z");
+        let crypto = article("Rate cut lifts crypto", "CoinDesk", "2024-05-01T11:00:00Z");
+        let undated = article("Rate cut ahead", "WSJ", "");
+        assert!(query.matches_at(&fomc, now));
+        assert!(!query.matches_at(&old, now));
+        assert!(!query.matches_at(&crypto, now));
+        assert!(!query.matches_at(&undated, now));
+
+        let nvda = article("Chips rally as $NVDA beats", "CNBC", "");
+        assert!(
+            Query::parse("ticker:nvda source:cnbc")
+                .unwrap()
+                .matches_at(&nvda, now)
+        );
+        assert!(!Query::parse("ticker:AMD").unwrap().matches_at(&nvda, now));
+
+        let mut tagged = article("Payrolls and the Fed", "CNBC", "");
+        tagged.extra_fields.insert(
+            MACRO_EVENT_FIELD.to_string(),
+            vec!["FOMC".to_string(), "NFP".to_string()].into(),
+        );
+        assert!(Query::parse("event:nfp").unwrap().matches_at(&tagged, now));
+        assert!(!Query::parse("event:CPI").unwrap().matches_at(&tagged, now));
+        assert!(
+            !Query::parse("kind:opinion")
+                .unwrap()
+                .matches_at(&tagged, now)
+        );
+        tagged
+            .extra_fields
+            .insert(CONTENT_KIND_FIELD.to_string(), "opinion".into());
+        assert!(
+            Query::parse("kind:opinion")
+                .unwrap()
+                .matches_at(&tagged, now)
+        );
+
+        let high = Query::parse("priority:high").unwrap();
+        assert!(!high.matches_at(&tagged, now));
+        tagged.priority = Priority::Breaking;
+        assert!(high.matches_at(&tagged, now));
+    }
+
+    #[test]
+    fn test_filter_records_matches() {
+        let articles = vec![
+            article("FOMC signals rate cut, NVDA jumps", "CNBC", ""),
+            article("Bitcoin and FOMC", "CoinDesk", ""),
+        ];
+        let query = Query::parse("(FOMC OR ticker:NVDA) NOT bitcoin").unwrap();
+
+        let filtered = query.filter(articles);
+        assert_eq!(filtered.len(), 1);
+        let terms: Vec<&str> = filtered[0]
+            .matches
+            .iter()
+            .map(|m| m.term.as_str())
+            .collect();
+        assert_eq!(terms, ["FOMC", "NVDA"]);
+    }
+}
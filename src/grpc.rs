@@ -0,0 +1,407 @@
+//! gRPC service streaming and fetching articles (feature "grpc")
+//!
+//! [`NewsService`] serves `finance_news.NewsService`, as defined in
+//! `proto/news_service.proto`, to consumers that would rather hold a gRPC stream
+//! open than receive webhooks:
+//!
+//! - `Subscribe(FilterRequest) returns (stream NewsArticle)` streams the new articles
+//!   a `Poller` publishes on an `EventBus`, keeping those that match the request's
+//!   filter query and topics.
+//! - `Fetch(Query) returns (Articles)` fetches a topic of a source on demand, the way
+//!   `fan fetch` does.
+//!
+//! ```rust,no_run
+//! use finance_news_aggregator_rs::NewsClient;
+//! use finance_news_aggregator_rs::events::EventBus;
+//! use finance_news_aggregator_rs::grpc::NewsService;
+//! use finance_news_aggregator_rs::grpc::tonic::transport::Server;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let bus = EventBus::new();
+//! // Poller::new(..).with_event_bus(bus.clone()).start();
+//! let service = NewsService::new(NewsClient::new(), bus);
+//! Server::builder()
+//!     .add_service(service)
+//!     .serve("0.0.0.0:50051".parse()?)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`NewsServiceClient`] calls the service from Rust; other languages generate
+//! clients from the proto files. `tonic` is re-exported so both can be used without
+//! matching its version.
+
+use crate::error::FanError;
+use crate::events::{Event, EventBus};
+use crate::filter;
+use crate::news_client::NewsClient;
+use crate::proto::NewsArticle;
+use crate::types;
+use futures::StreamExt;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tonic::codegen::{Body, BoxFuture, BoxStream, Service, StdError, http};
+use tonic::server::{Grpc, NamedService, ServerStreamingService, UnaryService};
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Request, Response, Status, Streaming};
+use tonic_prost::ProstCodec;
+
+pub use tonic;
+
+/// Path of the `Subscribe` method
+const SUBSCRIBE_PATH: &str = "/finance_news.NewsService/Subscribe";
+/// Path of the `Fetch` method
+const FETCH_PATH: &str = "/finance_news.NewsService/Fetch";
+
+/// `finance_news.FilterRequest`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FilterRequest {
+    /// Filter query, as parsed by `filter::Query::parse()`; empty for every article
+    #[prost(string, tag = "1")]
+    pub query: String,
+    /// Topics to stream; empty for every topic
+    #[prost(string, repeated, tag = "2")]
+    pub topics: Vec<String>,
+}
+
+/// `finance_news.Query`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Query {
+    /// Source name, matched ignoring case
+    #[prost(string, tag = "1")]
+    pub source: String,
+    #[prost(string, tag = "2")]
+    pub topic: String,
+    /// Filter query applied to the fetched articles; empty for every article
+    #[prost(string, tag = "3")]
+    pub query: String,
+}
+
+/// `finance_news.Articles`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Articles {
+    #[prost(message, repeated, tag = "1")]
+    pub articles: Vec<NewsArticle>,
+}
+
+/// Server of `finance_news.NewsService`, added to a `tonic` server
+///
+/// Cloning a service gives another handle to the same client and event bus.
+#[derive(Clone)]
+pub struct NewsService {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    client: Mutex<NewsClient>,
+    events: EventBus,
+}
+
+impl NewsService {
+    /// Serve `Fetch` with `client` and `Subscribe` from the events on `events`
+    pub fn new(client: NewsClient, events: EventBus) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                client: Mutex::new(client),
+                events,
+            }),
+        }
+    }
+}
+
+impl NamedService for NewsService {
+    const NAME: &'static str = "finance_news.NewsService";
+}
+
+impl<B> Service<http::Request<B>> for NewsService
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let inner = Arc::clone(&self.inner);
+        match request.uri().path() {
+            SUBSCRIBE_PATH => Box::pin(async move {
+                let mut grpc = Grpc::new(ProstCodec::default());
+                Ok(grpc.server_streaming(Subscribe(inner), request).await)
+            }),
+            FETCH_PATH => Box::pin(async move {
+                let mut grpc = Grpc::new(ProstCodec::default());
+                Ok(grpc.unary(Fetch(inner), request).await)
+            }),
+            _ => Box::pin(async move { Ok(Status::unimplemented("").into_http()) }),
+        }
+    }
+}
+
+/// Handler of `Subscribe`
+struct Subscribe(Arc<Inner>);
+
+impl ServerStreamingService<FilterRequest> for Subscribe {
+    type Response = NewsArticle;
+    type ResponseStream = BoxStream<NewsArticle>;
+    type Future = BoxFuture<Response<Self::ResponseStream>, Status>;
+
+    fn call(&mut self, request: Request<FilterRequest>) -> Self::Future {
+        let request = request.into_inner();
+        let query = parse_query(&request.query);
+        // Subscribe before returning so no article is missed while the stream starts
+        let events = self.0.events.subscribe();
+        Box::pin(async move {
+            let query = query?;
+            let topics = request.topics;
+            let articles = events.into_stream().filter_map(move |event| {
+                let article = match event {
+                    Event::NewArticle(event)
+                        if topics.is_empty() || topics.contains(&event.topic) =>
+                    {
+                        Some(event.article)
+                    }
+                    _ => None,
+                };
+                let article = article
+                    .filter(|article| query.as_ref().is_none_or(|query| query.matches(article)))
+                    .map(|article| Ok(NewsArticle::from(&article)));
+                futures::future::ready(article)
+            });
+            Ok(Response::new(Box::pin(articles) as Self::ResponseStream))
+        })
+    }
+}
+
+/// Handler of `Fetch`
+struct Fetch(Arc<Inner>);
+
+impl UnaryService<Query> for Fetch {
+    type Response = Articles;
+    type Future = BoxFuture<Response<Articles>, Status>;
+
+    fn call(&mut self, request: Request<Query>) -> Self::Future {
+        let request = request.into_inner();
+        let source = self
+            .0
+            .client
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .source(&request.source);
+        Box::pin(async move {
+            let query = parse_query(&request.query)?;
+            let source = source
+                .ok_or_else(|| Status::not_found(format!("unknown source '{}'", request.source)))?;
+            let mut articles = source.fetch_topic(&request.topic).await.map_err(status)?;
+            if let Some(query) = &query {
+                articles = query.filter(articles);
+            }
+            Ok(Response::new(Articles {
+                articles: articles.iter().map(NewsArticle::from).collect(),
+            }))
+        })
+    }
+}
+
+/// Parse a request's filter query; an empty query matches every article
+fn parse_query(query: &str) -> Result<Option<filter::Query>, Status> {
+    if query.trim().is_empty() {
+        return Ok(None);
+    }
+    filter::Query::parse(query)
+        .map(Some)
+        .map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+/// gRPC status of a failed fetch
+fn status(error: FanError) -> Status {
+    let message = error.to_string();
+    match error {
+        FanError::InvalidQuery(_) | FanError::InvalidSymbol(_) => Status::invalid_argument(message),
+        FanError::HttpStatus { status: 404, .. } => Status::not_found(message),
+        FanError::RateLimited(_) => Status::resource_exhausted(message),
+        FanError::Timeout(_) => Status::deadline_exceeded(message),
+        FanError::MissingApiKey(_) => Status::failed_precondition(message),
+        _ => Status::unavailable(message),
+    }
+}
+
+/// Client of `finance_news.NewsService`
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use finance_news_aggregator_rs::grpc::{FilterRequest, NewsServiceClient};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut client = NewsServiceClient::connect("http://localhost:50051").await?;
+/// let request = FilterRequest {
+///     query: "ticker:NVDA".to_string(),
+///     topics: Vec::new(),
+/// };
+/// let mut articles = client.subscribe(request).await?;
+/// while let Some(article) = articles.message().await? {
+///     println!("{:?}", article.title);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NewsServiceClient {
+    inner: tonic::client::Grpc<Channel>,
+}
+
+impl NewsServiceClient {
+    /// Connect to a server such as `http://localhost:50051`
+    pub async fn connect(url: impl Into<String>) -> Result<Self, tonic::transport::Error> {
+        let channel = Endpoint::from_shared(url.into())?.connect().await?;
+        Ok(Self::new(channel))
+    }
+
+    /// Call the service over an existing channel
+    pub fn new(channel: Channel) -> Self {
+        Self {
+            inner: tonic::client::Grpc::new(channel),
+        }
+    }
+
+    /// Stream new articles matching `request`
+    pub async fn subscribe(
+        &mut self,
+        request: FilterRequest,
+    ) -> Result<Streaming<NewsArticle>, Status> {
+        self.ready().await?;
+        let path = http::uri::PathAndQuery::from_static(SUBSCRIBE_PATH);
+        let response = self
+            .inner
+            .server_streaming(Request::new(request), path, ProstCodec::default())
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// Fetch the articles of a topic of a source
+    pub async fn fetch(&mut self, query: Query) -> Result<Vec<types::NewsArticle>, Status> {
+        self.ready().await?;
+        let path = http::uri::PathAndQuery::from_static(FETCH_PATH);
+        let response: Response<Articles> = self
+            .inner
+            .unary(Request::new(query), path, ProstCodec::default())
+            .await?;
+        Ok(response
+            .into_inner()
+            .articles
+            .into_iter()
+            .map(types::NewsArticle::from)
+            .collect())
+    }
+
+    async fn ready(&mut self) -> Result<(), Status> {
+        self.inner
+            .ready()
+            .await
+            .map_err(|e| Status::unavailable(format!("service was not ready: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::NewArticle;
+    use tokio::net::TcpListener;
+    use tonic::codegen::tokio_stream::wrappers::TcpListenerStream;
+    use tonic::transport::Server;
+
+    fn new_article(topic: &str, title: &str) -> Event {
+        let mut article = types::NewsArticle::new();
+        article.title = Some(title.to_string());
+        Event::NewArticle(Box::new(NewArticle {
+            source: "CNBC".to_string(),
+            topic: topic.to_string(),
+            article,
+        }))
+    }
+
+    /// Serve `service` on a local port and connect a client to it
+    async fn connect(service: NewsService) -> NewsServiceClient {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(
+            Server::builder()
+                .add_service(service)
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+        NewsServiceClient::connect(url).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_subscribe() {
+        let bus = EventBus::new();
+        let mut client = connect(NewsService::new(NewsClient::new(), bus.clone())).await;
+
+        let request = FilterRequest {
+            query: "nvidia OR oil".to_string(),
+            topics: vec!["earnings".to_string()],
+        };
+        let mut articles = client.subscribe(request).await.unwrap();
+        bus.publish(new_article("earnings", "Nvidia guidance beats"));
+        bus.publish(new_article("energy", "Oil slips"));
+        bus.publish(new_article("earnings", "Apple sets event date"));
+        bus.publish(new_article("earnings", "Oil majors report"));
+
+        let first = articles.message().await.unwrap().unwrap();
+        assert_eq!(first.title.as_deref(), Some("Nvidia guidance beats"));
+        let second = articles.message().await.unwrap().unwrap();
+        assert_eq!(second.title.as_deref(), Some("Oil majors report"));
+
+        let invalid = FilterRequest {
+            query: "(unbalanced".to_string(),
+            topics: Vec::new(),
+        };
+        let status = client.subscribe(invalid).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[cfg(feature = "cnbc")]
+    #[tokio::test]
+    async fn test_fetch() {
+        use crate::http::{HttpClient, HttpRequest, HttpResponse};
+        use async_trait::async_trait;
+
+        /// Serves the same two-item feed for every URL
+        struct Feeds;
+
+        #[async_trait]
+        impl HttpClient for Feeds {
+            async fn send(&self, _request: HttpRequest) -> crate::Result<HttpResponse> {
+                let body = "<rss><channel>\
+                    <item><title>Nvidia guidance beats</title><link>https://example.com/nvda</link></item>\
+                    <item><title>Oil slips</title><link>https://example.com/oil</link></item>\
+                    </channel></rss>";
+                Ok(HttpResponse::new(200, body).with_header("Content-Type", "application/rss+xml"))
+            }
+        }
+
+        let client = NewsClient::new().with_http_client(Feeds);
+        let mut client = connect(NewsService::new(client, EventBus::new())).await;
+
+        let query = |source: &str, query: &str| Query {
+            source: source.to_string(),
+            topic: "earnings".to_string(),
+            query: query.to_string(),
+        };
+        let articles = client.fetch(query("cnbc", "")).await.unwrap();
+        assert_eq!(articles.len(), 2);
+        let articles = client.fetch(query("CNBC", "nvidia")).await.unwrap();
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].title.as_deref(), Some("Nvidia guidance beats"));
+
+        let status = client.fetch(query("Nowhere", "")).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
+}
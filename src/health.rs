@@ -0,0 +1,342 @@
+//! Feed health tracking and degradation alerts
+//!
+//! A `Poller` keeps a [`FeedHealth`] for every feed it polls. When a feed crosses
+//! the thresholds of its [`DegradationPolicy`], too many failed polls in a row or
+//! too low a success rate over recent polls, it publishes a `FeedDegraded` event,
+//! and a `FeedRecovered` event once the feed is healthy again. A
+//! [`WebhookNotifier`] forwards those events to an HTTP endpoint, so operators learn
+//! about a dead feed from an alert rather than from users.
+
+use crate::error::{FanError, Result};
+use crate::events::{Event, EventBus, FeedDegraded, FeedRecovered};
+use crate::news_source::check_status;
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tokio::task::JoinHandle;
+
+/// Failed polls in a row after which a feed is degraded, unless set in the policy
+pub const DEFAULT_MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+/// Success rate below which a feed is degraded, unless set in the policy
+pub const DEFAULT_MIN_SUCCESS_RATE: f64 = 0.5;
+
+/// Recent polls the success rate is computed over, unless set in the policy
+pub const DEFAULT_HEALTH_WINDOW: usize = 20;
+
+/// Thresholds at which a feed counts as degraded
+///
+/// A feed is degraded when `max_consecutive_failures` polls in a row fail, or when
+/// fewer than `min_success_rate` of its last `window` polls succeeded. The rate only
+/// applies once the window is full, so a new feed is not degraded by one early
+/// failure. A degraded feed recovers at the first successful poll after which
+/// neither threshold is crossed.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DegradationPolicy {
+    pub max_consecutive_failures: u32,
+    pub min_success_rate: f64,
+    pub window: usize,
+}
+
+impl Default for DegradationPolicy {
+    fn default() -> Self {
+        Self {
+            max_consecutive_failures: DEFAULT_MAX_CONSECUTIVE_FAILURES,
+            min_success_rate: DEFAULT_MIN_SUCCESS_RATE,
+            window: DEFAULT_HEALTH_WINDOW,
+        }
+    }
+}
+
+impl DegradationPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Degrade a feed after this many failed polls in a row
+    pub fn with_max_consecutive_failures(mut self, failures: u32) -> Self {
+        self.max_consecutive_failures = failures.max(1);
+        self
+    }
+
+    /// Degrade a feed whose success rate over the window drops below `rate`
+    ///
+    /// A rate of 0 turns the check off.
+    pub fn with_min_success_rate(mut self, rate: f64) -> Self {
+        self.min_success_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Compute the success rate over this many recent polls
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = window.max(1);
+        self
+    }
+}
+
+/// Recent poll outcomes of one feed
+#[derive(Debug, Clone, Default)]
+pub struct FeedHealth {
+    /// Outcomes of recent polls, oldest first; `true` for a success
+    recent: VecDeque<bool>,
+    consecutive_failures: u32,
+    failing_since: Option<DateTime<Utc>>,
+    degraded_since: Option<DateTime<Utc>>,
+}
+
+impl FeedHealth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Failed polls since the last success
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Share of recent polls that succeeded, or `None` before the first poll
+    pub fn success_rate(&self) -> Option<f64> {
+        if self.recent.is_empty() {
+            return None;
+        }
+        let successes = self.recent.iter().filter(|success| **success).count();
+        Some(successes as f64 / self.recent.len() as f64)
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        self.degraded_since.is_some()
+    }
+
+    /// Record the outcome of a poll, returning the event it causes
+    ///
+    /// `error` is the error of a failed poll, or `None` for a successful one.
+    pub fn record(
+        &mut self,
+        source: &str,
+        topic: &str,
+        error: Option<&FanError>,
+        policy: &DegradationPolicy,
+    ) -> Option<Event> {
+        if self.recent.len() >= policy.window.max(1) {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(error.is_none());
+        let success_rate = self.success_rate().unwrap_or(1.0);
+
+        let Some(error) = error else {
+            self.consecutive_failures = 0;
+            self.failing_since = None;
+            if self.crosses(policy) {
+                return None;
+            }
+            let degraded_since = self.degraded_since.take()?;
+            info!("{} topic '{}' recovered", source, topic);
+            return Some(Event::FeedRecovered(FeedRecovered {
+                source: source.to_string(),
+                topic: topic.to_string(),
+                degraded_since,
+                success_rate,
+            }));
+        };
+
+        self.consecutive_failures += 1;
+        let failing_since = *self.failing_since.get_or_insert_with(Utc::now);
+        if self.is_degraded() || !self.crosses(policy) {
+            return None;
+        }
+        self.degraded_since = Some(Utc::now());
+        warn!(
+            "{} topic '{}' degraded: {} failures in a row, {:.0}% of recent polls succeeded",
+            source,
+            topic,
+            self.consecutive_failures,
+            success_rate * 100.0
+        );
+        Some(Event::FeedDegraded(FeedDegraded {
+            source: source.to_string(),
+            topic: topic.to_string(),
+            consecutive_failures: self.consecutive_failures,
+            success_rate,
+            error: error.to_string(),
+            failing_since,
+        }))
+    }
+
+    /// Whether the recorded polls cross either threshold of the policy
+    fn crosses(&self, policy: &DegradationPolicy) -> bool {
+        let window_full = self.recent.len() >= policy.window.max(1);
+        self.consecutive_failures >= policy.max_consecutive_failures.max(1)
+            || (window_full && self.success_rate().unwrap_or(1.0) < policy.min_success_rate)
+    }
+}
+
+/// Posts feed health events to a webhook as JSON
+///
+/// Each event is the body of one POST request, serialized as in `Event`'s `Serialize`
+/// implementation, for example:
+///
+/// ```json
+/// {"type": "feed_degraded", "source": "MarketWatch", "topic": "topstories",
+///  "consecutive_failures": 3, "success_rate": 0.85, "error": "HTTP 404 from ...",
+///  "failing_since": "2024-05-01T14:00:00Z"}
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    url: String,
+    client: Client,
+    all_events: bool,
+}
+
+impl WebhookNotifier {
+    /// Notify the webhook at `url` of `FeedDegraded` and `FeedRecovered` events
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            client: Client::new(),
+            all_events: false,
+        }
+    }
+
+    /// Send requests with this client, for example one with a timeout or auth headers
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Post every event, including each `NewArticle` and `ArticleUpdated`
+    pub fn with_all_events(mut self, all_events: bool) -> Self {
+        self.all_events = all_events;
+        self
+    }
+
+    /// Post one event, failing on a non-success response
+    pub async fn notify(&self, event: &Event) -> Result<()> {
+        self.post(event).await
+    }
+
+    /// Post a JSON body, failing on a non-success response
+    pub(crate) async fn post(&self, body: &(impl Serialize + Sync)) -> Result<()> {
+        let response = self.client.post(&self.url).json(body).send().await?;
+        check_status(&self.url, response.into()).await?;
+        Ok(())
+    }
+
+    /// Post the events of a bus from a background task until the bus is dropped
+    ///
+    /// Failed posts are logged and not retried.
+    pub fn spawn(self, bus: &EventBus) -> JoinHandle<()> {
+        let mut events = bus.subscribe();
+        tokio::spawn(async move {
+            while let Some(event) = events.recv().await {
+                let article_event =
+                    matches!(event, Event::NewArticle(_) | Event::ArticleUpdated(_));
+                if !self.all_events && article_event {
+                    continue;
+                }
+                if let Err(e) = self.notify(&event).await {
+                    warn!("Failed to notify {}: {}", self.url, e);
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn fail(health: &mut FeedHealth, policy: &DegradationPolicy) -> Option<Event> {
+        let error = FanError::Timeout("topstories".to_string());
+        health.record("MarketWatch", "topstories", Some(&error), policy)
+    }
+
+    fn succeed(health: &mut FeedHealth, policy: &DegradationPolicy) -> Option<Event> {
+        health.record("MarketWatch", "topstories", None, policy)
+    }
+
+    #[test]
+    fn test_consecutive_failures_degrade_once() {
+        let policy = DegradationPolicy::new().with_max_consecutive_failures(2);
+        let mut health = FeedHealth::new();
+        assert!(succeed(&mut health, &policy).is_none());
+        assert!(fail(&mut health, &policy).is_none());
+        match fail(&mut health, &policy) {
+            Some(Event::FeedDegraded(event)) => {
+                assert_eq!(event.consecutive_failures, 2);
+                assert!(event.error.contains("topstories"));
+            }
+            event => panic!("unexpected {:?}", event),
+        }
+        assert!(fail(&mut health, &policy).is_none());
+        assert!(health.is_degraded());
+
+        assert!(matches!(
+            succeed(&mut health, &policy),
+            Some(Event::FeedRecovered(_))
+        ));
+        assert!(!health.is_degraded());
+    }
+
+    #[test]
+    fn test_low_success_rate_degrades() {
+        let policy = DegradationPolicy::new()
+            .with_max_consecutive_failures(10)
+            .with_min_success_rate(0.6)
+            .with_window(4);
+        let mut health = FeedHealth::new();
+        // Alternating failures never reach 10 in a row, but only half succeed
+        assert!(succeed(&mut health, &policy).is_none());
+        assert!(fail(&mut health, &policy).is_none());
+        assert!(succeed(&mut health, &policy).is_none());
+        match fail(&mut health, &policy) {
+            Some(Event::FeedDegraded(event)) => assert_eq!(event.success_rate, 0.5),
+            event => panic!("unexpected {:?}", event),
+        }
+
+        // 3 of the last 4 succeed after two more successes
+        assert!(succeed(&mut health, &policy).is_none());
+        match succeed(&mut health, &policy) {
+            Some(Event::FeedRecovered(event)) => assert_eq!(event.success_rate, 0.75),
+            event => panic!("unexpected {:?}", event),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_webhook_posts_event_json() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/alerts", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 4096];
+            // Read until the JSON body is complete
+            while !request.ends_with(b"}") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+            }
+            socket
+                .write_all(b"HTTP/1.1 204 No Content\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let mut health = FeedHealth::new();
+        let policy = DegradationPolicy::new().with_max_consecutive_failures(1);
+        let event = fail(&mut health, &policy).unwrap();
+        WebhookNotifier::new(url).notify(&event).await.unwrap();
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /alerts"));
+        assert!(request.contains(r#""type":"feed_degraded""#));
+        assert!(request.contains(r#""topic":"topstories""#));
+    }
+}
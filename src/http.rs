@@ -0,0 +1,193 @@
+//! HTTP transport behind the news sources
+//!
+//! Sources fetch their feeds through the [`HttpClient`] trait rather than reqwest
+//! directly, so another backend (a blocking client, a wasm `fetch`, a test double
+//! serving fixtures) can be swapped in. `reqwest::Client` implements it, and is what
+//! `NewsClient` uses unless given another client with `with_http_client()`.
+
+use crate::error::Result;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+/// A GET request for a feed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub url: String,
+    /// Header names and values, sent in order
+    pub headers: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    /// Create a GET request for `url`
+    pub fn get(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            headers: Vec::new(),
+        }
+    }
+
+    /// Add a header
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Add an `Authorization: Bearer` header
+    pub fn with_bearer_auth(self, token: &str) -> Self {
+        self.with_header("Authorization", &format!("Bearer {}", token))
+    }
+
+    /// Set a query parameter of the URL, replacing any previous value
+    ///
+    /// Leaves the URL unchanged if it does not parse.
+    pub fn with_query(mut self, key: &str, value: &str) -> Self {
+        if let Some(url) = crate::news_source::with_query_param(&self.url, key, value) {
+            self.url = url;
+        }
+        self
+    }
+
+    /// Value of a header, matched ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+/// Response to an [`HttpRequest`], with a body read as it arrives
+pub struct HttpResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: BoxStream<'static, io::Result<Bytes>>,
+}
+
+impl HttpResponse {
+    /// Create a response with a body already in memory
+    pub fn new(status: u16, body: impl Into<Bytes>) -> Self {
+        Self::from_stream(status, stream::iter([Ok(body.into())]).boxed())
+    }
+
+    /// Create a response whose body arrives in chunks
+    pub fn from_stream(status: u16, body: BoxStream<'static, io::Result<Bytes>>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    /// Add a header
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// The HTTP status code
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Whether the status is 2xx
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Value of a header, matched ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Replace the body with one wrapping it, keeping the status and headers
+    #[cfg(feature = "client")]
+    pub(crate) fn map_body(
+        mut self,
+        f: impl FnOnce(BoxStream<'static, io::Result<Bytes>>) -> BoxStream<'static, io::Result<Bytes>>,
+    ) -> Self {
+        self.body = f(self.body);
+        self
+    }
+
+    /// The body as chunks of bytes
+    pub fn bytes_stream(self) -> BoxStream<'static, io::Result<Bytes>> {
+        self.body
+    }
+
+    /// Read the whole body as text, replacing invalid UTF-8
+    pub async fn text(self) -> Result<String> {
+        Ok(String::from_utf8_lossy(&self.bytes().await?).into_owned())
+    }
+
+    /// Read the whole body
+    pub async fn bytes(self) -> Result<Bytes> {
+        let chunks: Vec<Bytes> = self.body.try_collect().await?;
+        Ok(chunks.concat().into())
+    }
+}
+
+impl fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Sends the requests of news sources
+#[async_trait]
+pub trait HttpClient: Send + Sync {
+    /// Send a request and return the response, whatever its status
+    ///
+    /// Errors are for requests that got no response at all, such as connection
+    /// failures and timeouts.
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
+
+#[async_trait]
+impl HttpClient for reqwest::Client {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let mut builder = self.get(&request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        // Errors drop the URL: its query can carry an API key
+        Ok(builder.send().await.map_err(|e| e.without_url())?.into())
+    }
+}
+
+impl From<reqwest::Response> for HttpResponse {
+    fn from(response: reqwest::Response) -> Self {
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let status = response.status().as_u16();
+        let body = response
+            .bytes_stream()
+            .map_err(|e| io::Error::other(e.without_url()))
+            .boxed();
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+}
+
+#[async_trait]
+impl<T: HttpClient + ?Sized> HttpClient for Arc<T> {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        self.as_ref().send(request).await
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
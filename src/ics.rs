@@ -0,0 +1,470 @@
+//! iCalendar export of upcoming earnings and IPO dates
+//!
+//! [`CalendarExport`] turns earnings and IPO articles into all-day events of an
+//! iCalendar (`.ics`) file, so a calendar app subscribed to the file reminds the
+//! user of catalysts found in the news.
+//!
+//! An article becomes an event when it mentions earnings or an IPO and names an
+//! upcoming date, such as "Apple to report results on July 25" or "expected to price
+//! 2024-06-12". Dates without a year are taken as the next such date after the
+//! article was published. Articles that name no date can be placed on their
+//! publication date with `with_published_dates(true)`.
+
+use crate::aggregation::{mentions_any, word_spans};
+use crate::disk_cache::fnv1a_64;
+use crate::error::Result;
+use crate::news_client::IPO_KEYWORDS;
+use crate::types::NewsArticle;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// Keywords identifying earnings coverage
+const EARNINGS_KEYWORDS: &[&str] = &[
+    "earnings",
+    "quarterly results",
+    "financial results",
+    "earnings call",
+    "conference call",
+    "to report",
+];
+
+/// Month names and abbreviations, lowercase, by month number
+const MONTHS: &[(&str, u32)] = &[
+    ("january", 1),
+    ("jan", 1),
+    ("february", 2),
+    ("feb", 2),
+    ("march", 3),
+    ("mar", 3),
+    ("april", 4),
+    ("apr", 4),
+    ("may", 5),
+    ("june", 6),
+    ("jun", 6),
+    ("july", 7),
+    ("jul", 7),
+    ("august", 8),
+    ("aug", 8),
+    ("september", 9),
+    ("sept", 9),
+    ("sep", 9),
+    ("october", 10),
+    ("oct", 10),
+    ("november", 11),
+    ("nov", 11),
+    ("december", 12),
+    ("dec", 12),
+];
+
+/// Mentioned dates further than this after publication are ignored, as they are
+/// more likely history or fiscal-year ends than a scheduled catalyst
+const MAX_DAYS_AHEAD: i64 = 180;
+
+/// Longest line of an iCalendar file, in bytes, before folding
+const MAX_LINE_BYTES: usize = 75;
+
+/// What an event is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CatalystKind {
+    Earnings,
+    Ipo,
+}
+
+impl CatalystKind {
+    /// Stable identifier for the kind
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CatalystKind::Earnings => "earnings",
+            CatalystKind::Ipo => "ipo",
+        }
+    }
+
+    /// Label of the kind in event titles and categories
+    fn label(&self) -> &'static str {
+        match self {
+            CatalystKind::Earnings => "Earnings",
+            CatalystKind::Ipo => "IPO",
+        }
+    }
+}
+
+impl fmt::Display for CatalystKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An all-day calendar event derived from an article
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CatalystEvent {
+    pub kind: CatalystKind,
+    pub date: NaiveDate,
+    /// Title of the article
+    pub title: String,
+    pub description: Option<String>,
+    pub link: Option<String>,
+    pub source: Option<String>,
+}
+
+impl CatalystEvent {
+    /// Stable identifier of the event, so re-exports update rather than duplicate it
+    pub fn uid(&self) -> String {
+        let key = self.link.as_deref().unwrap_or(&self.title);
+        format!(
+            "{:016x}-{}@finance-news-aggregator-rs",
+            fnv1a_64(key.as_bytes()),
+            self.kind
+        )
+    }
+}
+
+/// Builds an iCalendar file of earnings and IPO events from articles
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarExport {
+    name: String,
+    published_dates: bool,
+}
+
+impl Default for CalendarExport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CalendarExport {
+    /// An export named "Market Catalysts" using only dates named in articles
+    pub fn new() -> Self {
+        Self {
+            name: "Market Catalysts".to_string(),
+            published_dates: false,
+        }
+    }
+
+    /// Name of the calendar shown by calendar apps
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Place articles that name no upcoming date on their publication date
+    pub fn with_published_dates(mut self, published_dates: bool) -> Self {
+        self.published_dates = published_dates;
+        self
+    }
+
+    /// The event of an article, if it is about earnings or an IPO and has a date
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsArticle;
+    /// use finance_news_aggregator_rs::ics::{CalendarExport, CatalystKind};
+    ///
+    /// let mut article = NewsArticle::new();
+    /// article.title = Some("Apple to report fiscal Q3 earnings on July 25".to_string());
+    /// article.pub_date = Some("Mon, 01 Jul 2024 12:00:00 GMT".to_string());
+    ///
+    /// let event = CalendarExport::new().event(&article).unwrap();
+    /// assert_eq!(event.kind, CatalystKind::Earnings);
+    /// assert_eq!(event.date.to_string(), "2024-07-25");
+    /// ```
+    pub fn event(&self, article: &NewsArticle) -> Option<CatalystEvent> {
+        let title = article.title.as_deref()?.trim();
+        let kind = if mentions_any(article, IPO_KEYWORDS) {
+            CatalystKind::Ipo
+        } else if mentions_any(article, EARNINGS_KEYWORDS) {
+            CatalystKind::Earnings
+        } else {
+            return None;
+        };
+        let published = article.published_at()?.date_naive();
+        let mentioned = [article.title.as_deref(), article.description.as_deref()]
+            .into_iter()
+            .flatten()
+            .find_map(|text| upcoming_date(text, published));
+        let date = match mentioned {
+            Some(date) => date,
+            None if self.published_dates => published,
+            None => return None,
+        };
+        Some(CatalystEvent {
+            kind,
+            date,
+            title: title.to_string(),
+            description: article.description.clone(),
+            link: article.link.clone(),
+            source: article.source.clone(),
+        })
+    }
+
+    /// Events of the articles, one per article link, ordered by date
+    pub fn events(&self, articles: &[NewsArticle]) -> Vec<CatalystEvent> {
+        let mut events = BTreeMap::new();
+        for event in articles.iter().filter_map(|article| self.event(article)) {
+            events.entry(event.uid()).or_insert(event);
+        }
+        let mut events: Vec<CatalystEvent> = events.into_values().collect();
+        events.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.title.cmp(&b.title)));
+        events
+    }
+
+    /// Render the events of the articles as an iCalendar file
+    pub fn to_ics(&self, articles: &[NewsArticle]) -> String {
+        self.render(&self.events(articles), Utc::now())
+    }
+
+    /// Write the events of the articles to an `.ics` file
+    ///
+    /// # Returns
+    /// The number of events written
+    pub fn write(&self, path: impl AsRef<Path>, articles: &[NewsArticle]) -> Result<usize> {
+        let events = self.events(articles);
+        std::fs::write(path, self.render(&events, Utc::now()))?;
+        Ok(events.len())
+    }
+
+    fn render(&self, events: &[CatalystEvent], stamp: DateTime<Utc>) -> String {
+        let stamp = stamp.format("%Y%m%dT%H%M%SZ").to_string();
+        let mut ics = String::new();
+        let mut line = |name: &str, value: &str| push_line(&mut ics, name, value);
+        line("BEGIN", "VCALENDAR");
+        line("VERSION", "2.0");
+        line(
+            "PRODID",
+            "-//finance-news-aggregator-rs//Market Catalysts//EN",
+        );
+        line("CALSCALE", "GREGORIAN");
+        line("X-WR-CALNAME", &escape(&self.name));
+        for event in events {
+            let mut description = event.description.clone().unwrap_or_default();
+            if let Some(link) = &event.link {
+                if !description.is_empty() {
+                    description.push_str("\n\n");
+                }
+                description.push_str(link);
+            }
+
+            line("BEGIN", "VEVENT");
+            line("UID", &event.uid());
+            line("DTSTAMP", &stamp);
+            line(
+                "DTSTART;VALUE=DATE",
+                &event.date.format("%Y%m%d").to_string(),
+            );
+            line(
+                "DTEND;VALUE=DATE",
+                &event
+                    .date
+                    .succ_opt()
+                    .unwrap_or(event.date)
+                    .format("%Y%m%d")
+                    .to_string(),
+            );
+            line(
+                "SUMMARY",
+                &escape(&format!("{}: {}", event.kind.label(), event.title)),
+            );
+            if !description.is_empty() {
+                line("DESCRIPTION", &escape(&description));
+            }
+            if let Some(link) = &event.link {
+                line("URL", link);
+            }
+            line("CATEGORIES", &event.kind.label().to_uppercase());
+            line("TRANSP", "TRANSPARENT");
+            line("END", "VEVENT");
+        }
+        line("END", "VCALENDAR");
+        ics
+    }
+}
+
+/// The first date mentioned in `text` that falls within `MAX_DAYS_AHEAD` days on or
+/// after `published`
+///
+/// Recognizes "July 25", "Jul. 25, 2024", "25 July 2024", and "2024-07-25".
+fn upcoming_date(text: &str, published: NaiveDate) -> Option<NaiveDate> {
+    let words: Vec<String> = word_spans(text, char::is_alphanumeric)
+        .into_iter()
+        .map(|(_, word)| word.to_lowercase())
+        .collect();
+    let month = |word: &str| {
+        MONTHS
+            .iter()
+            .find(|(name, _)| *name == word)
+            .map(|(_, m)| *m)
+    };
+    let day = |word: &str| {
+        word.parse::<u32>()
+            .ok()
+            .filter(|day| (1..=31).contains(day))
+    };
+    let year = |word: Option<&String>| {
+        word.filter(|word| word.len() == 4)
+            .and_then(|word| word.parse::<i32>().ok())
+    };
+
+    let mut candidates = (0..words.len()).filter_map(|i| {
+        let word = words[i].as_str();
+        let next = words.get(i + 1).map(String::as_str).unwrap_or_default();
+        if let (Some(month), Some(day)) = (month(word), day(next)) {
+            return dated(year(words.get(i + 2)), month, day, published);
+        }
+        if let (Some(day), Some(month)) = (day(word), month(next)) {
+            return dated(year(words.get(i + 2)), month, day, published);
+        }
+        let year = year(Some(&words[i]))?;
+        let month = words.get(i + 1)?.parse().ok()?;
+        let day = day(words.get(i + 2)?)?;
+        NaiveDate::from_ymd_opt(year, month, day)
+    });
+    candidates.find(|date| *date >= published && (*date - published).num_days() <= MAX_DAYS_AHEAD)
+}
+
+/// A date in `year`, or without a year the next such date on or after `published`
+fn dated(year: Option<i32>, month: u32, day: u32, published: NaiveDate) -> Option<NaiveDate> {
+    if let Some(year) = year {
+        return NaiveDate::from_ymd_opt(year, month, day);
+    }
+    NaiveDate::from_ymd_opt(published.year(), month, day)
+        .filter(|date| *date >= published)
+        .or_else(|| NaiveDate::from_ymd_opt(published.year() + 1, month, day))
+}
+
+/// Escape text for an iCalendar property value
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Append a content line, folded into lines of at most `MAX_LINE_BYTES` bytes
+fn push_line(ics: &mut String, name: &str, value: &str) {
+    let line = format!("{}:{}", name, value);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_BYTES {
+            ics.push_str("\r\n ");
+            // The leading space of a continuation line counts toward its length
+            width = 1;
+        }
+        ics.push(c);
+        width += c.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(title: &str, description: &str, pub_date: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some(title.to_string());
+        article.description = Some(description.to_string());
+        article.pub_date = Some(pub_date.to_string());
+        article.link = Some(format!("https://example.com/{}", title.replace(' ', "-")));
+        article
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_upcoming_date() {
+        let published = date(2024, 12, 20);
+        for (text, expected) in [
+            ("Results on Jan. 5", Some(date(2025, 1, 5))),
+            (
+                "Call scheduled for 22 December 2024",
+                Some(date(2024, 12, 22)),
+            ),
+            ("Pricing expected 2025-01-15", Some(date(2025, 1, 15))),
+            (
+                "Beat on December 1, 2024 and guides for March 3",
+                Some(date(2025, 3, 3)),
+            ),
+            ("Shares may 10x", None),
+            ("Fiscal year ending September 30, 2025", None),
+        ] {
+            assert_eq!(upcoming_date(text, published), expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_events() {
+        let articles = vec![
+            article(
+                "Acme Corp. to Report Fourth Quarter Results",
+                "Conference call on February 6, 2025, at 5:00 p.m. ET",
+                "Mon, 20 Jan 2025 13:00:00 GMT",
+            ),
+            article(
+                "Widget Inc. sets terms for IPO",
+                "Expected to price the week of Jan 27",
+                "Tue, 21 Jan 2025 13:00:00 GMT",
+            ),
+            article("Stocks rally on Feb 3", "", "Mon, 03 Feb 2025 21:00:00 GMT"),
+            article("Acme earnings preview", "", "Wed, 05 Feb 2025 13:00:00 GMT"),
+        ];
+
+        let events = CalendarExport::new().events(&articles);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, CatalystKind::Ipo);
+        assert_eq!(events[0].date, date(2025, 1, 27));
+        assert_eq!(events[1].kind, CatalystKind::Earnings);
+        assert_eq!(events[1].date, date(2025, 2, 6));
+
+        let with_published = CalendarExport::new().with_published_dates(true);
+        assert_eq!(with_published.events(&articles).len(), 3);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut acme = article(
+            "Acme, Inc. to report Q4 earnings; call on Feb 6",
+            "A long description that goes on and on so the line has to be folded somewhere",
+            "Mon, 20 Jan 2025 13:00:00 GMT",
+        );
+        acme.link = Some("https://example.com/acme".to_string());
+        let export = CalendarExport::new().with_name("Watchlist");
+        let events = export.events(&[acme]);
+        let stamp = DateTime::parse_from_rfc3339("2025-01-20T15:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let ics = export.render(&events, stamp);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(ics.contains("X-WR-CALNAME:Watchlist\r\n"));
+        assert!(ics.contains("DTSTAMP:20250120T150000Z\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250206\r\nDTEND;VALUE=DATE:20250207\r\n"));
+        assert!(
+            ics.contains(
+                "SUMMARY:Earnings: Acme\\, Inc. to report Q4 earnings\\; call on Feb 6\r\n"
+            )
+        );
+        assert!(ics.contains("CATEGORIES:EARNINGS\r\n"));
+        assert!(ics.lines().all(|line| line.len() <= MAX_LINE_BYTES + 1));
+        let unfolded = ics.replace("\r\n ", "");
+        assert!(
+            unfolded.contains(
+                "so the line has to be folded somewhere\\n\\nhttps://example.com/acme\r\n"
+            )
+        );
+    }
+}
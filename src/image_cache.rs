@@ -0,0 +1,371 @@
+//! Downloading article images for offline use
+//!
+//! An [`ImageCache`] downloads the image of an article (`extra_fields["thumbnail"]`)
+//! into a directory and returns the local path, for digests and terminal UIs that
+//! show thumbnails without going back to the network.
+//!
+//! Files are named by a hash of the image URL, so each image is downloaded once no
+//! matter how many articles use it. Images over `with_max_image_bytes()` are refused,
+//! and `with_max_total_bytes()` bounds the directory by removing the oldest files.
+
+use crate::disk_cache::fnv1a_64;
+use crate::error::{FanError, Result};
+use crate::http::{HttpClient, HttpRequest};
+use crate::news_source::check_status;
+use crate::types::NewsArticle;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use log::{debug, warn};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// Key of the image URL in `NewsArticle::extra_fields`
+const THUMBNAIL_FIELD: &str = "thumbnail";
+
+/// File extensions of cached images; `img` when the format is unknown
+const EXTENSIONS: &[&str] = &["jpg", "png", "gif", "webp", "avif", "svg", "img"];
+
+const DEFAULT_MAX_IMAGE_BYTES: u64 = 5 * 1024 * 1024;
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Directory of downloaded article images
+#[derive(Clone)]
+pub struct ImageCache {
+    dir: PathBuf,
+    client: Arc<dyn HttpClient>,
+    max_image_bytes: u64,
+    max_total_bytes: Option<u64>,
+    concurrency: usize,
+}
+
+impl ImageCache {
+    /// Open a cache in `dir`, creating the directory if needed
+    pub fn new(dir: impl AsRef<Path>, client: impl HttpClient + 'static) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            client: Arc::new(client),
+            max_image_bytes: DEFAULT_MAX_IMAGE_BYTES,
+            max_total_bytes: None,
+            concurrency: DEFAULT_CONCURRENCY,
+        })
+    }
+
+    /// Refuse images larger than this, 5 MiB by default
+    pub fn with_max_image_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_image_bytes = max_bytes;
+        self
+    }
+
+    /// Remove the oldest images when the directory grows over this size
+    pub fn with_max_total_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_total_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Download at most this many images at once in `fetch_all()`, 4 by default
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Directory holding the images
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Local path of an image URL, if it has been downloaded
+    pub fn cached(&self, url: &str) -> Option<PathBuf> {
+        let stem = file_stem(url);
+        EXTENSIONS
+            .iter()
+            .map(|ext| self.dir.join(format!("{}.{}", stem, ext)))
+            .find(|path| path.is_file())
+    }
+
+    /// Local path of an image, downloading it unless already cached
+    ///
+    /// Fails with `FanError::NotAnImage` when the server answers with something else,
+    /// such as an HTML page, and `FanError::TooLarge` when the image is over the limit.
+    pub async fn fetch(&self, url: &str) -> Result<PathBuf> {
+        if let Some(path) = self.cached(url) {
+            return Ok(path);
+        }
+
+        let response = check_status(url, self.client.send(HttpRequest::get(url)).await?).await?;
+        let content_type = response.header("Content-Type").map(str::to_string);
+        if let Some(content_type) = content_type.as_deref().filter(|content_type| {
+            !content_type.starts_with("image/")
+                && !content_type.starts_with("application/octet-stream")
+        }) {
+            return Err(FanError::NotAnImage {
+                url: url.to_string(),
+                content_type: content_type.to_string(),
+            });
+        }
+        let too_large = || FanError::TooLarge {
+            url: url.to_string(),
+            limit: self.max_image_bytes,
+        };
+        if response
+            .header("Content-Length")
+            .and_then(|length| length.parse::<u64>().ok())
+            .is_some_and(|length| length > self.max_image_bytes)
+        {
+            return Err(too_large());
+        }
+
+        // Stop reading as soon as the limit is passed; the length header can be absent
+        let mut body = response.bytes_stream();
+        let mut image = Vec::new();
+        while let Some(chunk) = body.try_next().await? {
+            image.extend_from_slice(&chunk);
+            if image.len() as u64 > self.max_image_bytes {
+                return Err(too_large());
+            }
+        }
+
+        let ext = extension(url, content_type.as_deref());
+        let path = self.dir.join(format!("{}.{}", file_stem(url), ext));
+        // Write to a temporary file first so readers never see a partial image
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, &image)?;
+        fs::rename(&tmp_path, &path)?;
+        debug!("Cached {} bytes of {} at {:?}", image.len(), url, path);
+
+        if let Some(max_total_bytes) = self.max_total_bytes {
+            self.prune(max_total_bytes)?;
+        }
+        Ok(path)
+    }
+
+    /// Local path of an article's image, or `None` when it has no image
+    pub async fn fetch_article(&self, article: &NewsArticle) -> Result<Option<PathBuf>> {
+        match image_url(article) {
+            Some(url) => self.fetch(url).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Local paths of the images of several articles, in the same order
+    ///
+    /// Articles without an image, or whose image fails to download, get `None`;
+    /// failures are logged.
+    pub async fn fetch_all(&self, articles: &[NewsArticle]) -> Vec<Option<PathBuf>> {
+        stream::iter(articles)
+            .map(|article| async move {
+                self.fetch_article(article).await.unwrap_or_else(|e| {
+                    warn!("Failed to cache image of {:?}: {}", article.link, e);
+                    None
+                })
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+
+    /// Remove the least recently downloaded images until the directory is within
+    /// `max_total_bytes`
+    ///
+    /// # Returns
+    /// The number of files removed
+    pub fn prune(&self, max_total_bytes: u64) -> Result<usize> {
+        let mut removed = 0;
+        let mut images = Vec::new();
+        for dir_entry in fs::read_dir(&self.dir)? {
+            let dir_entry = dir_entry?;
+            let path = dir_entry.path();
+            // Temporary files of downloads in progress are left alone
+            if path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| EXTENSIONS.contains(&ext))
+            {
+                let metadata = dir_entry.metadata()?;
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                images.push((modified, metadata.len(), path));
+            }
+        }
+
+        images.sort_by_key(|(modified, _, _)| std::cmp::Reverse(*modified));
+        let mut total = 0;
+        for (_, len, path) in images {
+            total += len;
+            if total > max_total_bytes {
+                fs::remove_file(&path)?;
+                removed += 1;
+            }
+        }
+
+        debug!("Pruned {} images from {:?}", removed, self.dir);
+        Ok(removed)
+    }
+}
+
+impl std::fmt::Debug for ImageCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImageCache")
+            .field("dir", &self.dir)
+            .field("max_image_bytes", &self.max_image_bytes)
+            .field("max_total_bytes", &self.max_total_bytes)
+            .finish_non_exhaustive()
+    }
+}
+
+/// URL of an article's image, if it has one
+fn image_url(article: &NewsArticle) -> Option<&str> {
+    Some(article.extra_fields.get(THUMBNAIL_FIELD)?.as_str())
+        .filter(|url| url.starts_with("http://") || url.starts_with("https://"))
+}
+
+fn file_stem(url: &str) -> String {
+    format!("{:016x}", fnv1a_64(url.as_bytes()))
+}
+
+/// File extension of an image, from its `Content-Type` or else its URL
+fn extension(url: &str, content_type: Option<&str>) -> &'static str {
+    let from_type = content_type.and_then(|content_type| {
+        let subtype = content_type
+            .split(';')
+            .next()?
+            .trim()
+            .strip_prefix("image/")?;
+        Some(match subtype.to_ascii_lowercase().as_str() {
+            "jpeg" | "jpg" | "pjpeg" => "jpg",
+            "png" => "png",
+            "gif" => "gif",
+            "webp" => "webp",
+            "avif" => "avif",
+            "svg+xml" => "svg",
+            _ => "img",
+        })
+    });
+    from_type.unwrap_or_else(|| {
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        let ext = path
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase());
+        match ext.as_deref() {
+            Some("jpeg" | "jpg") => "jpg",
+            Some(ext) => EXTENSIONS
+                .iter()
+                .find(|known| **known == ext)
+                .copied()
+                .unwrap_or("img"),
+            None => "img",
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::HttpResponse;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Serves a 100-byte PNG under /img/, a page under /page, and 404 otherwise
+    #[derive(Default)]
+    struct Images {
+        requests: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl HttpClient for Images {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            if request.url.contains("/img/") {
+                Ok(HttpResponse::new(200, vec![0u8; 100]).with_header("content-type", "image/png"))
+            } else if request.url.ends_with("/page") {
+                Ok(HttpResponse::new(200, "<html></html>")
+                    .with_header("content-type", "text/html; charset=utf-8"))
+            } else {
+                Ok(HttpResponse::new(404, "Not Found"))
+            }
+        }
+    }
+
+    fn article(image: Option<&str>) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        if let Some(image) = image {
+            article
+                .extra_fields
+                .insert(THUMBNAIL_FIELD.to_string(), image.into());
+        }
+        article
+    }
+
+    #[tokio::test]
+    async fn test_fetch_is_cached_by_url() {
+        let dir = std::env::temp_dir().join(format!("fan-images-{}", std::process::id()));
+        let images = Arc::new(Images::default());
+        let cache = ImageCache::new(&dir, Arc::clone(&images)).unwrap();
+        let url = "https://example.com/img/a?w=200";
+
+        let path = cache.fetch(url).await.unwrap();
+        assert_eq!(path.extension().unwrap(), "png");
+        assert_eq!(fs::read(&path).unwrap().len(), 100);
+        assert_eq!(cache.fetch(url).await.unwrap(), path);
+        assert_eq!(images.requests.load(Ordering::SeqCst), 1);
+
+        let paths = cache
+            .fetch_all(&[
+                article(Some(url)),
+                article(None),
+                article(Some("https://example.com/missing.jpg")),
+            ])
+            .await;
+        assert_eq!(paths, vec![Some(path), None, None]);
+        assert_eq!(images.requests.load(Ordering::SeqCst), 2);
+
+        assert!(matches!(
+            cache.fetch("https://example.com/page").await,
+            Err(FanError::NotAnImage { .. })
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_size_limits() {
+        let dir = std::env::temp_dir().join(format!("fan-images-limits-{}", std::process::id()));
+        let small = ImageCache::new(&dir, Images::default())
+            .unwrap()
+            .with_max_image_bytes(99);
+        assert!(matches!(
+            small.fetch("https://example.com/img/a").await,
+            Err(FanError::TooLarge { limit: 99, .. })
+        ));
+        assert!(small.cached("https://example.com/img/a").is_none());
+
+        let bounded = ImageCache::new(&dir, Images::default())
+            .unwrap()
+            .with_max_total_bytes(250);
+        for name in ["a", "b", "c"] {
+            bounded
+                .fetch(&format!("https://example.com/img/{}", name))
+                .await
+                .unwrap();
+            // Modification times are the age of an image; keep them apart
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+        assert!(bounded.cached("https://example.com/img/c").is_some());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_extension() {
+        assert_eq!(extension("https://a.com/x", Some("image/jpeg")), "jpg");
+        assert_eq!(extension("https://a.com/x.JPEG?w=1", None), "jpg");
+        assert_eq!(
+            extension("https://a.com/x.webp", Some("application/octet-stream")),
+            "webp"
+        );
+        assert_eq!(extension("https://a.com/x", Some("image/x-icon")), "img");
+        assert_eq!(extension("https://a.com/x.php", None), "img");
+    }
+}
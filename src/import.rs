@@ -0,0 +1,424 @@
+//! Importing article datasets from other tools
+//!
+//! [`from_ndjson()`] and [`from_csv()`] read historical articles, such as a news
+//! dataset download or another scraper's output, into `NewsArticle`s that go through
+//! the same store, search, and deduplication as fetched ones. Each record's columns,
+//! or keys of a JSON object, are matched to article fields by a [`FieldMapping`],
+//! which recognizes the usual names:
+//!
+//! | Field | Columns |
+//! |-------|---------|
+//! | `title` | `title`, `headline` |
+//! | `link` | `link`, `url`, `article_url` |
+//! | `description` | `description`, `summary`, `abstract`, `body`, `text`, `content` |
+//! | `pub_date` | `pub_date`, `published`, `published_at`, `date`, `datetime`, `timestamp`, `time`, `created_at` |
+//! | `guid` | `guid`, `id`, `uuid` |
+//! | `category` | `category`, `section`, `topic` |
+//! | `author` | `author`, `authors`, `byline`, `creator` |
+//! | `source` | `source`, `publisher`, `site`, `outlet` |
+//! | `symbols` | `symbols`, `tickers`, `ticker`, `stocks` |
+//!
+//! Column names match ignoring case. Tickers go to `extra_fields["symbols"]`, where
+//! `ticker:` queries find them, and the other columns to `extra_fields` under their
+//! own names. Dates given as Unix timestamps, in seconds or milliseconds, become
+//! RFC 3339 dates; other dates are kept as written, since `NewsArticle::published_at()`
+//! reads the common formats. Records without a title, link, GUID, or description are
+//! skipped.
+//!
+//! Articles serialized by this crate import as they were, through `schema` when they
+//! carry a `schema_version`.
+
+use crate::error::{FanError, Result};
+use crate::schema::{self, SCHEMA_VERSION_FIELD};
+use crate::types::{Enclosure, ExtraValue, NewsArticle};
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs::File;
+#[cfg(feature = "csv")]
+use std::io::Read;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// Article fields and the columns recognized for them, in order of preference
+const FIELD_COLUMNS: &[(&str, &[&str])] = &[
+    ("title", &["title", "headline"]),
+    ("link", &["link", "url", "article_url"]),
+    (
+        "description",
+        &[
+            "description",
+            "summary",
+            "abstract",
+            "body",
+            "text",
+            "content",
+        ],
+    ),
+    (
+        "pub_date",
+        &[
+            "pub_date",
+            "published",
+            "published_at",
+            "date",
+            "datetime",
+            "timestamp",
+            "time",
+            "created_at",
+        ],
+    ),
+    ("guid", &["guid", "id", "uuid"]),
+    ("category", &["category", "section", "topic"]),
+    ("author", &["author", "authors", "byline", "creator"]),
+    ("source", &["source", "publisher", "site", "outlet"]),
+    ("symbols", &["symbols", "tickers", "ticker", "stocks"]),
+];
+
+/// Keys of articles serialized by this crate that are not columns of their own
+const ARTICLE_STATE_KEYS: &[&str] = &["stale", "updated", "matches", "priority"];
+
+/// Timestamps above this, in the year 5138 as seconds, are taken as milliseconds
+const MAX_TIMESTAMP_SECONDS: i64 = 100_000_000_000;
+
+/// Digits of the shortest timestamp read from text, so that dates such as `20240522`
+/// stay dates
+const MIN_TIMESTAMP_DIGITS: usize = 9;
+
+/// How the columns of a dataset map to article fields
+///
+/// Columns set with `with_field()` take precedence over the recognized names; see
+/// the [module docs](self). Deserializes from a config file as
+/// `{ "fields": { "title": "headline_text" }, "source": "Kaggle" }`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FieldMapping {
+    /// Column of each article field, by field name such as `title` or `symbols`
+    pub fields: HashMap<String, String>,
+    /// Source of records without one
+    pub source: Option<String>,
+}
+
+impl FieldMapping {
+    /// Recognize the usual column names
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read an article field, such as `title` or `symbols`, from a column
+    pub fn with_field(mut self, field: &str, column: &str) -> Self {
+        self.fields.insert(field.to_string(), column.to_string());
+        self
+    }
+
+    /// Set the source of records that have none, such as the dataset's outlet
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.source = Some(source.to_string());
+        self
+    }
+
+    /// The article field a column is read into, or `None` for an extra field
+    ///
+    /// `columns` are all the dataset's columns, so a column only maps to a field
+    /// when no column preferred for that field is present.
+    fn field_of(&self, column: &str, columns: &[&str]) -> Option<&'static str> {
+        let present = |name: &str| columns.iter().any(|c| c.eq_ignore_ascii_case(name));
+        FIELD_COLUMNS
+            .iter()
+            .find(|(field, names)| match self.fields.get(*field) {
+                Some(mapped) => mapped.eq_ignore_ascii_case(column),
+                None => names
+                    .iter()
+                    .find(|name| present(name))
+                    .is_some_and(|name| name.eq_ignore_ascii_case(column)),
+            })
+            .map(|(field, _)| *field)
+    }
+
+    /// Build an article from the columns and values of a record
+    fn article(&self, record: Vec<(String, Value)>) -> Option<NewsArticle> {
+        let columns: Vec<&str> = record.iter().map(|(column, _)| column.as_str()).collect();
+        let fields: Vec<Option<&'static str>> = columns
+            .iter()
+            .map(|column| self.field_of(column, &columns))
+            .collect();
+
+        let mut article = NewsArticle::new();
+        for ((column, value), field) in record.into_iter().zip(fields) {
+            match (field, column.as_str(), value) {
+                (_, _, Value::Null) => {}
+                (Some("symbols"), _, value) => {
+                    let symbols = symbols(value).join(",");
+                    if !symbols.is_empty() {
+                        article
+                            .extra_fields
+                            .insert("symbols".to_string(), symbols.into());
+                    }
+                }
+                (Some("pub_date"), _, value) => article.pub_date = date(value),
+                (Some(field), _, value) => {
+                    let text = Some(joined(value)).filter(|text| !text.is_empty());
+                    match field {
+                        "title" => article.title = text,
+                        "link" => article.link = text,
+                        "description" => article.description = text,
+                        "guid" => article.guid = text,
+                        "category" => article.category = text,
+                        "author" => article.author = text,
+                        _ => article.source = text,
+                    }
+                }
+                (None, "extra_fields", Value::Object(extra)) => {
+                    for (name, value) in extra {
+                        insert_extra(&mut article, name, value);
+                    }
+                }
+                (None, "enclosures", value) => {
+                    article.enclosures =
+                        serde_json::from_value::<Vec<Enclosure>>(value).unwrap_or_default();
+                }
+                (None, column, _) if ARTICLE_STATE_KEYS.contains(&column) => {}
+                (None, _, value) => insert_extra(&mut article, column, value),
+            }
+        }
+        if article.source.is_none() {
+            article.source = self.source.clone();
+        }
+        let identified = article.title.is_some()
+            || article.link.is_some()
+            || article.guid.is_some()
+            || article.description.is_some();
+        identified.then_some(article)
+    }
+}
+
+/// Read articles from a file of JSON objects, one per line
+pub fn from_ndjson(path: impl AsRef<Path>, mapping: &FieldMapping) -> Result<Vec<NewsArticle>> {
+    from_ndjson_reader(BufReader::new(File::open(path)?), mapping)
+}
+
+/// Read articles from JSON objects, one per line
+///
+/// Blank lines are skipped; a line that is not a JSON object fails the import.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::import::{FieldMapping, from_ndjson_reader};
+///
+/// let data = r#"{"headline": "Fed holds rates", "url": "https://example.com/fed", "published": 1714680000, "tickers": ["SPY", "TLT"]}
+/// {"headline": "Oil slips", "url": "https://example.com/oil", "publisher": "Reuters"}"#;
+/// let mapping = FieldMapping::new().with_source("Example");
+///
+/// let articles = from_ndjson_reader(data.as_bytes(), &mapping).unwrap();
+/// assert_eq!(articles[0].title.as_deref(), Some("Fed holds rates"));
+/// assert_eq!(articles[0].pub_date.as_deref(), Some("2024-05-02T20:00:00+00:00"));
+/// assert_eq!(articles[0].extra_fields["symbols"], "SPY,TLT");
+/// assert_eq!(articles[0].source.as_deref(), Some("Example"));
+/// assert_eq!(articles[1].source.as_deref(), Some("Reuters"));
+/// ```
+pub fn from_ndjson_reader(
+    reader: impl BufRead,
+    mapping: &FieldMapping,
+) -> Result<Vec<NewsArticle>> {
+    let mut articles = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: Map<String, Value> = serde_json::from_str(&line)
+            .map_err(|e| FanError::InvalidImport(format!("line {}: {}", index + 1, e)))?;
+        if record.contains_key(SCHEMA_VERSION_FIELD) {
+            // Written by this crate
+            let article = schema::from_value(Value::Object(record))
+                .map_err(|e| FanError::InvalidImport(format!("line {}: {}", index + 1, e)))?;
+            articles.push(article);
+            continue;
+        }
+        articles.extend(mapping.article(record.into_iter().collect()));
+    }
+    Ok(articles)
+}
+
+/// Read articles from a CSV file with a header row
+#[cfg(feature = "csv")]
+pub fn from_csv(path: impl AsRef<Path>, mapping: &FieldMapping) -> Result<Vec<NewsArticle>> {
+    from_csv_reader(File::open(path)?, mapping)
+}
+
+/// Read articles from CSV data with a header row
+///
+/// Empty cells are left out. A `symbols` cell lists tickers separated by commas,
+/// spaces, or semicolons.
+#[cfg(feature = "csv")]
+pub fn from_csv_reader(reader: impl Read, mapping: &FieldMapping) -> Result<Vec<NewsArticle>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+    let header = reader.headers()?.clone();
+    let mut articles = Vec::new();
+    for row in reader.records() {
+        let record = header
+            .iter()
+            .zip(row?.iter())
+            .filter(|(_, cell)| !cell.is_empty())
+            .map(|(column, cell)| (column.to_string(), Value::String(cell.to_string())))
+            .collect();
+        articles.extend(mapping.article(record));
+    }
+    Ok(articles)
+}
+
+/// Store a column that is not an article field in `extra_fields`
+fn insert_extra(article: &mut NewsArticle, name: String, value: Value) {
+    let value = match value {
+        Value::Array(items) => ExtraValue::List(items.into_iter().map(text).collect()),
+        value => ExtraValue::Text(text(value)),
+    };
+    if !value.as_str().is_empty() {
+        article.extra_fields.insert(name, value);
+    }
+}
+
+/// The tickers of a cell: the items of an array, or a list in text
+fn symbols(value: Value) -> Vec<String> {
+    match value {
+        Value::Array(items) => items
+            .into_iter()
+            .map(text)
+            .filter(|item| !item.is_empty())
+            .collect(),
+        Value::String(list) => list
+            .split([',', ';', ' '])
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect(),
+        value => vec![text(value)],
+    }
+}
+
+/// The text of a cell, with the items of an array separated by ", "
+fn joined(value: Value) -> String {
+    match value {
+        Value::Array(items) => items
+            .into_iter()
+            .map(text)
+            .filter(|item| !item.is_empty())
+            .collect::<Vec<_>>()
+            .join(", "),
+        value => text(value),
+    }
+}
+
+fn text(value: Value) -> String {
+    match value {
+        Value::String(text) => text.trim().to_string(),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+/// A date as stored in `pub_date`, with Unix timestamps turned into RFC 3339
+fn date(value: Value) -> Option<String> {
+    let timestamp = match &value {
+        Value::Number(number) => number.as_f64().map(|seconds| seconds as i64),
+        Value::String(text) if text.trim().len() >= MIN_TIMESTAMP_DIGITS => {
+            text.trim().parse::<i64>().ok()
+        }
+        _ => None,
+    };
+    let converted = timestamp.and_then(|timestamp| {
+        if timestamp > MAX_TIMESTAMP_SECONDS {
+            DateTime::from_timestamp_millis(timestamp)
+        } else {
+            DateTime::from_timestamp(timestamp, 0)
+        }
+    });
+    match converted {
+        Some(date) => Some(date.to_rfc3339()),
+        None => Some(text(value)).filter(|text| !text.is_empty()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_from_csv() {
+        let data = "\
+Headline,Link,Date,Tickers,Sentiment,Summary
+Nvidia beats,https://example.com/nvda,2024-05-22,NVDA,0.8,
+\"Apple, Microsoft rally\",https://example.com/big-tech,1716400000000,AAPL; MSFT,0.4,Big tech leads
+,,,,0.1,
+";
+        let articles = from_csv_reader(data.as_bytes(), &FieldMapping::new()).unwrap();
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0].title.as_deref(), Some("Nvidia beats"));
+        assert_eq!(articles[0].pub_date.as_deref(), Some("2024-05-22"));
+        assert!(articles[0].description.is_none());
+        assert_eq!(articles[0].extra_fields["Sentiment"], "0.8");
+        assert_eq!(articles[1].title.as_deref(), Some("Apple, Microsoft rally"));
+        assert_eq!(
+            articles[1].pub_date.as_deref(),
+            Some("2024-05-22T17:46:40+00:00")
+        );
+        assert_eq!(articles[1].extra_fields["symbols"], "AAPL,MSFT");
+        assert_eq!(articles[1].description.as_deref(), Some("Big tech leads"));
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_mapped_fields() {
+        // "title" would be picked by name; the mapping takes "headline_text" instead
+        let data = "title,headline_text,id\nignored,Fed holds rates,42\n";
+        let mapping = FieldMapping::new()
+            .with_field("title", "headline_text")
+            .with_source("Kaggle");
+        let articles = from_csv_reader(data.as_bytes(), &mapping).unwrap();
+        assert_eq!(articles[0].title.as_deref(), Some("Fed holds rates"));
+        assert_eq!(articles[0].extra_fields["title"], "ignored");
+        assert_eq!(articles[0].guid.as_deref(), Some("42"));
+        assert_eq!(articles[0].source.as_deref(), Some("Kaggle"));
+
+        // Only the preferred of two recognized columns is the field
+        let data = "url,link\nhttps://example.com/a,https://example.com/b\n";
+        let articles = from_csv_reader(data.as_bytes(), &FieldMapping::new()).unwrap();
+        assert_eq!(articles[0].link.as_deref(), Some("https://example.com/b"));
+        assert_eq!(articles[0].extra_fields["url"], "https://example.com/a");
+    }
+
+    #[test]
+    fn test_own_articles_round_trip() {
+        let mut article = NewsArticle::new();
+        article.title = Some("Nvidia beats".to_string());
+        article.source = Some("CNBC".to_string());
+        article.pub_date = Some("Wed, 22 May 2024 20:21:00 GMT".to_string());
+        article.extra_fields.insert(
+            "media:credit".to_string(),
+            vec!["A".to_string(), "B".to_string()].into(),
+        );
+        article
+            .enclosures
+            .push(Enclosure::new("https://example.com/a.mp3"));
+        let line = serde_json::to_string(&article).unwrap();
+
+        let imported = from_ndjson_reader(line.as_bytes(), &FieldMapping::new()).unwrap();
+        let imported = &imported[0];
+        assert_eq!(imported.title, article.title);
+        assert_eq!(imported.pub_date, article.pub_date);
+        assert_eq!(imported.extra_fields, article.extra_fields);
+        assert_eq!(imported.enclosures, article.enclosures);
+    }
+
+    #[test]
+    fn test_invalid_line() {
+        let data = "{\"title\": \"ok\"}\n\nnot json\n";
+        let error = from_ndjson_reader(data.as_bytes(), &FieldMapping::new()).unwrap_err();
+        assert!(error.to_string().contains("line 3"), "{}", error);
+    }
+}
@@ -3,12 +3,87 @@
 //! A Rust library for aggregating financial news from various sources.
 //! This is a port of the Python finance-news-aggregator project.
 
+pub mod aggregation;
+#[cfg(feature = "client")]
+pub mod alerts;
+pub mod analytics;
+#[cfg(feature = "client")]
+pub mod audit;
+pub mod availability;
+#[cfg(feature = "backfill")]
+pub mod backfill;
+pub mod breaking;
+pub mod cache;
+pub mod cluster;
+#[cfg(feature = "arrow")]
+pub mod columnar;
+pub mod content_kind;
+pub mod credentials;
+#[cfg(feature = "polars")]
+pub mod dataframe;
+pub mod dates;
+pub mod deprecation;
+pub mod disk_cache;
+#[cfg(feature = "client")]
+pub mod dns;
+pub mod earnings;
 pub mod error;
+#[cfg(feature = "client")]
+pub mod events;
+pub mod export;
+pub mod feed_format;
+pub mod filter;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(feature = "client")]
+pub mod health;
+#[cfg(feature = "fetch")]
+pub mod http;
+#[cfg(feature = "client")]
+pub mod ics;
+#[cfg(feature = "fetch")]
+pub mod image_cache;
+pub mod import;
+pub mod macro_events;
+pub mod market_hours;
+pub mod monitoring;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+#[cfg(feature = "nats")]
+pub mod nats;
+#[cfg(feature = "client")]
 pub mod news_client;
+#[cfg(feature = "fetch")]
 pub mod news_source;
+#[cfg(feature = "object-store")]
+pub mod object_export;
+pub mod output;
 pub mod parser;
+#[cfg(feature = "client")]
+pub mod poller;
+#[cfg(feature = "proto")]
+pub mod proto;
+pub mod quirks;
+pub mod schema;
+pub mod sector;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod source_info;
+#[cfg(feature = "sqlite")]
+pub mod store;
+pub mod symbols;
+pub mod topic_group;
 pub mod types;
+#[cfg(feature = "fetch")]
+pub mod unfurl;
+#[cfg(feature = "client")]
+pub mod volume;
+#[cfg(feature = "csv")]
+pub mod watchlist;
 
 pub use error::{FanError, Result};
+#[cfg(feature = "client")]
 pub use news_client::NewsClient;
-pub use types::NewsArticle;
+pub use sector::Sector;
+pub use topic_group::TopicGroup;
+pub use types::{ExtraValue, NewsArticle};
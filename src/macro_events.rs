@@ -0,0 +1,423 @@
+//! Tagging of articles about scheduled macro-economic events
+//!
+//! A [`MacroCalendar`] knows when each [`MacroEvent`] is scheduled. An article that
+//! mentions an event within a few days of one of its dates is tagged with the
+//! event's name in `extra_fields["macro_event"]`, a list when it covers several, so
+//! alert rules can select it with `event:FOMC`:
+//!
+//! | Event | Mentions | Schedule |
+//! |-------|----------|----------|
+//! | `FOMC` | FOMC, Federal Open Market Committee, Fed meeting, Fed decision | Fed rate decisions |
+//! | `CPI` | CPI, consumer price index, inflation report, inflation data | the 10th to the 15th of each month |
+//! | `NFP` | NFP, nonfarm payrolls, jobs report | the first Friday of each month |
+//! | `ECB` | ECB, European Central Bank | ECB monetary policy meetings |
+//!
+//! The built-in FOMC and ECB dates cover 2024 through 2026; later meetings are added
+//! with `MacroCalendar::with_schedule()`. Dates are US Eastern.
+
+use crate::aggregation::mentions_any;
+use crate::dates::to_us_eastern;
+use crate::error::FanError;
+use crate::types::{ExtraValue, NewsArticle};
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Key of the tag in `NewsArticle::extra_fields`
+pub const MACRO_EVENT_FIELD: &str = "macro_event";
+
+/// Days either side of a scheduled date in which mentions count, by default
+const DEFAULT_WINDOW_DAYS: u64 = 4;
+
+/// FOMC rate decisions, the second day of each meeting
+const FOMC_DATES: &[(i32, u32, u32)] = &[
+    (2024, 1, 31),
+    (2024, 3, 20),
+    (2024, 5, 1),
+    (2024, 6, 12),
+    (2024, 7, 31),
+    (2024, 9, 18),
+    (2024, 11, 7),
+    (2024, 12, 18),
+    (2025, 1, 29),
+    (2025, 3, 19),
+    (2025, 5, 7),
+    (2025, 6, 18),
+    (2025, 7, 30),
+    (2025, 9, 17),
+    (2025, 10, 29),
+    (2025, 12, 10),
+    (2026, 1, 28),
+    (2026, 3, 18),
+    (2026, 4, 29),
+    (2026, 6, 17),
+    (2026, 7, 29),
+    (2026, 9, 16),
+    (2026, 10, 28),
+    (2026, 12, 9),
+];
+
+/// ECB monetary policy meetings
+const ECB_DATES: &[(i32, u32, u32)] = &[
+    (2024, 1, 25),
+    (2024, 3, 7),
+    (2024, 4, 11),
+    (2024, 6, 6),
+    (2024, 7, 18),
+    (2024, 9, 12),
+    (2024, 10, 17),
+    (2024, 12, 12),
+    (2025, 1, 30),
+    (2025, 3, 6),
+    (2025, 4, 17),
+    (2025, 6, 5),
+    (2025, 7, 24),
+    (2025, 9, 11),
+    (2025, 10, 30),
+    (2025, 12, 18),
+    (2026, 2, 5),
+    (2026, 3, 19),
+    (2026, 4, 30),
+    (2026, 6, 11),
+    (2026, 7, 23),
+    (2026, 9, 10),
+    (2026, 10, 29),
+    (2026, 12, 17),
+];
+
+/// A scheduled macro-economic release or meeting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum MacroEvent {
+    /// Federal Open Market Committee rate decision
+    Fomc,
+    /// US consumer price index release
+    Cpi,
+    /// US nonfarm payrolls, the monthly jobs report
+    Nfp,
+    /// European Central Bank monetary policy meeting
+    Ecb,
+}
+
+impl MacroEvent {
+    /// All events
+    pub fn all() -> Vec<MacroEvent> {
+        vec![
+            MacroEvent::Fomc,
+            MacroEvent::Cpi,
+            MacroEvent::Nfp,
+            MacroEvent::Ecb,
+        ]
+    }
+
+    /// Name of the event, as tagged on articles
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MacroEvent::Fomc => "FOMC",
+            MacroEvent::Cpi => "CPI",
+            MacroEvent::Nfp => "NFP",
+            MacroEvent::Ecb => "ECB",
+        }
+    }
+
+    /// Words and phrases that refer to the event, matched as in
+    /// `aggregation::mentions_any()`
+    pub fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            MacroEvent::Fomc => &[
+                "FOMC",
+                "Federal Open Market Committee",
+                "Fed meeting",
+                "Fed decision",
+                "Fed rate decision",
+            ],
+            MacroEvent::Cpi => &[
+                "CPI",
+                "consumer price index",
+                "inflation report",
+                "inflation data",
+            ],
+            // "non farm" also matches "non-farm"
+            MacroEvent::Nfp => &[
+                "NFP",
+                "nonfarm payrolls",
+                "non farm payrolls",
+                "jobs report",
+            ],
+            MacroEvent::Ecb => &["ECB", "European Central Bank"],
+        }
+    }
+}
+
+impl fmt::Display for MacroEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MacroEvent {
+    type Err = FanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MacroEvent::all()
+            .into_iter()
+            .find(|event| event.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| FanError::Unknown(format!("Unknown macro event: {}", s)))
+    }
+}
+
+/// When an event takes place
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Schedule {
+    /// Fixed dates, such as central bank meetings
+    Dates(Vec<NaiveDate>),
+    /// The nth weekday of every month, counted from 1
+    NthWeekday { nth: u8, weekday: Weekday },
+    /// Any day of every month from `first` to `last`, for releases whose exact day
+    /// varies
+    DayRange { first: u32, last: u32 },
+}
+
+impl Schedule {
+    fn from_table(dates: &[(i32, u32, u32)]) -> Self {
+        Schedule::Dates(
+            dates
+                .iter()
+                .filter_map(|&(year, month, day)| NaiveDate::from_ymd_opt(year, month, day))
+                .collect(),
+        )
+    }
+
+    /// Days between `date` and the nearest scheduled date, if one is in the
+    /// surrounding months
+    fn days_from(&self, date: NaiveDate) -> Option<u64> {
+        let month = date.with_day(1)?;
+        let months = [
+            month.checked_sub_months(Months::new(1)),
+            Some(month),
+            month.checked_add_months(Months::new(1)),
+        ];
+        let candidates: Vec<NaiveDate> = match self {
+            Schedule::Dates(dates) => dates.clone(),
+            Schedule::NthWeekday { nth, weekday } => months
+                .iter()
+                .flatten()
+                .filter_map(|month| {
+                    NaiveDate::from_weekday_of_month_opt(
+                        month.year(),
+                        month.month(),
+                        *weekday,
+                        *nth,
+                    )
+                })
+                .collect(),
+            Schedule::DayRange { first, last } => months
+                .iter()
+                .flatten()
+                .flat_map(|month| (*first..=*last).filter_map(|day| month.with_day(day)))
+                .collect(),
+        };
+        candidates
+            .into_iter()
+            .map(|scheduled| (scheduled - date).num_days().unsigned_abs())
+            .min()
+    }
+}
+
+/// When macro events are scheduled, for tagging the articles that cover them
+#[derive(Debug, Clone, PartialEq)]
+pub struct MacroCalendar {
+    schedules: Vec<(MacroEvent, Schedule)>,
+    window_days: u64,
+}
+
+impl Default for MacroCalendar {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl MacroCalendar {
+    /// A calendar without events
+    pub fn new() -> Self {
+        Self {
+            schedules: Vec::new(),
+            window_days: DEFAULT_WINDOW_DAYS,
+        }
+    }
+
+    /// The built-in calendar; see the [module docs](self) for its schedules
+    pub fn builtin() -> Self {
+        Self::new()
+            .with_schedule(MacroEvent::Fomc, Schedule::from_table(FOMC_DATES))
+            .with_schedule(
+                MacroEvent::Cpi,
+                Schedule::DayRange {
+                    first: 10,
+                    last: 15,
+                },
+            )
+            .with_schedule(
+                MacroEvent::Nfp,
+                Schedule::NthWeekday {
+                    nth: 1,
+                    weekday: Weekday::Fri,
+                },
+            )
+            .with_schedule(MacroEvent::Ecb, Schedule::from_table(ECB_DATES))
+    }
+
+    /// Add a schedule of an event, in addition to those it has
+    pub fn with_schedule(mut self, event: MacroEvent, schedule: Schedule) -> Self {
+        self.schedules.push((event, schedule));
+        self
+    }
+
+    /// Count mentions up to `window` before or after a scheduled date, 4 days by
+    /// default, so previews and reactions are tagged too
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window_days = window.as_secs() / 86_400;
+        self
+    }
+
+    /// The events scheduled within the window of a date
+    pub fn events_near(&self, date: NaiveDate) -> Vec<MacroEvent> {
+        let mut events = Vec::new();
+        for (event, schedule) in &self.schedules {
+            let near = schedule
+                .days_from(date)
+                .is_some_and(|days| days <= self.window_days);
+            if near && !events.contains(event) {
+                events.push(*event);
+            }
+        }
+        events
+    }
+
+    /// Tag an article with the scheduled events it mentions
+    ///
+    /// Undated articles are taken to be published now. Returns whether the article
+    /// was tagged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsArticle;
+    /// use finance_news_aggregator_rs::macro_events::MacroCalendar;
+    ///
+    /// let mut article = NewsArticle::new();
+    /// article.title = Some("Stocks slip ahead of FOMC decision".to_string());
+    /// article.pub_date = Some("Mon, 29 Apr 2024 14:00:00 GMT".to_string());
+    ///
+    /// assert!(MacroCalendar::builtin().tag(&mut article));
+    /// assert_eq!(article.extra("", "macro_event").unwrap(), "FOMC");
+    /// ```
+    pub fn tag(&self, article: &mut NewsArticle) -> bool {
+        self.tag_at(article, Utc::now())
+    }
+
+    /// Tag an article, taking undated articles to be published at `now`
+    pub fn tag_at(&self, article: &mut NewsArticle, now: DateTime<Utc>) -> bool {
+        let date = to_us_eastern(article.published_at().unwrap_or(now)).date();
+        let names: Vec<String> = self
+            .events_near(date)
+            .into_iter()
+            .filter(|event| mentions_any(article, event.keywords()))
+            .map(|event| event.as_str().to_string())
+            .collect();
+        let value = match names.len() {
+            0 => return false,
+            1 => ExtraValue::from(names[0].clone()),
+            _ => ExtraValue::from(names),
+        };
+        article
+            .extra_fields
+            .insert(MACRO_EVENT_FIELD.to_string(), value);
+        true
+    }
+
+    /// Tag every article of a list
+    pub fn tag_all(&self, articles: &mut [NewsArticle]) {
+        let now = Utc::now();
+        for article in articles {
+            self.tag_at(article, now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(title: &str, pub_date: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some(title.to_string());
+        article.pub_date = Some(pub_date.to_string());
+        article
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_events_near() {
+        let calendar = MacroCalendar::builtin();
+        // Friday 2024-05-03 was the first Friday of May, two days after the FOMC
+        assert_eq!(
+            calendar.events_near(date(2024, 5, 3)),
+            [MacroEvent::Fomc, MacroEvent::Nfp]
+        );
+        assert_eq!(calendar.events_near(date(2024, 5, 13)), [MacroEvent::Cpi]);
+        // The first Friday of a month can be near the end of the one before
+        assert_eq!(calendar.events_near(date(2024, 10, 29)), [MacroEvent::Nfp]);
+        assert!(calendar.events_near(date(2024, 5, 22)).is_empty());
+
+        let narrow = MacroCalendar::builtin().with_window(Duration::from_secs(86_400));
+        assert!(narrow.events_near(date(2024, 10, 29)).is_empty());
+    }
+
+    #[test]
+    fn test_tag_only_near_scheduled_dates() {
+        let calendar = MacroCalendar::builtin();
+        let mut preview = article(
+            "What to watch at this week's Fed meeting",
+            "2024-07-29T12:00:00Z",
+        );
+        assert!(calendar.tag(&mut preview));
+        assert_eq!(preview.extra_fields[MACRO_EVENT_FIELD], "FOMC");
+
+        // No meeting that week
+        let mut commentary = article("FOMC members split on rate path", "2024-08-21T12:00:00Z");
+        assert!(!calendar.tag(&mut commentary));
+        assert!(!commentary.extra_fields.contains_key(MACRO_EVENT_FIELD));
+
+        let mut both = article(
+            "Non-farm payrolls beat as traders eye FOMC",
+            "2024-05-02T12:00:00Z",
+        );
+        assert!(calendar.tag(&mut both));
+        assert_eq!(
+            both.extra_fields[MACRO_EVENT_FIELD].values(),
+            ["FOMC", "NFP"]
+        );
+    }
+
+    #[test]
+    fn test_custom_schedule() {
+        let calendar = MacroCalendar::new()
+            .with_schedule(MacroEvent::Ecb, Schedule::Dates(vec![date(2027, 2, 4)]));
+        let mut articles = vec![
+            article("ECB seen holding rates", "2027-02-02T08:00:00Z"),
+            article("CPI cools", "2027-02-12T08:00:00Z"),
+        ];
+        calendar.tag_all(&mut articles);
+        assert_eq!(articles[0].extra_fields[MACRO_EVENT_FIELD], "ECB");
+        assert!(articles[1].extra_fields.is_empty());
+
+        assert_eq!("fomc".parse::<MacroEvent>().unwrap(), MacroEvent::Fomc);
+        assert!("BOJ".parse::<MacroEvent>().is_err());
+    }
+}
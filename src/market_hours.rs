@@ -0,0 +1,341 @@
+//! US equity market sessions and polling profiles keyed to them
+//!
+//! Financial news clusters around the trading day: pre-market movers, the opening
+//! bell, earnings after the close. A [`PollingProfile`] gives each [`MarketSession`]
+//! its own poll interval so a `Poller` polls hardest around the open and close and
+//! relaxes overnight and on weekends.
+//!
+//! Sessions follow NYSE and Nasdaq hours in US Eastern time. Exchange holidays and
+//! early closes are not known and are treated as ordinary trading days.
+
+use crate::dates::to_us_eastern;
+use chrono::{DateTime, Datelike, NaiveDateTime, Timelike, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Start of pre-market trading, in seconds after midnight Eastern time
+const PRE_MARKET_OPEN: u32 = 4 * 3600;
+/// Opening bell
+const MARKET_OPEN: u32 = 9 * 3600 + 30 * 60;
+/// Closing bell
+const MARKET_CLOSE: u32 = 16 * 3600;
+/// End of after-hours trading
+const AFTER_HOURS_CLOSE: u32 = 20 * 3600;
+const DAY: u32 = 24 * 3600;
+
+/// Part of the US equity trading week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketSession {
+    /// 4:00 to 9:30 on weekdays
+    PreMarket,
+    /// Regular trading hours, 9:30 to 16:00 on weekdays
+    Regular,
+    /// 16:00 to 20:00 on weekdays
+    AfterHours,
+    /// 20:00 to 4:00 between weekdays
+    Overnight,
+    /// Saturday and Sunday
+    Weekend,
+}
+
+impl MarketSession {
+    /// Session in effect at a time
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use finance_news_aggregator_rs::market_hours::MarketSession;
+    ///
+    /// // 10:00 in New York on a Wednesday
+    /// let time = Utc.with_ymd_and_hms(2024, 5, 1, 14, 0, 0).unwrap();
+    /// assert_eq!(MarketSession::at(time), MarketSession::Regular);
+    /// ```
+    pub fn at(time: DateTime<Utc>) -> Self {
+        Self::at_local(to_us_eastern(time))
+    }
+
+    fn at_local(local: NaiveDateTime) -> Self {
+        if is_weekend(local) {
+            return MarketSession::Weekend;
+        }
+        match local.num_seconds_from_midnight() {
+            seconds if seconds < PRE_MARKET_OPEN => MarketSession::Overnight,
+            seconds if seconds < MARKET_OPEN => MarketSession::PreMarket,
+            seconds if seconds < MARKET_CLOSE => MarketSession::Regular,
+            seconds if seconds < AFTER_HOURS_CLOSE => MarketSession::AfterHours,
+            _ => MarketSession::Overnight,
+        }
+    }
+}
+
+/// Poll intervals for each market session
+///
+/// The default is the built-in market-hours profile: every minute within 15 minutes
+/// of the opening and closing bells, every 2 minutes during regular hours, every 5
+/// minutes pre-market and after hours, every 30 minutes overnight, and hourly on
+/// weekends. In a config file, `{}` selects it and any field overrides it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PollingProfile {
+    pub pre_market_seconds: u64,
+    pub regular_seconds: u64,
+    pub after_hours_seconds: u64,
+    pub overnight_seconds: u64,
+    pub weekend_seconds: u64,
+    /// Interval near the opening and closing bells, overriding the session's own
+    pub bell_seconds: u64,
+    /// How long before and after each bell `bell_seconds` applies
+    pub bell_window_seconds: u64,
+}
+
+impl Default for PollingProfile {
+    fn default() -> Self {
+        Self::market_hours()
+    }
+}
+
+impl PollingProfile {
+    /// The built-in market-hours profile
+    pub fn market_hours() -> Self {
+        Self {
+            pre_market_seconds: 300,
+            regular_seconds: 120,
+            after_hours_seconds: 300,
+            overnight_seconds: 1800,
+            weekend_seconds: 3600,
+            bell_seconds: 60,
+            bell_window_seconds: 900,
+        }
+    }
+
+    /// Profile polling at the same interval in every session
+    pub fn constant(interval: Duration) -> Self {
+        let seconds = interval.as_secs();
+        Self {
+            pre_market_seconds: seconds,
+            regular_seconds: seconds,
+            after_hours_seconds: seconds,
+            overnight_seconds: seconds,
+            weekend_seconds: seconds,
+            bell_seconds: seconds,
+            bell_window_seconds: 0,
+        }
+    }
+
+    /// Set the interval of one session
+    pub fn with_session(mut self, session: MarketSession, interval: Duration) -> Self {
+        let seconds = interval.as_secs();
+        match session {
+            MarketSession::PreMarket => self.pre_market_seconds = seconds,
+            MarketSession::Regular => self.regular_seconds = seconds,
+            MarketSession::AfterHours => self.after_hours_seconds = seconds,
+            MarketSession::Overnight => self.overnight_seconds = seconds,
+            MarketSession::Weekend => self.weekend_seconds = seconds,
+        }
+        self
+    }
+
+    /// Set the interval near the bells and how far either side of them it applies
+    pub fn with_bell(mut self, interval: Duration, window: Duration) -> Self {
+        self.bell_seconds = interval.as_secs();
+        self.bell_window_seconds = window.as_secs();
+        self
+    }
+
+    /// Interval of a session, ignoring the bells
+    pub fn session_interval(&self, session: MarketSession) -> Duration {
+        let seconds = match session {
+            MarketSession::PreMarket => self.pre_market_seconds,
+            MarketSession::Regular => self.regular_seconds,
+            MarketSession::AfterHours => self.after_hours_seconds,
+            MarketSession::Overnight => self.overnight_seconds,
+            MarketSession::Weekend => self.weekend_seconds,
+        };
+        Duration::from_secs(seconds.max(1))
+    }
+
+    /// Interval in effect at a time
+    pub fn interval_at(&self, time: DateTime<Utc>) -> Duration {
+        self.interval_at_local(to_us_eastern(time))
+    }
+
+    /// Delay from `time` until the next poll
+    ///
+    /// The interval in effect, cut short when a session or bell window with a
+    /// different interval starts sooner, so that a feed relaxed overnight is still
+    /// polled right at the pre-market open.
+    pub fn next_poll_delay(&self, time: DateTime<Utc>) -> Duration {
+        let mut local = to_us_eastern(time);
+        let interval = self.interval_at_local(local);
+        let mut delay = Duration::ZERO;
+        while delay < interval {
+            let step = self.until_next_change(local);
+            delay += step;
+            local += chrono::Duration::seconds(step.as_secs() as i64);
+            if self.interval_at_local(local) != interval {
+                return delay.min(interval);
+            }
+        }
+        interval
+    }
+
+    fn interval_at_local(&self, local: NaiveDateTime) -> Duration {
+        if self.near_bell(local) {
+            Duration::from_secs(self.bell_seconds.max(1))
+        } else {
+            self.session_interval(MarketSession::at_local(local))
+        }
+    }
+
+    fn near_bell(&self, local: NaiveDateTime) -> bool {
+        let window = self.bell_window_seconds.min(u64::from(DAY)) as u32;
+        let seconds = local.num_seconds_from_midnight();
+        !is_weekend(local)
+            && [MARKET_OPEN, MARKET_CLOSE]
+                .iter()
+                .any(|bell| seconds + window >= *bell && seconds < bell + window)
+    }
+
+    /// Time from `local` until the session or bell window next changes
+    fn until_next_change(&self, local: NaiveDateTime) -> Duration {
+        let window = self.bell_window_seconds.min(u64::from(MARKET_OPEN)) as u32;
+        let seconds = local.num_seconds_from_midnight();
+        let next = if is_weekend(local) {
+            DAY
+        } else {
+            [
+                PRE_MARKET_OPEN,
+                MARKET_OPEN - window,
+                MARKET_OPEN + window,
+                MARKET_CLOSE - window,
+                MARKET_CLOSE + window,
+                AFTER_HOURS_CLOSE,
+                DAY,
+            ]
+            .into_iter()
+            .filter(|change| *change > seconds)
+            .min()
+            .unwrap_or(DAY)
+        };
+        Duration::from_secs(u64::from(next - seconds))
+    }
+}
+
+fn is_weekend(local: NaiveDateTime) -> bool {
+    matches!(local.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// A time given in New York local time, during daylight saving time
+    fn new_york(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        let midnight = Utc.with_ymd_and_hms(2024, 5, day, 4, 0, 0).unwrap();
+        midnight + chrono::Duration::minutes(i64::from(hour * 60 + minute))
+    }
+
+    #[test]
+    fn test_sessions() {
+        // 2024-05-01 is a Wednesday
+        assert_eq!(
+            MarketSession::at(new_york(1, 3, 59)),
+            MarketSession::Overnight
+        );
+        assert_eq!(
+            MarketSession::at(new_york(1, 4, 0)),
+            MarketSession::PreMarket
+        );
+        assert_eq!(
+            MarketSession::at(new_york(1, 9, 30)),
+            MarketSession::Regular
+        );
+        assert_eq!(
+            MarketSession::at(new_york(1, 16, 0)),
+            MarketSession::AfterHours
+        );
+        assert_eq!(
+            MarketSession::at(new_york(1, 19, 59)),
+            MarketSession::AfterHours
+        );
+        assert_eq!(
+            MarketSession::at(new_york(3, 19, 59)),
+            MarketSession::AfterHours
+        );
+        assert_eq!(
+            MarketSession::at(new_york(4, 12, 0)),
+            MarketSession::Weekend
+        );
+        assert_eq!(
+            MarketSession::at(new_york(5, 19, 0)),
+            MarketSession::Weekend
+        );
+    }
+
+    #[test]
+    fn test_market_hours_intervals() {
+        let profile = PollingProfile::market_hours();
+        let minutes = |minutes: u64| Duration::from_secs(minutes * 60);
+        assert_eq!(profile.interval_at(new_york(1, 2, 0)), minutes(30));
+        assert_eq!(profile.interval_at(new_york(1, 7, 0)), minutes(5));
+        assert_eq!(profile.interval_at(new_york(1, 9, 20)), minutes(1));
+        assert_eq!(profile.interval_at(new_york(1, 9, 45)), minutes(2));
+        assert_eq!(profile.interval_at(new_york(1, 16, 10)), minutes(1));
+        assert_eq!(profile.interval_at(new_york(1, 17, 0)), minutes(5));
+        assert_eq!(profile.interval_at(new_york(4, 9, 30)), minutes(60));
+    }
+
+    #[test]
+    fn test_next_poll_stops_at_session_changes() {
+        let profile = PollingProfile::market_hours();
+        // 3:50 overnight: the pre-market open comes before the 30 minute interval
+        assert_eq!(
+            profile.next_poll_delay(new_york(1, 3, 50)),
+            Duration::from_secs(600)
+        );
+        // 9:10 pre-market: the bell window opens at 9:15
+        assert_eq!(
+            profile.next_poll_delay(new_york(1, 9, 10)),
+            Duration::from_secs(300)
+        );
+        assert_eq!(
+            profile.next_poll_delay(new_york(1, 9, 12)),
+            Duration::from_secs(180)
+        );
+        // Friday evening runs until the weekend starts at midnight
+        assert_eq!(
+            profile.next_poll_delay(new_york(3, 23, 45)),
+            Duration::from_secs(900)
+        );
+    }
+
+    #[test]
+    fn test_constant_profile_ignores_sessions() {
+        let profile = PollingProfile::constant(Duration::from_secs(600));
+        assert_eq!(
+            profile.next_poll_delay(new_york(1, 3, 55)),
+            Duration::from_secs(600)
+        );
+        assert_eq!(
+            profile.next_poll_delay(new_york(1, 9, 25)),
+            Duration::from_secs(600)
+        );
+    }
+
+    #[test]
+    fn test_profile_config_defaults() {
+        let profile: PollingProfile = serde_json::from_str("{}").unwrap();
+        assert_eq!(profile, PollingProfile::market_hours());
+
+        let profile: PollingProfile =
+            serde_json::from_str(r#"{"weekend_seconds": 86400}"#).unwrap();
+        assert_eq!(
+            profile,
+            PollingProfile::market_hours()
+                .with_session(MarketSession::Weekend, Duration::from_secs(86400))
+        );
+    }
+}
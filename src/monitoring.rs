@@ -0,0 +1,281 @@
+//! Per-source fetch statistics and success-rate objectives
+//!
+//! A `NewsClient` records the outcome of every network fetch in its [`FetchMonitor`]:
+//! whether it succeeded, how long it took, and how many articles it returned. Cache
+//! hits are not recorded. `summary()` turns the recent outcomes into a
+//! [`SourceSummary`] per source for dashboards, and checks each source against its
+//! success-rate objective.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long fetch outcomes are kept
+pub const DEFAULT_MONITOR_RETENTION: Duration = Duration::from_secs(24 * 3600);
+
+/// Most fetch outcomes kept, whatever their age
+pub const MAX_MONITOR_RECORDS: usize = 100_000;
+
+/// Outcome of one network fetch
+#[derive(Debug, Clone)]
+struct FetchRecord {
+    source: &'static str,
+    topic: String,
+    at: Instant,
+    latency: Duration,
+    /// Articles returned, or `None` for a failed fetch
+    articles: Option<usize>,
+}
+
+/// Records fetch outcomes and summarizes them by source
+#[derive(Debug)]
+pub struct FetchMonitor {
+    records: Mutex<VecDeque<FetchRecord>>,
+    retention: Duration,
+    objectives: Mutex<HashMap<String, f64>>,
+}
+
+impl Default for FetchMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FetchMonitor {
+    /// Create a monitor keeping outcomes for `DEFAULT_MONITOR_RETENTION`
+    pub fn new() -> Self {
+        Self::with_retention(DEFAULT_MONITOR_RETENTION)
+    }
+
+    /// Create a monitor keeping outcomes for `retention`
+    pub fn with_retention(retention: Duration) -> Self {
+        Self {
+            records: Mutex::new(VecDeque::new()),
+            retention,
+            objectives: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record the outcome of a fetch
+    ///
+    /// `articles` is the number of articles returned, or `None` if the fetch failed.
+    pub fn record(
+        &self,
+        source: &'static str,
+        topic: &str,
+        latency: Duration,
+        articles: Option<usize>,
+    ) {
+        let now = Instant::now();
+        let mut records = self.records.lock().unwrap();
+        while records.len() >= MAX_MONITOR_RECORDS
+            || records
+                .front()
+                .is_some_and(|record| now.duration_since(record.at) > self.retention)
+        {
+            records.pop_front();
+        }
+        records.push_back(FetchRecord {
+            source,
+            topic: topic.to_string(),
+            at: now,
+            latency,
+            articles,
+        });
+    }
+
+    /// Set the share of fetches of a source that should succeed, from 0 to 1
+    pub fn set_objective(&self, source: &str, success_rate: f64) {
+        self.objectives
+            .lock()
+            .unwrap()
+            .insert(source.to_string(), success_rate.clamp(0.0, 1.0));
+    }
+
+    /// Summarize the fetches of the last `window`, by source
+    ///
+    /// Only sources fetched within the window appear.
+    pub fn summary(&self, window: Duration) -> MonitoringSummary {
+        let now = Instant::now();
+        let records = self.records.lock().unwrap();
+        let objectives = self.objectives.lock().unwrap();
+        let mut sources: BTreeMap<String, SourceSummary> = BTreeMap::new();
+        let mut latencies: HashMap<&str, Duration> = HashMap::new();
+
+        for record in records
+            .iter()
+            .filter(|record| now.duration_since(record.at) <= window)
+        {
+            let summary = sources
+                .entry(record.source.to_string())
+                .or_insert_with(|| SourceSummary::new(record.source, &objectives));
+            summary.fetches += 1;
+            *latencies.entry(record.source).or_default() += record.latency;
+            match record.articles {
+                Some(articles) => {
+                    summary.successes += 1;
+                    summary.articles += articles;
+                }
+                None => {
+                    if !summary.failed_topics.contains(&record.topic) {
+                        summary.failed_topics.push(record.topic.clone());
+                    }
+                }
+            }
+        }
+
+        for summary in sources.values_mut() {
+            summary.success_rate = summary.successes as f64 / summary.fetches as f64;
+            summary.average_latency =
+                latencies[summary.source.as_str()] / summary.fetches.max(1) as u32;
+            summary.failed_topics.sort();
+        }
+        MonitoringSummary { window, sources }
+    }
+}
+
+/// Fetch statistics of one source over a window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SourceSummary {
+    pub source: String,
+    pub fetches: usize,
+    pub successes: usize,
+    /// Share of fetches that succeeded
+    pub success_rate: f64,
+    /// Mean time a fetch took, successful or not
+    pub average_latency: Duration,
+    /// Articles returned by successful fetches
+    pub articles: usize,
+    /// Topics with at least one failed fetch, in name order
+    pub failed_topics: Vec<String>,
+    /// Success rate the source should reach, if set
+    pub objective: Option<f64>,
+}
+
+impl SourceSummary {
+    fn new(source: &str, objectives: &HashMap<String, f64>) -> Self {
+        Self {
+            source: source.to_string(),
+            fetches: 0,
+            successes: 0,
+            success_rate: 0.0,
+            average_latency: Duration::ZERO,
+            articles: 0,
+            failed_topics: Vec::new(),
+            objective: objectives.get(source).copied(),
+        }
+    }
+
+    /// Whether the source reaches its objective; sources without one always do
+    pub fn meets_objective(&self) -> bool {
+        self.objective
+            .is_none_or(|objective| self.success_rate >= objective)
+    }
+}
+
+/// Fetch statistics of every source over a window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MonitoringSummary {
+    pub window: Duration,
+    /// Statistics by source name
+    pub sources: BTreeMap<String, SourceSummary>,
+}
+
+impl MonitoringSummary {
+    /// Sources below their success-rate objective
+    pub fn missing_objectives(&self) -> Vec<&SourceSummary> {
+        self.sources
+            .values()
+            .filter(|summary| !summary.meets_objective())
+            .collect()
+    }
+
+    /// Share of fetches of every source that succeeded, or `None` without fetches
+    pub fn success_rate(&self) -> Option<f64> {
+        let fetches: usize = self.sources.values().map(|summary| summary.fetches).sum();
+        let successes: usize = self.sources.values().map(|summary| summary.successes).sum();
+        (fetches > 0).then(|| successes as f64 / fetches as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_summary_by_source() {
+        let monitor = FetchMonitor::new();
+        monitor.record("CNBC", "markets", millis(100), Some(20));
+        monitor.record("CNBC", "investing", millis(300), None);
+        monitor.record("CNBC", "earnings", millis(200), Some(10));
+        monitor.record("NASDAQ", "Stocks", millis(50), Some(5));
+
+        let summary = monitor.summary(Duration::from_secs(60));
+        let cnbc = &summary.sources["CNBC"];
+        assert_eq!(cnbc.fetches, 3);
+        assert_eq!(cnbc.successes, 2);
+        assert!((cnbc.success_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(cnbc.average_latency, millis(200));
+        assert_eq!(cnbc.articles, 30);
+        assert_eq!(cnbc.failed_topics, ["investing"]);
+        assert_eq!(summary.sources["NASDAQ"].success_rate, 1.0);
+        assert_eq!(summary.success_rate(), Some(0.75));
+    }
+
+    #[test]
+    fn test_objectives() {
+        let monitor = FetchMonitor::new();
+        monitor.set_objective("CNBC", 0.9);
+        monitor.set_objective("NASDAQ", 0.5);
+        monitor.record("CNBC", "markets", millis(100), Some(20));
+        monitor.record("CNBC", "markets", millis(100), None);
+        monitor.record("NASDAQ", "Stocks", millis(50), Some(5));
+        monitor.record("NASDAQ", "Stocks", millis(50), None);
+        monitor.record("WSJ", "markets", millis(50), None);
+
+        let summary = monitor.summary(Duration::from_secs(60));
+        let missing: Vec<&str> = summary
+            .missing_objectives()
+            .iter()
+            .map(|summary| summary.source.as_str())
+            .collect();
+        // NASDAQ meets 0.5 exactly, and WSJ has no objective
+        assert_eq!(missing, ["CNBC"]);
+    }
+
+    #[test]
+    fn test_window_and_retention() {
+        let monitor = FetchMonitor::with_retention(Duration::ZERO);
+        monitor.record("CNBC", "markets", millis(100), Some(20));
+        std::thread::sleep(millis(5));
+        monitor.record("CNBC", "markets", millis(100), Some(20));
+
+        // The first record expired when the second was added
+        assert_eq!(monitor.records.lock().unwrap().len(), 1);
+        assert_eq!(
+            monitor.summary(Duration::from_secs(60)).sources["CNBC"].fetches,
+            1
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_summary_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(MonitoringSummary)).unwrap();
+
+        assert!(schema["properties"]["sources"]["additionalProperties"].is_object());
+        let source = &schema["$defs"]["SourceSummary"]["properties"];
+        assert!(source["average_latency"].is_object());
+        assert_eq!(
+            source["objective"]["type"],
+            serde_json::json!(["number", "null"])
+        );
+    }
+}
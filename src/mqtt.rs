@@ -0,0 +1,219 @@
+//! MQTT sink publishing new articles (feature "mqtt")
+//!
+//! [`MqttSink`] is an `ArticleSink` publishing each new article as JSON to an MQTT
+//! broker, for dashboards and home-automation setups that already speak MQTT. Topics
+//! are templates with placeholders filled in per article:
+//!
+//! | Placeholder | Value |
+//! |-------------|-------|
+//! | `{source}` | the source name, such as `CNBC` |
+//! | `{topic}` | the feed topic, such as `earnings` |
+//! | `{ticker}` | each ticker the article is about, from `extra_fields["symbols"]` and `$CASHTAGS` |
+//!
+//! A template with `{ticker}` is published to once per ticker, and not at all for
+//! articles without one, so `news/tickers/{ticker}` lets a client subscribe to
+//! `news/tickers/AAPL` alone. Characters MQTT reserves (`/`, `+`, `#`) are replaced
+//! with `-` in the values.
+
+use crate::error::Result;
+use crate::output::expand_topics;
+use crate::poller::{ArticleSink, NewArticles};
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use log::warn;
+use rumqttc::{AsyncClient, MqttOptions, QoS};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+pub use rumqttc;
+
+/// Topic published to unless set with `MqttSink::with_topics()`
+pub const DEFAULT_MQTT_TOPIC: &str = "news/{source}/{topic}";
+
+/// Requests queued for the broker before publishing waits
+const REQUEST_CAPACITY: usize = 100;
+
+/// Wait after a connection error before the event loop reconnects
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Sink publishing new articles to an MQTT broker
+///
+/// Publishing queues the message for the client's event loop, which sends it once
+/// connected; a full queue holds up `deliver()` until the broker catches up.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use finance_news_aggregator_rs::mqtt::MqttSink;
+/// use finance_news_aggregator_rs::mqtt::rumqttc::{MqttOptions, QoS};
+///
+/// # async fn run() {
+/// let options = MqttOptions::new("finance-news", "localhost", 1883);
+/// let sink = MqttSink::connect(options)
+///     .with_topics(["news/{source}/{topic}", "news/tickers/{ticker}"])
+///     .with_qos(QoS::AtLeastOnce);
+/// // Poller::new(..).with_sink(sink)
+/// # }
+/// ```
+pub struct MqttSink {
+    client: AsyncClient,
+    topics: Vec<String>,
+    qos: QoS,
+    retain: bool,
+    /// Event loop started by `connect()`, stopped when the sink is dropped
+    event_loop: Option<JoinHandle<()>>,
+}
+
+impl MqttSink {
+    /// Connect to the broker of `options`, running the client's event loop on a task
+    ///
+    /// The event loop reconnects after connection errors, logging them. Must be called
+    /// from within a Tokio runtime.
+    pub fn connect(options: MqttOptions) -> Self {
+        let (client, mut event_loop) = AsyncClient::new(options, REQUEST_CAPACITY);
+        let task = tokio::spawn(async move {
+            loop {
+                if let Err(e) = event_loop.poll().await {
+                    warn!("MQTT connection failed: {}", e);
+                    tokio::time::sleep(RECONNECT_DELAY).await;
+                }
+            }
+        });
+        let mut sink = Self::new(client);
+        sink.event_loop = Some(task);
+        sink
+    }
+
+    /// Publish with a client whose event loop the caller polls
+    pub fn new(client: AsyncClient) -> Self {
+        Self {
+            client,
+            topics: vec![DEFAULT_MQTT_TOPIC.to_string()],
+            qos: QoS::AtLeastOnce,
+            retain: false,
+            event_loop: None,
+        }
+    }
+
+    /// Replace the topic templates published to
+    pub fn with_topics<I, S>(mut self, topics: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.topics = topics.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the quality of service of the messages, `AtLeastOnce` by default
+    pub fn with_qos(mut self, qos: QoS) -> Self {
+        self.qos = qos;
+        self
+    }
+
+    /// Have the broker keep the latest message of each topic for new subscribers
+    pub fn with_retain(mut self, retain: bool) -> Self {
+        self.retain = retain;
+        self
+    }
+
+    /// Topics an article of a source topic is published to
+    fn topics_for(&self, source: &str, topic: &str, article: &NewsArticle) -> Vec<String> {
+        expand_topics(&self.topics, source, topic, article, topic_level)
+    }
+}
+
+impl Drop for MqttSink {
+    fn drop(&mut self) {
+        if let Some(task) = &self.event_loop {
+            task.abort();
+        }
+    }
+}
+
+#[async_trait]
+impl ArticleSink for MqttSink {
+    async fn deliver(&self, batch: &NewArticles) -> Result<()> {
+        for article in &batch.articles {
+            let payload = serde_json::to_vec(article)?;
+            for topic in self.topics_for(&batch.source, &batch.topic, article) {
+                self.client
+                    .publish(topic, self.qos, self.retain, payload.clone())
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A value placed in a topic, with the characters MQTT reserves replaced
+fn topic_level(value: &str) -> String {
+    value.replace(['/', '+', '#'], "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rumqttc::Request;
+
+    fn article(title: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some(title.to_string());
+        article
+    }
+
+    #[test]
+    fn test_topics_for() {
+        let (client, _event_loop) =
+            AsyncClient::new(MqttOptions::new("test", "localhost", 1883), 1);
+        let sink =
+            MqttSink::new(client).with_topics(["news/{source}/{topic}", "news/tickers/{ticker}"]);
+
+        let mut apple = article("Apple and $MSFT rally");
+        apple
+            .extra_fields
+            .insert("symbols".to_string(), "AAPL".into());
+        assert_eq!(
+            sink.topics_for("Seeking Alpha", "market/news", &apple),
+            vec![
+                "news/Seeking Alpha/market-news",
+                "news/tickers/AAPL",
+                "news/tickers/MSFT",
+            ]
+        );
+        assert_eq!(
+            sink.topics_for("CNBC", "earnings", &article("Oil slips")),
+            vec!["news/CNBC/earnings"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deliver() {
+        let (requests, published) = flume::unbounded();
+        let sink = MqttSink::new(AsyncClient::from_senders(requests))
+            .with_topics(["news/tickers/{ticker}"])
+            .with_retain(true);
+
+        let mut nvidia = article("Nvidia beats");
+        nvidia
+            .extra_fields
+            .insert("symbols".to_string(), "NVDA".into());
+        let batch = NewArticles {
+            source: "CNBC".to_string(),
+            topic: "earnings".to_string(),
+            articles: vec![nvidia, article("Oil slips")],
+        };
+        sink.deliver(&batch).await.unwrap();
+
+        let messages: Vec<_> = published.drain().collect();
+        assert_eq!(messages.len(), 1);
+        let Request::Publish(publish) = &messages[0] else {
+            panic!("unexpected {:?}", messages[0]);
+        };
+        assert_eq!(publish.topic, "news/tickers/NVDA");
+        assert_eq!(publish.qos, QoS::AtLeastOnce);
+        assert!(publish.retain);
+        let article: NewsArticle = serde_json::from_slice(&publish.payload).unwrap();
+        assert_eq!(article.title.as_deref(), Some("Nvidia beats"));
+    }
+}
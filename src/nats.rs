@@ -0,0 +1,252 @@
+//! NATS sink publishing new articles (feature "nats")
+//!
+//! [`NatsSink`] is an `ArticleSink` publishing each new article as JSON to NATS
+//! subjects, or to JetStream streams with `with_jetstream()`. Subjects are templates
+//! with placeholders filled in per article:
+//!
+//! | Placeholder | Value |
+//! |-------------|-------|
+//! | `{source}` | the source name, such as `CNBC` |
+//! | `{topic}` | the feed topic, such as `earnings` |
+//! | `{ticker}` | each ticker the article is about, from `extra_fields["symbols"]` and `$CASHTAGS` |
+//!
+//! A template with `{ticker}` is published to once per ticker, and not at all for
+//! articles without one, so with `news.tickers.{ticker}` a consumer subscribes to
+//! `news.tickers.NVDA`, or to `news.tickers.*` for every ticker. Characters that
+//! separate or match subject tokens (`.`, `*`, `>`, whitespace) are replaced with `_`
+//! in the values, so `Seeking Alpha` becomes `Seeking_Alpha` and `BRK.B` becomes
+//! `BRK_B`.
+
+use crate::disk_cache::fnv1a_64;
+use crate::error::{FanError, Result};
+use crate::output::expand_topics;
+use crate::poller::{ArticleSink, NewArticles, article_id};
+use crate::types::NewsArticle;
+use async_nats::jetstream::{self, context::Publish};
+use async_nats::{Client, ToServerAddrs};
+use async_trait::async_trait;
+use bytes::Bytes;
+
+pub use async_nats;
+
+/// Subject published to unless set with `NatsSink::with_subjects()`
+pub const DEFAULT_NATS_SUBJECT: &str = "news.{source}.{topic}";
+
+/// Sink publishing new articles to NATS
+///
+/// With JetStream, each message carries a `Nats-Msg-Id` made of its subject and the
+/// article's identity, so a stream's duplicate window drops articles published twice,
+/// for instance after a restart without watermarks.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use finance_news_aggregator_rs::nats::NatsSink;
+///
+/// # async fn run() -> finance_news_aggregator_rs::Result<()> {
+/// let sink = NatsSink::connect("nats://localhost:4222")
+///     .await?
+///     .with_subjects(["news.{source}.{topic}", "news.tickers.{ticker}"])
+///     .with_jetstream(true);
+/// // Poller::new(..).with_sink(sink)
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NatsSink {
+    client: Client,
+    jetstream: Option<jetstream::Context>,
+    subjects: Vec<String>,
+}
+
+impl NatsSink {
+    /// Connect to a NATS server, such as `nats://localhost:4222`
+    pub async fn connect(addrs: impl ToServerAddrs) -> Result<Self> {
+        let client = async_nats::connect(addrs).await.map_err(nats_error)?;
+        Ok(Self::new(client))
+    }
+
+    /// Publish with a connected client, for options such as credentials or TLS
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            jetstream: None,
+            subjects: vec![DEFAULT_NATS_SUBJECT.to_string()],
+        }
+    }
+
+    /// Replace the subject templates published to
+    pub fn with_subjects<I, S>(mut self, subjects: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.subjects = subjects.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Publish to JetStream and wait for each message to be stored
+    ///
+    /// A stream has to capture the subjects; publishing to a subject no stream
+    /// captures fails.
+    pub fn with_jetstream(mut self, jetstream: bool) -> Self {
+        self.jetstream = jetstream.then(|| jetstream::new(self.client.clone()));
+        self
+    }
+
+    /// Subjects an article of a source topic is published to
+    fn subjects_for(&self, source: &str, topic: &str, article: &NewsArticle) -> Vec<String> {
+        expand_topics(&self.subjects, source, topic, article, subject_token)
+    }
+
+    async fn publish(&self, subject: String, article: &NewsArticle, payload: Bytes) -> Result<()> {
+        let Some(jetstream) = &self.jetstream else {
+            return self
+                .client
+                .publish(subject, payload)
+                .await
+                .map_err(nats_error);
+        };
+        let mut publish = Publish::build().payload(payload);
+        if let Some(id) = article_id(article) {
+            let id = format!("{}:{:016x}", subject, fnv1a_64(id.as_bytes()));
+            publish = publish.message_id(id);
+        }
+        jetstream
+            .send_publish(subject, publish)
+            .await
+            .map_err(nats_error)?
+            .await
+            .map_err(nats_error)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ArticleSink for NatsSink {
+    async fn deliver(&self, batch: &NewArticles) -> Result<()> {
+        for article in &batch.articles {
+            let payload = Bytes::from(serde_json::to_vec(article)?);
+            for subject in self.subjects_for(&batch.source, &batch.topic, article) {
+                self.publish(subject, article, payload.clone()).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn flush(&self) -> Result<()> {
+        self.client.flush().await.map_err(nats_error)
+    }
+}
+
+/// A value placed in a subject, with the characters NATS gives a meaning replaced
+fn subject_token(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '.' | '*' | '>' => '_',
+            c if c.is_whitespace() => '_',
+            c => c,
+        })
+        .collect()
+}
+
+fn nats_error(error: impl std::fmt::Display) -> FanError {
+    FanError::Nats(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+
+    /// Accept one client, speaking just enough of the NATS protocol to take its
+    /// publications, and send each subject and payload on the returned channel
+    async fn fake_server() -> (String, mpsc::UnboundedReceiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("nats://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            writer
+                .write_all(b"INFO {\"server_id\":\"test\",\"version\":\"2.10.0\",\"proto\":1,\"max_payload\":1048576}\r\n")
+                .await
+                .unwrap();
+            let mut reader = BufReader::new(reader);
+            let mut line = String::new();
+            while reader.read_line(&mut line).await.unwrap() > 0 {
+                let words: Vec<&str> = line.split_whitespace().collect();
+                match words.as_slice() {
+                    ["PING"] => writer.write_all(b"PONG\r\n").await.unwrap(),
+                    ["PUB", subject, .., length] => {
+                        let mut payload = vec![0; length.parse::<usize>().unwrap() + 2];
+                        reader.read_exact(&mut payload).await.unwrap();
+                        payload.truncate(payload.len() - 2);
+                        let payload = String::from_utf8(payload).unwrap();
+                        sender.send((subject.to_string(), payload)).unwrap();
+                    }
+                    _ => {}
+                }
+                line.clear();
+            }
+        });
+        (url, receiver)
+    }
+
+    fn article(title: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some(title.to_string());
+        article
+    }
+
+    #[test]
+    fn test_subject_token() {
+        assert_eq!(subject_token("Seeking Alpha"), "Seeking_Alpha");
+        assert_eq!(subject_token("BRK.B"), "BRK_B");
+        assert_eq!(subject_token("a*b>c"), "a_b_c");
+    }
+
+    #[tokio::test]
+    async fn test_deliver() {
+        let (url, mut published) = fake_server().await;
+        let sink = NatsSink::connect(url)
+            .await
+            .unwrap()
+            .with_subjects(["news.{source}.{topic}", "news.tickers.{ticker}"]);
+
+        let mut berkshire = article("Berkshire buys more Apple");
+        berkshire
+            .extra_fields
+            .insert("symbols".to_string(), "BRK.B,AAPL".into());
+        let batch = NewArticles {
+            source: "Seeking Alpha".to_string(),
+            topic: "market-news".to_string(),
+            articles: vec![berkshire, article("Oil slips")],
+        };
+        sink.deliver(&batch).await.unwrap();
+        sink.flush().await.unwrap();
+
+        let mut subjects = Vec::new();
+        for _ in 0..4 {
+            let (subject, payload) = published.recv().await.unwrap();
+            let article: NewsArticle = serde_json::from_str(&payload).unwrap();
+            subjects.push((subject, article.title.unwrap()));
+        }
+        let expected = [
+            (
+                "news.Seeking_Alpha.market-news",
+                "Berkshire buys more Apple",
+            ),
+            ("news.tickers.AAPL", "Berkshire buys more Apple"),
+            ("news.tickers.BRK_B", "Berkshire buys more Apple"),
+            ("news.Seeking_Alpha.market-news", "Oil slips"),
+        ];
+        assert_eq!(
+            subjects,
+            expected.map(|(subject, title)| (subject.to_string(), title.to_string()))
+        );
+    }
+}
@@ -1,24 +1,122 @@
 use crate::Result;
+use crate::aggregation::{
+    AggregationBudget, BudgetedArticles, dedup_articles, mentions_any, sort_by_date_desc,
+};
+use crate::audit::AuditLog;
+use crate::availability::{AvailableTopics, TopicStatus};
+use crate::cache::TopicCache;
+use crate::cluster::SyndicationDedup;
+use crate::credentials::Credentials;
+use crate::deprecation::DeprecationHistory;
+use crate::disk_cache::{CachedBody, DiskCache, RetentionPolicy};
+use crate::dns::CachingResolver;
+use crate::earnings::{EarningsQuery, EarningsReport};
+use crate::error::FanError;
+use crate::filter::Query;
+use crate::http::HttpClient;
+use crate::image_cache::ImageCache;
+use crate::monitoring::FetchMonitor;
 use crate::news_source::*;
-use crate::types::{NewsArticle, SourceConfig};
-use log::debug;
+use crate::output::JsonOptions;
+use crate::poller::Poller;
+use crate::sector::Sector;
+use crate::source_info::Icon;
+use crate::symbols::Symbol;
+use crate::topic_group::TopicGroup;
+use crate::types::{HttpVersion, NewsArticle, SourceConfig};
+use crate::unfurl::Unfurler;
+use futures::future::{BoxFuture, join_all};
+use futures::stream::{self, FuturesUnordered, StreamExt};
+use log::{debug, warn};
 use reqwest::Client;
-use serde_json;
+use std::collections::{BTreeSet, HashMap};
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// Topics a health check fetches at once
+const CHECK_CONCURRENCY: usize = 8;
+
+/// Keywords identifying IPO coverage in general news feeds
+pub(crate) const IPO_KEYWORDS: &[&str] = &[
+    "ipo",
+    "initial public offering",
+    "going public",
+    "goes public",
+    "public debut",
+    "market debut",
+    "direct listing",
+    "spac",
+];
+
+/// Result of [`NewsClient::fetch_topic_swr`]
+#[derive(Debug)]
+pub struct Revalidation {
+    /// Last known articles for the topic, whatever their age
+    pub cached: Option<Vec<NewsArticle>>,
+    /// Resolves with the result of the background refresh
+    pub refreshed: oneshot::Receiver<Result<Vec<NewsArticle>>>,
+}
 
 /// Main news client that provides access to different news sources
 pub struct NewsClient {
-    http_client: Client,
+    http_client: Arc<dyn HttpClient>,
     default_config: SourceConfig,
-    generic_client: Option<GenericSource>,
-    wsj_client: Option<WallStreetJournal>,
-    cnbc_client: Option<CNBC>,
-    nasdaq_client: Option<NASDAQ>,
-    market_watch_client: Option<MarketWatch>,
-    seeking_alpha_client: Option<SeekingAlpha>,
-    yahoo_finance_client: Option<YahooFinance>,
+    caches: Arc<FeedCaches>,
+    credentials: Credentials,
+    availability: Option<AvailableTopics>,
+    deprecation: Option<DeprecationHistory>,
+    audit_log: Option<AuditLog>,
+    generic_client: Option<Arc<GenericSource>>,
+    #[cfg(feature = "wsj")]
+    wsj_client: Option<Arc<WallStreetJournal>>,
+    #[cfg(feature = "cnbc")]
+    cnbc_client: Option<Arc<CNBC>>,
+    #[cfg(feature = "nasdaq")]
+    nasdaq_client: Option<Arc<NASDAQ>>,
+    #[cfg(feature = "market-watch")]
+    market_watch_client: Option<Arc<MarketWatch>>,
+    #[cfg(feature = "seeking-alpha")]
+    seeking_alpha_client: Option<Arc<SeekingAlpha>>,
+    #[cfg(feature = "yahoo-finance")]
+    yahoo_finance_client: Option<Arc<YahooFinance>>,
+    #[cfg(feature = "coin-desk")]
+    coin_desk_client: Option<Arc<CoinDesk>>,
+    #[cfg(feature = "coin-telegraph")]
+    coin_telegraph_client: Option<Arc<CoinTelegraph>>,
+    #[cfg(feature = "stock-twits")]
+    stock_twits_client: Option<Arc<StockTwits>>,
+    #[cfg(feature = "reddit")]
+    reddit_client: Option<Arc<Reddit>>,
+    #[cfg(feature = "globe-newswire")]
+    globe_newswire_client: Option<Arc<GlobeNewswire>>,
+    #[cfg(feature = "pr-newswire")]
+    pr_newswire_client: Option<Arc<PRNewswire>>,
+    #[cfg(feature = "business-wire")]
+    business_wire_client: Option<Arc<BusinessWire>>,
+    #[cfg(feature = "nytimes")]
+    nytimes_client: Option<Arc<NYTimes>>,
+    #[cfg(feature = "financial-post")]
+    financial_post_client: Option<Arc<FinancialPost>>,
+    #[cfg(feature = "globe-and-mail")]
+    globe_and_mail_client: Option<Arc<GlobeAndMail>>,
+    #[cfg(feature = "nikkei-asia")]
+    nikkei_asia_client: Option<Arc<NikkeiAsia>>,
+    #[cfg(feature = "scmp-business")]
+    scmp_business_client: Option<Arc<SCMPBusiness>>,
+    #[cfg(feature = "gdelt")]
+    gdelt_client: Option<Arc<Gdelt>>,
+    #[cfg(feature = "news-api-org")]
+    news_api_org_client: Option<Arc<NewsApiOrg>>,
+    #[cfg(feature = "finnhub-news")]
+    finnhub_news_client: Option<Arc<FinnhubNews>>,
+    #[cfg(feature = "alpha-vantage")]
+    alpha_vantage_client: Option<Arc<AlphaVantage>>,
+    #[cfg(feature = "polygon")]
+    polygon_client: Option<Arc<Polygon>>,
 }
 
 impl NewsClient {
@@ -31,22 +129,65 @@ impl NewsClient {
     pub fn with_config(config: SourceConfig) -> Self {
         debug!("Creating new NewsClient with config");
 
-        let http_client = Client::builder()
-            .timeout(config.timeout_duration())
-            .user_agent(&config.user_agent)
-            .build()
-            .expect("Failed to create HTTP client");
+        let http_client = Arc::new(build_http_client(&config));
+        let caches = Arc::new(FeedCaches::new(&config));
+        let credentials = Credentials::from_env().with_credentials(&config.api_keys);
 
         Self {
             http_client,
             default_config: config,
+            caches,
+            credentials,
+            availability: None,
+            deprecation: None,
+            audit_log: None,
             generic_client: None,
+            #[cfg(feature = "wsj")]
             wsj_client: None,
+            #[cfg(feature = "cnbc")]
             cnbc_client: None,
+            #[cfg(feature = "nasdaq")]
             nasdaq_client: None,
+            #[cfg(feature = "market-watch")]
             market_watch_client: None,
+            #[cfg(feature = "seeking-alpha")]
             seeking_alpha_client: None,
+            #[cfg(feature = "yahoo-finance")]
             yahoo_finance_client: None,
+            #[cfg(feature = "coin-desk")]
+            coin_desk_client: None,
+            #[cfg(feature = "coin-telegraph")]
+            coin_telegraph_client: None,
+            #[cfg(feature = "stock-twits")]
+            stock_twits_client: None,
+            #[cfg(feature = "reddit")]
+            reddit_client: None,
+            #[cfg(feature = "globe-newswire")]
+            globe_newswire_client: None,
+            #[cfg(feature = "pr-newswire")]
+            pr_newswire_client: None,
+            #[cfg(feature = "business-wire")]
+            business_wire_client: None,
+            #[cfg(feature = "nytimes")]
+            nytimes_client: None,
+            #[cfg(feature = "financial-post")]
+            financial_post_client: None,
+            #[cfg(feature = "globe-and-mail")]
+            globe_and_mail_client: None,
+            #[cfg(feature = "nikkei-asia")]
+            nikkei_asia_client: None,
+            #[cfg(feature = "scmp-business")]
+            scmp_business_client: None,
+            #[cfg(feature = "gdelt")]
+            gdelt_client: None,
+            #[cfg(feature = "news-api-org")]
+            news_api_org_client: None,
+            #[cfg(feature = "finnhub-news")]
+            finnhub_news_client: None,
+            #[cfg(feature = "alpha-vantage")]
+            alpha_vantage_client: None,
+            #[cfg(feature = "polygon")]
+            polygon_client: None,
         }
     }
 
@@ -55,12 +196,55 @@ impl NewsClient {
         &self.default_config
     }
 
+    /// Use `credentials` for key-based sources
+    ///
+    /// Replaces the keys read from the environment and set with
+    /// `SourceConfig::with_api_key()`.
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = credentials;
+        // Rebuild key-based sources with the new keys on next access
+        #[cfg(feature = "news-api-org")]
+        self.news_api_org_client.take();
+        #[cfg(feature = "finnhub-news")]
+        self.finnhub_news_client.take();
+        #[cfg(feature = "alpha-vantage")]
+        self.alpha_vantage_client.take();
+        #[cfg(feature = "polygon")]
+        self.polygon_client.take();
+        self
+    }
+
+    /// Send the requests of every source through `client` instead of reqwest
+    ///
+    /// Call before accessing any source: sources already created keep their client.
+    /// The timeout, user agent and HTTP version settings of the config only apply to
+    /// the default reqwest client.
+    pub fn with_http_client(mut self, client: impl HttpClient + 'static) -> Self {
+        self.http_client = Arc::new(client);
+        self
+    }
+
+    /// Record every request, and every poll of the pollers this client creates, in `log`
+    ///
+    /// Call after `with_http_client()` and before accessing any source: sources
+    /// already created keep their unrecorded client.
+    pub fn with_audit_log(mut self, log: AuditLog) -> Self {
+        self.http_client = Arc::new(log.client(Arc::clone(&self.http_client)));
+        self.audit_log = Some(log);
+        self
+    }
+
+    /// Get the API keys available to key-based sources
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+
     /// Get generic RSS feed client for fetching arbitrary feeds
     ///
     /// # Example
     /// ```rust
     /// use finance_news_aggregator_rs::NewsClient;
-    /// use finance_news_aggregator_rs::news_source::NewsSource;
+    /// use finance_news_aggregator_rs::news_source::FeedSource;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -73,9 +257,9 @@ impl NewsClient {
     /// ```
     pub fn generic(&mut self) -> &GenericSource {
         if self.generic_client.is_none() {
-            self.generic_client = Some(GenericSource::new(self.http_client.clone()));
+            self.generic_client = Some(Arc::new(GenericSource::new(self.http_client.clone())));
         }
-        self.generic_client.as_ref().unwrap()
+        self.generic_client.as_deref().unwrap()
     }
 
     /// Get Wall Street Journal client
@@ -93,11 +277,12 @@ impl NewsClient {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(feature = "wsj")]
     pub fn wsj(&mut self) -> &WallStreetJournal {
         if self.wsj_client.is_none() {
-            self.wsj_client = Some(WallStreetJournal::new(self.http_client.clone()));
+            self.wsj_client = Some(Arc::new(WallStreetJournal::new(self.http_client.clone())));
         }
-        self.wsj_client.as_ref().unwrap()
+        self.wsj_client.as_deref().unwrap()
     }
 
     /// Get CNBC client
@@ -115,11 +300,12 @@ impl NewsClient {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(feature = "cnbc")]
     pub fn cnbc(&mut self) -> &CNBC {
         if self.cnbc_client.is_none() {
-            self.cnbc_client = Some(CNBC::new(self.http_client.clone()));
+            self.cnbc_client = Some(Arc::new(CNBC::new(self.http_client.clone())));
         }
-        self.cnbc_client.as_ref().unwrap()
+        self.cnbc_client.as_deref().unwrap()
     }
 
     /// Get NASDAQ client
@@ -137,11 +323,12 @@ impl NewsClient {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(feature = "nasdaq")]
     pub fn nasdaq(&mut self) -> &NASDAQ {
         if self.nasdaq_client.is_none() {
-            self.nasdaq_client = Some(NASDAQ::new(self.http_client.clone()));
+            self.nasdaq_client = Some(Arc::new(NASDAQ::new(self.http_client.clone())));
         }
-        self.nasdaq_client.as_ref().unwrap()
+        self.nasdaq_client.as_deref().unwrap()
     }
 
     /// Get MarketWatch client
@@ -159,11 +346,12 @@ impl NewsClient {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(feature = "market-watch")]
     pub fn market_watch(&mut self) -> &MarketWatch {
         if self.market_watch_client.is_none() {
-            self.market_watch_client = Some(MarketWatch::new(self.http_client.clone()));
+            self.market_watch_client = Some(Arc::new(MarketWatch::new(self.http_client.clone())));
         }
-        self.market_watch_client.as_ref().unwrap()
+        self.market_watch_client.as_deref().unwrap()
     }
 
     /// Get Seeking Alpha client
@@ -181,11 +369,12 @@ impl NewsClient {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(feature = "seeking-alpha")]
     pub fn seeking_alpha(&mut self) -> &SeekingAlpha {
         if self.seeking_alpha_client.is_none() {
-            self.seeking_alpha_client = Some(SeekingAlpha::new(self.http_client.clone()));
+            self.seeking_alpha_client = Some(Arc::new(SeekingAlpha::new(self.http_client.clone())));
         }
-        self.seeking_alpha_client.as_ref().unwrap()
+        self.seeking_alpha_client.as_deref().unwrap()
     }
 
     /// Get Yahoo Finance client
@@ -203,18 +392,15 @@ impl NewsClient {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(feature = "yahoo-finance")]
     pub fn yahoo_finance(&mut self) -> &YahooFinance {
         if self.yahoo_finance_client.is_none() {
-            self.yahoo_finance_client = Some(YahooFinance::new(self.http_client.clone()));
+            self.yahoo_finance_client = Some(Arc::new(YahooFinance::new(self.http_client.clone())));
         }
-        self.yahoo_finance_client.as_ref().unwrap()
+        self.yahoo_finance_client.as_deref().unwrap()
     }
 
-    /// Save news articles to a JSON file
-    ///
-    /// # Arguments
-    /// * `articles` - Vector of news articles to save
-    /// * `filename` - Name of the file (without extension)
+    /// Get CoinDesk client
     ///
     /// # Example
     /// ```rust
@@ -223,111 +409,2332 @@ impl NewsClient {
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let mut client = NewsClient::new();
-    ///     let wsj = client.wsj();
-    ///     let opinions = wsj.opinions().await?;
-    ///     client.save_to_file(&opinions, "wsj_opinions").await?;
+    ///     let coin_desk = client.coin_desk();
+    ///     let latest = coin_desk.latest().await?;
+    ///     println!("Found {} articles", latest.len());
     ///     Ok(())
     /// }
     /// ```
-    pub async fn save_to_file(&self, articles: &[NewsArticle], filename: &str) -> Result<()> {
-        // Create examples/responses directory if it doesn't exist
-        let dir_path = Path::new("examples/responses");
-        std::fs::create_dir_all(dir_path)?;
-
-        let file_path = dir_path.join(format!("{}.json", filename));
-        let json_content = serde_json::to_string_pretty(articles)?;
-
-        let mut file = File::create(&file_path)?;
-        file.write_all(json_content.as_bytes())?;
+    #[cfg(feature = "coin-desk")]
+    pub fn coin_desk(&mut self) -> &CoinDesk {
+        if self.coin_desk_client.is_none() {
+            self.coin_desk_client = Some(Arc::new(CoinDesk::new(self.http_client.clone())));
+        }
+        self.coin_desk_client.as_deref().unwrap()
+    }
 
-        debug!("Saved {} articles to {:?}", articles.len(), file_path);
-        Ok(())
+    /// Get CoinTelegraph client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let coin_telegraph = client.coin_telegraph();
+    ///     let bitcoin = coin_telegraph.bitcoin().await?;
+    ///     println!("Found {} articles", bitcoin.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "coin-telegraph")]
+    pub fn coin_telegraph(&mut self) -> &CoinTelegraph {
+        if self.coin_telegraph_client.is_none() {
+            self.coin_telegraph_client =
+                Some(Arc::new(CoinTelegraph::new(self.http_client.clone())));
+        }
+        self.coin_telegraph_client.as_deref().unwrap()
     }
-}
 
-impl Default for NewsClient {
-    fn default() -> Self {
-        Self::new()
+    /// Get StockTwits client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let stock_twits = client.stock_twits();
+    ///     let articles = stock_twits.trending().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "stock-twits")]
+    pub fn stock_twits(&mut self) -> &StockTwits {
+        if self.stock_twits_client.is_none() {
+            self.stock_twits_client = Some(Arc::new(StockTwits::new(self.http_client.clone())));
+        }
+        self.stock_twits_client.as_deref().unwrap()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Get Reddit client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let reddit = client.reddit();
+    ///     let articles = reddit.stocks().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "reddit")]
+    pub fn reddit(&mut self) -> &Reddit {
+        if self.reddit_client.is_none() {
+            self.reddit_client = Some(Arc::new(Reddit::new(self.http_client.clone())));
+        }
+        self.reddit_client.as_deref().unwrap()
+    }
 
-    #[test]
-    fn test_client_creation() {
-        let client = NewsClient::new();
-        assert!(client.generic_client.is_none());
-        assert!(client.wsj_client.is_none());
-        assert!(client.cnbc_client.is_none());
-        assert!(client.nasdaq_client.is_none());
-        assert!(client.market_watch_client.is_none());
-        assert!(client.seeking_alpha_client.is_none());
-        assert!(client.yahoo_finance_client.is_none());
+    /// Get GlobeNewswire client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let globe_newswire = client.globe_newswire();
+    ///     let articles = globe_newswire.earnings().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "globe-newswire")]
+    pub fn globe_newswire(&mut self) -> &GlobeNewswire {
+        if self.globe_newswire_client.is_none() {
+            self.globe_newswire_client =
+                Some(Arc::new(GlobeNewswire::new(self.http_client.clone())));
+        }
+        self.globe_newswire_client.as_deref().unwrap()
     }
 
-    #[tokio::test]
-    async fn test_generic_client_access() {
-        let mut client = NewsClient::new();
-        let _generic = client.generic();
-        assert!(client.generic_client.is_some());
+    /// Get PR Newswire client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let pr_newswire = client.pr_newswire();
+    ///     let articles = pr_newswire.financial_services().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "pr-newswire")]
+    pub fn pr_newswire(&mut self) -> &PRNewswire {
+        if self.pr_newswire_client.is_none() {
+            self.pr_newswire_client = Some(Arc::new(PRNewswire::new(self.http_client.clone())));
+        }
+        self.pr_newswire_client.as_deref().unwrap()
     }
 
-    #[tokio::test]
-    async fn test_wsj_client_access() {
-        let mut client = NewsClient::new();
-        let _wsj = client.wsj();
-        assert!(client.wsj_client.is_some());
+    /// Get Business Wire client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let business_wire = client.business_wire();
+    ///     let articles = business_wire.all().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "business-wire")]
+    pub fn business_wire(&mut self) -> &BusinessWire {
+        if self.business_wire_client.is_none() {
+            self.business_wire_client = Some(Arc::new(BusinessWire::new(self.http_client.clone())));
+        }
+        self.business_wire_client.as_deref().unwrap()
     }
 
-    #[tokio::test]
-    async fn test_cnbc_client_access() {
-        let mut client = NewsClient::new();
-        let _cnbc = client.cnbc();
-        assert!(client.cnbc_client.is_some());
+    /// Get New York Times client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let nytimes = client.nytimes();
+    ///     let articles = nytimes.business().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "nytimes")]
+    pub fn nytimes(&mut self) -> &NYTimes {
+        if self.nytimes_client.is_none() {
+            self.nytimes_client = Some(Arc::new(NYTimes::new(self.http_client.clone())));
+        }
+        self.nytimes_client.as_deref().unwrap()
     }
 
-    #[tokio::test]
-    async fn test_nasdaq_client_access() {
-        let mut client = NewsClient::new();
-        let _nasdaq = client.nasdaq();
-        assert!(client.nasdaq_client.is_some());
+    /// Get Financial Post client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let financial_post = client.financial_post();
+    ///     let articles = financial_post.top_stories().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "financial-post")]
+    pub fn financial_post(&mut self) -> &FinancialPost {
+        if self.financial_post_client.is_none() {
+            self.financial_post_client =
+                Some(Arc::new(FinancialPost::new(self.http_client.clone())));
+        }
+        self.financial_post_client.as_deref().unwrap()
     }
 
-    #[tokio::test]
-    async fn test_market_watch_client_access() {
-        let mut client = NewsClient::new();
-        let _mw = client.market_watch();
-        assert!(client.market_watch_client.is_some());
+    /// Get Globe and Mail client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let globe_and_mail = client.globe_and_mail();
+    ///     let articles = globe_and_mail.business().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "globe-and-mail")]
+    pub fn globe_and_mail(&mut self) -> &GlobeAndMail {
+        if self.globe_and_mail_client.is_none() {
+            self.globe_and_mail_client =
+                Some(Arc::new(GlobeAndMail::new(self.http_client.clone())));
+        }
+        self.globe_and_mail_client.as_deref().unwrap()
     }
 
-    #[tokio::test]
-    async fn test_seeking_alpha_client_access() {
-        let mut client = NewsClient::new();
-        let _sa = client.seeking_alpha();
-        assert!(client.seeking_alpha_client.is_some());
+    /// Get Nikkei Asia client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let nikkei_asia = client.nikkei_asia();
+    ///     let articles = nikkei_asia.latest().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "nikkei-asia")]
+    pub fn nikkei_asia(&mut self) -> &NikkeiAsia {
+        if self.nikkei_asia_client.is_none() {
+            self.nikkei_asia_client = Some(Arc::new(NikkeiAsia::new(self.http_client.clone())));
+        }
+        self.nikkei_asia_client.as_deref().unwrap()
     }
 
-    #[tokio::test]
-    async fn test_yahoo_finance_client_access() {
-        let mut client = NewsClient::new();
-        let _yahoo = client.yahoo_finance();
-        assert!(client.yahoo_finance_client.is_some());
+    /// Get South China Morning Post business client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let scmp = client.scmp_business();
+    ///     let articles = scmp.business().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "scmp-business")]
+    pub fn scmp_business(&mut self) -> &SCMPBusiness {
+        if self.scmp_business_client.is_none() {
+            self.scmp_business_client = Some(Arc::new(SCMPBusiness::new(self.http_client.clone())));
+        }
+        self.scmp_business_client.as_deref().unwrap()
     }
 
-    #[tokio::test]
-    async fn test_all_clients_independent() {
-        let mut client = NewsClient::new();
+    /// Get GDELT client
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let gdelt = client.gdelt();
+    ///     let articles = gdelt.markets().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "gdelt")]
+    pub fn gdelt(&mut self) -> &Gdelt {
+        if self.gdelt_client.is_none() {
+            self.gdelt_client = Some(Arc::new(Gdelt::new(self.http_client.clone())));
+        }
+        self.gdelt_client.as_deref().unwrap()
+    }
 
-        // Access all clients
-        let _generic = client.generic();
-        let _wsj = client.wsj();
-        let _cnbc = client.cnbc();
-        let _nasdaq = client.nasdaq();
-        let _mw = client.market_watch();
+    /// Get NewsAPI.org client
+    ///
+    /// Uses the "NewsAPI" key from the client's [`Credentials`]; without one the source
+    /// skips topic groups and direct fetches fail with `FanError::MissingApiKey`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::types::SourceConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let config = SourceConfig::default().with_api_key("NewsAPI", "your-api-key");
+    ///     let mut client = NewsClient::with_config(config);
+    ///     let news_api = client.news_api_org();
+    ///     let articles = news_api.business().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "news-api-org")]
+    pub fn news_api_org(&mut self) -> &NewsApiOrg {
+        if self.news_api_org_client.is_none() {
+            let source =
+                NewsApiOrg::new(self.http_client.clone()).with_credentials(&self.credentials);
+            self.news_api_org_client = Some(Arc::new(source));
+        }
+        self.news_api_org_client.as_deref().unwrap()
+    }
+
+    /// Get Finnhub client
+    ///
+    /// Uses the "Finnhub" key from the client's [`Credentials`]; without one the source
+    /// skips topic groups and direct fetches fail with `FanError::MissingApiKey`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::types::SourceConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let config = SourceConfig::default().with_api_key("Finnhub", "your-api-key");
+    ///     let mut client = NewsClient::with_config(config);
+    ///     let finnhub = client.finnhub_news();
+    ///     let articles = finnhub.general().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "finnhub-news")]
+    pub fn finnhub_news(&mut self) -> &FinnhubNews {
+        if self.finnhub_news_client.is_none() {
+            let source =
+                FinnhubNews::new(self.http_client.clone()).with_credentials(&self.credentials);
+            self.finnhub_news_client = Some(Arc::new(source));
+        }
+        self.finnhub_news_client.as_deref().unwrap()
+    }
+
+    /// Get Alpha Vantage client
+    ///
+    /// Uses the "AlphaVantage" key from the client's [`Credentials`]; without one the source
+    /// skips topic groups and direct fetches fail with `FanError::MissingApiKey`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::types::SourceConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let config = SourceConfig::default().with_api_key("AlphaVantage", "your-api-key");
+    ///     let mut client = NewsClient::with_config(config);
+    ///     let alpha_vantage = client.alpha_vantage();
+    ///     let articles = alpha_vantage.earnings().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "alpha-vantage")]
+    pub fn alpha_vantage(&mut self) -> &AlphaVantage {
+        if self.alpha_vantage_client.is_none() {
+            let source =
+                AlphaVantage::new(self.http_client.clone()).with_credentials(&self.credentials);
+            self.alpha_vantage_client = Some(Arc::new(source));
+        }
+        self.alpha_vantage_client.as_deref().unwrap()
+    }
+
+    /// Get Polygon.io client
+    ///
+    /// Uses the "Polygon" key from the client's [`Credentials`]; without one the source
+    /// skips topic groups and direct fetches fail with `FanError::MissingApiKey`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::types::SourceConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let config = SourceConfig::default().with_api_key("Polygon", "your-api-key");
+    ///     let mut client = NewsClient::with_config(config);
+    ///     let polygon = client.polygon();
+    ///     let articles = polygon.latest().await?;
+    ///     println!("Found {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn polygon(&mut self) -> &Polygon {
+        if self.polygon_client.is_none() {
+            let source = Polygon::new(self.http_client.clone()).with_credentials(&self.credentials);
+            self.polygon_client = Some(Arc::new(source));
+        }
+        self.polygon_client.as_deref().unwrap()
+    }
+
+    /// Fetch a cross-source topic group
+    ///
+    /// Fans out to every source that maps native topics to `group`, fetches them
+    /// concurrently, and returns the merged articles with duplicates removed, weighted
+    /// by `SourceConfig::merge_policy`.
+    /// Individual feed failures are logged and skipped; an error is only returned
+    /// when every feed in the group failed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::{NewsClient, TopicGroup};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let crypto = client.fetch_group(TopicGroup::Crypto).await?;
+    ///     println!("Found {} crypto articles", crypto.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_group(&mut self, group: TopicGroup) -> Result<Vec<NewsArticle>> {
+        let deadline = self.default_config.source_deadline_duration();
+        self.fetch_group_inner(group, deadline).await
+    }
+
+    /// Fetch a cross-source topic group with a per-source deadline
+    ///
+    /// Same as [`fetch_group`](Self::fetch_group), but any feed that takes longer than
+    /// `deadline` is abandoned and logged, overriding the configured
+    /// `SourceConfig::source_deadline_ms` for this call.
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::{NewsClient, TopicGroup};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let markets = client
+    ///         .fetch_group_with_deadline(TopicGroup::Markets, Duration::from_secs(5))
+    ///         .await?;
+    ///     println!("Found {} market articles", markets.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_group_with_deadline(
+        &mut self,
+        group: TopicGroup,
+        deadline: Duration,
+    ) -> Result<Vec<NewsArticle>> {
+        self.fetch_group_inner(group, Some(deadline)).await
+    }
+
+    /// Fetch a cross-source topic group within a budget
+    ///
+    /// Same as [`fetch_group`](Self::fetch_group), but stops gathering once the
+    /// budget's article count or deadline is reached and keeps at most its quota of
+    /// articles per source. Articles are kept in the order their feeds answered, and
+    /// duplicates are removed after the budget is applied, so fewer than
+    /// `max_articles` articles may be returned. Feeds still in flight when the budget
+    /// runs out are abandoned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::aggregation::AggregationBudget;
+    /// use finance_news_aggregator_rs::{NewsClient, TopicGroup};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let budget = AggregationBudget::new()
+    ///         .with_max_articles(50)
+    ///         .with_deadline(Duration::from_millis(800))
+    ///         .with_per_source_quota(10);
+    ///     let markets = client.fetch_group_with_budget(TopicGroup::Markets, &budget).await?;
+    ///     println!("Found {} market articles", markets.articles.len());
+    ///     if !markets.is_complete() {
+    ///         println!("Truncated: {}", markets.truncated.join(", "));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_group_with_budget(
+        &mut self,
+        group: TopicGroup,
+        budget: &AggregationBudget,
+    ) -> Result<BudgetedArticles> {
+        let config = self.default_config.clone();
+        let caches = Arc::clone(&self.caches);
+        let sources = self.sources();
+        let requests = self.group_requests(&sources, group);
+
+        let mut gathered =
+            fetch_budgeted(requests, group.as_str(), budget, &config, &caches).await?;
+        gathered.articles = config
+            .merge_policy
+            .apply(dedup_merged(&config, gathered.articles));
+        Ok(gathered)
+    }
+
+    /// Fetch a composite feed of a market sector
+    ///
+    /// Fans out to every source whose `NewsSource::sector_topics()` cover `sector`,
+    /// such as Seeking Alpha's `sectors-technology`, NASDAQ's `technology`, and WSJ's
+    /// `RSSWSJD` for `Sector::Technology`. Merging, deduplication, and failure
+    /// handling are the same as for [`fetch_group`](Self::fetch_group).
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::{NewsClient, Sector};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let tech = client.sector_news(Sector::Technology).await?;
+    ///     println!("Found {} technology articles", tech.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn sector_news(&mut self, sector: Sector) -> Result<Vec<NewsArticle>> {
+        let deadline = self.default_config.source_deadline_duration();
+        let config = self.default_config.clone();
+        let caches = Arc::clone(&self.caches);
+        let sources = self.sources();
+        let requests = self.sector_requests(&sources, sector);
+
+        let articles = fetch_merged(requests, sector.as_str(), deadline, &config, &caches).await?;
+        Ok(config.merge_policy.apply(dedup_merged(&config, articles)))
+    }
+
+    async fn fetch_group_inner(
+        &mut self,
+        group: TopicGroup,
+        deadline: Option<Duration>,
+    ) -> Result<Vec<NewsArticle>> {
+        let config = self.default_config.clone();
+        let caches = Arc::clone(&self.caches);
+        let sources = self.sources();
+        let requests = self.group_requests(&sources, group);
+
+        let articles = fetch_merged(requests, group.as_str(), deadline, &config, &caches).await?;
+        Ok(config.merge_policy.apply(dedup_merged(&config, articles)))
+    }
+
+    /// The available topics of `sources` that belong to `group`
+    fn group_requests<'a>(
+        &self,
+        sources: &'a [Arc<dyn NewsSource>],
+        group: TopicGroup,
+    ) -> Vec<(&'a dyn NewsSource, &'static str)> {
+        self.topic_requests(sources, |source| source.group_topics(group))
+    }
+
+    /// The available topics of `sources` that cover `sector`
+    fn sector_requests<'a>(
+        &self,
+        sources: &'a [Arc<dyn NewsSource>],
+        sector: Sector,
+    ) -> Vec<(&'a dyn NewsSource, &'static str)> {
+        self.topic_requests(sources, |source| source.sector_topics(sector))
+    }
+
+    fn topic_requests<'a>(
+        &self,
+        sources: &'a [Arc<dyn NewsSource>],
+        topics: impl Fn(&dyn NewsSource) -> Vec<&'static str>,
+    ) -> Vec<(&'a dyn NewsSource, &'static str)> {
+        sources
+            .iter()
+            .flat_map(|source| {
+                topics(source.as_ref())
+                    .into_iter()
+                    .map(move |topic| (source.as_ref(), topic))
+            })
+            .filter(|(source, topic)| self.is_available(source.name(), topic))
+            .collect()
+    }
+
+    /// Fetch one topic of a named source, using the topic cache when enabled
+    ///
+    /// With `SourceConfig::with_cache()` set, articles fetched within the topic's TTL
+    /// are returned from memory instead of requesting the feed again, so UI code can
+    /// call this as often as it redraws. Mirrors and the per-source deadline from the
+    /// configuration apply as in [`fetch_group`](Self::fetch_group).
+    ///
+    /// # Arguments
+    /// * `source` - Source name as returned by `NewsSource::name()` (e.g., "CNBC")
+    /// * `topic` - Topic identifier on that source
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::types::SourceConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::with_config(SourceConfig::default().with_cache(300));
+    ///     let articles = client.fetch_topic("CNBC", "investing").await?;
+    ///     // Served from the cache
+    ///     let again = client.fetch_topic("CNBC", "investing").await?;
+    ///     assert_eq!(articles.len(), again.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn fetch_topic(&mut self, source: &str, topic: &str) -> Result<Vec<NewsArticle>> {
+        let config = self.default_config.clone();
+        let deadline = config.source_deadline_duration();
+        let caches = Arc::clone(&self.caches);
+        let source = self.source_named(source)?;
+        fetch_cached(source.as_ref(), topic, deadline, &config, &caches).await
+    }
+
+    /// Fetch one topic of a named source, bypassing the topic cache
+    ///
+    /// Any cached entry for the topic is dropped and the feed is requested again; the
+    /// new articles are cached as usual.
+    ///
+    /// # Arguments
+    /// * `source` - Source name as returned by `NewsSource::name()` (e.g., "CNBC")
+    /// * `topic` - Topic identifier on that source
+    pub async fn force_refresh(&mut self, source: &str, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.caches.topics.lock().unwrap().invalidate(source, topic);
+        self.fetch_topic(source, topic).await
+    }
+
+    /// Fetch one topic of a named source in stale-while-revalidate mode
+    ///
+    /// Returns at once with the topic's last known articles, if any, and refreshes it
+    /// in a background task whose result arrives on [`Revalidation::refreshed`]. The
+    /// cached articles come from the topic cache regardless of their age, or from the
+    /// disk cache when the topic has not been fetched in this process yet. When the
+    /// topic cache entry is still within its TTL, the refresh resolves with it without
+    /// a network request.
+    ///
+    /// Must be called from within a Tokio runtime.
+    ///
+    /// # Arguments
+    /// * `source` - Source name as returned by `NewsSource::name()` (e.g., "CNBC")
+    /// * `topic` - Topic identifier on that source
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::types::SourceConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::with_config(SourceConfig::default().with_cache(300));
+    ///     let revalidation = client.fetch_topic_swr("CNBC", "investing")?;
+    ///     if let Some(articles) = &revalidation.cached {
+    ///         println!("Showing {} cached articles", articles.len());
+    ///     }
+    ///     let articles = revalidation.refreshed.await??;
+    ///     println!("Refreshed with {} articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn fetch_topic_swr(&mut self, source: &str, topic: &str) -> Result<Revalidation> {
+        let source = self.source_named(source)?;
+        let caches = Arc::clone(&self.caches);
+        let config = self.default_config.clone();
+
+        let cached = caches
+            .topics
+            .lock()
+            .unwrap()
+            .get_any(source.name(), topic)
+            .or_else(|| {
+                let feed = source.feed()?;
+                let url = feed.build_topic_url(topic).ok()?;
+                let body = caches.disk.as_ref()?.load(&url)?.body;
+                feed.parse_feed(&body).ok()
+            });
+
+        let (sender, refreshed) = oneshot::channel();
+        let topic = topic.to_string();
+        tokio::spawn(async move {
+            let deadline = config.source_deadline_duration();
+            let result = fetch_cached(source.as_ref(), &topic, deadline, &config, &caches).await;
+            // The caller may have stopped waiting for the refresh
+            let _ = sender.send(result);
+        });
+
+        Ok(Revalidation { cached, refreshed })
+    }
+
+    /// Drop every cached topic
+    pub fn clear_cache(&mut self) {
+        self.caches.topics.lock().unwrap().clear();
+    }
+
+    /// Apply the configured retention policy to the disk cache
+    ///
+    /// Does nothing when the disk cache is disabled. Pollers made by this client
+    /// prune hourly on their own; other long-running aggregators should call this
+    /// periodically. See `SourceConfig::with_retention()`.
+    ///
+    /// # Returns
+    /// The number of cache entries removed
+    pub fn prune(&self) -> Result<usize> {
+        match &self.caches.disk {
+            Some(disk) => disk.prune(&self.default_config.retention),
+            None => Ok(0),
+        }
+    }
+
+    /// Check every topic of every source and record which ones work
+    ///
+    /// Each topic is fetched once, bypassing the caches, and classified with
+    /// `TopicStatus::of()`. The snapshot replaces the previous one, keeping its
+    /// overrides, and from then on `fetch_group()`, `earnings()`, and `ipo_news()` skip
+    /// topics found deprecated. Topics of sources without an API key are left out.
+    /// With a history from `with_deprecation_history()`, every result is also
+    /// recorded in it.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::availability::TopicStatus;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let topics = client.check_topics().await;
+    ///     for topic in topics.with_status("MarketWatch", TopicStatus::Deprecated) {
+    ///         println!("MarketWatch dropped '{}'", topic);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn check_topics(&mut self) -> &AvailableTopics {
+        let deadline = self.default_config.source_deadline_duration();
+        let sources = self.sources();
+        let checks: Vec<(Arc<dyn NewsSource>, &'static str)> = sources
+            .iter()
+            .flat_map(|source| {
+                source
+                    .available_topics()
+                    .into_iter()
+                    .map(move |topic| (Arc::clone(source), topic))
+            })
+            .collect();
+        debug!("Checking {} topics", checks.len());
+
+        let results: Vec<_> = stream::iter(checks)
+            .map(|(source, topic)| async move {
+                let fetch = source.fetch_topic(topic);
+                let result = match deadline {
+                    Some(deadline) => tokio::time::timeout(deadline, fetch)
+                        .await
+                        .unwrap_or_else(|_| Err(FanError::Timeout(topic.to_string()))),
+                    None => fetch.await,
+                };
+                (source.name(), topic, result)
+            })
+            .buffer_unordered(CHECK_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut snapshot = AvailableTopics::new();
+        for (source, topic, result) in results {
+            if let Some(status) = TopicStatus::of(&result) {
+                snapshot.record(source, topic, status);
+            }
+            if let Some(history) = &mut self.deprecation {
+                history.record(source, topic, &result);
+            }
+        }
+        if let Some(previous) = self.availability.take() {
+            snapshot.overrides = previous.overrides;
+        }
+        self.availability.insert(snapshot)
+    }
+
+    /// Use a topic availability snapshot, such as one saved from `check_topics()`
+    pub fn with_available_topics(mut self, topics: AvailableTopics) -> Self {
+        self.availability = Some(topics);
+        self
+    }
+
+    /// Statistics of the network fetches this client made
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example(client: &NewsClient) {
+    /// client.monitor().set_objective("CNBC", 0.95);
+    /// let summary = client.monitor().summary(Duration::from_secs(3600));
+    /// for source in summary.missing_objectives() {
+    ///     println!("{}: {:.0}% of fetches succeeded", source.source, source.success_rate * 100.0);
+    /// }
+    /// # }
+    /// ```
+    pub fn monitor(&self) -> &FetchMonitor {
+        &self.caches.monitor
+    }
+
+    pub(crate) fn http_client(&self) -> &Arc<dyn HttpClient> {
+        &self.http_client
+    }
+
+    /// A link [`Unfurler`] sending its requests through this client's HTTP client
+    ///
+    /// Previews are cached by the unfurler, so keep it around to reuse them.
+    pub fn unfurler(&self) -> Unfurler {
+        Unfurler::new(Arc::clone(&self.http_client))
+    }
+
+    /// An [`ImageCache`] in `dir` downloading through this client's HTTP client
+    pub fn image_cache(&self, dir: impl AsRef<Path>) -> Result<ImageCache> {
+        ImageCache::new(dir, Arc::clone(&self.http_client))
+    }
+
+    /// Favicons fetched through this client, per URL
+    pub(crate) fn icons(&self) -> &Mutex<HashMap<String, Icon>> {
+        &self.caches.icons
+    }
+
+    /// The topic availability snapshot, if any
+    pub fn available_topics(&self) -> Option<&AvailableTopics> {
+        self.availability.as_ref()
+    }
+
+    /// Record the results of `check_topics()` in a deprecation history, such as one
+    /// loaded from the previous run
+    pub fn with_deprecation_history(mut self, history: DeprecationHistory) -> Self {
+        self.deprecation = Some(history);
+        self
+    }
+
+    /// The deprecation history, if any
+    pub fn deprecation_history(&self) -> Option<&DeprecationHistory> {
+        self.deprecation.as_ref()
+    }
+
+    /// Pin the status of a topic, overriding what checks find
+    ///
+    /// Overriding a dead topic as `TopicStatus::Working` makes aggregation calls fetch
+    /// it again; overriding a topic as `TopicStatus::Deprecated` skips it.
+    pub fn override_topic(&mut self, source: &str, topic: &str, status: TopicStatus) {
+        self.availability
+            .get_or_insert_with(AvailableTopics::new)
+            .set_override(source, topic, status);
+    }
+
+    /// Whether aggregation calls should fetch a topic
+    fn is_available(&self, source: &str, topic: &str) -> bool {
+        self.availability
+            .as_ref()
+            .is_none_or(|topics| topics.is_available(source, topic))
+    }
+
+    /// Create a poller for topics of named sources
+    ///
+    /// The poller fetches every `(source, topic)` pair through this client's
+    /// configuration and caches, and hands articles it has not seen before to its
+    /// sinks. See [`Poller`] for starting and stopping it.
+    ///
+    /// # Arguments
+    /// * `feeds` - Pairs of source name, as returned by `NewsSource::name()`, and topic
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::poller::ChannelSink;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let (sink, mut new_articles) = ChannelSink::new();
+    ///     let handle = client
+    ///         .poller(&[("CNBC", "investing"), ("Wall Street Journal", "RSSMarketsMain")])?
+    ///         .with_interval(Duration::from_secs(120))
+    ///         .with_sink(sink)
+    ///         .start();
+    ///
+    ///     while let Some(batch) = new_articles.recv().await {
+    ///         println!("{} new articles from {}", batch.articles.len(), batch.source);
+    ///     }
+    ///     handle.shutdown().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn poller(&mut self, feeds: &[(&str, &str)]) -> Result<Poller> {
+        let resolver = FeedResolver {
+            sources: self.sources(),
+            config: self.default_config.clone(),
+            caches: Arc::clone(&self.caches),
+        };
+        let fetchers = feeds
+            .iter()
+            .map(|(source, topic)| resolver.resolve(source, topic))
+            .collect::<Result<Vec<_>>>()?;
+        let poller = Poller::new(resolver, fetchers);
+        Ok(match &self.audit_log {
+            Some(log) => poller.with_audit_log(log.clone()),
+            None => poller,
+        })
+    }
+
+    /// Fetch earnings news for a set of tickers and/or a date window
+    ///
+    /// Pulls every feed in [`TopicGroup::Earnings`] (NASDAQ earnings, Seeking Alpha
+    /// transcripts, CNBC earnings, and the press-release wires), filters the merged
+    /// articles with `query`, and groups them into an [`EarningsReport`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::earnings::EarningsQuery;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let query = EarningsQuery::for_symbols(["AAPL", "MSFT", "NVDA"]);
+    ///     let report = client.earnings(&query).await?;
+    ///     for (symbol, articles) in &report.groups {
+    ///         println!("{}: {} articles", symbol, articles.len());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn earnings(&mut self, query: &EarningsQuery) -> Result<EarningsReport> {
+        let articles = self.fetch_group(TopicGroup::Earnings).await?;
+        Ok(EarningsReport::build(query, articles))
+    }
+
+    /// Fetch IPO news from every source that covers it
+    ///
+    /// Combines Seeking Alpha IPO analysis, NASDAQ original content mentioning an
+    /// IPO, and the IPO feeds of the press-release wires. The merged articles are
+    /// deduplicated and sorted newest first. Individual feed failures are logged and
+    /// skipped; an error is only returned when every feed failed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let articles = client.ipo_news().await?;
+    ///     println!("Found {} IPO articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn ipo_news(&mut self) -> Result<Vec<NewsArticle>> {
+        #[cfg(feature = "seeking-alpha")]
+        self.seeking_alpha();
+        #[cfg(feature = "nasdaq")]
+        self.nasdaq();
+        #[cfg(feature = "globe-newswire")]
+        self.globe_newswire();
+        #[cfg(feature = "pr-newswire")]
+        self.pr_newswire();
+        #[cfg(feature = "business-wire")]
+        self.business_wire();
+
+        let config = self.default_config.clone();
+        let deadline = config.source_deadline_duration();
+        let caches = Arc::clone(&self.caches);
+        let mut requests: Vec<(&dyn NewsSource, &str)> = vec![
+            #[cfg(feature = "seeking-alpha")]
+            (
+                self.seeking_alpha_client.as_deref().unwrap(),
+                "ipo-analysis",
+            ),
+            #[cfg(feature = "nasdaq")]
+            (self.nasdaq_client.as_deref().unwrap(), "original"),
+            #[cfg(feature = "globe-newswire")]
+            (self.globe_newswire_client.as_deref().unwrap(), "ipo"),
+            #[cfg(feature = "pr-newswire")]
+            (self.pr_newswire_client.as_deref().unwrap(), "ipo"),
+            #[cfg(feature = "business-wire")]
+            (self.business_wire_client.as_deref().unwrap(), "ipo"),
+        ];
+        requests.retain(|(source, topic)| self.is_available(source.name(), topic));
+
+        // NASDAQ original content covers everything, so keep only IPO stories from it
+        let mut articles: Vec<NewsArticle> =
+            fetch_merged(requests, "ipo", deadline, &config, &caches)
+                .await?
+                .into_iter()
+                .filter(|article| {
+                    article.source.as_deref() != Some("NASDAQ")
+                        || mentions_any(article, IPO_KEYWORDS)
+                })
+                .collect();
+
+        articles = dedup_merged(&config, articles);
+        sort_by_date_desc(&mut articles);
+        Ok(articles)
+    }
+
+    /// Fetch news about one ticker from every source that covers it
+    ///
+    /// Combines the symbol feeds of Yahoo Finance, Seeking Alpha, NASDAQ, and
+    /// StockTwits with the articles of the market and earnings feeds that mention the
+    /// ticker, matched as `ticker:` in a `filter::Query`. The merged articles are
+    /// deduplicated and sorted newest first. Individual feed failures are logged and
+    /// skipped; an error is only returned when every feed failed, or when `symbol` is
+    /// not a valid ticker.
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let articles = client.symbol_news("AAPL").await?;
+    ///     println!("Found {} AAPL articles", articles.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn symbol_news(&mut self, symbol: &str) -> Result<Vec<NewsArticle>> {
+        let symbol = Symbol::parse(symbol)?.to_string();
+        let config = self.default_config.clone();
+        let deadline = config.source_deadline_duration();
+        let caches = Arc::clone(&self.caches);
+        let sources = self.sources();
+        let mut requests = self.group_requests(&sources, TopicGroup::Markets);
+        for (source, topic) in self.group_requests(&sources, TopicGroup::Earnings) {
+            if !requests
+                .iter()
+                .any(|(other, other_topic)| other.name() == source.name() && *other_topic == topic)
+            {
+                requests.push((source, topic));
+            }
+        }
+        let general_feeds = requests.len();
+
+        let ticker = symbol.as_str();
+        // Stays empty when every symbol source feature is off
+        #[allow(unused_mut)]
+        let mut feeds: Vec<(&str, BoxFuture<'_, Result<Vec<NewsArticle>>>)> = Vec::new();
+        #[cfg(feature = "yahoo-finance")]
+        {
+            let yahoo = self.yahoo_finance_client.as_deref().unwrap();
+            let headline = async move { yahoo.headline(&[ticker]).await };
+            feeds.push((yahoo.name(), Box::pin(headline)));
+        }
+        #[cfg(feature = "seeking-alpha")]
+        {
+            let seeking_alpha = self.seeking_alpha_client.as_deref().unwrap();
+            feeds.push((seeking_alpha.name(), Box::pin(seeking_alpha.stocks(ticker))));
+        }
+        #[cfg(feature = "nasdaq")]
+        {
+            let nasdaq = self.nasdaq_client.as_deref().unwrap();
+            feeds.push((nasdaq.name(), Box::pin(nasdaq.symbol(ticker))));
+        }
+        #[cfg(feature = "stock-twits")]
+        {
+            let stock_twits = self.stock_twits_client.as_deref().unwrap();
+            feeds.push((stock_twits.name(), Box::pin(stock_twits.symbol(ticker))));
+        }
+        let symbol_feeds = join_all(feeds.into_iter().map(|(name, feed)| async move {
+            let result = match deadline {
+                Some(deadline) => tokio::time::timeout(deadline, feed)
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(FanError::Timeout(format!(
+                            "{} news for {} did not complete within {:?}",
+                            name, ticker, deadline
+                        )))
+                    }),
+                None => feed.await,
+            };
+            (name, result)
+        }));
+        let (symbol_results, general) = tokio::join!(
+            symbol_feeds,
+            fetch_merged(requests, ticker, deadline, &config, &caches)
+        );
+
+        let mut articles = Vec::new();
+        let mut first_error = None;
+        let mut any_success = false;
+        for (name, result) in symbol_results {
+            match result {
+                Ok(fetched) => {
+                    any_success = true;
+                    articles.extend(fetched);
+                }
+                Err(e) => {
+                    warn!("Failed to fetch {} news for {}: {}", name, ticker, e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        match general {
+            Ok(general) => {
+                any_success |= general_feeds > 0;
+                articles.extend(Query::Ticker(symbol.clone()).filter(general));
+            }
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+        if let Some(e) = first_error.filter(|_| !any_success) {
+            return Err(e);
+        }
+
+        articles = dedup_merged(&config, articles);
+        sort_by_date_desc(&mut articles);
+        Ok(articles)
+    }
+
+    /// Names of every named source
+    pub fn source_names(&mut self) -> Vec<&'static str> {
+        self.sources().iter().map(|source| source.name()).collect()
+    }
+
+    /// A named source, matched ignoring case
+    pub fn source(&mut self, name: &str) -> Option<Arc<dyn NewsSource>> {
+        self.sources()
+            .into_iter()
+            .find(|source| source.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Initialize every named source and return them as trait objects
+    ///
+    /// The generic source is excluded since it has no topics of its own.
+    fn sources(&mut self) -> Vec<Arc<dyn NewsSource>> {
+        #[cfg(feature = "wsj")]
+        self.wsj();
+        #[cfg(feature = "cnbc")]
+        self.cnbc();
+        #[cfg(feature = "nasdaq")]
+        self.nasdaq();
+        #[cfg(feature = "market-watch")]
+        self.market_watch();
+        #[cfg(feature = "seeking-alpha")]
+        self.seeking_alpha();
+        #[cfg(feature = "yahoo-finance")]
+        self.yahoo_finance();
+        #[cfg(feature = "coin-desk")]
+        self.coin_desk();
+        #[cfg(feature = "coin-telegraph")]
+        self.coin_telegraph();
+        #[cfg(feature = "stock-twits")]
+        self.stock_twits();
+        #[cfg(feature = "reddit")]
+        self.reddit();
+        #[cfg(feature = "globe-newswire")]
+        self.globe_newswire();
+        #[cfg(feature = "pr-newswire")]
+        self.pr_newswire();
+        #[cfg(feature = "business-wire")]
+        self.business_wire();
+        #[cfg(feature = "nytimes")]
+        self.nytimes();
+        #[cfg(feature = "financial-post")]
+        self.financial_post();
+        #[cfg(feature = "globe-and-mail")]
+        self.globe_and_mail();
+        #[cfg(feature = "nikkei-asia")]
+        self.nikkei_asia();
+        #[cfg(feature = "scmp-business")]
+        self.scmp_business();
+        #[cfg(feature = "gdelt")]
+        self.gdelt();
+        #[cfg(feature = "news-api-org")]
+        self.news_api_org();
+        #[cfg(feature = "finnhub-news")]
+        self.finnhub_news();
+        #[cfg(feature = "alpha-vantage")]
+        self.alpha_vantage();
+        #[cfg(feature = "polygon")]
+        self.polygon();
+
+        // Stays empty when every source feature is off
+        #[allow(unused_mut)]
+        let mut sources: Vec<Arc<dyn NewsSource>> = Vec::new();
+        #[cfg(feature = "wsj")]
+        if let Some(source) = &self.wsj_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "cnbc")]
+        if let Some(source) = &self.cnbc_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "nasdaq")]
+        if let Some(source) = &self.nasdaq_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "market-watch")]
+        if let Some(source) = &self.market_watch_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "seeking-alpha")]
+        if let Some(source) = &self.seeking_alpha_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "yahoo-finance")]
+        if let Some(source) = &self.yahoo_finance_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "coin-desk")]
+        if let Some(source) = &self.coin_desk_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "coin-telegraph")]
+        if let Some(source) = &self.coin_telegraph_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "stock-twits")]
+        if let Some(source) = &self.stock_twits_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "reddit")]
+        if let Some(source) = &self.reddit_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "globe-newswire")]
+        if let Some(source) = &self.globe_newswire_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "pr-newswire")]
+        if let Some(source) = &self.pr_newswire_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "business-wire")]
+        if let Some(source) = &self.business_wire_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "nytimes")]
+        if let Some(source) = &self.nytimes_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "financial-post")]
+        if let Some(source) = &self.financial_post_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "globe-and-mail")]
+        if let Some(source) = &self.globe_and_mail_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "nikkei-asia")]
+        if let Some(source) = &self.nikkei_asia_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "scmp-business")]
+        if let Some(source) = &self.scmp_business_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "gdelt")]
+        if let Some(source) = &self.gdelt_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "news-api-org")]
+        if let Some(source) = &self.news_api_org_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "finnhub-news")]
+        if let Some(source) = &self.finnhub_news_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "alpha-vantage")]
+        if let Some(source) = &self.alpha_vantage_client {
+            sources.push(source.clone());
+        }
+        #[cfg(feature = "polygon")]
+        if let Some(source) = &self.polygon_client {
+            sources.push(source.clone());
+        }
+        sources
+    }
+
+    /// Find a named source by its `NewsSource::name()`
+    fn source_named(&mut self, name: &str) -> Result<Arc<dyn NewsSource>> {
+        self.sources()
+            .into_iter()
+            .find(|source| source.name() == name)
+            .ok_or_else(|| FanError::InvalidUrl(format!("Unknown source: {}", name)))
+    }
+
+    /// Save news articles to a JSON file
+    ///
+    /// # Arguments
+    /// * `articles` - Vector of news articles to save
+    /// * `filename` - Name of the file (without extension)
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let wsj = client.wsj();
+    ///     let opinions = wsj.opinions().await?;
+    ///     client.save_to_file(&opinions, "wsj_opinions").await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn save_to_file(&self, articles: &[NewsArticle], filename: &str) -> Result<()> {
+        self.save_to_file_with(articles, filename, JsonOptions::new())
+            .await
+    }
+
+    /// Save news articles to a JSON file in the shape set by `options`
+    ///
+    /// Same as `save_to_file()`, but with camelCase keys, `null` fields left out, or
+    /// extra fields flattened as the options ask.
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::output::JsonOptions;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = NewsClient::new();
+    ///     let opinions = client.wsj().opinions().await?;
+    ///     let options = JsonOptions::new().with_camel_case(true).with_skip_nulls(true);
+    ///     client
+    ///         .save_to_file_with(&opinions, "wsj_opinions", options)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn save_to_file_with(
+        &self,
+        articles: &[NewsArticle],
+        filename: &str,
+        options: JsonOptions,
+    ) -> Result<()> {
+        // Create examples/responses directory if it doesn't exist
+        let dir_path = Path::new("examples/responses");
+        std::fs::create_dir_all(dir_path)?;
+
+        let file_path = dir_path.join(format!("{}.json", filename));
+        let json_content = options.to_string_pretty(articles)?;
+
+        let mut file = File::create(&file_path)?;
+        file.write_all(json_content.as_bytes())?;
+
+        debug!("Saved {} articles to {:?}", articles.len(), file_path);
+        Ok(())
+    }
+}
+
+impl Default for NewsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Build the shared HTTP client from a configuration
+/// Remove duplicates from merged articles, then syndicated copies when configured
+fn dedup_merged(config: &SourceConfig, articles: Vec<NewsArticle>) -> Vec<NewsArticle> {
+    let articles = dedup_articles(articles);
+    match config.syndication_threshold {
+        Some(threshold) => SyndicationDedup::new()
+            .with_threshold(threshold)
+            .dedup(articles),
+        None => articles,
+    }
+}
+
+fn build_http_client(config: &SourceConfig) -> Client {
+    let mut builder = Client::builder()
+        .timeout(config.timeout_duration())
+        .user_agent(&config.user_agent);
+
+    if let Some(idle_timeout) = config.pool_idle_timeout_seconds {
+        builder = builder.pool_idle_timeout(Duration::from_secs(idle_timeout));
+    }
+    if let Some(max_idle) = config.pool_max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max_idle);
+    }
+    if let Some(keepalive) = config.tcp_keepalive_seconds {
+        builder = builder.tcp_keepalive(Duration::from_secs(keepalive));
+    }
+    if let Some(ttl) = config.dns_cache_ttl_seconds {
+        builder = builder.dns_resolver(Arc::new(CachingResolver::new(Duration::from_secs(ttl))));
+    }
+
+    builder = match config.http_version {
+        HttpVersion::Auto => builder,
+        HttpVersion::Http1Only => builder.http1_only(),
+        HttpVersion::Http2PriorKnowledge => builder.http2_prior_knowledge(),
+    };
+
+    builder.build().expect("Failed to create HTTP client")
+}
+
+/// Fetch a list of (source, topic) feeds concurrently and merge the results
+///
+/// Each feed goes through `fetch_cached()`. Individual feed failures, including feeds
+/// that overrun `deadline`, are logged and skipped; an error is only returned when
+/// every feed failed. `label` names the batch in log output.
+async fn fetch_merged(
+    requests: Vec<(&dyn NewsSource, &str)>,
+    label: &str,
+    deadline: Option<Duration>,
+    config: &SourceConfig,
+    caches: &FeedCaches,
+) -> Result<Vec<NewsArticle>> {
+    let results = join_all(requests.into_iter().map(|(source, topic)| async move {
+        let result = fetch_cached(source, topic, deadline, config, caches).await;
+        (source.name(), topic, result)
+    }))
+    .await;
+    debug!("Fetched {} feeds for '{}'", results.len(), label);
+
+    let mut articles = Vec::new();
+    let mut first_error = None;
+    let mut any_success = false;
+    for (source_name, topic, result) in results {
+        match result {
+            Ok(fetched) => {
+                any_success = true;
+                articles.extend(fetched);
+            }
+            Err(e) => {
+                warn!("Failed to fetch {} topic '{}': {}", source_name, topic, e);
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+
+    match first_error {
+        Some(e) if !any_success => Err(e),
+        _ => Ok(articles),
+    }
+}
+
+/// Fetch a list of (source, topic) feeds concurrently, gathering results within `budget`
+///
+/// Feeds go through `fetch_cached()` with the configured per-source deadline, and
+/// their articles are taken in the order the feeds answer until a limit of the budget
+/// is reached. As in `fetch_merged()`, an error is only returned when every feed failed.
+async fn fetch_budgeted(
+    requests: Vec<(&dyn NewsSource, &str)>,
+    label: &str,
+    budget: &AggregationBudget,
+    config: &SourceConfig,
+    caches: &FeedCaches,
+) -> Result<BudgetedArticles> {
+    let deadline = config.source_deadline_duration();
+    let cutoff = budget
+        .deadline
+        // A deadline too far to represent is no deadline
+        .and_then(|budget| tokio::time::Instant::now().checked_add(budget));
+    let mut in_flight: Vec<&'static str> =
+        requests.iter().map(|(source, _)| source.name()).collect();
+    let mut pending: FuturesUnordered<_> = requests
+        .into_iter()
+        .map(|(source, topic)| async move {
+            let result = fetch_cached(source, topic, deadline, config, caches).await;
+            (source.name(), topic, result)
+        })
+        .collect();
+
+    let mut articles = Vec::new();
+    let mut per_source: HashMap<&'static str, usize> = HashMap::new();
+    let mut truncated = BTreeSet::new();
+    let mut first_error = None;
+    let mut any_success = false;
+    while budget
+        .max_articles
+        .is_none_or(|max_articles| articles.len() < max_articles)
+    {
+        let next = match cutoff {
+            Some(cutoff) => match tokio::time::timeout_at(cutoff, pending.next()).await {
+                Ok(next) => next,
+                Err(_) => {
+                    debug!("Budget deadline reached for '{}'", label);
+                    break;
+                }
+            },
+            None => pending.next().await,
+        };
+        let Some((source_name, topic, result)) = next else {
+            break;
+        };
+        if let Some(index) = in_flight.iter().position(|name| *name == source_name) {
+            in_flight.swap_remove(index);
+        }
+
+        match result {
+            Ok(fetched) => {
+                any_success = true;
+                let taken = per_source.entry(source_name).or_default();
+                for article in fetched {
+                    let over_quota = budget.per_source_quota.is_some_and(|quota| *taken >= quota);
+                    let full = budget
+                        .max_articles
+                        .is_some_and(|max_articles| articles.len() >= max_articles);
+                    if over_quota || full {
+                        truncated.insert(source_name);
+                        break;
+                    }
+                    articles.push(article);
+                    *taken += 1;
+                }
+            }
+            Err(e) => {
+                warn!("Failed to fetch {} topic '{}': {}", source_name, topic, e);
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    debug!(
+        "Gathered {} articles for '{}', {} feeds abandoned",
+        articles.len(),
+        label,
+        in_flight.len()
+    );
+    truncated.extend(in_flight);
+
+    match first_error {
+        Some(e) if !any_success && truncated.is_empty() => Err(e),
+        _ => Ok(BudgetedArticles {
+            articles,
+            truncated: truncated.into_iter().map(str::to_string).collect(),
+        }),
+    }
+}
+
+/// Caches shared by every fetch of a `NewsClient`
+struct FeedCaches {
+    /// Parsed articles per (source, topic)
+    topics: Mutex<TopicCache>,
+    /// Raw feed bodies per URL, when enabled
+    disk: Option<DiskCache>,
+    /// Outcomes of network fetches
+    monitor: FetchMonitor,
+    /// Outlet favicons per URL, fetched by `SourceInfo::fetch_icon()`
+    icons: Mutex<HashMap<String, Icon>>,
+}
+
+impl FeedCaches {
+    fn new(config: &SourceConfig) -> Self {
+        let disk = config
+            .disk_cache_dir
+            .as_ref()
+            .and_then(|dir| match DiskCache::new(dir) {
+                Ok(disk) => Some(disk),
+                Err(e) => {
+                    warn!("Disk cache disabled, cannot open {:?}: {}", dir, e);
+                    None
+                }
+            });
+        Self {
+            topics: Mutex::new(TopicCache::new()),
+            disk,
+            monitor: FetchMonitor::new(),
+            icons: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+/// A client's sources, configuration, and caches, for binding topics after the fact
+#[derive(Clone)]
+pub(crate) struct FeedResolver {
+    sources: Vec<Arc<dyn NewsSource>>,
+    config: SourceConfig,
+    caches: Arc<FeedCaches>,
+}
+
+impl FeedResolver {
+    /// Resolve a set of sources with `config` and caches of their own
+    #[cfg(test)]
+    pub(crate) fn new(sources: Vec<Arc<dyn NewsSource>>, config: SourceConfig) -> Self {
+        let caches = Arc::new(FeedCaches::new(&config));
+        Self {
+            sources,
+            config,
+            caches,
+        }
+    }
+
+    /// Bind a topic of a named source
+    pub(crate) fn resolve(&self, source: &str, topic: &str) -> Result<TopicFetcher> {
+        let source = self
+            .sources
+            .iter()
+            .find(|candidate| candidate.name() == source)
+            .ok_or_else(|| FanError::InvalidUrl(format!("Unknown source: {}", source)))?;
+        Ok(TopicFetcher {
+            source: Arc::clone(source),
+            topic: topic.to_string(),
+            config: self.config.clone(),
+            caches: Arc::clone(&self.caches),
+        })
+    }
+
+    /// Apply the configured retention policy to the disk cache
+    ///
+    /// Does nothing when the disk cache is disabled or the policy keeps everything.
+    pub(crate) fn prune(&self) -> Result<usize> {
+        match &self.caches.disk {
+            Some(disk) if self.config.retention != RetentionPolicy::default() => {
+                disk.prune(&self.config.retention)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+/// One source topic bound to a client's configuration and caches
+#[derive(Clone)]
+pub(crate) struct TopicFetcher {
+    source: Arc<dyn NewsSource>,
+    topic: String,
+    config: SourceConfig,
+    caches: Arc<FeedCaches>,
+}
+
+impl TopicFetcher {
+    pub(crate) fn source_name(&self) -> &'static str {
+        self.source.name()
+    }
+
+    pub(crate) fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Whether the source lists the topic under a topic group
+    pub(crate) fn in_group(&self, group: TopicGroup) -> bool {
+        self.source
+            .group_topics(group)
+            .contains(&self.topic.as_str())
+    }
+
+    /// Fetch the topic as `NewsClient::fetch_topic()` would
+    pub(crate) async fn fetch(&self) -> Result<Vec<NewsArticle>> {
+        let deadline = self.config.source_deadline_duration();
+        fetch_cached(
+            self.source.as_ref(),
+            &self.topic,
+            deadline,
+            &self.config,
+            &self.caches,
+        )
+        .await
+    }
+}
+
+/// Fetch a single source topic through the client's caches
+///
+/// Answers from the topic cache while its entry is fresh. Otherwise the feed is
+/// fetched, hedged when mirrors are registered in `config` and revalidated against
+/// the disk cache when one is enabled. Successful results are stored in the topic
+/// cache for the TTL chosen by `SourceConfig::cache_ttl_for()`. When the fetch fails
+/// or overruns `deadline` and the disk cache holds the feed, its last-known articles
+/// are returned marked stale.
+async fn fetch_cached(
+    source: &dyn NewsSource,
+    topic: &str,
+    deadline: Option<Duration>,
+    config: &SourceConfig,
+    caches: &FeedCaches,
+) -> Result<Vec<NewsArticle>> {
+    let caching = config.cache_ttl_seconds.is_some();
+    if caching && let Some(articles) = caches.topics.lock().unwrap().get(source.name(), topic) {
+        debug!("Cache hit for {} topic '{}'", source.name(), topic);
+        return Ok(articles);
+    }
+
+    let mirrors = config.mirrors_for(source.name(), topic);
+    let fetch = async {
+        match (caches.disk.as_ref().zip(source.feed()), mirrors.is_empty()) {
+            (Some((disk, feed)), true) => fetch_revalidated(feed, topic, disk).await,
+            (None, true) => source.fetch_topic_with_ttl(topic).await,
+            (_, false) => {
+                let articles = source.fetch_topic_hedged(topic, mirrors).await?;
+                Ok((articles, None))
+            }
+        }
+    };
+    let started = Instant::now();
+    let fetched = match deadline {
+        Some(deadline) => tokio::time::timeout(deadline, fetch)
+            .await
+            .unwrap_or_else(|_| {
+                Err(FanError::Timeout(format!(
+                    "{} topic '{}' did not complete within {:?}",
+                    source.name(),
+                    topic,
+                    deadline
+                )))
+            }),
+        None => fetch.await,
+    };
+    caches.monitor.record(
+        source.name(),
+        topic,
+        started.elapsed(),
+        fetched.as_ref().ok().map(|(articles, _)| articles.len()),
+    );
+
+    let (articles, feed_ttl) = match fetched {
+        Ok(fetched) => fetched,
+        Err(e) => return stale_fallback(source, topic, caches, e),
+    };
+
+    if let Some(ttl) = config.cache_ttl_for(source.name(), topic, feed_ttl) {
+        debug!("Caching {} topic '{}' for {:?}", source.name(), topic, ttl);
+        caches
+            .topics
+            .lock()
+            .unwrap()
+            .insert(source.name(), topic, articles.clone(), ttl);
+    }
+    Ok(articles)
+}
+
+/// Fetch a topic with a conditional GET against its disk-cached body
+///
+/// The body is written back to the disk cache only once it has parsed, so a broken
+/// response never replaces a good one.
+async fn fetch_revalidated(
+    source: &dyn FeedSource,
+    topic: &str,
+    disk: &DiskCache,
+) -> Result<(Vec<NewsArticle>, Option<Duration>)> {
+    let url = source.build_topic_url(topic)?;
+    let cached = disk.load(&url);
+
+    let entry = match source.fetch_body_conditional(&url, cached.as_ref()).await? {
+        Some(fresh) => fresh,
+        None => {
+            let cached = cached.ok_or_else(|| {
+                FanError::FeedParsing(format!("{} returned 304 without a cached body", url))
+            })?;
+            CachedBody::new(&url, cached.body, cached.etag, cached.last_modified)
+        }
+    };
+
+    let entry = entry.with_source(source.name());
+    let ttl = source.parser().parse_channel_ttl(&entry.body);
+    let articles = source.parse_feed(&entry.body)?;
+    if let Err(e) = disk.store(&entry) {
+        warn!("Failed to write disk cache for {}: {}", url, e);
+    }
+    Ok((articles, ttl))
+}
+
+/// Serve a topic's last-known articles from the disk cache after `error`
+///
+/// Returns `error` itself when there is no usable cached body.
+fn stale_fallback(
+    source: &dyn NewsSource,
+    topic: &str,
+    caches: &FeedCaches,
+    error: FanError,
+) -> Result<Vec<NewsArticle>> {
+    let Some(feed) = source.feed() else {
+        return Err(error);
+    };
+    let cached = caches
+        .disk
+        .as_ref()
+        .zip(feed.build_topic_url(topic).ok())
+        .and_then(|(disk, url)| disk.load(&url));
+    let Some(cached) = cached else {
+        return Err(error);
+    };
+    let Ok(mut articles) = feed.parse_feed(&cached.body) else {
+        return Err(error);
+    };
+
+    warn!(
+        "Serving stale {} topic '{}' from {}: {}",
+        source.name(),
+        topic,
+        cached.fetched_at,
+        error
+    );
+    for article in &mut articles {
+        article.stale = true;
+    }
+    Ok(articles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "all-sources")]
+    #[test]
+    fn test_client_creation() {
+        let client = NewsClient::new();
+        assert!(client.generic_client.is_none());
+        assert!(client.wsj_client.is_none());
+        assert!(client.cnbc_client.is_none());
+        assert!(client.nasdaq_client.is_none());
+        assert!(client.market_watch_client.is_none());
+        assert!(client.seeking_alpha_client.is_none());
+        assert!(client.yahoo_finance_client.is_none());
+        assert!(client.coin_desk_client.is_none());
+        assert!(client.coin_telegraph_client.is_none());
+        assert!(client.stock_twits_client.is_none());
+        assert!(client.reddit_client.is_none());
+        assert!(client.globe_newswire_client.is_none());
+        assert!(client.pr_newswire_client.is_none());
+        assert!(client.business_wire_client.is_none());
+        assert!(client.nytimes_client.is_none());
+        assert!(client.financial_post_client.is_none());
+        assert!(client.globe_and_mail_client.is_none());
+        assert!(client.nikkei_asia_client.is_none());
+        assert!(client.scmp_business_client.is_none());
+        assert!(client.gdelt_client.is_none());
+        assert!(client.news_api_org_client.is_none());
+        assert!(client.finnhub_news_client.is_none());
+        assert!(client.alpha_vantage_client.is_none());
+        assert!(client.polygon_client.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_generic_client_access() {
+        let mut client = NewsClient::new();
+        let _generic = client.generic();
+        assert!(client.generic_client.is_some());
+    }
+
+    #[cfg(feature = "wsj")]
+    #[tokio::test]
+    async fn test_wsj_client_access() {
+        let mut client = NewsClient::new();
+        let _wsj = client.wsj();
+        assert!(client.wsj_client.is_some());
+    }
+
+    #[cfg(feature = "cnbc")]
+    #[tokio::test]
+    async fn test_cnbc_client_access() {
+        let mut client = NewsClient::new();
+        let _cnbc = client.cnbc();
+        assert!(client.cnbc_client.is_some());
+    }
+
+    #[cfg(feature = "nasdaq")]
+    #[tokio::test]
+    async fn test_nasdaq_client_access() {
+        let mut client = NewsClient::new();
+        let _nasdaq = client.nasdaq();
+        assert!(client.nasdaq_client.is_some());
+    }
+
+    #[cfg(feature = "market-watch")]
+    #[tokio::test]
+    async fn test_market_watch_client_access() {
+        let mut client = NewsClient::new();
+        let _mw = client.market_watch();
+        assert!(client.market_watch_client.is_some());
+    }
+
+    #[cfg(feature = "seeking-alpha")]
+    #[tokio::test]
+    async fn test_seeking_alpha_client_access() {
+        let mut client = NewsClient::new();
+        let _sa = client.seeking_alpha();
+        assert!(client.seeking_alpha_client.is_some());
+    }
+
+    #[cfg(feature = "yahoo-finance")]
+    #[tokio::test]
+    async fn test_yahoo_finance_client_access() {
+        let mut client = NewsClient::new();
+        let _yahoo = client.yahoo_finance();
+        assert!(client.yahoo_finance_client.is_some());
+    }
+
+    #[cfg(feature = "coin-desk")]
+    #[tokio::test]
+    async fn test_coin_desk_client_access() {
+        let mut client = NewsClient::new();
+        let _coin_desk = client.coin_desk();
+        assert!(client.coin_desk_client.is_some());
+    }
+
+    #[cfg(feature = "coin-telegraph")]
+    #[tokio::test]
+    async fn test_coin_telegraph_client_access() {
+        let mut client = NewsClient::new();
+        let _coin_telegraph = client.coin_telegraph();
+        assert!(client.coin_telegraph_client.is_some());
+    }
+
+    #[cfg(all(feature = "coin-desk", feature = "coin-telegraph", feature = "nasdaq"))]
+    #[test]
+    fn test_crypto_group_sources() {
+        let mut client = NewsClient::new();
+        let names: Vec<&str> = client
+            .sources()
+            .into_iter()
+            .filter(|source| !source.group_topics(TopicGroup::Crypto).is_empty())
+            .map(|source| source.name())
+            .collect();
+        assert_eq!(names, vec!["NASDAQ", "CoinDesk", "CoinTelegraph"]);
+    }
+
+    #[test]
+    fn test_group_topics_are_available_topics() {
+        let mut client = NewsClient::new();
+        for source in client.sources() {
+            let available = source.available_topics();
+            for group in TopicGroup::all() {
+                for topic in source.group_topics(group) {
+                    assert!(
+                        available.contains(&topic),
+                        "{} maps unknown topic '{}' to group '{}'",
+                        source.name(),
+                        topic,
+                        group
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sector_topics_are_available_topics() {
+        let mut client = NewsClient::new();
+        for source in client.sources() {
+            let available = source.available_topics();
+            for sector in Sector::all() {
+                for topic in source.sector_topics(sector) {
+                    assert!(
+                        available.contains(&topic),
+                        "{} maps unknown topic '{}' to sector '{}'",
+                        source.name(),
+                        topic,
+                        sector
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "all-sources")]
+    #[test]
+    fn test_every_sector_has_sources() {
+        let mut client = NewsClient::new();
+        let sources = client.sources();
+        for sector in Sector::all() {
+            assert!(
+                sources
+                    .iter()
+                    .any(|source| !source.sector_topics(sector).is_empty()),
+                "no source covers sector '{}'",
+                sector
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_source_has_info() {
+        let mut client = NewsClient::new();
+        for source in client.sources() {
+            let info = crate::source_info::source_info(source.name())
+                .unwrap_or_else(|| panic!("no info for {}", source.name()));
+            assert_eq!(info.name, source.name());
+            assert!(info.favicon_url.starts_with(info.home_url));
+        }
+    }
+
+    #[cfg(feature = "all-sources")]
+    #[test]
+    fn test_every_group_has_sources() {
+        let mut client = NewsClient::new();
+        let sources = client.sources();
+        for group in TopicGroup::all() {
+            assert!(
+                sources
+                    .iter()
+                    .any(|source| !source.group_topics(group).is_empty()),
+                "no source covers group '{}'",
+                group
+            );
+        }
+    }
+
+    #[cfg(feature = "cnbc")]
+    #[tokio::test]
+    async fn test_fetch_topic_served_from_cache() {
+        let mut client = NewsClient::with_config(SourceConfig::default().with_cache(300));
+        let cached = NewsArticle {
+            title: Some("Cached".to_string()),
+            ..NewsArticle::new()
+        };
+        client.caches.topics.lock().unwrap().insert(
+            "CNBC",
+            "investing",
+            vec![cached],
+            Duration::from_secs(60),
+        );
+
+        let articles = client.fetch_topic("CNBC", "investing").await.unwrap();
+        assert_eq!(articles[0].title.as_deref(), Some("Cached"));
+
+        let unknown = client.fetch_topic("Unknown Source", "investing").await;
+        assert!(matches!(unknown, Err(FanError::InvalidUrl(_))));
+
+        client.clear_cache();
+        assert!(client.caches.topics.lock().unwrap().is_empty());
+    }
+
+    #[cfg(feature = "cnbc")]
+    #[tokio::test]
+    async fn test_fetch_topic_caps_huge_feed_ttl() {
+        use crate::http::{HttpRequest, HttpResponse};
+        use async_trait::async_trait;
+
+        /// Serves a feed asking to be cached for billions of years
+        struct Forever;
+
+        #[async_trait]
+        impl HttpClient for Forever {
+            async fn send(&self, _request: HttpRequest) -> Result<HttpResponse> {
+                Ok(HttpResponse::new(
+                    200,
+                    "<rss><channel><ttl>200000000000000000</ttl>\
+                     <item><title>Pinned</title></item></channel></rss>",
+                ))
+            }
+        }
+
+        let config = SourceConfig::default().with_cache(300);
+        let mut client = NewsClient::with_config(config).with_http_client(Forever);
+        for _ in 0..2 {
+            let articles = client.fetch_topic("CNBC", "investing").await.unwrap();
+            assert_eq!(articles[0].title.as_deref(), Some("Pinned"));
+        }
+    }
+
+    #[cfg(feature = "cnbc")]
+    #[tokio::test]
+    async fn test_fetch_topic_serves_stale_disk_cache() {
+        use crate::http::{HttpRequest, HttpResponse};
+        use async_trait::async_trait;
+
+        /// Never answers, so a request cannot fail before the deadline does
+        struct Unresponsive;
+
+        #[async_trait]
+        impl HttpClient for Unresponsive {
+            async fn send(&self, _request: HttpRequest) -> Result<HttpResponse> {
+                std::future::pending().await
+            }
+        }
+
+        let dir = std::env::temp_dir().join(format!("fan-stale-{}", std::process::id()));
+        // A zero deadline makes every network fetch time out
+        let config = SourceConfig::default()
+            .with_disk_cache(&dir)
+            .with_source_deadline(0);
+        let mut client = NewsClient::with_config(config).with_http_client(Unresponsive);
+
+        let url = client.cnbc().build_topic_url("investing").unwrap();
+        let body = "<rss><channel><item><title>Last known</title></item></channel></rss>";
+        DiskCache::new(&dir)
+            .unwrap()
+            .store(&CachedBody::new(&url, body.to_string(), None, None))
+            .unwrap();
+
+        let articles = client.fetch_topic("CNBC", "investing").await.unwrap();
+        assert_eq!(articles[0].title.as_deref(), Some("Last known"));
+        assert_eq!(articles[0].source.as_deref(), Some("CNBC"));
+        assert!(articles[0].stale);
+
+        let uncached = client.fetch_topic("CNBC", "energy").await;
+        assert!(matches!(uncached, Err(FanError::Timeout(_))));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "cnbc")]
+    #[tokio::test]
+    async fn test_fetch_topic_swr_returns_cached_then_refreshes() {
+        // A zero deadline makes the background refresh fail
+        let config = SourceConfig::default()
+            .with_cache(300)
+            .with_source_deadline(0);
+        let mut client = NewsClient::with_config(config);
+        let expired = NewsArticle {
+            title: Some("Expired".to_string()),
+            ..NewsArticle::new()
+        };
+        client.caches.topics.lock().unwrap().insert(
+            "CNBC",
+            "investing",
+            vec![expired],
+            Duration::ZERO,
+        );
+
+        let revalidation = client.fetch_topic_swr("CNBC", "investing").unwrap();
+        let cached = revalidation.cached.unwrap();
+        assert_eq!(cached[0].title.as_deref(), Some("Expired"));
+
+        let refreshed = revalidation.refreshed.await.unwrap();
+        assert!(refreshed.is_err());
+
+        let uncached = client.fetch_topic_swr("CNBC", "energy").unwrap();
+        assert!(uncached.cached.is_none());
+    }
+
+    #[cfg(feature = "stock-twits")]
+    #[tokio::test]
+    async fn test_stock_twits_client_access() {
+        let mut client = NewsClient::new();
+        let _stock_twits = client.stock_twits();
+        assert!(client.stock_twits_client.is_some());
+    }
+
+    #[cfg(feature = "reddit")]
+    #[tokio::test]
+    async fn test_reddit_client_access() {
+        let mut client = NewsClient::new();
+        let _reddit = client.reddit();
+        assert!(client.reddit_client.is_some());
+    }
+
+    #[cfg(feature = "globe-newswire")]
+    #[tokio::test]
+    async fn test_globe_newswire_client_access() {
+        let mut client = NewsClient::new();
+        let _globe_newswire = client.globe_newswire();
+        assert!(client.globe_newswire_client.is_some());
+    }
+
+    #[cfg(feature = "pr-newswire")]
+    #[tokio::test]
+    async fn test_pr_newswire_client_access() {
+        let mut client = NewsClient::new();
+        let _pr_newswire = client.pr_newswire();
+        assert!(client.pr_newswire_client.is_some());
+    }
+
+    #[cfg(feature = "business-wire")]
+    #[tokio::test]
+    async fn test_business_wire_client_access() {
+        let mut client = NewsClient::new();
+        let _business_wire = client.business_wire();
+        assert!(client.business_wire_client.is_some());
+    }
+
+    #[cfg(feature = "nytimes")]
+    #[tokio::test]
+    async fn test_nytimes_client_access() {
+        let mut client = NewsClient::new();
+        let _nytimes = client.nytimes();
+        assert!(client.nytimes_client.is_some());
+    }
+
+    #[cfg(feature = "financial-post")]
+    #[tokio::test]
+    async fn test_financial_post_client_access() {
+        let mut client = NewsClient::new();
+        let _financial_post = client.financial_post();
+        assert!(client.financial_post_client.is_some());
+    }
+
+    #[cfg(feature = "globe-and-mail")]
+    #[tokio::test]
+    async fn test_globe_and_mail_client_access() {
+        let mut client = NewsClient::new();
+        let _globe_and_mail = client.globe_and_mail();
+        assert!(client.globe_and_mail_client.is_some());
+    }
+
+    #[cfg(feature = "nikkei-asia")]
+    #[tokio::test]
+    async fn test_nikkei_asia_client_access() {
+        let mut client = NewsClient::new();
+        let _nikkei_asia = client.nikkei_asia();
+        assert!(client.nikkei_asia_client.is_some());
+    }
+
+    #[cfg(feature = "scmp-business")]
+    #[tokio::test]
+    async fn test_scmp_business_client_access() {
+        let mut client = NewsClient::new();
+        let _scmp = client.scmp_business();
+        assert!(client.scmp_business_client.is_some());
+    }
+
+    #[cfg(feature = "gdelt")]
+    #[tokio::test]
+    async fn test_gdelt_client_access() {
+        let mut client = NewsClient::new();
+        let _gdelt = client.gdelt();
+        assert!(client.gdelt_client.is_some());
+    }
+
+    #[cfg(feature = "news-api-org")]
+    #[tokio::test]
+    async fn test_news_api_org_client_access() {
+        let mut client = NewsClient::new();
+        let _news_api = client.news_api_org();
+        assert!(client.news_api_org_client.is_some());
+    }
+
+    #[cfg(all(feature = "news-api-org", feature = "polygon", feature = "wsj"))]
+    #[test]
+    fn test_credentials_gate_key_based_sources() {
+        let credentials = Credentials::new().with_key("Polygon", "test-key");
+        let mut client = NewsClient::new().with_credentials(credentials);
+
+        let markets: Vec<&str> = client
+            .sources()
+            .into_iter()
+            .filter(|source| !source.group_topics(TopicGroup::Markets).is_empty())
+            .map(|source| source.name())
+            .collect();
+        assert!(markets.contains(&"Polygon"));
+        assert!(!markets.contains(&"NewsAPI"));
+        assert!(markets.contains(&"Wall Street Journal"));
+    }
+
+    #[cfg(all(feature = "cnbc", feature = "market-watch"))]
+    #[test]
+    fn test_source_by_name() {
+        let mut client = NewsClient::new();
+        assert_eq!(client.source("marketwatch").unwrap().name(), "MarketWatch");
+        assert!(client.source("Nonexistent").is_none());
+        assert!(client.source_names().contains(&"CNBC"));
+    }
+
+    #[cfg(feature = "all-sources")]
+    #[tokio::test]
+    async fn test_group_fetch_skips_deprecated_topics() {
+        let mut client = NewsClient::new();
+        let crypto: Vec<(&'static str, &'static str)> = client
+            .sources()
+            .iter()
+            .flat_map(|source| {
+                let name = source.name();
+                source
+                    .group_topics(TopicGroup::Crypto)
+                    .into_iter()
+                    .map(move |topic| (name, topic))
+            })
+            .collect();
+        for (source, topic) in &crypto {
+            client.override_topic(source, topic, TopicStatus::Deprecated);
+        }
+
+        // Nothing is left to fetch, so no request is made
+        let articles = client.fetch_group(TopicGroup::Crypto).await.unwrap();
+        assert!(articles.is_empty());
+        let (source, topic) = crypto[0];
+        assert_eq!(
+            client.available_topics().unwrap().status(source, topic),
+            Some(TopicStatus::Deprecated)
+        );
+    }
+
+    #[cfg(feature = "finnhub-news")]
+    #[tokio::test]
+    async fn test_finnhub_news_client_access() {
+        let mut client = NewsClient::new();
+        let _finnhub = client.finnhub_news();
+        assert!(client.finnhub_news_client.is_some());
+    }
+
+    #[cfg(feature = "alpha-vantage")]
+    #[tokio::test]
+    async fn test_alpha_vantage_client_access() {
+        let mut client = NewsClient::new();
+        let _alpha_vantage = client.alpha_vantage();
+        assert!(client.alpha_vantage_client.is_some());
+    }
+
+    #[cfg(feature = "polygon")]
+    #[tokio::test]
+    async fn test_polygon_client_access() {
+        let mut client = NewsClient::new();
+        let _polygon = client.polygon();
+        assert!(client.polygon_client.is_some());
+    }
+
+    #[cfg(feature = "all-sources")]
+    #[tokio::test]
+    async fn test_all_clients_independent() {
+        let mut client = NewsClient::new();
+
+        // Access all clients
+        let _generic = client.generic();
+        let _wsj = client.wsj();
+        let _cnbc = client.cnbc();
+        let _nasdaq = client.nasdaq();
+        let _mw = client.market_watch();
         let _sa = client.seeking_alpha();
         let _yahoo = client.yahoo_finance();
+        let _coin_desk = client.coin_desk();
+        let _coin_telegraph = client.coin_telegraph();
+        let _stock_twits = client.stock_twits();
+        let _reddit = client.reddit();
+        let _globe_newswire = client.globe_newswire();
+        let _pr_newswire = client.pr_newswire();
+        let _business_wire = client.business_wire();
+        let _nytimes = client.nytimes();
+        let _financial_post = client.financial_post();
+        let _globe_and_mail = client.globe_and_mail();
+        let _nikkei_asia = client.nikkei_asia();
+        let _scmp = client.scmp_business();
+        let _gdelt = client.gdelt();
+        let _news_api = client.news_api_org();
+        let _finnhub = client.finnhub_news();
+        let _alpha_vantage = client.alpha_vantage();
+        let _polygon = client.polygon();
 
         // Verify all are initialized
         assert!(client.generic_client.is_some());
@@ -337,5 +2744,167 @@ mod tests {
         assert!(client.market_watch_client.is_some());
         assert!(client.seeking_alpha_client.is_some());
         assert!(client.yahoo_finance_client.is_some());
+        assert!(client.coin_desk_client.is_some());
+        assert!(client.coin_telegraph_client.is_some());
+        assert!(client.stock_twits_client.is_some());
+        assert!(client.reddit_client.is_some());
+        assert!(client.globe_newswire_client.is_some());
+        assert!(client.pr_newswire_client.is_some());
+        assert!(client.business_wire_client.is_some());
+        assert!(client.nytimes_client.is_some());
+        assert!(client.financial_post_client.is_some());
+        assert!(client.globe_and_mail_client.is_some());
+        assert!(client.nikkei_asia_client.is_some());
+        assert!(client.scmp_business_client.is_some());
+        assert!(client.gdelt_client.is_some());
+        assert!(client.news_api_org_client.is_some());
+        assert!(client.finnhub_news_client.is_some());
+        assert!(client.alpha_vantage_client.is_some());
+        assert!(client.polygon_client.is_some());
+    }
+
+    /// Source answering every topic with `articles` articles after `delay`, or
+    /// failing when `articles` is zero
+    struct DelayedSource {
+        name: &'static str,
+        articles: usize,
+        delay: Duration,
+    }
+
+    #[async_trait::async_trait]
+    impl NewsSource for DelayedSource {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn available_topics(&self) -> Vec<&'static str> {
+            vec!["news"]
+        }
+
+        async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+            tokio::time::sleep(self.delay).await;
+            if self.articles == 0 {
+                return Err(FanError::FeedParsing(format!("{} unavailable", topic)));
+            }
+            Ok((0..self.articles)
+                .map(|i| NewsArticle {
+                    title: Some(format!("{} {}", self.name, i)),
+                    ..NewsArticle::new()
+                })
+                .collect())
+        }
+    }
+
+    #[cfg(feature = "all-sources")]
+    #[tokio::test]
+    async fn test_symbol_news() {
+        use crate::http::{HttpRequest, HttpResponse};
+        use async_trait::async_trait;
+
+        /// Serves an AAPL story on symbol feeds and two general stories on every other feed
+        struct SymbolFeeds;
+
+        #[async_trait]
+        impl HttpClient for SymbolFeeds {
+            async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+                let items = if request.url.contains("AAPL") {
+                    "<item><title>Apple sets event date</title><link>https://example.com/event</link></item>"
+                } else {
+                    "<item><title>Suppliers gain as $AAPL orders rise</title><link>https://example.com/suppliers</link></item>\
+                     <item><title>Oil slips</title><link>https://example.com/oil</link></item>"
+                };
+                let body = format!("<rss><channel>{}</channel></rss>", items);
+                Ok(HttpResponse::new(200, body).with_header("Content-Type", "application/rss+xml"))
+            }
+        }
+
+        let mut client = NewsClient::new().with_http_client(SymbolFeeds);
+        let articles = client.symbol_news("aapl").await.unwrap();
+        let titles: Vec<_> = articles.iter().filter_map(|a| a.title.as_deref()).collect();
+        assert_eq!(titles.len(), 2);
+        assert!(titles.contains(&"Apple sets event date"));
+        let suppliers = articles
+            .iter()
+            .find(|a| a.title.as_deref() == Some("Suppliers gain as $AAPL orders rise"))
+            .unwrap();
+        assert_eq!(suppliers.matches[0].term, "AAPL");
+
+        assert!(matches!(
+            client.symbol_news("AAPL&s=MSFT").await,
+            Err(FanError::InvalidSymbol(_))
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_fetch_budgeted() {
+        let source = |name, articles, millis| DelayedSource {
+            name,
+            articles,
+            delay: Duration::from_millis(millis),
+        };
+        let sources = [
+            source("Fast", 3, 10),
+            source("Prolific", 5, 20),
+            source("Slow", 2, 10_000),
+        ];
+        let requests = || {
+            sources
+                .iter()
+                .map(|s| (s as &dyn NewsSource, "news"))
+                .collect()
+        };
+        let config = SourceConfig::default();
+        let caches = FeedCaches::new(&config);
+        let fetch = |budget| {
+            let (config, caches) = (&config, &caches);
+            async move { fetch_budgeted(requests(), "test", &budget, config, caches).await }
+        };
+
+        let all = fetch(AggregationBudget::new()).await.unwrap();
+        assert_eq!(all.articles.len(), 10);
+        assert!(all.is_complete());
+
+        let budget = AggregationBudget::new()
+            .with_deadline(Duration::from_secs(1))
+            .with_per_source_quota(4);
+        let gathered = fetch(budget).await.unwrap();
+        assert_eq!(gathered.articles.len(), 7);
+        assert_eq!(gathered.truncated, ["Prolific", "Slow"]);
+
+        // The first feed to answer fills the budget and the others are abandoned
+        let gathered = fetch(AggregationBudget::new().with_max_articles(2))
+            .await
+            .unwrap();
+        let titles: Vec<_> = gathered
+            .articles
+            .iter()
+            .filter_map(|a| a.title.as_deref())
+            .collect();
+        assert_eq!(titles, ["Fast 0", "Fast 1"]);
+        assert_eq!(gathered.truncated, ["Fast", "Prolific", "Slow"]);
+
+        let gathered = fetch(
+            AggregationBudget::new()
+                .with_deadline(Duration::MAX)
+                .with_max_articles(8),
+        )
+        .await
+        .unwrap();
+        assert_eq!(gathered.articles.len(), 8);
+
+        let failing = [source("Down", 0, 10)];
+        let requests = failing
+            .iter()
+            .map(|s| (s as &dyn NewsSource, "news"))
+            .collect();
+        let result = fetch_budgeted(
+            requests,
+            "test",
+            &AggregationBudget::new(),
+            &config,
+            &caches,
+        )
+        .await;
+        assert!(result.is_err());
     }
 }
@@ -0,0 +1,439 @@
+use crate::credentials::{Credentials, require_key};
+use crate::error::{FanError, Result};
+use crate::http::{HttpClient, HttpRequest};
+use crate::news_source::{FeedSource, NewsSource, keyed_group_topics};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use reqwest::Url;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Environment variable read for the API key when none is configured
+pub const API_KEY_ENV: &str = "ALPHAVANTAGE_API_KEY";
+
+/// Alpha Vantage NEWS_SENTIMENT client
+///
+/// Reads Alpha Vantage's news and sentiment feed by topic or ticker. Requires an API
+/// key, taken from `with_api_key()` or the `ALPHAVANTAGE_API_KEY` environment variable.
+/// The API only accepts the key as a query parameter, so it is added when the request
+/// is sent and never appears in topic URLs, logs, or cache entries.
+///
+/// Each article carries the overall sentiment (`extra_fields["sentiment"]`, e.g.
+/// "Somewhat-Bullish", and `extra_fields["sentiment_score"]` from -1 to 1), the
+/// tickers it covers (`extra_fields["symbols"]`), per-ticker sentiment labels
+/// (`extra_fields["ticker_sentiment"]`, e.g. "AAPL:Bullish,MSFT:Neutral"), the
+/// originating outlet (`extra_fields["outlet"]`), and its banner image
+/// (`extra_fields["thumbnail"]`).
+pub struct AlphaVantage {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    api_key: Option<String>,
+}
+
+/// Envelope returned by the NEWS_SENTIMENT function
+///
+/// Errors and rate-limit notices come back with status 200 as an object holding one
+/// of the message fields instead of `feed`.
+#[derive(Debug, Deserialize)]
+struct Response {
+    feed: Option<Vec<Article>>,
+    #[serde(rename = "Information")]
+    information: Option<String>,
+    #[serde(rename = "Note")]
+    note: Option<String>,
+    #[serde(rename = "Error Message")]
+    error_message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Article {
+    title: Option<String>,
+    url: Option<String>,
+    time_published: Option<String>,
+    #[serde(default)]
+    authors: Vec<String>,
+    summary: Option<String>,
+    banner_image: Option<String>,
+    source: Option<String>,
+    #[serde(default)]
+    topics: Vec<TopicRelevance>,
+    overall_sentiment_score: Option<f64>,
+    overall_sentiment_label: Option<String>,
+    #[serde(default)]
+    ticker_sentiment: Vec<TickerSentiment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TopicRelevance {
+    topic: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TickerSentiment {
+    ticker: String,
+    ticker_sentiment_label: Option<String>,
+}
+
+impl AlphaVantage {
+    /// Create a new Alpha Vantage client
+    ///
+    /// Initializes the client with the NEWS_SENTIMENT endpoint and reads the API key
+    /// from `ALPHAVANTAGE_API_KEY`, if set.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://www.alphavantage.co/query?function=NEWS_SENTIMENT&sort=LATEST&topics={topic}"
+                .to_string(),
+        );
+        url_map.insert(
+            "tickers".to_string(),
+            "https://www.alphavantage.co/query?function=NEWS_SENTIMENT&sort=LATEST".to_string(),
+        );
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::AlphaVantage),
+            api_key: std::env::var(API_KEY_ENV)
+                .ok()
+                .filter(|key| !key.is_empty()),
+        }
+    }
+
+    /// Use the given API key instead of the environment variable
+    pub fn with_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = Some(api_key.to_string());
+        self
+    }
+
+    /// Take the API key from a credentials store, ignoring the environment variable
+    pub fn with_credentials(mut self, credentials: &Credentials) -> Self {
+        self.api_key = credentials.get(self.name()).map(str::to_string);
+        self
+    }
+
+    /// Whether an API key is available
+    pub fn has_api_key(&self) -> bool {
+        self.api_key.is_some()
+    }
+
+    /// Get financial markets news
+    pub async fn financial_markets(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("financial_markets").await
+    }
+
+    /// Get earnings news
+    pub async fn earnings(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("earnings").await
+    }
+
+    /// Get IPO news
+    pub async fn ipo(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("ipo").await
+    }
+
+    /// Get mergers and acquisitions news
+    pub async fn mergers_and_acquisitions(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("mergers_and_acquisitions").await
+    }
+
+    /// Get monetary policy news
+    pub async fn economy_monetary(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("economy_monetary").await
+    }
+
+    /// Get technology news
+    pub async fn technology(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("technology").await
+    }
+
+    /// Get news mentioning all of the given tickers
+    ///
+    /// # Arguments
+    /// * `tickers` - Symbols such as "AAPL", "CRYPTO:BTC", or "FOREX:USD"
+    pub async fn tickers(&self, tickers: &[&str]) -> Result<Vec<NewsArticle>> {
+        let base_url = self
+            .url_map
+            .get("tickers")
+            .ok_or_else(|| FanError::InvalidUrl("Tickers URL not found".to_string()))?;
+        let url = Url::parse_with_params(base_url, [("tickers", tickers.join(","))])
+            .map_err(|e| FanError::InvalidUrl(e.to_string()))?;
+
+        self.fetch_feed_by_url(url.as_str()).await
+    }
+
+    /// Convert an Alpha Vantage article into a `NewsArticle`
+    fn article_to_news(article: Article) -> NewsArticle {
+        let mut news = NewsArticle::new();
+
+        news.title = article.title;
+        news.description = article.summary.filter(|summary| !summary.is_empty());
+        news.guid = article.url.clone();
+        news.link = article.url;
+        news.pub_date = article.time_published.as_deref().map(normalize_time);
+        if !article.authors.is_empty() {
+            news.author = Some(article.authors.join(", "));
+        }
+        news.category = article.topics.into_iter().next().map(|topic| topic.topic);
+
+        let symbols = article
+            .ticker_sentiment
+            .iter()
+            .map(|ticker| ticker.ticker.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        let ticker_sentiment = article
+            .ticker_sentiment
+            .iter()
+            .filter_map(|ticker| {
+                let label = ticker.ticker_sentiment_label.as_ref()?;
+                Some(format!("{}:{}", ticker.ticker, label))
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let fields = [
+            ("sentiment", article.overall_sentiment_label),
+            (
+                "sentiment_score",
+                article
+                    .overall_sentiment_score
+                    .map(|score| score.to_string()),
+            ),
+            ("symbols", Some(symbols)),
+            ("ticker_sentiment", Some(ticker_sentiment)),
+            ("outlet", article.source),
+            ("thumbnail", article.banner_image),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value.filter(|value| !value.is_empty()) {
+                news.extra_fields.insert(key.to_string(), value.into());
+            }
+        }
+
+        news
+    }
+}
+
+/// Convert Alpha Vantage's compact `20240501T143000` timestamps (UTC) to RFC 3339
+///
+/// Unrecognized values are kept as they are.
+fn normalize_time(time: &str) -> String {
+    NaiveDateTime::parse_from_str(time, "%Y%m%dT%H%M%S")
+        .map(|date| date.and_utc().to_rfc3339())
+        .unwrap_or_else(|_| time.to_string())
+}
+
+#[async_trait]
+impl NewsSource for AlphaVantage {
+    fn name(&self) -> &'static str {
+        "AlphaVantage"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec![
+            "financial_markets",
+            "earnings",
+            "ipo",
+            "mergers_and_acquisitions",
+            "economy_fiscal",
+            "economy_monetary",
+            "economy_macro",
+            "blockchain",
+            "technology",
+            "energy_transportation",
+            "finance",
+            "life_sciences",
+            "manufacturing",
+            "real_estate",
+            "retail_wholesale",
+        ]
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        let topics = match group {
+            TopicGroup::Markets => vec!["financial_markets"],
+            TopicGroup::Earnings => vec!["earnings"],
+            TopicGroup::Crypto => vec!["blockchain"],
+            TopicGroup::Macro => vec!["economy_monetary"],
+            TopicGroup::Tech => vec!["technology"],
+            TopicGroup::Energy => vec!["energy_transportation"],
+            TopicGroup::PersonalFinance | TopicGroup::Podcasts => vec![],
+        };
+        keyed_group_topics(self.has_api_key(), topics)
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["technology"],
+            Sector::Healthcare => vec!["life_sciences"],
+            Sector::Financials => vec!["finance"],
+            Sector::Energy => vec!["energy_transportation"],
+            Sector::RealEstate => vec!["real_estate"],
+            Sector::Consumer => vec!["retail_wholesale"],
+            Sector::Industrials => vec!["manufacturing"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for AlphaVantage {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override authenticate: send the key as the apikey query parameter
+    fn authenticate(&self, request: HttpRequest) -> Result<HttpRequest> {
+        let api_key = require_key(self.name(), self.api_key.as_deref())?;
+        Ok(request.with_query("apikey", api_key))
+    }
+
+    // Override parse_body: Alpha Vantage returns JSON instead of RSS
+    fn parse_body(&self, body: &str) -> Result<Vec<NewsArticle>> {
+        let response: Response = serde_json::from_str(body)?;
+
+        match response.feed {
+            Some(feed) => Ok(feed.into_iter().map(Self::article_to_news).collect()),
+            None => Err(FanError::FeedParsing(format!(
+                "Alpha Vantage error: {}",
+                response
+                    .error_message
+                    .or(response.information)
+                    .or(response.note)
+                    .unwrap_or_else(|| "response has no feed".to_string())
+            ))),
+        }
+    }
+
+    // Override streams_body: JSON bodies are parsed in one piece
+    fn streams_body(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+
+    const SAMPLE: &str = r#"{
+        "items": "2",
+        "sentiment_score_definition": "x <= -0.35: Bearish; ...",
+        "feed": [
+            {
+                "title": "Apple earnings top estimates",
+                "url": "https://www.example.com/apple-earnings",
+                "time_published": "20240501T143000",
+                "authors": ["Jane Reporter", "John Writer"],
+                "summary": "Apple reported...",
+                "banner_image": "https://www.example.com/apple.jpg",
+                "source": "Benzinga",
+                "category_within_source": "News",
+                "source_domain": "www.benzinga.com",
+                "topics": [{"topic": "Earnings", "relevance_score": "0.99"}],
+                "overall_sentiment_score": 0.31,
+                "overall_sentiment_label": "Somewhat-Bullish",
+                "ticker_sentiment": [
+                    {"ticker": "AAPL", "relevance_score": "0.9", "ticker_sentiment_score": "0.4", "ticker_sentiment_label": "Bullish"},
+                    {"ticker": "MSFT", "relevance_score": "0.1", "ticker_sentiment_score": "0.0", "ticker_sentiment_label": "Neutral"}
+                ]
+            },
+            {
+                "title": "Markets wrap",
+                "url": "https://www.example.com/wrap",
+                "time_published": "20240501T120000",
+                "authors": [],
+                "summary": "",
+                "banner_image": null,
+                "source": "Reuters",
+                "topics": [],
+                "ticker_sentiment": []
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_parse_feed_with_sentiment() {
+        let source = AlphaVantage::new(Client::new());
+        let articles = source.parse_body(SAMPLE).unwrap();
+
+        assert_eq!(articles.len(), 2);
+        let first = &articles[0];
+        assert_eq!(first.pub_date.as_deref(), Some("2024-05-01T14:30:00+00:00"));
+        assert_eq!(first.author.as_deref(), Some("Jane Reporter, John Writer"));
+        assert_eq!(first.category.as_deref(), Some("Earnings"));
+        assert_eq!(first.extra_fields["sentiment"], "Somewhat-Bullish");
+        assert_eq!(first.extra_fields["sentiment_score"], "0.31");
+        assert_eq!(first.extra_fields["symbols"], "AAPL,MSFT");
+        assert_eq!(
+            first.extra_fields["ticker_sentiment"],
+            "AAPL:Bullish,MSFT:Neutral"
+        );
+
+        let second = &articles[1];
+        assert!(second.author.is_none());
+        assert!(second.description.is_none());
+        assert!(!second.extra_fields.contains_key("symbols"));
+        assert!(!second.extra_fields.contains_key("sentiment"));
+    }
+
+    #[test]
+    fn test_parse_api_error() {
+        let source = AlphaVantage::new(Client::new());
+        let body = r#"{"Information": "Thank you for using Alpha Vantage! Our standard API rate limit is 25 requests per day."}"#;
+        assert!(matches!(
+            source.parse_body(body),
+            Err(FanError::FeedParsing(message)) if message.contains("rate limit")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_missing_api_key() {
+        let mut source = AlphaVantage::new(Client::new());
+        source.api_key = None;
+
+        assert!(source.group_topics(TopicGroup::Earnings).is_empty());
+        assert!(matches!(
+            source.earnings().await,
+            Err(FanError::MissingApiKey(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_api_key_not_in_errors() {
+        // Nothing listens on port 1, so the request fails to connect
+        let source = AlphaVantage::new(Client::new()).with_api_key("SECRETKEY123");
+        let error = source
+            .fetch_body("http://127.0.0.1:1/query?function=NEWS_SENTIMENT")
+            .await
+            .unwrap_err();
+
+        assert!(!error.to_string().contains("SECRETKEY123"));
+        assert!(!format!("{:?}", error).contains("SECRETKEY123"));
+    }
+}
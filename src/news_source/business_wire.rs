@@ -0,0 +1,142 @@
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Feed tokens for Business Wire subjects and industries, in topic order
+const TOPICS: [(&str, &str); 8] = [
+    ("all", "G1QFDERJXkJeGVtRWA=="),
+    ("earnings", "G1QFDERJXkJeGVtXWQ=="),
+    ("mergers_acquisitions", "G1QFDERJXkJeGVtWWA=="),
+    ("dividends", "G1QFDERJXkJeGVtXXQ=="),
+    ("ipo", "G1QFDERJXkJeGVtWXQ=="),
+    ("banking", "G1QFDERJXkJeEFpRWQ=="),
+    ("technology", "G1QFDERJXkJeEF9YXA=="),
+    ("energy", "G1QFDERJXkJeEFtRWA=="),
+];
+
+/// Business Wire press release client
+///
+/// Provides access to Business Wire RSS feeds of company press releases. Business Wire
+/// identifies each subject and industry feed by an opaque `rss` query token.
+pub struct BusinessWire {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    topic_categories: HashMap<&'static str, &'static str>,
+}
+
+impl BusinessWire {
+    /// Create a new Business Wire client
+    ///
+    /// Initializes the client with the Business Wire feed URL pattern and feed token mappings.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://feed.businesswire.com/rss/home/?rss={topic}".to_string(),
+        );
+
+        let topic_categories = TOPICS.iter().copied().collect();
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::BusinessWire),
+            topic_categories,
+        }
+    }
+
+    /// Get all news releases
+    pub async fn all(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("all").await
+    }
+
+    /// Get earnings releases
+    pub async fn earnings(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("earnings").await
+    }
+
+    /// Get mergers and acquisitions releases
+    pub async fn mergers_acquisitions(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("mergers_acquisitions").await
+    }
+
+    /// Get IPO releases
+    pub async fn ipo(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("ipo").await
+    }
+}
+
+#[async_trait]
+impl NewsSource for BusinessWire {
+    fn name(&self) -> &'static str {
+        "Business Wire"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        TOPICS.iter().map(|(name, _)| *name).collect()
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Earnings => vec!["earnings"],
+            TopicGroup::Tech => vec!["technology"],
+            TopicGroup::Energy => vec!["energy"],
+            _ => vec![],
+        }
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["technology"],
+            Sector::Financials => vec!["banking"],
+            Sector::Energy => vec!["energy"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for BusinessWire {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url to map topic names to feed tokens
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let token = self.topic_categories.get(topic).ok_or_else(|| {
+            crate::error::FanError::InvalidUrl(format!("Invalid topic: {}", topic))
+        })?;
+
+        let base_url = self
+            .url_map()
+            .get("base")
+            .ok_or_else(|| crate::error::FanError::InvalidUrl("Base URL not found".to_string()))?;
+
+        Ok(base_url.replace("{topic}", token))
+    }
+}
@@ -1,10 +1,14 @@
 use crate::error::Result;
-use crate::news_source::NewsSource;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
 use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::topic_group::TopicGroup;
 use crate::types::{NewsArticle, SourceConfig};
 use async_trait::async_trait;
-use reqwest::Client;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// CNBC news client
 ///
@@ -12,7 +16,7 @@ use std::collections::HashMap;
 /// politics, healthcare, and more across global markets.
 pub struct CNBC {
     url_map: HashMap<String, String>,
-    client: Client,
+    client: Arc<dyn HttpClient>,
     parser: NewsParser,
     topic_categories: HashMap<&'static str, u32>,
 }
@@ -21,7 +25,7 @@ impl CNBC {
     /// Create a new CNBC client
     ///
     /// Initializes the client with CNBC RSS feed URL patterns and topic ID mappings.
-    pub fn new(client: Client) -> Self {
+    pub fn new(client: impl HttpClient + 'static) -> Self {
         Self::with_config(
             client,
             SourceConfig::new("https://www.cnbc.com/id/{topic_id}/device/rss/rss.html"),
@@ -33,7 +37,7 @@ impl CNBC {
     /// # Arguments
     /// * `client` - HTTP client for making requests
     /// * `config` - Source configuration (only base_url is used)
-    pub fn with_config(client: Client, config: SourceConfig) -> Self {
+    pub fn with_config(client: impl HttpClient + 'static, config: SourceConfig) -> Self {
         let mut url_map = HashMap::new();
         url_map.insert("base".to_string(), config.base_url.clone());
 
@@ -66,8 +70,8 @@ impl CNBC {
 
         Self {
             url_map,
-            client,
-            parser: NewsParser::new("cnbc"),
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::CNBC),
             topic_categories,
         }
     }
@@ -104,12 +108,51 @@ impl NewsSource for CNBC {
         "CNBC"
     }
 
+    fn available_topics(&self) -> Vec<&'static str> {
+        self.topic_categories.keys().copied().collect()
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Markets => vec!["investing"],
+            TopicGroup::Earnings => vec!["earnings"],
+            TopicGroup::Macro => vec!["economy"],
+            TopicGroup::Tech => vec!["technology"],
+            TopicGroup::Energy => vec!["energy"],
+            TopicGroup::PersonalFinance => vec!["personal_finance"],
+            _ => vec![],
+        }
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["technology"],
+            Sector::Healthcare => vec!["health_care"],
+            Sector::Financials => vec!["finance"],
+            Sector::Energy => vec!["energy"],
+            Sector::RealEstate => vec!["real_estate"],
+            Sector::Consumer => vec!["retail", "autos"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for CNBC {
     fn url_map(&self) -> &HashMap<String, String> {
         &self.url_map
     }
 
-    fn client(&self) -> &Client {
-        &self.client
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
     }
 
     fn parser(&self) -> &NewsParser {
@@ -129,10 +172,4 @@ impl NewsSource for CNBC {
 
         Ok(base_url.replace("{topic_id}", &topic_id.to_string()))
     }
-
-    // Uses default fetch_topic implementation
-
-    fn available_topics(&self) -> Vec<&'static str> {
-        self.topic_categories.keys().copied().collect()
-    }
 }
@@ -0,0 +1,115 @@
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// CoinDesk news client
+///
+/// Provides access to CoinDesk RSS feeds covering digital asset markets,
+/// policy, technology, and business.
+pub struct CoinDesk {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+}
+
+impl CoinDesk {
+    /// Create a new CoinDesk client
+    ///
+    /// Initializes the client with the CoinDesk main feed and category feed URL pattern.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://www.coindesk.com/arc/outboundfeeds/rss/?outputType=xml".to_string(),
+        );
+        url_map.insert(
+            "category".to_string(),
+            "https://www.coindesk.com/arc/outboundfeeds/rss/category/{topic}/?outputType=xml"
+                .to_string(),
+        );
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::CoinDesk),
+        }
+    }
+
+    /// Get latest news across all categories
+    pub async fn latest(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("latest").await
+    }
+
+    /// Get markets news
+    pub async fn markets(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("markets").await
+    }
+
+    /// Get policy and regulation news
+    pub async fn policy(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("policy").await
+    }
+}
+
+#[async_trait]
+impl NewsSource for CoinDesk {
+    fn name(&self) -> &'static str {
+        "CoinDesk"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec!["latest", "markets", "policy", "tech", "business"]
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Crypto => vec!["latest"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for CoinDesk {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url: "latest" is the main feed, everything else is a category feed
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let key = if topic == "latest" {
+            "base"
+        } else {
+            "category"
+        };
+        let url = self
+            .url_map()
+            .get(key)
+            .ok_or_else(|| crate::error::FanError::InvalidUrl(format!("{} URL not found", key)))?;
+
+        Ok(url.replace("{topic}", topic))
+    }
+}
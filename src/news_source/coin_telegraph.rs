@@ -0,0 +1,129 @@
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// CoinTelegraph news client
+///
+/// Provides access to CoinTelegraph RSS feeds covering cryptocurrency markets,
+/// blockchain technology, DeFi, and regulation.
+pub struct CoinTelegraph {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+}
+
+impl CoinTelegraph {
+    /// Create a new CoinTelegraph client
+    ///
+    /// Initializes the client with the CoinTelegraph main feed and tag feed URL pattern.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://cointelegraph.com/rss".to_string(),
+        );
+        url_map.insert(
+            "tag".to_string(),
+            "https://cointelegraph.com/rss/tag/{topic}".to_string(),
+        );
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::CoinTelegraph),
+        }
+    }
+
+    /// Get latest news across all categories
+    pub async fn latest(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("latest").await
+    }
+
+    /// Get Bitcoin news
+    pub async fn bitcoin(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("bitcoin").await
+    }
+
+    /// Get Ethereum news
+    pub async fn ethereum(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("ethereum").await
+    }
+
+    /// Get altcoin news
+    pub async fn altcoin(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("altcoin").await
+    }
+
+    /// Get regulation news
+    pub async fn regulation(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("regulation").await
+    }
+}
+
+#[async_trait]
+impl NewsSource for CoinTelegraph {
+    fn name(&self) -> &'static str {
+        "CoinTelegraph"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec![
+            "latest",
+            "bitcoin",
+            "ethereum",
+            "altcoin",
+            "blockchain",
+            "defi",
+            "nft",
+            "regulation",
+        ]
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Crypto => vec!["latest"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for CoinTelegraph {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url: "latest" is the main feed, everything else is a tag feed
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let key = if topic == "latest" { "base" } else { "tag" };
+        let url = self
+            .url_map()
+            .get(key)
+            .ok_or_else(|| crate::error::FanError::InvalidUrl(format!("{} URL not found", key)))?;
+
+        Ok(url.replace("{topic}", topic))
+    }
+}
@@ -0,0 +1,144 @@
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Financial Post news client
+///
+/// Provides access to Financial Post RSS feeds covering Canadian business, markets,
+/// the economy, and personal finance.
+pub struct FinancialPost {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    topic_categories: HashMap<&'static str, &'static str>,
+}
+
+impl FinancialPost {
+    /// Create a new Financial Post client
+    ///
+    /// Initializes the client with Financial Post RSS feed URL patterns and topic mappings.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://financialpost.com/{topic}".to_string(),
+        );
+
+        let mut topic_categories = HashMap::new();
+        // Feed paths for Financial Post sections
+        topic_categories.insert("top_stories", "feed");
+        topic_categories.insert("news", "category/news/feed");
+        topic_categories.insert("economy", "category/news/economy/feed");
+        topic_categories.insert("investing", "category/investing/feed");
+        topic_categories.insert("markets", "category/markets/feed");
+        topic_categories.insert("energy", "category/commodities/energy/feed");
+        topic_categories.insert("mining", "category/commodities/mining/feed");
+        topic_categories.insert("technology", "category/technology/feed");
+        topic_categories.insert("personal_finance", "category/personal-finance/feed");
+        topic_categories.insert("real_estate", "category/real-estate/feed");
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::FinancialPost),
+            topic_categories,
+        }
+    }
+
+    /// Get top stories
+    pub async fn top_stories(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("top_stories").await
+    }
+
+    /// Get economy news
+    pub async fn economy(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("economy").await
+    }
+
+    /// Get investing news
+    pub async fn investing(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("investing").await
+    }
+
+    /// Get markets news
+    pub async fn markets(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("markets").await
+    }
+}
+
+#[async_trait]
+impl NewsSource for FinancialPost {
+    fn name(&self) -> &'static str {
+        "Financial Post"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        self.topic_categories.keys().copied().collect()
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Markets => vec!["markets"],
+            TopicGroup::Macro => vec!["economy"],
+            TopicGroup::Tech => vec!["technology"],
+            TopicGroup::Energy => vec!["energy"],
+            TopicGroup::PersonalFinance => vec!["personal_finance"],
+            _ => vec![],
+        }
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["technology"],
+            Sector::Energy => vec!["energy"],
+            Sector::RealEstate => vec!["real_estate"],
+            Sector::Materials => vec!["mining"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for FinancialPost {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url to map topic names to section feed paths
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let topic_path = self.topic_categories.get(topic).ok_or_else(|| {
+            crate::error::FanError::InvalidUrl(format!("Invalid topic: {}", topic))
+        })?;
+
+        let base_url = self
+            .url_map()
+            .get("base")
+            .ok_or_else(|| crate::error::FanError::InvalidUrl("Base URL not found".to_string()))?;
+
+        Ok(base_url.replace("{topic}", topic_path))
+    }
+}
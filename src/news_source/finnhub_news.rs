@@ -0,0 +1,353 @@
+use crate::credentials::{Credentials, require_key};
+use crate::error::{FanError, Result};
+use crate::http::{HttpClient, HttpRequest};
+use crate::news_source::{FeedSource, NewsSource, keyed_group_topics};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::symbols::Symbol;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate};
+use reqwest::Url;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Environment variable read for the API key when none is configured
+pub const API_KEY_ENV: &str = "FINNHUB_API_KEY";
+
+/// Finnhub market news client
+///
+/// Reads Finnhub's general, forex, crypto, and merger news categories and per-company
+/// news through its JSON API. Requires an API key, taken from `with_api_key()` or the
+/// `FINNHUB_API_KEY` environment variable; the key is sent in a request header. The
+/// originating outlet is kept in `extra_fields["outlet"]`, related tickers in
+/// `extra_fields["symbols"]`, and the article image in `extra_fields["thumbnail"]`.
+pub struct FinnhubNews {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    api_key: Option<String>,
+}
+
+/// Finnhub answers with an article array, or an object describing the error
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Response {
+    Articles(Vec<Article>),
+    Error { error: String },
+}
+
+#[derive(Debug, Deserialize)]
+struct Article {
+    category: Option<String>,
+    datetime: Option<i64>,
+    headline: Option<String>,
+    id: Option<i64>,
+    image: Option<String>,
+    related: Option<String>,
+    source: Option<String>,
+    summary: Option<String>,
+    url: Option<String>,
+}
+
+impl FinnhubNews {
+    /// Create a new Finnhub client
+    ///
+    /// Initializes the client with the market news and company news endpoints and reads
+    /// the API key from `FINNHUB_API_KEY`, if set.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://finnhub.io/api/v1/news?category={topic}".to_string(),
+        );
+        url_map.insert(
+            "company_news".to_string(),
+            "https://finnhub.io/api/v1/company-news".to_string(),
+        );
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::FinnhubNews),
+            api_key: std::env::var(API_KEY_ENV)
+                .ok()
+                .filter(|key| !key.is_empty()),
+        }
+    }
+
+    /// Use the given API key instead of the environment variable
+    pub fn with_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = Some(api_key.to_string());
+        self
+    }
+
+    /// Take the API key from a credentials store, ignoring the environment variable
+    pub fn with_credentials(mut self, credentials: &Credentials) -> Self {
+        self.api_key = credentials.get(self.name()).map(str::to_string);
+        self
+    }
+
+    /// Whether an API key is available
+    pub fn has_api_key(&self) -> bool {
+        self.api_key.is_some()
+    }
+
+    /// Get general market news
+    pub async fn general(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("general").await
+    }
+
+    /// Get forex news
+    pub async fn forex(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("forex").await
+    }
+
+    /// Get cryptocurrency news
+    pub async fn crypto(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("crypto").await
+    }
+
+    /// Get merger and acquisition news
+    pub async fn merger(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("merger").await
+    }
+
+    /// Get news about one company
+    ///
+    /// # Arguments
+    /// * `symbol` - Ticker of a North American listed company (e.g., "AAPL")
+    /// * `from` - First day of the date range
+    /// * `to` - Last day of the date range
+    pub async fn company_news(
+        &self,
+        symbol: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<NewsArticle>> {
+        let base_url = self
+            .url_map
+            .get("company_news")
+            .ok_or_else(|| FanError::InvalidUrl("Company news URL not found".to_string()))?;
+        let url = Url::parse_with_params(
+            base_url,
+            [
+                ("symbol", Symbol::parse(symbol)?.to_string()),
+                ("from", from.format("%Y-%m-%d").to_string()),
+                ("to", to.format("%Y-%m-%d").to_string()),
+            ],
+        )
+        .map_err(|e| FanError::InvalidUrl(e.to_string()))?;
+
+        self.fetch_feed_by_url(url.as_str()).await
+    }
+
+    /// Convert a Finnhub article into a `NewsArticle`
+    fn article_to_news(article: Article) -> NewsArticle {
+        let mut news = NewsArticle::new();
+
+        news.title = article.headline;
+        news.description = article.summary.filter(|summary| !summary.is_empty());
+        news.link = article.url;
+        news.guid = article.id.map(|id| id.to_string());
+        news.pub_date = article
+            .datetime
+            .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+            .map(|date| date.to_rfc3339());
+        news.category = article.category;
+
+        let fields = [
+            ("outlet", article.source),
+            ("symbols", article.related),
+            ("thumbnail", article.image),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value.filter(|value| !value.is_empty()) {
+                news.extra_fields.insert(key.to_string(), value.into());
+            }
+        }
+
+        news
+    }
+}
+
+#[async_trait]
+impl NewsSource for FinnhubNews {
+    fn name(&self) -> &'static str {
+        "Finnhub"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec!["general", "forex", "crypto", "merger"]
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        let topics = match group {
+            TopicGroup::Markets => vec!["general"],
+            TopicGroup::Crypto => vec!["crypto"],
+            _ => vec![],
+        };
+        keyed_group_topics(self.has_api_key(), topics)
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for FinnhubNews {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override authenticate: send the key in the X-Finnhub-Token header
+    fn authenticate(&self, request: HttpRequest) -> Result<HttpRequest> {
+        let api_key = require_key(self.name(), self.api_key.as_deref())?;
+        Ok(request.with_header("X-Finnhub-Token", api_key))
+    }
+
+    // Override parse_body: Finnhub returns JSON instead of RSS
+    fn parse_body(&self, body: &str) -> Result<Vec<NewsArticle>> {
+        match serde_json::from_str(body)? {
+            Response::Articles(articles) => {
+                Ok(articles.into_iter().map(Self::article_to_news).collect())
+            }
+            Response::Error { error } => {
+                Err(FanError::FeedParsing(format!("Finnhub error: {}", error)))
+            }
+        }
+    }
+
+    // Override streams_body: JSON bodies are parsed in one piece
+    fn streams_body(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+
+    const SAMPLE: &str = r#"[
+        {
+            "category": "company",
+            "datetime": 1714573800,
+            "headline": "Apple beats quarterly estimates",
+            "id": 127011234,
+            "image": "https://example.com/apple.jpg",
+            "related": "AAPL",
+            "source": "Yahoo",
+            "summary": "Apple reported revenue of...",
+            "url": "https://finnhub.io/api/news?id=abc"
+        },
+        {
+            "category": "top news",
+            "datetime": 1714560000,
+            "headline": "Treasury yields slip",
+            "id": 127011235,
+            "image": "",
+            "related": "",
+            "source": "CNBC",
+            "summary": "",
+            "url": "https://finnhub.io/api/news?id=def"
+        }
+    ]"#;
+
+    #[test]
+    fn test_parse_articles() {
+        let source = FinnhubNews::new(Client::new());
+        let articles = source.parse_body(SAMPLE).unwrap();
+
+        assert_eq!(articles.len(), 2);
+        let first = &articles[0];
+        assert_eq!(first.guid.as_deref(), Some("127011234"));
+        assert_eq!(first.pub_date.as_deref(), Some("2024-05-01T14:30:00+00:00"));
+        assert_eq!(first.category.as_deref(), Some("company"));
+        assert_eq!(first.extra_fields.get("symbols").unwrap(), "AAPL");
+        assert_eq!(first.extra_fields.get("outlet").unwrap(), "Yahoo");
+
+        let second = &articles[1];
+        assert!(second.description.is_none());
+        assert!(!second.extra_fields.contains_key("symbols"));
+        assert!(!second.extra_fields.contains_key("thumbnail"));
+    }
+
+    #[test]
+    fn test_parse_api_error() {
+        let source = FinnhubNews::new(Client::new());
+        assert!(matches!(
+            source.parse_body(r#"{"error": "Invalid API key"}"#),
+            Err(FanError::FeedParsing(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_missing_api_key() {
+        let mut source = FinnhubNews::new(Client::new());
+        source.api_key = None;
+
+        assert!(source.group_topics(TopicGroup::Crypto).is_empty());
+        assert!(matches!(
+            source.general().await,
+            Err(FanError::MissingApiKey(_))
+        ));
+
+        let source = source.with_api_key("test-key");
+        assert_eq!(source.group_topics(TopicGroup::Crypto), vec!["crypto"]);
+    }
+
+    #[tokio::test]
+    async fn test_conditional_fetch_is_authenticated() {
+        use crate::http::HttpResponse;
+        use futures::StreamExt;
+
+        struct Validating;
+
+        #[async_trait]
+        impl HttpClient for Validating {
+            async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+                assert_eq!(request.header("X-Finnhub-Token"), Some("test-key"));
+                if request.header("If-None-Match") == Some("\"v1\"") {
+                    return Ok(HttpResponse::new(304, ""));
+                }
+                Ok(HttpResponse::new(200, SAMPLE).with_header("ETag", "\"v1\""))
+            }
+        }
+
+        let source = FinnhubNews::new(Validating).with_api_key("test-key");
+        let url = source.build_topic_url("general").unwrap();
+        let cached = source
+            .fetch_body_conditional(&url, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.etag.as_deref(), Some("\"v1\""));
+        assert!(
+            source
+                .fetch_body_conditional(&url, Some(&cached))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            source.stream_feed_by_url(&url).await.unwrap().count().await,
+            2
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use crate::error::{FanError, Result};
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use reqwest::Url;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Default number of articles requested per query (the API maximum is 250)
+const DEFAULT_MAX_RECORDS: u32 = 75;
+
+/// Finance topics and the GDELT queries behind them
+const TOPIC_QUERIES: [(&str, &str); 8] = [
+    ("markets", "theme:ECON_STOCKMARKET"),
+    ("inflation", "theme:ECON_INFLATION"),
+    ("interest_rates", "theme:ECON_INTEREST_RATES"),
+    ("central_banks", "theme:ECON_CENTRALBANK"),
+    ("bankruptcy", "theme:ECON_BANKRUPTCY"),
+    (
+        "earnings",
+        "(\"earnings report\" OR \"quarterly earnings\")",
+    ),
+    ("ipo", "(\"initial public offering\" OR IPO)"),
+    ("crypto", "(bitcoin OR cryptocurrency)"),
+];
+
+/// GDELT 2.0 DOC API client
+///
+/// Searches the worldwide news coverage indexed by the GDELT Project. Topics are
+/// predefined finance queries built on GDELT's themes and keywords; `search()` runs
+/// any GDELT query, e.g. `"\"rate cut\" sourcecountry:US"`. Results are limited to
+/// English-language articles. Each article carries the publishing domain
+/// (`extra_fields["domain"]`), its language and source country, and a preview image
+/// when GDELT has one (`extra_fields["thumbnail"]`).
+///
+/// GDELT throttles clients that send more than one request every few seconds, so the
+/// source does not take part in topic groups.
+pub struct Gdelt {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    max_records: u32,
+}
+
+/// Envelope returned by the DOC API in `ArtList` mode
+#[derive(Debug, Deserialize)]
+struct ArtList {
+    #[serde(default)]
+    articles: Vec<Article>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Article {
+    url: String,
+    title: Option<String>,
+    seendate: Option<String>,
+    socialimage: Option<String>,
+    domain: Option<String>,
+    language: Option<String>,
+    sourcecountry: Option<String>,
+}
+
+impl Gdelt {
+    /// Create a new GDELT client
+    ///
+    /// Initializes the client with the GDELT 2.0 DOC API endpoint.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://api.gdeltproject.org/api/v2/doc/doc".to_string(),
+        );
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::Gdelt),
+            max_records: DEFAULT_MAX_RECORDS,
+        }
+    }
+
+    /// Request up to `max_records` articles per query (capped at 250 by the API)
+    pub fn with_max_records(mut self, max_records: u32) -> Self {
+        self.max_records = max_records.min(250);
+        self
+    }
+
+    /// Get stock market coverage
+    pub async fn markets(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("markets").await
+    }
+
+    /// Get inflation coverage
+    pub async fn inflation(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("inflation").await
+    }
+
+    /// Get interest rate coverage
+    pub async fn interest_rates(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("interest_rates").await
+    }
+
+    /// Get central bank coverage
+    pub async fn central_banks(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("central_banks").await
+    }
+
+    /// Get earnings coverage
+    pub async fn earnings(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("earnings").await
+    }
+
+    /// Run an arbitrary GDELT query
+    ///
+    /// # Arguments
+    /// * `query` - Keywords, quoted phrases, and GDELT operators such as `theme:`,
+    ///   `domain:`, or `sourcecountry:`
+    pub async fn search(&self, query: &str) -> Result<Vec<NewsArticle>> {
+        let url = self.query_url(query)?;
+        self.fetch_feed_by_url(&url).await
+    }
+
+    /// Build the DOC API URL for a query, newest articles first
+    fn query_url(&self, query: &str) -> Result<String> {
+        let base_url = self
+            .url_map
+            .get("base")
+            .ok_or_else(|| FanError::InvalidUrl("Base URL not found".to_string()))?;
+        let max_records = self.max_records.to_string();
+
+        let url = Url::parse_with_params(
+            base_url,
+            [
+                ("query", format!("{} sourcelang:english", query).as_str()),
+                ("mode", "ArtList"),
+                ("format", "json"),
+                ("sort", "DateDesc"),
+                ("maxrecords", max_records.as_str()),
+            ],
+        )
+        .map_err(|e| FanError::InvalidUrl(e.to_string()))?;
+        Ok(url.into())
+    }
+
+    /// Convert a GDELT article into a `NewsArticle`
+    fn article_to_news(article: Article) -> NewsArticle {
+        let mut news = NewsArticle::new();
+
+        news.title = article.title.map(|title| title.trim().to_string());
+        news.link = Some(article.url.clone());
+        news.guid = Some(article.url);
+        news.pub_date = article.seendate.as_deref().map(normalize_seendate);
+
+        let fields = [
+            ("domain", article.domain),
+            ("language", article.language),
+            ("source_country", article.sourcecountry),
+            ("thumbnail", article.socialimage),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value.filter(|value| !value.is_empty()) {
+                news.extra_fields.insert(key.to_string(), value.into());
+            }
+        }
+
+        news
+    }
+}
+
+/// Convert GDELT's compact `20240501T143000Z` timestamps to RFC 3339
+///
+/// Unrecognized values are kept as they are.
+fn normalize_seendate(seendate: &str) -> String {
+    NaiveDateTime::parse_from_str(seendate, "%Y%m%dT%H%M%SZ")
+        .map(|date| date.and_utc().to_rfc3339())
+        .unwrap_or_else(|_| seendate.to_string())
+}
+
+#[async_trait]
+impl NewsSource for Gdelt {
+    fn name(&self) -> &'static str {
+        "GDELT"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        TOPIC_QUERIES.iter().map(|(name, _)| *name).collect()
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for Gdelt {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url: topics map to search queries
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let query = TOPIC_QUERIES
+            .iter()
+            .find(|(name, _)| *name == topic)
+            .map(|(_, query)| *query)
+            .ok_or_else(|| FanError::InvalidUrl(format!("Invalid topic: {}", topic)))?;
+        self.query_url(query)
+    }
+
+    // Override parse_body: the DOC API returns JSON, or plain text for invalid queries
+    fn parse_body(&self, body: &str) -> Result<Vec<NewsArticle>> {
+        let body = body.trim();
+        if !body.starts_with('{') {
+            return Err(FanError::FeedParsing(format!("GDELT API error: {}", body)));
+        }
+
+        let list: ArtList = serde_json::from_str(body)?;
+        Ok(list
+            .articles
+            .into_iter()
+            .map(Self::article_to_news)
+            .collect())
+    }
+
+    // Override streams_body: JSON bodies are parsed in one piece
+    fn streams_body(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+
+    const SAMPLE: &str = r#"{"articles": [
+        {
+            "url": "https://www.example.com/markets/stocks-rally",
+            "url_mobile": "",
+            "title": " Stocks rally as yields fall ",
+            "seendate": "20240501T143000Z",
+            "socialimage": "https://www.example.com/rally.jpg",
+            "domain": "example.com",
+            "language": "English",
+            "sourcecountry": "United States"
+        },
+        {
+            "url": "https://news.example.org/fed",
+            "title": "Fed holds rates",
+            "seendate": "20240501T120000Z",
+            "socialimage": "",
+            "domain": "news.example.org",
+            "language": "English",
+            "sourcecountry": "United Kingdom"
+        }
+    ]}"#;
+
+    #[test]
+    fn test_parse_art_list() {
+        let source = Gdelt::new(Client::new());
+        let articles = source.parse_body(SAMPLE).unwrap();
+
+        assert_eq!(articles.len(), 2);
+        let first = &articles[0];
+        assert_eq!(first.title.as_deref(), Some("Stocks rally as yields fall"));
+        assert_eq!(
+            first.guid.as_deref(),
+            Some("https://www.example.com/markets/stocks-rally")
+        );
+        assert_eq!(first.pub_date.as_deref(), Some("2024-05-01T14:30:00+00:00"));
+        assert!(first.published_at().is_some());
+        assert_eq!(first.extra_fields.get("domain").unwrap(), "example.com");
+        assert_eq!(
+            first.extra_fields.get("source_country").unwrap(),
+            "United States"
+        );
+        assert!(!articles[1].extra_fields.contains_key("thumbnail"));
+
+        assert!(source.parse_body("{}").unwrap().is_empty());
+        assert!(matches!(
+            source.parse_body("Invalid query. Please check your syntax."),
+            Err(FanError::FeedParsing(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_topic_url() {
+        let source = Gdelt::new(Client::new()).with_max_records(500);
+
+        let url = Url::parse(&source.build_topic_url("inflation").unwrap()).unwrap();
+        let params: HashMap<_, _> = url.query_pairs().into_owned().collect();
+        assert_eq!(params["query"], "theme:ECON_INFLATION sourcelang:english");
+        assert_eq!(params["mode"], "ArtList");
+        assert_eq!(params["maxrecords"], "250");
+
+        assert!(source.build_topic_url("unknown").is_err());
+    }
+}
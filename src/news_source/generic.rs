@@ -1,27 +1,72 @@
-use crate::news_source::NewsSource;
-use crate::parser::NewsParser;
+use crate::error::Result;
+use crate::feed_format::FeedFormat;
+use crate::http::{HttpClient, HttpRequest};
+use crate::news_source::{
+    FeedSource, NewsSource, check_feed_body, check_status, content_type, resolve_link,
+};
+use crate::parser::{NewsParser, ParserLimits, ParserOptions};
+use crate::types::NewsArticle;
 use async_trait::async_trait;
-use reqwest::Client;
+use futures::stream::{self, BoxStream, StreamExt};
+use log::debug;
 use std::collections::HashMap;
+use std::sync::Arc;
 
-/// Generic news source for fetching arbitrary RSS feeds
+/// Generic news source for fetching arbitrary feeds
 ///
 /// This source doesn't have predefined feeds or topics. It's designed
-/// for fetching any RSS feed URL directly using `fetch_feed_by_url()`.
+/// for fetching any feed URL directly using `fetch_feed_by_url()`. RSS 2.0,
+/// RSS 1.0, Atom, and JSON Feed are told apart by their root element and
+/// `Content-Type`, so any feed URL can be used as-is.
 pub struct GenericSource {
-    client: Client,
+    client: Arc<dyn HttpClient>,
     parser: NewsParser,
     url_map: HashMap<String, String>,
 }
 
 impl GenericSource {
-    pub fn new(client: Client) -> Self {
+    pub fn new(client: impl HttpClient + 'static) -> Self {
         Self {
-            client,
+            client: Arc::new(client),
             parser: NewsParser::new("generic"),
             url_map: HashMap::new(),
         }
     }
+
+    /// Adapt the RSS parser to a nonstandard feed
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::news_source::GenericSource;
+    /// use finance_news_aggregator_rs::parser::{Field, ParserOptions};
+    ///
+    /// let source = GenericSource::new(reqwest::Client::new())
+    ///     .with_parser_options(ParserOptions::new().map_tag("url", Field::Link));
+    /// ```
+    pub fn with_parser_options(mut self, options: ParserOptions) -> Self {
+        self.parser = self.parser.with_options(options);
+        self
+    }
+
+    /// Parse under custom bounds on document structure
+    ///
+    /// See [`ParserLimits`] for the defaults.
+    pub fn with_parser_limits(mut self, limits: ParserLimits) -> Self {
+        self.parser = self.parser.with_limits(limits);
+        self
+    }
+
+    /// Parse a body in whichever format it is in
+    ///
+    /// Bodies that are not recognized are parsed as RSS 2.0.
+    fn parse_detected(&self, content_type: Option<&str>, body: &str) -> Result<Vec<NewsArticle>> {
+        let format = FeedFormat::detect(content_type, body).unwrap_or_else(|| {
+            debug!("Unrecognized feed format, parsing as RSS");
+            FeedFormat::Rss2
+        });
+        debug!("Parsing {:?} feed", format);
+        format.parse(&self.parser, body)
+    }
 }
 
 #[async_trait]
@@ -30,20 +75,72 @@ impl NewsSource for GenericSource {
         "Generic"
     }
 
+    fn available_topics(&self) -> Vec<&'static str> {
+        // Generic source doesn't have predefined topics
+        vec![]
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for GenericSource {
     fn url_map(&self) -> &HashMap<String, String> {
         &self.url_map
     }
 
-    fn client(&self) -> &Client {
-        &self.client
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
     }
 
     fn parser(&self) -> &NewsParser {
         &self.parser
     }
 
-    fn available_topics(&self) -> Vec<&'static str> {
-        // Generic source doesn't have predefined topics
-        vec![]
+    // Override parse_body: cached bodies carry no headers, so detect from the body alone
+    fn parse_body(&self, body: &str) -> Result<Vec<NewsArticle>> {
+        self.parse_detected(None, body)
+    }
+
+    // Override fetch_feed_by_url to use the Content-Type header for format detection
+    async fn fetch_feed_by_url(&self, url: &str) -> Result<Vec<NewsArticle>> {
+        debug!("Fetching {} feed from URL: {}", self.name(), url);
+
+        let response = self.http_client().send(HttpRequest::get(url)).await?;
+        let response = check_status(url, response).await?;
+        let content_type = content_type(&response);
+        let content = response.text().await?;
+        check_feed_body(url, content_type.as_deref(), &content)?;
+        debug!("Received {} bytes of content", content.len());
+
+        let mut articles = self.parse_detected(content_type.as_deref(), &content)?;
+        for article in &mut articles {
+            article.source = Some(self.name().to_string());
+            self.annotate_article(article);
+        }
+
+        debug!("Parsed {} articles from {}", articles.len(), self.name());
+        Ok(articles)
+    }
+
+    // Override next_page_url to also follow the `next_url` of JSON Feeds
+    fn next_page_url(&self, url: &str, body: &str) -> Option<String> {
+        let format = FeedFormat::detect(None, body).unwrap_or(FeedFormat::Rss2);
+        resolve_link(url, &format.next_page_link(&self.parser, body)?)
+    }
+
+    // Override stream_feed_by_url: the format is only known once the body has arrived
+    async fn stream_feed_by_url<'a>(
+        &'a self,
+        url: &str,
+    ) -> Result<BoxStream<'a, Result<NewsArticle>>> {
+        let articles = self.fetch_feed_by_url(url).await?;
+        Ok(stream::iter(articles.into_iter().map(Ok)).boxed())
     }
 }
@@ -0,0 +1,138 @@
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Globe and Mail news client
+///
+/// Provides access to The Globe and Mail business and investing RSS feeds covering
+/// Canadian companies, markets, and the economy.
+pub struct GlobeAndMail {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    topic_categories: HashMap<&'static str, &'static str>,
+}
+
+impl GlobeAndMail {
+    /// Create a new Globe and Mail client
+    ///
+    /// Initializes the client with Globe and Mail RSS feed URL patterns and topic mappings.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://www.theglobeandmail.com/arc/outboundfeeds/rss/category/{topic}/".to_string(),
+        );
+
+        let mut topic_categories = HashMap::new();
+        // Section paths for Globe and Mail feeds
+        topic_categories.insert("business", "business");
+        topic_categories.insert("economy", "business/economy");
+        topic_categories.insert("industry_news", "business/industry-news");
+        topic_categories.insert("international_business", "business/international-business");
+        topic_categories.insert("technology", "business/technology");
+        topic_categories.insert("investing", "investing");
+        topic_categories.insert("markets", "investing/markets");
+        topic_categories.insert("personal_finance", "investing/personal-finance");
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::GlobeAndMail),
+            topic_categories,
+        }
+    }
+
+    /// Get business news
+    pub async fn business(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("business").await
+    }
+
+    /// Get economy news
+    pub async fn economy(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("economy").await
+    }
+
+    /// Get investing news
+    pub async fn investing(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("investing").await
+    }
+
+    /// Get markets news
+    pub async fn markets(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("markets").await
+    }
+}
+
+#[async_trait]
+impl NewsSource for GlobeAndMail {
+    fn name(&self) -> &'static str {
+        "Globe and Mail"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        self.topic_categories.keys().copied().collect()
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Markets => vec!["markets"],
+            TopicGroup::Macro => vec!["economy"],
+            TopicGroup::Tech => vec!["technology"],
+            TopicGroup::PersonalFinance => vec!["personal_finance"],
+            _ => vec![],
+        }
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["technology"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for GlobeAndMail {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url to map topic names to section paths
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let topic_path = self.topic_categories.get(topic).ok_or_else(|| {
+            crate::error::FanError::InvalidUrl(format!("Invalid topic: {}", topic))
+        })?;
+
+        let base_url = self
+            .url_map()
+            .get("base")
+            .ok_or_else(|| crate::error::FanError::InvalidUrl("Base URL not found".to_string()))?;
+
+        Ok(base_url.replace("{topic}", topic_path))
+    }
+}
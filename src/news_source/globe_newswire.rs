@@ -0,0 +1,166 @@
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Feed paths for GlobeNewswire subjects and industries, in topic order
+const TOPICS: [(&str, &str); 10] = [
+    ("public_companies", "orgclass/1/feedTitle/GlobeNewswire"),
+    (
+        "earnings",
+        "subjectcode/13-Earnings%20Releases%20and%20Operating%20Results/feedTitle/GlobeNewswire",
+    ),
+    (
+        "mergers_acquisitions",
+        "subjectcode/27-Mergers%20and%20Acquisitions/feedTitle/GlobeNewswire",
+    ),
+    (
+        "dividends",
+        "subjectcode/12-Dividend%20Reports%20and%20Estimates/feedTitle/GlobeNewswire",
+    ),
+    (
+        "ipo",
+        "subjectcode/22-Initial%20Public%20Offerings/feedTitle/GlobeNewswire",
+    ),
+    (
+        "financing",
+        "subjectcode/15-Financing%20Agreements/feedTitle/GlobeNewswire",
+    ),
+    ("banking", "industry/8355-Banks/feedTitle/GlobeNewswire"),
+    (
+        "technology",
+        "industry/9000-Technology/feedTitle/GlobeNewswire",
+    ),
+    (
+        "biotechnology",
+        "industry/4573-Biotechnology/feedTitle/GlobeNewswire",
+    ),
+    ("energy", "industry/0001-Energy/feedTitle/GlobeNewswire"),
+];
+
+/// GlobeNewswire press release client
+///
+/// Provides access to GlobeNewswire RSS feeds of company press releases, filtered
+/// either by subject (earnings, M&A, dividends, ...) or by industry.
+pub struct GlobeNewswire {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    topic_categories: HashMap<&'static str, &'static str>,
+}
+
+impl GlobeNewswire {
+    /// Create a new GlobeNewswire client
+    ///
+    /// Initializes the client with GlobeNewswire RSS feed URL patterns and subject/industry mappings.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://www.globenewswire.com/RssFeed/{topic}".to_string(),
+        );
+
+        let topic_categories = TOPICS.iter().copied().collect();
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::GlobeNewswire),
+            topic_categories,
+        }
+    }
+
+    /// Get releases from public companies
+    pub async fn public_companies(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("public_companies").await
+    }
+
+    /// Get earnings releases
+    pub async fn earnings(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("earnings").await
+    }
+
+    /// Get mergers and acquisitions releases
+    pub async fn mergers_acquisitions(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("mergers_acquisitions").await
+    }
+
+    /// Get IPO releases
+    pub async fn ipo(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("ipo").await
+    }
+}
+
+#[async_trait]
+impl NewsSource for GlobeNewswire {
+    fn name(&self) -> &'static str {
+        "GlobeNewswire"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        TOPICS.iter().map(|(name, _)| *name).collect()
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Earnings => vec!["earnings"],
+            TopicGroup::Tech => vec!["technology"],
+            TopicGroup::Energy => vec!["energy"],
+            _ => vec![],
+        }
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["technology"],
+            Sector::Healthcare => vec!["biotechnology"],
+            Sector::Financials => vec!["banking"],
+            Sector::Energy => vec!["energy"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for GlobeNewswire {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url to map topic names to subject/industry feed paths
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let topic_path = self.topic_categories.get(topic).ok_or_else(|| {
+            crate::error::FanError::InvalidUrl(format!("Invalid topic: {}", topic))
+        })?;
+
+        let base_url = self
+            .url_map()
+            .get("base")
+            .ok_or_else(|| crate::error::FanError::InvalidUrl("Base URL not found".to_string()))?;
+
+        Ok(base_url.replace("{topic}", topic_path))
+    }
+}
@@ -0,0 +1,186 @@
+/// Declare a news source backed by a single RSS feed URL pattern
+///
+/// Generates the source struct, a `new(client)` constructor, one helper method per
+/// topic, and its `NewsSource` and `FeedSource` implementations. Topic URLs are built
+/// by substituting the topic into `{topic}` in `url`. `parser` defaults to
+/// `NewsParser::new(name)`; `groups` maps `TopicGroup` variants to topics, and
+/// `sectors` maps `Sector` variants to topics; both may be left out.
+///
+/// Sources that need custom URL building or response parsing implement the traits by
+/// hand instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::define_source;
+/// use finance_news_aggregator_rs::news_source::NewsSource;
+/// use finance_news_aggregator_rs::{Sector, TopicGroup};
+///
+/// define_source! {
+///     /// Example Times news client
+///     pub struct ExampleTimes {
+///         name: "Example Times",
+///         url: "https://example.com/rss/{topic}.xml",
+///         topics: {
+///             /// Get market news
+///             markets => "markets",
+///             /// Get technology news
+///             technology => "tech",
+///         },
+///         groups: {
+///             Markets => ["markets"],
+///             Tech => ["tech"],
+///         },
+///         sectors: {
+///             Technology => ["tech"],
+///         },
+///     }
+/// }
+///
+/// let source = ExampleTimes::new(reqwest::Client::new());
+/// assert_eq!(source.name(), "Example Times");
+/// assert_eq!(source.available_topics(), ["markets", "tech"]);
+/// assert_eq!(source.group_topics(TopicGroup::Tech), ["tech"]);
+/// assert_eq!(source.sector_topics(Sector::Technology), ["tech"]);
+/// ```
+#[macro_export]
+macro_rules! define_source {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $source:ident {
+            name: $name:literal,
+            url: $url:literal,
+            $(parser: $parser:expr,)?
+            topics: {
+                $($(#[$topic_meta:meta])* $method:ident => $topic:literal),* $(,)?
+            }
+            $(, groups: {
+                $($group:ident => [$($group_topic:literal),* $(,)?]),* $(,)?
+            })?
+            $(, sectors: {
+                $($sector:ident => [$($sector_topic:literal),* $(,)?]),* $(,)?
+            })?
+            $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $source {
+            url_map: ::std::collections::HashMap<String, String>,
+            client: ::std::sync::Arc<dyn $crate::http::HttpClient>,
+            parser: $crate::parser::NewsParser,
+        }
+
+        impl $source {
+            #[doc = concat!("Create a new ", $name, " client")]
+            pub fn new(client: impl $crate::http::HttpClient + 'static) -> Self {
+                let mut url_map = ::std::collections::HashMap::new();
+                url_map.insert("base".to_string(), $url.to_string());
+
+                Self {
+                    url_map,
+                    client: ::std::sync::Arc::new(client),
+                    parser: $crate::define_source!(@parser $name $(, $parser)?),
+                }
+            }
+
+            $(
+                $(#[$topic_meta])*
+                pub async fn $method(&self) -> $crate::Result<Vec<$crate::NewsArticle>> {
+                    <Self as $crate::news_source::NewsSource>::fetch_topic(self, $topic).await
+                }
+            )*
+        }
+
+        #[$crate::news_source::__private::async_trait]
+        impl $crate::news_source::NewsSource for $source {
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn available_topics(&self) -> Vec<&'static str> {
+                vec![$($topic),*]
+            }
+
+            fn group_topics(&self, group: $crate::TopicGroup) -> Vec<&'static str> {
+                match group {
+                    $($($crate::TopicGroup::$group => vec![$($group_topic),*],)*)?
+                    #[allow(unreachable_patterns)]
+                    _ => vec![],
+                }
+            }
+
+            fn sector_topics(&self, sector: $crate::Sector) -> Vec<&'static str> {
+                match sector {
+                    $($($crate::Sector::$sector => vec![$($sector_topic),*],)*)?
+                    #[allow(unreachable_patterns)]
+                    _ => vec![],
+                }
+            }
+
+            async fn fetch_topic(
+                &self,
+                topic: &str,
+            ) -> $crate::Result<Vec<$crate::NewsArticle>> {
+                $crate::news_source::FeedSource::fetch_feed_topic(self, topic).await
+            }
+
+            fn feed(&self) -> Option<&dyn $crate::news_source::FeedSource> {
+                Some(self)
+            }
+        }
+
+        #[$crate::news_source::__private::async_trait]
+        impl $crate::news_source::FeedSource for $source {
+            fn url_map(&self) -> &::std::collections::HashMap<String, String> {
+                &self.url_map
+            }
+
+            fn http_client(&self) -> &dyn $crate::http::HttpClient {
+                self.client.as_ref()
+            }
+
+            fn parser(&self) -> &$crate::parser::NewsParser {
+                &self.parser
+            }
+        }
+    };
+    (@parser $name:literal) => {
+        $crate::parser::NewsParser::new($name)
+    };
+    (@parser $name:literal, $parser:expr) => {
+        $parser
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::news_source::{FeedSource, NewsSource};
+    use crate::{Sector, TopicGroup};
+    use reqwest::Client;
+
+    crate::define_source! {
+        struct Minimal {
+            name: "Minimal",
+            url: "https://example.com/{topic}/rss",
+            topics: {
+                latest => "latest",
+            }
+        }
+    }
+
+    #[test]
+    fn test_defaults() {
+        let source = Minimal::new(Client::new());
+        let _ = Minimal::latest;
+        assert_eq!(
+            source.build_topic_url("latest").unwrap(),
+            "https://example.com/latest/rss"
+        );
+        assert!(source.group_topics(TopicGroup::Markets).is_empty());
+        assert!(source.sector_topics(Sector::Energy).is_empty());
+
+        let rss = "<rss><channel><item><title>Story</title></item></channel></rss>";
+        let articles = source.parse_feed(rss).unwrap();
+        assert_eq!(articles[0].source.as_deref(), Some("Minimal"));
+    }
+}
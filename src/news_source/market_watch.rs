@@ -1,10 +1,13 @@
 use crate::error::Result;
-use crate::news_source::NewsSource;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
 use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::topic_group::TopicGroup;
 use crate::types::NewsArticle;
 use async_trait::async_trait;
-use reqwest::Client;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// MarketWatch news client
 ///
@@ -12,7 +15,7 @@ use std::collections::HashMap;
 /// Note: Many MarketWatch RSS feeds have been deprecated or have XML parsing issues.
 pub struct MarketWatch {
     url_map: HashMap<String, String>,
-    client: Client,
+    client: Arc<dyn HttpClient>,
     parser: NewsParser,
     topic_categories: HashMap<&'static str, &'static str>,
 }
@@ -21,7 +24,7 @@ impl MarketWatch {
     /// Create a new MarketWatch client
     ///
     /// Initializes the client with MarketWatch RSS feed URL patterns and topic mappings.
-    pub fn new(client: Client) -> Self {
+    pub fn new(client: impl HttpClient + 'static) -> Self {
         let mut url_map = HashMap::new();
         url_map.insert(
             "base".to_string(),
@@ -37,8 +40,8 @@ impl MarketWatch {
 
         Self {
             url_map,
-            client,
-            parser: NewsParser::new("market_watch"),
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::MarketWatch),
             topic_categories,
         }
     }
@@ -70,12 +73,34 @@ impl NewsSource for MarketWatch {
         "MarketWatch"
     }
 
+    fn available_topics(&self) -> Vec<&'static str> {
+        self.topic_categories.keys().copied().collect()
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Markets => vec!["market_pulse"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for MarketWatch {
     fn url_map(&self) -> &HashMap<String, String> {
         &self.url_map
     }
 
-    fn client(&self) -> &Client {
-        &self.client
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
     }
 
     fn parser(&self) -> &NewsParser {
@@ -95,10 +120,4 @@ impl NewsSource for MarketWatch {
 
         Ok(base_url.replace("{topic}", topic_id))
     }
-
-    // Uses default fetch_topic implementation
-
-    fn available_topics(&self) -> Vec<&'static str> {
-        self.topic_categories.keys().copied().collect()
-    }
 }
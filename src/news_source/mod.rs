@@ -1,37 +1,407 @@
-use crate::error::Result;
+use crate::disk_cache::CachedBody;
+use crate::error::{FanError, Result};
+use crate::feed_format::FeedFormat;
+use crate::http::{HttpClient, HttpRequest, HttpResponse};
 use crate::parser::NewsParser;
+use crate::sector::Sector;
+use crate::topic_group::TopicGroup;
 use crate::types::NewsArticle;
 use async_trait::async_trait;
+use futures::future::{join_all, select_ok};
+use futures::stream::{self, BoxStream, StreamExt};
 use log::debug;
-use reqwest::Client;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use reqwest::header::{CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{StatusCode, Url};
 use std::collections::HashMap;
+use std::time::Duration;
+#[cfg(feature = "parallel")]
+use tokio::runtime::RuntimeFlavor;
 
+/// Characters of an error response body kept in `FanError::HttpStatus`
+const ERROR_SNIPPET_LEN: usize = 200;
+
+/// Parse fetched topic bodies, on rayon's global pool or a dedicated pool of `threads`
+#[cfg(feature = "parallel")]
+fn parse_bodies(
+    feed: &dyn FeedSource,
+    bodies: &[Result<String>],
+    threads: Option<usize>,
+) -> Vec<Result<Vec<NewsArticle>>> {
+    let parse_all = || -> Vec<Result<Vec<NewsArticle>>> {
+        bodies
+            .par_iter()
+            .map(|body| parse_body(feed, body))
+            .collect()
+    };
+    let parse = || match threads {
+        Some(threads) => match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+            Ok(pool) => pool.install(parse_all),
+            Err(e) => {
+                log::warn!("Failed to build parser pool, using global pool: {}", e);
+                parse_all()
+            }
+        },
+        None => parse_all(),
+    };
+
+    // Parsing is CPU-bound; move it off the async worker when the runtime allows it
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(parse)
+        }
+        _ => parse(),
+    }
+}
+
+/// Parse fetched topic bodies one after another; `threads` needs the `parallel` feature
+#[cfg(not(feature = "parallel"))]
+fn parse_bodies(
+    feed: &dyn FeedSource,
+    bodies: &[Result<String>],
+    _threads: Option<usize>,
+) -> Vec<Result<Vec<NewsArticle>>> {
+    bodies.iter().map(|body| parse_body(feed, body)).collect()
+}
+
+fn parse_body(feed: &dyn FeedSource, body: &Result<String>) -> Result<Vec<NewsArticle>> {
+    let body = body
+        .as_ref()
+        .map_err(|e| FanError::Unknown(e.to_string()))?;
+    feed.parse_feed(body)
+}
+
+#[cfg(feature = "alpha-vantage")]
+pub mod alpha_vantage;
+#[cfg(feature = "business-wire")]
+pub mod business_wire;
+#[cfg(feature = "cnbc")]
 pub mod cnbc;
+#[cfg(feature = "coin-desk")]
+pub mod coin_desk;
+#[cfg(feature = "coin-telegraph")]
+pub mod coin_telegraph;
+#[cfg(feature = "financial-post")]
+pub mod financial_post;
+#[cfg(feature = "finnhub-news")]
+pub mod finnhub_news;
+#[cfg(feature = "gdelt")]
+pub mod gdelt;
 pub mod generic;
+#[cfg(feature = "globe-and-mail")]
+pub mod globe_and_mail;
+#[cfg(feature = "globe-newswire")]
+pub mod globe_newswire;
+mod macros;
+#[cfg(feature = "market-watch")]
 pub mod market_watch;
+#[cfg(feature = "nasdaq")]
 pub mod nasdaq;
+#[cfg(feature = "news-api-org")]
+pub mod news_api_org;
+#[cfg(feature = "nikkei-asia")]
+pub mod nikkei_asia;
+#[cfg(feature = "nytimes")]
+pub mod nytimes;
+#[cfg(feature = "polygon")]
+pub mod polygon;
+#[cfg(feature = "pr-newswire")]
+pub mod pr_newswire;
+#[cfg(feature = "reddit")]
+pub mod reddit;
+#[cfg(feature = "scmp-business")]
+pub mod scmp_business;
+#[cfg(feature = "seeking-alpha")]
 pub mod seeking_alpha;
+#[cfg(feature = "stock-twits")]
+pub mod stock_twits;
+#[cfg(feature = "wsj")]
 pub mod wsj;
+#[cfg(feature = "yahoo-finance")]
 pub mod yahoo_finance;
 
+#[cfg(feature = "alpha-vantage")]
+pub use alpha_vantage::AlphaVantage;
+#[cfg(feature = "business-wire")]
+pub use business_wire::BusinessWire;
+#[cfg(feature = "cnbc")]
 pub use cnbc::CNBC;
+#[cfg(feature = "coin-desk")]
+pub use coin_desk::CoinDesk;
+#[cfg(feature = "coin-telegraph")]
+pub use coin_telegraph::CoinTelegraph;
+#[cfg(feature = "financial-post")]
+pub use financial_post::FinancialPost;
+#[cfg(feature = "finnhub-news")]
+pub use finnhub_news::FinnhubNews;
+#[cfg(feature = "gdelt")]
+pub use gdelt::Gdelt;
 pub use generic::GenericSource;
+#[cfg(feature = "globe-and-mail")]
+pub use globe_and_mail::GlobeAndMail;
+#[cfg(feature = "globe-newswire")]
+pub use globe_newswire::GlobeNewswire;
+#[cfg(feature = "market-watch")]
 pub use market_watch::MarketWatch;
+#[cfg(feature = "nasdaq")]
 pub use nasdaq::NASDAQ;
+#[cfg(feature = "news-api-org")]
+pub use news_api_org::NewsApiOrg;
+#[cfg(feature = "nikkei-asia")]
+pub use nikkei_asia::NikkeiAsia;
+#[cfg(feature = "nytimes")]
+pub use nytimes::NYTimes;
+#[cfg(feature = "polygon")]
+pub use polygon::Polygon;
+#[cfg(feature = "pr-newswire")]
+pub use pr_newswire::PRNewswire;
+#[cfg(feature = "reddit")]
+pub use reddit::Reddit;
+#[cfg(feature = "scmp-business")]
+pub use scmp_business::SCMPBusiness;
+#[cfg(feature = "seeking-alpha")]
 pub use seeking_alpha::SeekingAlpha;
+#[cfg(feature = "stock-twits")]
+pub use stock_twits::StockTwits;
+#[cfg(feature = "wsj")]
 pub use wsj::WallStreetJournal;
+#[cfg(feature = "yahoo-finance")]
 pub use yahoo_finance::YahooFinance;
 
+/// Paths used by `define_source!` expansions in other crates
+#[doc(hidden)]
+pub mod __private {
+    pub use async_trait::async_trait;
+}
+
 /// Common trait for all news sources
 ///
-/// This trait defines the interface for fetching news from various RSS feed sources.
-/// It provides both generic URL-based fetching and topic-based fetching with default
-/// implementations that handle common patterns.
+/// The object-safe core every source implements: its name, its topics, and how to
+/// fetch one, so sources of different kinds can share a `Vec<Box<dyn NewsSource>>`.
+/// Methods built on the core, such as `fetch_all_topics()`, come from
+/// [`NewsSourceExt`]. Sources backed by RSS feeds or JSON APIs also implement
+/// [`FeedSource`] and return it from `feed()`.
 #[async_trait]
-pub trait NewsSource {
+pub trait NewsSource: Send + Sync {
     /// Get the name of the news source
     fn name(&self) -> &'static str;
 
+    /// Get available topics/feeds for this source
+    ///
+    /// Returns a list of topic identifiers that can be used with `fetch_topic()`
+    fn available_topics(&self) -> Vec<&'static str>;
+
+    /// Get the native topics that belong to a cross-source topic group
+    ///
+    /// Used by `NewsClient::fetch_group()` to fan out to every source covering the
+    /// group. The default implementation returns no topics, meaning the source does
+    /// not participate in any group.
+    fn group_topics(&self, _group: TopicGroup) -> Vec<&'static str> {
+        vec![]
+    }
+
+    /// Get the native topics that cover a market sector
+    ///
+    /// Used by `NewsClient::sector_news()` the way `group_topics()` is used by
+    /// `fetch_group()`. The default implementation returns no topics.
+    fn sector_topics(&self, _sector: Sector) -> Vec<&'static str> {
+        vec![]
+    }
+
+    /// Fetch news articles for a specific topic
+    ///
+    /// Feed-backed sources implement this with `FeedSource::fetch_feed_topic()`.
+    ///
+    /// # Arguments
+    /// * `topic` - The topic identifier (e.g., "headlines", "technology", "markets")
+    ///
+    /// # Returns
+    /// A vector of parsed NewsArticle objects for the requested topic
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>>;
+
+    /// The feed plumbing behind this source, if it fetches feeds over HTTP
+    ///
+    /// `NewsClient` uses it to revalidate cached bodies and read feed TTLs. Sources
+    /// returning `None`, the default, are only ever fetched through `fetch_topic()`.
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        None
+    }
+}
+
+/// Methods built on the `NewsSource` core, available on every source
+///
+/// Sources with a `feed()` are fetched through their feed plumbing; the others fall
+/// back to `fetch_topic()`.
+#[async_trait]
+pub trait NewsSourceExt: NewsSource {
+    /// Fetch news articles for a topic along with the feed's advertised TTL
+    ///
+    /// Same as `fetch_topic()`, but also reads the channel `<ttl>` from the response
+    /// so callers that cache results know how long the publisher expects the feed to
+    /// stay fresh. The TTL is `None` for feeds without one, including JSON APIs and
+    /// sources without a `feed()`.
+    ///
+    /// # Arguments
+    /// * `topic` - The topic identifier
+    async fn fetch_topic_with_ttl(
+        &self,
+        topic: &str,
+    ) -> Result<(Vec<NewsArticle>, Option<Duration>)> {
+        let Some(feed) = self.feed() else {
+            return Ok((self.fetch_topic(topic).await?, None));
+        };
+        let url = feed.build_topic_url(topic)?;
+        debug!("Fetching {} topic '{}': {}", self.name(), topic, url);
+
+        let content = feed.fetch_body(&url).await?;
+        let ttl = feed.parser().parse_channel_ttl(&content);
+        let articles = feed.parse_feed(&content)?;
+
+        debug!(
+            "Parsed {} articles from {} (ttl {:?})",
+            articles.len(),
+            self.name(),
+            ttl
+        );
+        Ok((articles, ttl))
+    }
+
+    /// Stream news articles for a specific topic
+    ///
+    /// Streaming counterpart of `fetch_topic()`; see `FeedSource::stream_feed_by_url()`.
+    /// Sources without a `feed()` yield their articles once the whole topic is fetched.
+    ///
+    /// # Arguments
+    /// * `topic` - The topic identifier
+    async fn stream_topic<'a>(&'a self, topic: &str) -> Result<BoxStream<'a, Result<NewsArticle>>> {
+        let Some(feed) = self.feed() else {
+            let articles = self.fetch_topic(topic).await?;
+            return Ok(stream::iter(articles.into_iter().map(Ok)).boxed());
+        };
+        let url = feed.build_topic_url(topic)?;
+        debug!("Streaming {} topic '{}': {}", self.name(), topic, url);
+        feed.stream_feed_by_url(&url).await
+    }
+
+    /// Fetch every available topic, parsing the bodies in parallel
+    ///
+    /// Bodies are downloaded concurrently, then parsed on rayon's global thread pool
+    /// instead of one after another on the async task (with the `parallel` feature,
+    /// on by default). Each topic gets its own
+    /// result so one broken feed does not fail the whole refresh.
+    ///
+    /// # Returns
+    /// Articles (or the error) for each topic in `available_topics()`
+    async fn fetch_all_topics(&self) -> HashMap<&'static str, Result<Vec<NewsArticle>>> {
+        self.fetch_all_topics_with_threads(None).await
+    }
+
+    /// Fetch every available topic, parsing on at most `threads` worker threads
+    ///
+    /// Same as `fetch_all_topics()`; `Some(n)` parses on a dedicated pool of `n`
+    /// threads instead of rayon's global pool. Sources without a `feed()` fetch their
+    /// topics concurrently with `fetch_topic()`.
+    ///
+    /// # Arguments
+    /// * `threads` - Maximum number of parser threads, or `None` for the global pool
+    async fn fetch_all_topics_with_threads(
+        &self,
+        threads: Option<usize>,
+    ) -> HashMap<&'static str, Result<Vec<NewsArticle>>> {
+        let topics = self.available_topics();
+        let Some(feed) = self.feed() else {
+            let results = join_all(topics.iter().map(|topic| self.fetch_topic(topic))).await;
+            return topics.into_iter().zip(results).collect();
+        };
+        let bodies = join_all(topics.iter().map(|topic| async move {
+            let url = feed.build_topic_url(topic)?;
+            feed.fetch_body(&url).await
+        }))
+        .await;
+        debug!(
+            "Fetched {} {} topic bodies, parsing",
+            bodies.len(),
+            self.name()
+        );
+
+        let parsed = parse_bodies(feed, &bodies, threads);
+
+        let mut results = HashMap::new();
+        for ((topic, body), articles) in topics.into_iter().zip(bodies).zip(parsed) {
+            // Keep the original transport error rather than its string form
+            results.insert(topic, body.and(articles));
+        }
+        results
+    }
+
+    /// Fetch news articles for a topic, giving up after `timeout`
+    ///
+    /// The deadline covers the whole fetch, including any retries and parsing, and is
+    /// independent of the HTTP client's own request timeout. Aggregating callers use
+    /// this to keep one slow source from holding back a merged result.
+    ///
+    /// # Arguments
+    /// * `topic` - The topic identifier
+    /// * `timeout` - Maximum time to spend on the fetch
+    async fn fetch_topic_with_timeout(
+        &self,
+        topic: &str,
+        timeout: Duration,
+    ) -> Result<Vec<NewsArticle>> {
+        tokio::time::timeout(timeout, self.fetch_topic(topic))
+            .await
+            .map_err(|_| {
+                FanError::Timeout(format!(
+                    "{} topic '{}' did not complete within {:?}",
+                    self.name(),
+                    topic,
+                    timeout
+                ))
+            })?
+    }
+
+    /// Fetch a topic from its primary URL and mirrors at the same time
+    ///
+    /// Issues the request for `build_topic_url(topic)` and every URL in `mirrors`
+    /// concurrently and returns the first successful result, dropping the rest.
+    /// Useful for latency-sensitive topics where a slow CDN edge would otherwise
+    /// dominate the response time. Sources without a `feed()` ignore `mirrors`.
+    ///
+    /// # Arguments
+    /// * `topic` - The topic identifier
+    /// * `mirrors` - Alternate URLs serving the same feed
+    async fn fetch_topic_hedged(
+        &self,
+        topic: &str,
+        mirrors: &[String],
+    ) -> Result<Vec<NewsArticle>> {
+        let Some(feed) = self.feed() else {
+            return self.fetch_topic(topic).await;
+        };
+        let mut urls = vec![feed.build_topic_url(topic)?];
+        urls.extend(mirrors.iter().cloned());
+        debug!(
+            "Fetching {} topic '{}' from {} URLs",
+            self.name(),
+            topic,
+            urls.len()
+        );
+        feed.fetch_hedged(&urls).await
+    }
+}
+
+impl<T: NewsSource + ?Sized> NewsSourceExt for T {}
+
+/// Feed plumbing of sources that fetch RSS feeds or JSON APIs over HTTP
+///
+/// Provides generic URL-based fetching on top of the source's URL map, HTTP client,
+/// and parser, with default implementations that handle common patterns. These are
+/// implementation details of the source: implementors return `Some(self)` from
+/// `NewsSource::feed()` and usually implement `NewsSource::fetch_topic()` with
+/// `fetch_feed_topic()`.
+#[async_trait]
+pub trait FeedSource: NewsSource {
     /// Get the URL map containing named URLs for this source
     ///
     /// Returns a HashMap where keys are URL identifiers (e.g., "base", "buzz", "original")
@@ -39,7 +409,7 @@ pub trait NewsSource {
     fn url_map(&self) -> &HashMap<String, String>;
 
     /// Get the HTTP client for making requests
-    fn client(&self) -> &Client;
+    fn http_client(&self) -> &dyn HttpClient;
 
     /// Get the parser for this news source
     fn parser(&self) -> &NewsParser;
@@ -65,54 +435,804 @@ pub trait NewsSource {
         Ok(base_url.replace("{topic}", topic))
     }
 
-    /// Generic method to fetch a feed from any RSS URL
+    /// Add the source's credentials to a request before it is sent
     ///
-    /// This method provides a default implementation that can be used by all news sources.
-    /// It fetches the RSS feed from the given URL, parses it, and sets the source attribution.
+    /// Applied to every request of `fetch_body()`, `fetch_body_conditional()` and
+    /// `stream_feed_by_url()`. The default implementation sends requests unchanged;
+    /// sources behind an API key fail with `FanError::MissingApiKey` without one.
+    ///
+    /// # Arguments
+    /// * `request` - The feed request about to be sent
+    fn authenticate(&self, request: HttpRequest) -> Result<HttpRequest> {
+        Ok(request)
+    }
+
+    /// Whether response bodies can be parsed as they arrive
+    ///
+    /// `stream_feed_by_url()` parses bodies in one piece with `fetch_feed_by_url()`
+    /// when this is `false`, as JSON APIs need. The default is `true`.
+    fn streams_body(&self) -> bool {
+        true
+    }
+
+    /// Fetch the raw response body for a feed URL
+    ///
+    /// Non-success statuses fail with `FanError::HttpStatus` and HTML pages with
+    /// `FanError::NotAFeed` instead of handing them to the parser.
     ///
     /// # Arguments
-    /// * `url` - The complete RSS feed URL to fetch
+    /// * `url` - The complete feed URL to fetch
     ///
     /// # Returns
-    /// A vector of parsed NewsArticle objects
-    async fn fetch_feed_by_url(&self, url: &str) -> Result<Vec<NewsArticle>> {
+    /// The response body as text
+    async fn fetch_body(&self, url: &str) -> Result<String> {
         debug!("Fetching {} feed from URL: {}", self.name(), url);
 
-        let response = self.client().get(url).send().await?;
+        let request = self.authenticate(HttpRequest::get(url))?;
+        let response = self.http_client().send(request).await?;
+        let response = check_status(url, response).await?;
+        let content_type = content_type(&response);
         let content = response.text().await?;
+        check_feed_body(url, content_type.as_deref(), &content)?;
 
         debug!("Received {} bytes of content", content.len());
+        Ok(content)
+    }
+
+    /// Parse a raw response body into articles
+    ///
+    /// The default implementation parses RSS/XML with the source's `NewsParser`.
+    /// Sources backed by JSON APIs override this to decode their own payloads.
+    ///
+    /// # Arguments
+    /// * `body` - Raw response body returned by `fetch_body()`
+    fn parse_body(&self, body: &str) -> Result<Vec<NewsArticle>> {
+        self.parser().parse_response(body)
+    }
+
+    /// Generic method to fetch a feed from any URL
+    ///
+    /// This method provides a default implementation that can be used by all news sources.
+    /// It fetches the feed from the given URL, parses it with `parse_body()`, and sets the
+    /// source attribution.
+    ///
+    /// # Arguments
+    /// * `url` - The complete feed URL to fetch
+    ///
+    /// # Returns
+    /// A vector of parsed NewsArticle objects
+    async fn fetch_feed_by_url(&self, url: &str) -> Result<Vec<NewsArticle>> {
+        let content = self.fetch_body(url).await?;
+        let articles = self.parse_feed(&content)?;
+
+        debug!("Parsed {} articles from {}", articles.len(), self.name());
+        Ok(articles)
+    }
 
-        let mut articles = self.parser().parse_response(&content)?;
+    /// Parse a raw response body and attribute the articles to this source
+    ///
+    /// Runs `parse_body()`, then sets the source name and calls `annotate_article()`
+    /// on every article.
+    ///
+    /// # Arguments
+    /// * `body` - Raw response body returned by `fetch_body()`
+    fn parse_feed(&self, body: &str) -> Result<Vec<NewsArticle>> {
+        let mut articles = self.parse_body(body)?;
 
         // Set source for all articles
         for article in &mut articles {
             article.source = Some(self.name().to_string());
+            self.annotate_article(article);
         }
+        Ok(articles)
+    }
 
-        debug!("Parsed {} articles from {}", articles.len(), self.name());
+    /// Fetch a feed body, revalidating a previously cached copy
+    ///
+    /// Sends the `ETag` and `Last-Modified` validators of `cached` as
+    /// `If-None-Match`/`If-Modified-Since`. As with `fetch_body()`, non-success
+    /// statuses are errors so that error pages never replace a cached body.
+    ///
+    /// # Arguments
+    /// * `url` - The complete feed URL to fetch
+    /// * `cached` - The last successful response for `url`, if any
+    ///
+    /// # Returns
+    /// The new body with its validators, or `None` when the server answered
+    /// `304 Not Modified`
+    async fn fetch_body_conditional(
+        &self,
+        url: &str,
+        cached: Option<&CachedBody>,
+    ) -> Result<Option<CachedBody>> {
+        debug!("Fetching {} feed from URL: {}", self.name(), url);
+
+        let mut request = self.authenticate(HttpRequest::get(url))?;
+        if let Some(cached) = cached {
+            if let Some(etag) = &cached.etag {
+                request = request.with_header(IF_NONE_MATCH.as_str(), etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.with_header(IF_MODIFIED_SINCE.as_str(), last_modified);
+            }
+        }
+
+        let response = self.http_client().send(request).await?;
+        if response.status() == StatusCode::NOT_MODIFIED.as_u16() {
+            debug!("{} not modified since last fetch", url);
+            return Ok(None);
+        }
+
+        let response = check_status(url, response).await?;
+        let content_type = content_type(&response);
+        let etag = response.header(ETAG.as_str()).map(str::to_string);
+        let last_modified = response.header(LAST_MODIFIED.as_str()).map(str::to_string);
+        let content = response.text().await?;
+        check_feed_body(url, content_type.as_deref(), &content)?;
+
+        debug!("Received {} bytes of content", content.len());
+        Ok(Some(CachedBody::new(url, content, etag, last_modified)))
+    }
+
+    /// Add source-specific metadata to a parsed article
+    ///
+    /// Called for every article returned by `fetch_feed_by_url()` and
+    /// `stream_feed_by_url()`, after the source attribution is set. The default
+    /// implementation does nothing.
+    ///
+    /// # Arguments
+    /// * `article` - The parsed article to update
+    fn annotate_article(&self, _article: &mut NewsArticle) {}
+
+    /// Fetch a feed and yield articles as soon as each one has been parsed
+    ///
+    /// The response body is fed to the parser chunk by chunk as it arrives instead of
+    /// being buffered in full, which lowers peak memory and time-to-first-article on
+    /// large feeds. Sources whose `streams_body()` is `false` fall back to
+    /// `fetch_feed_by_url()`.
+    ///
+    /// # Arguments
+    /// * `url` - The complete feed URL to fetch
+    ///
+    /// # Returns
+    /// A stream of parsed articles; a transport or XML error ends the stream
+    async fn stream_feed_by_url<'a>(
+        &'a self,
+        url: &str,
+    ) -> Result<BoxStream<'a, Result<NewsArticle>>> {
+        if !self.streams_body() {
+            let articles = self.fetch_feed_by_url(url).await?;
+            return Ok(stream::iter(articles.into_iter().map(Ok)).boxed());
+        }
+        debug!("Streaming {} feed from URL: {}", self.name(), url);
+
+        let request = self.authenticate(HttpRequest::get(url))?;
+        let response = self.http_client().send(request).await?;
+        let response = check_status(url, response).await?;
+
+        // A page served as HTML is only parsed if its body turns out to be a feed
+        if let Some(content_type) = content_type(&response).filter(|ct| is_html(ct)) {
+            let content = response.text().await?;
+            check_feed_body(url, Some(&content_type), &content)?;
+            let articles = self.parse_feed(&content)?;
+            return Ok(stream::iter(articles.into_iter().map(Ok)).boxed());
+        }
+
+        let articles = self
+            .parser()
+            .parse_stream(response.bytes_stream())
+            .map(move |article| {
+                article.map(|mut article| {
+                    article.source = Some(self.name().to_string());
+                    self.annotate_article(&mut article);
+                    article
+                })
+            });
+        Ok(articles.boxed())
+    }
+
+    /// URL of the page that follows `body` in a paginated feed
+    ///
+    /// The default implementation follows a feed-level `rel="next"` link (see
+    /// `NewsParser::parse_next_link()`), resolved against `url`. Sources backed by
+    /// paginated JSON APIs override this to read their own cursor.
+    ///
+    /// # Arguments
+    /// * `url` - The URL `body` was fetched from
+    /// * `body` - Raw response body returned by `fetch_body()`
+    ///
+    /// # Returns
+    /// The next page's URL, or `None` on the last page
+    fn next_page_url(&self, url: &str, body: &str) -> Option<String> {
+        resolve_link(url, &self.parser().parse_next_link(body)?)
+    }
+
+    /// Fetch a paginated feed, following next-page links
+    ///
+    /// Pages are fetched one after another until a page has no next link or
+    /// `max_pages` pages have been read, and their articles are concatenated in order.
+    ///
+    /// # Arguments
+    /// * `url` - URL of the first page
+    /// * `max_pages` - Most pages to fetch, including the first
+    async fn fetch_feed_paginated(&self, url: &str, max_pages: usize) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_paginated_with_limit(url, max_pages, usize::MAX)
+            .await
+    }
+
+    /// Fetch a paginated feed, stopping at a page or item limit
+    ///
+    /// Like `fetch_feed_paginated()`, but also stops once `max_items` articles have
+    /// been collected; the result is cut to that length. A page that links back to one
+    /// already fetched ends pagination.
+    ///
+    /// # Arguments
+    /// * `url` - URL of the first page
+    /// * `max_pages` - Most pages to fetch, including the first
+    /// * `max_items` - Most articles to return
+    async fn fetch_feed_paginated_with_limit(
+        &self,
+        url: &str,
+        max_pages: usize,
+        max_items: usize,
+    ) -> Result<Vec<NewsArticle>> {
+        let mut articles = Vec::new();
+        let mut fetched = Vec::new();
+        let mut next = Some(url.to_string());
+
+        while let Some(page_url) = next.take() {
+            if fetched.len() >= max_pages
+                || articles.len() >= max_items
+                || fetched.contains(&page_url)
+            {
+                break;
+            }
+
+            let body = self.fetch_body(&page_url).await?;
+            articles.extend(self.parse_feed(&body)?);
+            next = self.next_page_url(&page_url, &body);
+            debug!(
+                "Fetched page {} of {} ({} articles so far)",
+                fetched.len() + 1,
+                url,
+                articles.len()
+            );
+            fetched.push(page_url);
+        }
+
+        articles.truncate(max_items);
         Ok(articles)
     }
 
     /// Fetch news articles for a specific topic
     ///
-    /// This method maps topic names to their corresponding feed URLs and fetches them.
-    /// The default implementation uses `build_topic_url()` for URL construction.
-    /// Sources with complex logic can override this method.
+    /// Maps the topic to its feed URL with `build_topic_url()` and fetches it with
+    /// `fetch_feed_by_url()`; the usual body of `NewsSource::fetch_topic()`.
     ///
     /// # Arguments
     /// * `topic` - The topic identifier (e.g., "headlines", "technology", "markets")
     ///
     /// # Returns
     /// A vector of parsed NewsArticle objects for the requested topic
-    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+    async fn fetch_feed_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
         let url = self.build_topic_url(topic)?;
         debug!("Fetching {} topic '{}': {}", self.name(), topic, url);
         self.fetch_feed_by_url(&url).await
     }
 
-    /// Get available topics/feeds for this source
+    /// Fetch the same feed from several URLs and keep the first success
     ///
-    /// Returns a list of topic identifiers that can be used with `fetch_topic()`
-    fn available_topics(&self) -> Vec<&'static str>;
+    /// Returns the last error when every URL failed.
+    ///
+    /// # Arguments
+    /// * `urls` - Equivalent feed URLs to request concurrently
+    async fn fetch_hedged(&self, urls: &[String]) -> Result<Vec<NewsArticle>> {
+        if urls.is_empty() {
+            return Err(FanError::InvalidUrl("No URLs to fetch".to_string()));
+        }
+
+        let requests = urls.iter().map(|url| self.fetch_feed_by_url(url));
+        let (articles, _pending) = select_ok(requests).await?;
+        Ok(articles)
+    }
+}
+
+/// Pass a successful response through, or fail with its status and the start of its body
+///
+/// Feeds answer failures with HTML error pages; reporting the status keeps those pages
+/// from surfacing as confusing XML parse errors.
+///
+/// `url` is the URL as requested, so that keys added as query parameters stay out of
+/// the error.
+pub(crate) async fn check_status(url: &str, response: HttpResponse) -> Result<HttpResponse> {
+    let status = response.status();
+    if response.is_success() {
+        return Ok(response);
+    }
+
+    // The body only adds detail; a failure to read it still reports the status
+    let body = response.text().await.unwrap_or_default();
+    Err(FanError::HttpStatus {
+        status,
+        url: url.to_string(),
+        snippet: error_snippet(&body),
+    })
+}
+
+/// Resolve a link found in a page against the page's URL
+pub(crate) fn resolve_link(page_url: &str, href: &str) -> Option<String> {
+    let url = Url::parse(page_url).ok()?.join(href).ok()?;
+    Some(url.into())
+}
+
+/// Set one query parameter of a URL, replacing any previous value
+#[cfg_attr(
+    not(any(feature = "news-api-org", feature = "reddit")),
+    allow(dead_code)
+)]
+pub(crate) fn with_query_param(url: &str, key: &str, value: &str) -> Option<String> {
+    let mut url = Url::parse(url).ok()?;
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| name != key)
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair(key, value);
+    Some(url.into())
+}
+
+/// Topics a source behind an API key contributes to a topic group
+///
+/// None without a key, so setups that did not configure one skip the source quietly.
+#[cfg_attr(
+    not(any(
+        feature = "alpha-vantage",
+        feature = "finnhub-news",
+        feature = "news-api-org",
+        feature = "polygon"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn keyed_group_topics(has_key: bool, topics: Vec<&'static str>) -> Vec<&'static str> {
+    if has_key { topics } else { Vec::new() }
+}
+
+/// The `Content-Type` header of a response
+pub(crate) fn content_type(response: &HttpResponse) -> Option<String> {
+    response.header(CONTENT_TYPE.as_str()).map(str::to_string)
+}
+
+/// Whether a `Content-Type` announces a web page
+fn is_html(content_type: &str) -> bool {
+    let mime = content_type.split(';').next().unwrap_or_default().trim();
+    mime.eq_ignore_ascii_case("text/html") || mime.eq_ignore_ascii_case("application/xhtml+xml")
+}
+
+/// Reject a body served as HTML unless it is a feed after all
+///
+/// Retired feed endpoints often redirect to a homepage; reporting that as
+/// `FanError::NotAFeed` beats an XML parse error. Feeds mislabeled as HTML still pass.
+pub(crate) fn check_feed_body(url: &str, content_type: Option<&str>, body: &str) -> Result<()> {
+    match content_type {
+        Some(content_type) if is_html(content_type) && FeedFormat::detect(None, body).is_none() => {
+            Err(FanError::NotAFeed {
+                url: url.to_string(),
+                content_type: content_type.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The start of an error body, with whitespace collapsed
+fn error_snippet(body: &str) -> String {
+    let mut snippet = String::new();
+    for (i, word) in body.split_whitespace().enumerate() {
+        if i > 0 {
+            snippet.push(' ');
+        }
+        snippet.push_str(word);
+        if snippet.chars().count() > ERROR_SNIPPET_LEN {
+            let mut truncated: String = snippet.chars().take(ERROR_SNIPPET_LEN).collect();
+            truncated.push('\u{2026}');
+            return truncated;
+        }
+    }
+    snippet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+    use std::sync::{Arc, Mutex};
+
+    /// Source whose response depends on the URL: paths containing "slow" take
+    /// `delay` to arrive and paths containing "fail" return an error
+    struct TestSource {
+        url_map: HashMap<String, String>,
+        client: Client,
+        parser: NewsParser,
+        delay: Duration,
+    }
+
+    impl TestSource {
+        fn new(base: &str, delay: Duration) -> Self {
+            let mut url_map = HashMap::new();
+            url_map.insert("base".to_string(), base.to_string());
+            Self {
+                url_map,
+                client: Client::new(),
+                parser: NewsParser::new("test"),
+                delay,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl NewsSource for TestSource {
+        fn name(&self) -> &'static str {
+            "Test"
+        }
+
+        fn available_topics(&self) -> Vec<&'static str> {
+            vec!["news", "markets", "fail"]
+        }
+
+        async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+            self.fetch_feed_topic(topic).await
+        }
+
+        fn feed(&self) -> Option<&dyn FeedSource> {
+            Some(self)
+        }
+    }
+
+    #[async_trait]
+    impl FeedSource for TestSource {
+        fn url_map(&self) -> &HashMap<String, String> {
+            &self.url_map
+        }
+
+        fn http_client(&self) -> &dyn HttpClient {
+            &self.client
+        }
+
+        fn parser(&self) -> &NewsParser {
+            &self.parser
+        }
+
+        async fn fetch_body(&self, url: &str) -> Result<String> {
+            if url.contains("fail") {
+                return Err(FanError::FeedParsing(format!("{} unavailable", url)));
+            }
+            if url.contains("slow") {
+                tokio::time::sleep(self.delay).await;
+            }
+            // ".../pageN" links on to page N + 1; ".../loop" links back to itself
+            let next = match url.rsplit_once("/page") {
+                Some((_, n)) => format!("page{}", n.parse::<u32>().unwrap() + 1),
+                None if url.ends_with("/loop") => "loop".to_string(),
+                None => String::new(),
+            };
+            Ok(format!(
+                "<rss><channel><ttl>5</ttl><atom:link rel=\"next\" href=\"{}\"/>\
+                 <item><title>{}</title></item></channel></rss>",
+                next, url
+            ))
+        }
+    }
+
+    /// Source implementing only the `NewsSource` core, serving one article per topic
+    struct StaticSource;
+
+    #[async_trait]
+    impl NewsSource for StaticSource {
+        fn name(&self) -> &'static str {
+            "Static"
+        }
+
+        fn available_topics(&self) -> Vec<&'static str> {
+            vec!["news", "markets"]
+        }
+
+        async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+            let mut article = NewsArticle::new();
+            article.title = Some(topic.to_string());
+            Ok(vec![article])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sources_without_feed() {
+        let sources: Vec<Box<dyn NewsSource>> = vec![
+            Box::new(StaticSource),
+            Box::new(TestSource::new(
+                "https://example.com/{topic}",
+                Duration::ZERO,
+            )),
+        ];
+        assert!(sources[0].feed().is_none());
+        assert!(sources[1].feed().is_some());
+
+        for source in &sources {
+            let (articles, _) = source.fetch_topic_with_ttl("markets").await.unwrap();
+            assert_eq!(articles.len(), 1);
+        }
+        let streamed: Vec<_> = sources[0]
+            .stream_topic("news")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(streamed.len(), 1);
+
+        let results = sources[0].fetch_all_topics().await;
+        assert_eq!(results.len(), 2);
+        let markets = results["markets"].as_ref().unwrap();
+        assert_eq!(markets[0].title.as_deref(), Some("markets"));
+        let (_, ttl) = sources[0].fetch_topic_with_ttl("news").await.unwrap();
+        assert_eq!(ttl, None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_fetch_topic_with_timeout() {
+        let source = TestSource::new("https://slow.example.com/{topic}", Duration::from_secs(10));
+
+        let result = source
+            .fetch_topic_with_timeout("news", Duration::from_secs(1))
+            .await;
+        assert!(matches!(result, Err(FanError::Timeout(_))));
+
+        let articles = source
+            .fetch_topic_with_timeout("news", Duration::from_secs(30))
+            .await
+            .unwrap();
+        assert_eq!(articles.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_topic_with_ttl() {
+        let source = TestSource::new("https://example.com/{topic}", Duration::ZERO);
+
+        let (articles, ttl) = source.fetch_topic_with_ttl("news").await.unwrap();
+        assert_eq!(articles[0].source.as_deref(), Some("Test"));
+        assert_eq!(ttl, Some(Duration::from_secs(5 * 60)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_fetch_topic_hedged_takes_fastest() {
+        let source = TestSource::new("https://slow.example.com/{topic}", Duration::from_secs(10));
+        let mirrors = vec![
+            "https://fail.example.com/news".to_string(),
+            "https://mirror.example.com/news".to_string(),
+        ];
+
+        let articles = source.fetch_topic_hedged("news", &mirrors).await.unwrap();
+        assert_eq!(
+            articles[0].title.as_deref(),
+            Some("https://mirror.example.com/news")
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_fetch_all_topics_parses_each_topic() {
+        let source = TestSource::new("https://example.com/{topic}", Duration::ZERO);
+
+        for threads in [None, Some(2)] {
+            let results = source.fetch_all_topics_with_threads(threads).await;
+            assert_eq!(results.len(), 3);
+            let markets = results["markets"].as_ref().unwrap();
+            assert_eq!(
+                markets[0].title.as_deref(),
+                Some("https://example.com/markets")
+            );
+            assert_eq!(markets[0].source.as_deref(), Some("Test"));
+            assert!(results["fail"].is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_hedged_all_failed() {
+        let source = TestSource::new("https://fail.example.com/{topic}", Duration::ZERO);
+        let mirrors = vec!["https://fail-too.example.com/news".to_string()];
+
+        assert!(source.fetch_topic_hedged("news", &mirrors).await.is_err());
+        assert!(source.fetch_hedged(&[]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_feed_paginated() {
+        let source = TestSource::new("https://example.com/{topic}", Duration::ZERO);
+
+        let articles = source
+            .fetch_feed_paginated("https://example.com/page1", 3)
+            .await
+            .unwrap();
+        let titles: Vec<_> = articles.iter().filter_map(|a| a.title.as_deref()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "https://example.com/page1",
+                "https://example.com/page2",
+                "https://example.com/page3"
+            ]
+        );
+
+        let limited = source
+            .fetch_feed_paginated_with_limit("https://example.com/page1", 10, 2)
+            .await
+            .unwrap();
+        assert_eq!(limited.len(), 2);
+
+        // A page linking to itself is fetched once
+        let looped = source
+            .fetch_feed_paginated("https://example.com/loop", 5)
+            .await
+            .unwrap();
+        assert_eq!(looped.len(), 1);
+    }
+
+    #[test]
+    fn test_with_query_param() {
+        assert_eq!(
+            with_query_param("https://example.com/api?q=fed&page=1", "page", "2").as_deref(),
+            Some("https://example.com/api?q=fed&page=2")
+        );
+        assert_eq!(
+            resolve_link("https://example.com/feeds/news.xml", "news.xml?p=2").as_deref(),
+            Some("https://example.com/feeds/news.xml?p=2")
+        );
+    }
+
+    #[test]
+    fn test_error_snippet() {
+        let page =
+            "<html>\n  <head><title>404 Not Found</title></head>\n  <body>Gone</body>\n</html>";
+        assert_eq!(
+            error_snippet(page),
+            "<html> <head><title>404 Not Found</title></head> <body>Gone</body> </html>"
+        );
+
+        let long = "word ".repeat(100);
+        let snippet = error_snippet(&long);
+        assert_eq!(snippet.chars().count(), ERROR_SNIPPET_LEN + 1);
+        assert!(snippet.ends_with('\u{2026}'));
+        assert_eq!(error_snippet(""), "");
+    }
+
+    /// HTTP client serving canned responses by URL and recording the requests sent
+    #[derive(Default)]
+    struct FixtureClient {
+        responses: HashMap<String, (u16, &'static str, &'static str)>,
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl FixtureClient {
+        fn with(
+            mut self,
+            url: &str,
+            status: u16,
+            content_type: &'static str,
+            body: &'static str,
+        ) -> Self {
+            self.responses
+                .insert(url.to_string(), (status, content_type, body));
+            self
+        }
+    }
+
+    #[async_trait]
+    impl HttpClient for FixtureClient {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+            let (status, content_type, body) = self.responses[&request.url];
+            let not_modified = request.header("if-none-match") == Some("\"v1\"");
+            self.requests.lock().unwrap().push(request);
+            if not_modified {
+                return Ok(HttpResponse::new(304, ""));
+            }
+            Ok(HttpResponse::new(status, body)
+                .with_header("Content-Type", content_type)
+                .with_header("ETag", "\"v1\""))
+        }
+    }
+
+    crate::define_source! {
+        struct FixtureSource {
+            name: "Fixture",
+            url: "https://example.com/{topic}.rss",
+            topics: {
+                news => "news",
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_through_http_client() {
+        let rss = "<rss><channel><item><title>Story</title></item></channel></rss>";
+        let client = Arc::new(
+            FixtureClient::default()
+                .with(
+                    "https://example.com/news.rss",
+                    200,
+                    "application/rss+xml",
+                    rss,
+                )
+                .with(
+                    "https://example.com/moved.rss",
+                    200,
+                    "text/html",
+                    "<html></html>",
+                )
+                .with(
+                    "https://example.com/down.rss",
+                    503,
+                    "text/plain",
+                    "Service Unavailable",
+                ),
+        );
+        let source = FixtureSource::new(Arc::clone(&client));
+
+        let articles = source.news().await.unwrap();
+        assert_eq!(articles[0].title.as_deref(), Some("Story"));
+        assert_eq!(articles[0].source.as_deref(), Some("Fixture"));
+        let streamed: Vec<_> = source
+            .stream_feed_by_url("https://example.com/news.rss")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(streamed.len(), 1);
+
+        assert!(matches!(
+            source
+                .fetch_feed_by_url("https://example.com/moved.rss")
+                .await,
+            Err(FanError::NotAFeed { .. })
+        ));
+        assert!(matches!(
+            source.fetch_feed_by_url("https://example.com/down.rss").await,
+            Err(FanError::HttpStatus { status: 503, ref snippet, .. }) if snippet == "Service Unavailable"
+        ));
+
+        // The ETag of the first response is sent back, and the 304 keeps the cached copy
+        let url = "https://example.com/news.rss";
+        let cached = source
+            .fetch_body_conditional(url, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.etag.as_deref(), Some("\"v1\""));
+        assert!(
+            source
+                .fetch_body_conditional(url, Some(&cached))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let requests = client.requests.lock().unwrap();
+        assert_eq!(
+            requests.last().unwrap().header("If-None-Match"),
+            Some("\"v1\"")
+        );
+    }
+
+    #[test]
+    fn test_check_feed_body() {
+        let url = "https://example.com/retired.rss";
+        let page = "<!DOCTYPE html><html><body>Welcome</body></html>";
+        let feed = "<?xml version=\"1.0\"?><rss><channel></channel></rss>";
+
+        assert!(matches!(
+            check_feed_body(url, Some("text/html; charset=utf-8"), page),
+            Err(FanError::NotAFeed { content_type, .. }) if content_type.starts_with("text/html")
+        ));
+        // Feeds mislabeled as HTML, and bodies without a content type, are parsed as usual
+        assert!(check_feed_body(url, Some("text/html"), feed).is_ok());
+        assert!(check_feed_body(url, Some("application/rss+xml"), feed).is_ok());
+        assert!(check_feed_body(url, None, page).is_ok());
+    }
 }
@@ -1,10 +1,15 @@
-use crate::error::Result;
-use crate::news_source::NewsSource;
+use crate::error::{FanError, Result};
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
 use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::symbols::Symbol;
+use crate::topic_group::TopicGroup;
 use crate::types::NewsArticle;
 use async_trait::async_trait;
-use reqwest::Client;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// NASDAQ news client
 ///
@@ -12,7 +17,7 @@ use std::collections::HashMap;
 /// earnings, economics, and technology news.
 pub struct NASDAQ {
     url_map: HashMap<String, String>,
-    client: Client,
+    client: Arc<dyn HttpClient>,
     parser: NewsParser,
 }
 
@@ -20,7 +25,7 @@ impl NASDAQ {
     /// Create a new NASDAQ client
     ///
     /// Initializes the client with NASDAQ RSS feed URLs.
-    pub fn new(client: Client) -> Self {
+    pub fn new(client: impl HttpClient + 'static) -> Self {
         let mut url_map = HashMap::new();
         url_map.insert(
             "base".to_string(),
@@ -33,8 +38,8 @@ impl NASDAQ {
 
         Self {
             url_map,
-            client,
-            parser: NewsParser::new("nasdaq"),
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::NASDAQ),
         }
     }
 
@@ -87,6 +92,21 @@ impl NASDAQ {
     pub async fn technology(&self) -> Result<Vec<NewsArticle>> {
         self.fetch_topic("technology").await
     }
+
+    /// Get news and press releases about one company
+    ///
+    /// # Arguments
+    /// * `ticker` - Stock ticker symbol (e.g., "AAPL"), validated with `Symbol::parse()`
+    pub async fn symbol(&self, ticker: &str) -> Result<Vec<NewsArticle>> {
+        let symbol = Symbol::parse(ticker)?;
+        let base_url = self
+            .url_map
+            .get("base")
+            .ok_or_else(|| FanError::InvalidUrl("Base URL not found".to_string()))?;
+
+        let url = format!("{}?symbol={}", base_url, symbol);
+        self.fetch_feed_by_url(&url).await
+    }
 }
 
 #[async_trait]
@@ -95,12 +115,57 @@ impl NewsSource for NASDAQ {
         "NASDAQ"
     }
 
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec![
+            "original",
+            "commodities",
+            "cryptocurrency",
+            "dividends",
+            "earnings",
+            "economics",
+            "financial-advisors",
+            "innovation",
+            "stocks",
+            "technology",
+        ]
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Markets => vec!["stocks"],
+            TopicGroup::Earnings => vec!["earnings"],
+            TopicGroup::Crypto => vec!["cryptocurrency"],
+            TopicGroup::Macro => vec!["economics"],
+            TopicGroup::Tech => vec!["technology"],
+            TopicGroup::Energy => vec!["commodities"],
+            _ => vec![],
+        }
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["technology"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for NASDAQ {
     fn url_map(&self) -> &HashMap<String, String> {
         &self.url_map
     }
 
-    fn client(&self) -> &Client {
-        &self.client
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
     }
 
     fn parser(&self) -> &NewsParser {
@@ -125,21 +190,4 @@ impl NewsSource for NASDAQ {
             Ok(format!("{}?category={}", base_url, topic))
         }
     }
-
-    // Uses default fetch_topic implementation
-
-    fn available_topics(&self) -> Vec<&'static str> {
-        vec![
-            "original",
-            "commodities",
-            "cryptocurrency",
-            "dividends",
-            "earnings",
-            "economics",
-            "financial-advisors",
-            "innovation",
-            "stocks",
-            "technology",
-        ]
-    }
 }
@@ -0,0 +1,348 @@
+use crate::credentials::{Credentials, require_key};
+use crate::error::{FanError, Result};
+use crate::http::{HttpClient, HttpRequest};
+use crate::news_source::{FeedSource, NewsSource, keyed_group_topics, with_query_param};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Results per page when a request does not set `pageSize`
+const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Environment variable read for the API key when none is configured
+pub const API_KEY_ENV: &str = "NEWSAPI_KEY";
+
+/// NewsAPI.org client
+///
+/// Reads US top headlines by category and searches all indexed articles through the
+/// NewsAPI.org v2 JSON API. Requires an API key, taken from `with_api_key()` or the
+/// `NEWSAPI_KEY` environment variable; the key is sent in a request header so it never
+/// appears in logged URLs. The originating outlet is kept in
+/// `extra_fields["outlet"]` and the article image in `extra_fields["thumbnail"]`.
+pub struct NewsApiOrg {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    api_key: Option<String>,
+}
+
+/// Envelope returned by the v2 endpoints
+#[derive(Debug, Deserialize)]
+struct Response {
+    status: String,
+    message: Option<String>,
+    #[serde(rename = "totalResults")]
+    total_results: Option<usize>,
+    #[serde(default)]
+    articles: Vec<Article>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Article {
+    source: Option<Outlet>,
+    author: Option<String>,
+    title: Option<String>,
+    description: Option<String>,
+    url: Option<String>,
+    url_to_image: Option<String>,
+    published_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Outlet {
+    name: Option<String>,
+}
+
+impl NewsApiOrg {
+    /// Create a new NewsAPI.org client
+    ///
+    /// Initializes the client with the top-headlines and everything endpoints and reads
+    /// the API key from `NEWSAPI_KEY`, if set.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://newsapi.org/v2/top-headlines?country=us&category={topic}".to_string(),
+        );
+        url_map.insert(
+            "everything".to_string(),
+            "https://newsapi.org/v2/everything".to_string(),
+        );
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::NewsApiOrg),
+            api_key: std::env::var(API_KEY_ENV)
+                .ok()
+                .filter(|key| !key.is_empty()),
+        }
+    }
+
+    /// Use the given API key instead of the environment variable
+    pub fn with_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = Some(api_key.to_string());
+        self
+    }
+
+    /// Take the API key from a credentials store, ignoring the environment variable
+    pub fn with_credentials(mut self, credentials: &Credentials) -> Self {
+        self.api_key = credentials.get(self.name()).map(str::to_string);
+        self
+    }
+
+    /// Whether an API key is available
+    pub fn has_api_key(&self) -> bool {
+        self.api_key.is_some()
+    }
+
+    /// Get US business headlines
+    pub async fn business(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("business").await
+    }
+
+    /// Get US technology headlines
+    pub async fn technology(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("technology").await
+    }
+
+    /// Get US general headlines
+    pub async fn general(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("general").await
+    }
+
+    /// Search all indexed articles, newest first
+    ///
+    /// # Arguments
+    /// * `query` - Keywords or phrases; supports `AND`/`OR`/`NOT` and quoted phrases
+    pub async fn everything(&self, query: &str) -> Result<Vec<NewsArticle>> {
+        let base_url = self
+            .url_map
+            .get("everything")
+            .ok_or_else(|| FanError::InvalidUrl("Everything URL not found".to_string()))?;
+        let url = Url::parse_with_params(
+            base_url,
+            [("q", query), ("language", "en"), ("sortBy", "publishedAt")],
+        )
+        .map_err(|e| FanError::InvalidUrl(e.to_string()))?;
+
+        self.fetch_feed_by_url(url.as_str()).await
+    }
+
+    /// Convert a NewsAPI.org article into a `NewsArticle`
+    fn article_to_news(article: Article) -> NewsArticle {
+        let mut news = NewsArticle::new();
+
+        news.title = article.title;
+        news.description = article.description;
+        news.guid = article.url.clone();
+        news.link = article.url;
+        news.pub_date = article.published_at;
+        news.author = article.author;
+
+        if let Some(outlet) = article.source.and_then(|source| source.name) {
+            news.extra_fields
+                .insert("outlet".to_string(), outlet.into());
+        }
+        if let Some(image) = article.url_to_image {
+            news.extra_fields
+                .insert("thumbnail".to_string(), image.into());
+        }
+
+        news
+    }
+}
+
+#[async_trait]
+impl NewsSource for NewsApiOrg {
+    fn name(&self) -> &'static str {
+        "NewsAPI"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec!["business", "technology", "general"]
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        let topics = match group {
+            TopicGroup::Markets => vec!["business"],
+            TopicGroup::Tech => vec!["technology"],
+            _ => vec![],
+        };
+        keyed_group_topics(self.has_api_key(), topics)
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["technology"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for NewsApiOrg {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override authenticate: send the key in the X-Api-Key header
+    fn authenticate(&self, request: HttpRequest) -> Result<HttpRequest> {
+        let api_key = require_key(self.name(), self.api_key.as_deref())?;
+        Ok(request.with_header("X-Api-Key", api_key))
+    }
+
+    // Override parse_body: NewsAPI.org returns JSON instead of RSS
+    fn parse_body(&self, body: &str) -> Result<Vec<NewsArticle>> {
+        let response: Response = serde_json::from_str(body)?;
+
+        if response.status != "ok" {
+            return Err(FanError::FeedParsing(format!(
+                "NewsAPI error: {}",
+                response.message.unwrap_or(response.status)
+            )));
+        }
+
+        Ok(response
+            .articles
+            .into_iter()
+            .map(Self::article_to_news)
+            .collect())
+    }
+
+    // Override next_page_url: results are paged with `page`/`pageSize` query parameters
+    fn next_page_url(&self, url: &str, body: &str) -> Option<String> {
+        let total = serde_json::from_str::<Response>(body).ok()?.total_results?;
+        let param = |name: &str| {
+            Url::parse(url)
+                .ok()?
+                .query_pairs()
+                .find(|(key, _)| key == name)
+                .and_then(|(_, value)| value.parse::<usize>().ok())
+        };
+        let page = param("page").unwrap_or(1);
+        let page_size = param("pageSize").unwrap_or(DEFAULT_PAGE_SIZE);
+
+        if page * page_size >= total {
+            return None;
+        }
+        with_query_param(url, "page", &(page + 1).to_string())
+    }
+
+    // Override streams_body: JSON bodies are parsed in one piece
+    fn streams_body(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+
+    const SAMPLE: &str = r#"{
+        "status": "ok",
+        "totalResults": 2,
+        "articles": [
+            {
+                "source": {"id": "reuters", "name": "Reuters"},
+                "author": "Jane Reporter",
+                "title": "Stocks edge higher ahead of Fed decision",
+                "description": "Wall Street rose on Tuesday...",
+                "url": "https://www.reuters.com/markets/us/stocks-edge-higher",
+                "urlToImage": "https://www.reuters.com/image.jpg",
+                "publishedAt": "2024-05-01T14:30:00Z",
+                "content": "Wall Street rose..."
+            },
+            {
+                "source": {"id": null, "name": "Example News"},
+                "author": null,
+                "title": "Oil slips",
+                "description": null,
+                "url": "https://news.example.com/oil",
+                "urlToImage": null,
+                "publishedAt": "2024-05-01T12:00:00Z",
+                "content": null
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_parse_articles() {
+        let source = NewsApiOrg::new(Client::new());
+        let articles = source.parse_body(SAMPLE).unwrap();
+
+        assert_eq!(articles.len(), 2);
+        let first = &articles[0];
+        assert_eq!(first.author.as_deref(), Some("Jane Reporter"));
+        assert_eq!(first.pub_date.as_deref(), Some("2024-05-01T14:30:00Z"));
+        assert_eq!(first.extra_fields.get("outlet").unwrap(), "Reuters");
+        assert_eq!(
+            first.extra_fields.get("thumbnail").unwrap(),
+            "https://www.reuters.com/image.jpg"
+        );
+        assert!(!articles[1].extra_fields.contains_key("thumbnail"));
+    }
+
+    #[test]
+    fn test_parse_api_error() {
+        let source = NewsApiOrg::new(Client::new());
+        let body = r#"{"status": "error", "code": "apiKeyInvalid", "message": "Your API key is invalid."}"#;
+        assert!(matches!(
+            source.parse_body(body),
+            Err(FanError::FeedParsing(_))
+        ));
+    }
+
+    #[test]
+    fn test_next_page_url() {
+        let source = NewsApiOrg::new(Client::new());
+        let url = "https://newsapi.org/v2/everything?q=fed&pageSize=1";
+
+        assert_eq!(
+            source.next_page_url(url, SAMPLE).as_deref(),
+            Some("https://newsapi.org/v2/everything?q=fed&pageSize=1&page=2")
+        );
+        let last = "https://newsapi.org/v2/everything?q=fed&pageSize=1&page=2";
+        assert!(source.next_page_url(last, SAMPLE).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_missing_api_key() {
+        let mut source = NewsApiOrg::new(Client::new());
+        source.api_key = None;
+
+        assert!(source.group_topics(TopicGroup::Markets).is_empty());
+        assert!(matches!(
+            source.business().await,
+            Err(FanError::MissingApiKey(_))
+        ));
+
+        let source = source.with_api_key("test-key");
+        assert_eq!(source.group_topics(TopicGroup::Markets), vec!["business"]);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Language of the Nikkei Asia edition
+const LANGUAGE: &str = "en";
+
+/// Nikkei Asia news client
+///
+/// Provides access to the Nikkei Asia RSS feed covering Asian business, markets, and
+/// politics, useful for following overnight sessions. Articles are tagged with
+/// `extra_fields["language"]`.
+pub struct NikkeiAsia {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+}
+
+impl NikkeiAsia {
+    /// Create a new Nikkei Asia client
+    ///
+    /// Initializes the client with the Nikkei Asia RSS feed URL.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://asia.nikkei.com/rss/feed/nar".to_string(),
+        );
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::NikkeiAsia),
+        }
+    }
+
+    /// Get latest news
+    pub async fn latest(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("latest").await
+    }
+}
+
+#[async_trait]
+impl NewsSource for NikkeiAsia {
+    fn name(&self) -> &'static str {
+        "Nikkei Asia"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec!["latest"]
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for NikkeiAsia {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url: Nikkei Asia publishes a single feed
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        if topic != "latest" {
+            return Err(crate::error::FanError::InvalidUrl(format!(
+                "Invalid topic: {}",
+                topic
+            )));
+        }
+
+        self.url_map()
+            .get("base")
+            .cloned()
+            .ok_or_else(|| crate::error::FanError::InvalidUrl("Base URL not found".to_string()))
+    }
+
+    // Tag articles with the edition language
+    fn annotate_article(&self, article: &mut NewsArticle) {
+        article
+            .extra_fields
+            .entry("language".to_string())
+            .or_insert_with(|| LANGUAGE.into());
+    }
+}
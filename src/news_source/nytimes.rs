@@ -0,0 +1,66 @@
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+
+crate::define_source! {
+    /// New York Times news client
+    ///
+    /// Provides access to the New York Times Business, Economy, and DealBook RSS feeds.
+    /// Article images published through the Media RSS namespace are exposed as
+    /// `extra_fields["thumbnail"]`.
+    pub struct NYTimes {
+        name: "New York Times",
+        url: "https://rss.nytimes.com/services/xml/rss/nyt/{topic}.xml",
+        parser: NewsParser::for_source(Source::NYTimes),
+        topics: {
+            /// Get business news
+            business => "Business",
+            /// Get economy news
+            economy => "Economy",
+            /// Get DealBook news
+            dealbook => "Dealbook",
+        },
+        groups: {
+            Macro => ["Economy"],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::news_source::FeedSource;
+    use reqwest::Client;
+
+    #[test]
+    fn test_media_thumbnail_extraction() {
+        let nytimes = NYTimes::new(Client::new());
+        let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:media="http://search.yahoo.com/mrss/" version="2.0">
+  <channel>
+    <item>
+      <title>Fed Holds Rates Steady</title>
+      <link>https://www.nytimes.com/2024/05/01/business/economy/fed-rates.html</link>
+      <dc:creator>Jeanna Smialek</dc:creator>
+      <media:content height="1800" medium="image" url="https://static01.nyt.com/images/fed.jpg" width="1800"/>
+      <media:credit>Kenny Holston/The New York Times</media:credit>
+    </item>
+    <item>
+      <title>No Image Here</title>
+    </item>
+  </channel>
+</rss>"#;
+
+        let articles = nytimes.parse_body(rss).unwrap();
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0].author.as_deref(), Some("Jeanna Smialek"));
+        assert_eq!(
+            articles[0].extra_fields.get("thumbnail").unwrap(),
+            "https://static01.nyt.com/images/fed.jpg"
+        );
+        assert_eq!(
+            articles[0].extra("media", "credit").unwrap(),
+            "Kenny Holston/The New York Times"
+        );
+        assert!(!articles[1].extra_fields.contains_key("thumbnail"));
+    }
+}
@@ -0,0 +1,381 @@
+use crate::credentials::{Credentials, require_key};
+use crate::error::{FanError, Result};
+use crate::http::{HttpClient, HttpRequest};
+use crate::news_source::{FeedSource, NewsSource, keyed_group_topics};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::symbols::Symbol;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Environment variable read for the API key when none is configured
+pub const API_KEY_ENV: &str = "POLYGON_API_KEY";
+
+/// Polygon.io reference news client
+///
+/// Reads the latest market news, overall or for one ticker, from Polygon's
+/// `/v2/reference/news` endpoint. Requires an API key, taken from `with_api_key()` or
+/// the `POLYGON_API_KEY` environment variable; the key is sent as a bearer token.
+///
+/// Each article carries the tickers it covers (`extra_fields["symbols"]`), Polygon's
+/// per-ticker sentiment insights (`extra_fields["ticker_sentiment"]`, e.g.
+/// "AAPL:positive,MSFT:neutral"), its keywords (`extra_fields["keywords"]`), the
+/// publisher (`extra_fields["outlet"]`), and its image (`extra_fields["thumbnail"]`).
+/// When every insight agrees, that sentiment is also kept in
+/// `extra_fields["sentiment"]`.
+pub struct Polygon {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    api_key: Option<String>,
+}
+
+/// Envelope returned by the reference news endpoint
+#[derive(Debug, Deserialize)]
+struct Response {
+    status: String,
+    error: Option<String>,
+    message: Option<String>,
+    #[serde(default)]
+    results: Vec<Article>,
+    /// Cursor URL of the next page of results
+    next_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Article {
+    id: Option<String>,
+    publisher: Option<Publisher>,
+    title: Option<String>,
+    author: Option<String>,
+    published_utc: Option<String>,
+    article_url: Option<String>,
+    #[serde(default)]
+    tickers: Vec<String>,
+    image_url: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    keywords: Vec<String>,
+    #[serde(default)]
+    insights: Vec<Insight>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Publisher {
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Insight {
+    ticker: String,
+    sentiment: Option<String>,
+}
+
+impl Polygon {
+    /// Create a new Polygon.io client
+    ///
+    /// Initializes the client with the reference news endpoint and reads the API key
+    /// from `POLYGON_API_KEY`, if set.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://api.polygon.io/v2/reference/news?order=desc&sort=published_utc&limit=50"
+                .to_string(),
+        );
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::Polygon),
+            api_key: std::env::var(API_KEY_ENV)
+                .ok()
+                .filter(|key| !key.is_empty()),
+        }
+    }
+
+    /// Use the given API key instead of the environment variable
+    pub fn with_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = Some(api_key.to_string());
+        self
+    }
+
+    /// Take the API key from a credentials store, ignoring the environment variable
+    pub fn with_credentials(mut self, credentials: &Credentials) -> Self {
+        self.api_key = credentials.get(self.name()).map(str::to_string);
+        self
+    }
+
+    /// Whether an API key is available
+    pub fn has_api_key(&self) -> bool {
+        self.api_key.is_some()
+    }
+
+    /// Get the latest news across all tickers
+    pub async fn latest(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("latest").await
+    }
+
+    /// Get the latest news about one ticker
+    ///
+    /// # Arguments
+    /// * `ticker` - Stock ticker (e.g., "AAPL")
+    pub async fn ticker_news(&self, ticker: &str) -> Result<Vec<NewsArticle>> {
+        let base_url = self
+            .url_map
+            .get("base")
+            .ok_or_else(|| FanError::InvalidUrl("Base URL not found".to_string()))?;
+        let url =
+            Url::parse_with_params(base_url, [("ticker", Symbol::parse(ticker)?.to_string())])
+                .map_err(|e| FanError::InvalidUrl(e.to_string()))?;
+
+        self.fetch_feed_by_url(url.as_str()).await
+    }
+
+    /// Convert a Polygon article into a `NewsArticle`
+    fn article_to_news(article: Article) -> NewsArticle {
+        let mut news = NewsArticle::new();
+
+        news.title = article.title;
+        news.description = article.description;
+        news.link = article.article_url;
+        news.guid = article.id;
+        news.pub_date = article.published_utc;
+        news.author = article.author;
+
+        let ticker_sentiment = article
+            .insights
+            .iter()
+            .filter_map(|insight| {
+                let sentiment = insight.sentiment.as_ref()?;
+                Some(format!("{}:{}", insight.ticker, sentiment))
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let sentiment = article
+            .insights
+            .first()
+            .and_then(|insight| insight.sentiment.clone())
+            .filter(|first| {
+                article
+                    .insights
+                    .iter()
+                    .all(|insight| insight.sentiment.as_ref() == Some(first))
+            });
+
+        let fields = [
+            ("sentiment", sentiment),
+            ("symbols", Some(article.tickers.join(","))),
+            ("ticker_sentiment", Some(ticker_sentiment)),
+            ("keywords", Some(article.keywords.join(","))),
+            (
+                "outlet",
+                article.publisher.and_then(|publisher| publisher.name),
+            ),
+            ("thumbnail", article.image_url),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value.filter(|value| !value.is_empty()) {
+                news.extra_fields.insert(key.to_string(), value.into());
+            }
+        }
+
+        news
+    }
+}
+
+#[async_trait]
+impl NewsSource for Polygon {
+    fn name(&self) -> &'static str {
+        "Polygon"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec!["latest"]
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        let topics = match group {
+            TopicGroup::Markets => vec!["latest"],
+            _ => vec![],
+        };
+        keyed_group_topics(self.has_api_key(), topics)
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for Polygon {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url: the only topic is the unfiltered news list
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        if topic != "latest" {
+            return Err(FanError::InvalidUrl(format!("Invalid topic: {}", topic)));
+        }
+        self.url_map
+            .get("base")
+            .cloned()
+            .ok_or_else(|| FanError::InvalidUrl("Base URL not found".to_string()))
+    }
+
+    // Override authenticate: send the key as a bearer token
+    fn authenticate(&self, request: HttpRequest) -> Result<HttpRequest> {
+        let api_key = require_key(self.name(), self.api_key.as_deref())?;
+        Ok(request.with_bearer_auth(api_key))
+    }
+
+    // Override parse_body: Polygon returns JSON instead of RSS
+    fn parse_body(&self, body: &str) -> Result<Vec<NewsArticle>> {
+        let response: Response = serde_json::from_str(body)?;
+
+        if response.status != "OK" {
+            return Err(FanError::FeedParsing(format!(
+                "Polygon error: {}",
+                response
+                    .error
+                    .or(response.message)
+                    .unwrap_or(response.status)
+            )));
+        }
+
+        Ok(response
+            .results
+            .into_iter()
+            .map(Self::article_to_news)
+            .collect())
+    }
+
+    // Override next_page_url: the envelope carries a cursor URL for the next page
+    fn next_page_url(&self, _url: &str, body: &str) -> Option<String> {
+        serde_json::from_str::<Response>(body).ok()?.next_url
+    }
+
+    // Override streams_body: JSON bodies are parsed in one piece
+    fn streams_body(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+
+    const SAMPLE: &str = r#"{
+        "results": [
+            {
+                "id": "8ec638777ca03b553ae516761c2a22ba",
+                "publisher": {"name": "The Motley Fool", "homepage_url": "https://www.fool.com/"},
+                "title": "Is Apple a buy after earnings?",
+                "author": "Jane Analyst",
+                "published_utc": "2024-05-01T14:30:00Z",
+                "article_url": "https://www.fool.com/investing/apple",
+                "tickers": ["AAPL", "MSFT"],
+                "image_url": "https://g.foolcdn.com/apple.png",
+                "description": "Apple shares rose...",
+                "keywords": ["earnings", "iphone"],
+                "insights": [
+                    {"ticker": "AAPL", "sentiment": "positive", "sentiment_reasoning": "Strong quarter"},
+                    {"ticker": "MSFT", "sentiment": "neutral", "sentiment_reasoning": "Mentioned in passing"}
+                ]
+            },
+            {
+                "id": "1f2e3d",
+                "publisher": {"name": "Benzinga"},
+                "title": "Nvidia rallies",
+                "published_utc": "2024-05-01T12:00:00Z",
+                "article_url": "https://www.benzinga.com/nvda",
+                "tickers": ["NVDA"],
+                "insights": [{"ticker": "NVDA", "sentiment": "positive"}]
+            }
+        ],
+        "status": "OK",
+        "request_id": "abc",
+        "count": 2
+    }"#;
+
+    #[test]
+    fn test_parse_results_with_insights() {
+        let source = Polygon::new(Client::new());
+        let articles = source.parse_body(SAMPLE).unwrap();
+
+        assert_eq!(articles.len(), 2);
+        let first = &articles[0];
+        assert_eq!(
+            first.guid.as_deref(),
+            Some("8ec638777ca03b553ae516761c2a22ba")
+        );
+        assert_eq!(first.extra_fields["symbols"], "AAPL,MSFT");
+        assert_eq!(
+            first.extra_fields["ticker_sentiment"],
+            "AAPL:positive,MSFT:neutral"
+        );
+        assert_eq!(first.extra_fields["keywords"], "earnings,iphone");
+        assert_eq!(first.extra_fields["outlet"], "The Motley Fool");
+        // Mixed insights have no overall sentiment
+        assert!(!first.extra_fields.contains_key("sentiment"));
+
+        let second = &articles[1];
+        assert_eq!(second.extra_fields["sentiment"], "positive");
+        assert!(!second.extra_fields.contains_key("keywords"));
+    }
+
+    #[test]
+    fn test_next_page_url() {
+        let source = Polygon::new(Client::new());
+        assert!(source.next_page_url("", SAMPLE).is_none());
+
+        let body = r#"{"status": "OK", "results": [],
+            "next_url": "https://api.polygon.io/v2/reference/news?cursor=YXA9MjAyNA"}"#;
+        assert_eq!(
+            source.next_page_url("", body).as_deref(),
+            Some("https://api.polygon.io/v2/reference/news?cursor=YXA9MjAyNA")
+        );
+    }
+
+    #[test]
+    fn test_parse_api_error() {
+        let source = Polygon::new(Client::new());
+        let body = r#"{"status": "ERROR", "request_id": "abc", "error": "Unknown API Key"}"#;
+        assert!(matches!(
+            source.parse_body(body),
+            Err(FanError::FeedParsing(message)) if message.contains("Unknown API Key")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_missing_api_key() {
+        let mut source = Polygon::new(Client::new());
+        source.api_key = None;
+
+        assert!(source.group_topics(TopicGroup::Markets).is_empty());
+        assert!(source.build_topic_url("unknown").is_err());
+        assert!(matches!(
+            source.latest().await,
+            Err(FanError::MissingApiKey(_))
+        ));
+    }
+}
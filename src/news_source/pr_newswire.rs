@@ -0,0 +1,161 @@
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// RSS list paths for PR Newswire subjects and industries, in topic order
+const TOPICS: [(&str, &str); 9] = [
+    ("all", "news-releases-list"),
+    (
+        "financial_services",
+        "financial-services-latest-news/financial-services-latest-news-list",
+    ),
+    ("earnings", "financial-services-latest-news/earnings-list"),
+    (
+        "mergers_acquisitions",
+        "financial-services-latest-news/acquisitions-mergers-and-takeovers-list",
+    ),
+    ("dividends", "financial-services-latest-news/dividends-list"),
+    (
+        "ipo",
+        "financial-services-latest-news/initial-public-offerings-list",
+    ),
+    (
+        "technology",
+        "consumer-technology-latest-news/consumer-technology-latest-news-list",
+    ),
+    ("energy", "energy-latest-news/energy-latest-news-list"),
+    ("health", "health-latest-news/health-latest-news-list"),
+];
+
+/// PR Newswire press release client
+///
+/// Provides access to PR Newswire RSS feeds of company press releases, including the
+/// all-releases list plus subject and industry lists.
+pub struct PRNewswire {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    topic_categories: HashMap<&'static str, &'static str>,
+}
+
+impl PRNewswire {
+    /// Create a new PR Newswire client
+    ///
+    /// Initializes the client with PR Newswire RSS feed URL patterns and list mappings.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://www.prnewswire.com/rss/{topic}.rss".to_string(),
+        );
+
+        let topic_categories = TOPICS.iter().copied().collect();
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::PRNewswire),
+            topic_categories,
+        }
+    }
+
+    /// Get all news releases
+    pub async fn all(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("all").await
+    }
+
+    /// Get financial services releases
+    pub async fn financial_services(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("financial_services").await
+    }
+
+    /// Get earnings releases
+    pub async fn earnings(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("earnings").await
+    }
+
+    /// Get mergers and acquisitions releases
+    pub async fn mergers_acquisitions(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("mergers_acquisitions").await
+    }
+
+    /// Get IPO releases
+    pub async fn ipo(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("ipo").await
+    }
+}
+
+#[async_trait]
+impl NewsSource for PRNewswire {
+    fn name(&self) -> &'static str {
+        "PR Newswire"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        TOPICS.iter().map(|(name, _)| *name).collect()
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Earnings => vec!["earnings"],
+            TopicGroup::Tech => vec!["technology"],
+            TopicGroup::Energy => vec!["energy"],
+            _ => vec![],
+        }
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["technology"],
+            Sector::Healthcare => vec!["health"],
+            Sector::Financials => vec!["financial_services"],
+            Sector::Energy => vec!["energy"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for PRNewswire {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url to map topic names to RSS list paths
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let topic_path = self.topic_categories.get(topic).ok_or_else(|| {
+            crate::error::FanError::InvalidUrl(format!("Invalid topic: {}", topic))
+        })?;
+
+        let base_url = self
+            .url_map()
+            .get("base")
+            .ok_or_else(|| crate::error::FanError::InvalidUrl("Base URL not found".to_string()))?;
+
+        Ok(base_url.replace("{topic}", topic_path))
+    }
+}
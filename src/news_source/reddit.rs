@@ -0,0 +1,403 @@
+use crate::disk_cache::CachedBody;
+use crate::error::{FanError, Result};
+use crate::http::{HttpClient, HttpRequest, HttpResponse};
+use crate::news_source::{FeedSource, NewsSource, check_status, with_query_param};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::types::{NewsArticle, SourceConfig};
+use async_trait::async_trait;
+use chrono::DateTime;
+use log::{debug, warn};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Upper bound on how long a single rate-limit wait may last
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+/// Reddit finance subreddit client
+///
+/// Reads subreddit listings through Reddit's public JSON endpoints. Any subreddit can be
+/// fetched by name with `fetch_topic()`; `available_topics()` lists the finance
+/// subreddits supported out of the box. Post score, flair, subreddit, and comment count
+/// are captured in `extra_fields`.
+///
+/// Reddit rate-limits anonymous clients aggressively. HTTP 429 responses are retried
+/// after the delay advertised by the server (`Retry-After` / `x-ratelimit-reset`),
+/// falling back to the configured retry delay.
+pub struct Reddit {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    min_score: Option<i64>,
+    max_retries: u32,
+    retry_delay: Duration,
+}
+
+/// Listing envelope returned by subreddit JSON endpoints
+#[derive(Debug, Deserialize)]
+struct Listing {
+    data: ListingData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListingData {
+    #[serde(default)]
+    children: Vec<Child>,
+    /// Name of the last post, used as the cursor for the next page
+    after: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Child {
+    data: Post,
+}
+
+#[derive(Debug, Deserialize)]
+struct Post {
+    id: String,
+    title: String,
+    #[serde(default)]
+    selftext: String,
+    url: Option<String>,
+    permalink: String,
+    author: Option<String>,
+    created_utc: f64,
+    score: i64,
+    link_flair_text: Option<String>,
+    subreddit: String,
+    num_comments: Option<u64>,
+    #[serde(default)]
+    stickied: bool,
+}
+
+impl Reddit {
+    /// Create a new Reddit client
+    ///
+    /// Initializes the client with the subreddit listing URL pattern and default retry settings.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        Self::with_config(
+            client,
+            SourceConfig::new("https://www.reddit.com/r/{topic}/.json"),
+        )
+    }
+
+    /// Create a new Reddit client with custom config
+    ///
+    /// # Arguments
+    /// * `client` - HTTP client for making requests
+    /// * `config` - Source configuration (base_url and retry settings are used)
+    pub fn with_config(client: impl HttpClient + 'static, config: SourceConfig) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert("base".to_string(), config.base_url.clone());
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::Reddit),
+            min_score: None,
+            max_retries: config.max_retries,
+            retry_delay: config.retry_delay_duration(),
+        }
+    }
+
+    /// Only keep posts with at least `min_score` upvotes
+    pub fn with_min_score(mut self, min_score: i64) -> Self {
+        self.min_score = Some(min_score);
+        self
+    }
+
+    /// Get r/stocks posts
+    pub async fn stocks(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("stocks").await
+    }
+
+    /// Get r/investing posts
+    pub async fn investing(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("investing").await
+    }
+
+    /// Get r/wallstreetbets posts
+    pub async fn wallstreetbets(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("wallstreetbets").await
+    }
+
+    /// Get posts from any subreddit
+    ///
+    /// # Arguments
+    /// * `name` - Subreddit name without the `r/` prefix (e.g., "SecurityAnalysis")
+    pub async fn subreddit(&self, name: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic(name).await
+    }
+
+    /// Convert a Reddit post into a `NewsArticle`
+    fn post_to_article(post: Post) -> NewsArticle {
+        let mut article = NewsArticle::new();
+
+        let permalink = format!("https://www.reddit.com{}", post.permalink);
+        article.title = Some(post.title);
+        article.link = Some(post.url.unwrap_or_else(|| permalink.clone()));
+        if !post.selftext.is_empty() {
+            article.description = Some(post.selftext);
+        }
+        article.pub_date =
+            DateTime::from_timestamp(post.created_utc as i64, 0).map(|date| date.to_rfc2822());
+        article.guid = Some(post.id);
+        article.author = post.author;
+        article.category = post.link_flair_text.clone();
+
+        article
+            .extra_fields
+            .insert("score".to_string(), post.score.to_string().into());
+        article
+            .extra_fields
+            .insert("subreddit".to_string(), post.subreddit.into());
+        article
+            .extra_fields
+            .insert("permalink".to_string(), permalink.into());
+        if let Some(flair) = post.link_flair_text {
+            article
+                .extra_fields
+                .insert("flair".to_string(), flair.into());
+        }
+        if let Some(num_comments) = post.num_comments {
+            article
+                .extra_fields
+                .insert("num_comments".to_string(), num_comments.to_string().into());
+        }
+
+        article
+    }
+}
+
+/// Read the server-advertised wait time from a rate-limited response
+fn rate_limit_wait(response: &HttpResponse) -> Option<Duration> {
+    ["retry-after", "x-ratelimit-reset"]
+        .iter()
+        .filter_map(|name| response.header(name))
+        .filter_map(|value| value.trim().parse::<f64>().ok())
+        // Clamped before converting: `inf` or `1e30` does not fit a `Duration`, and
+        // NaN becomes 0
+        .map(|secs| Duration::from_secs_f64(secs.max(0.0).min(MAX_RATE_LIMIT_WAIT.as_secs_f64())))
+        .next()
+}
+
+/// Check that a topic is a plausible subreddit name
+fn is_valid_subreddit(name: &str) -> bool {
+    (2..=21).contains(&name.len()) && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[async_trait]
+impl NewsSource for Reddit {
+    fn name(&self) -> &'static str {
+        "Reddit"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec![
+            "stocks",
+            "investing",
+            "wallstreetbets",
+            "StockMarket",
+            "options",
+            "SecurityAnalysis",
+            "economics",
+        ]
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for Reddit {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url to reject names that are not valid subreddits
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let subreddit = topic.trim_start_matches("r/");
+        if !is_valid_subreddit(subreddit) {
+            return Err(FanError::InvalidUrl(format!(
+                "Invalid subreddit: {}",
+                topic
+            )));
+        }
+
+        let base_url = self
+            .url_map()
+            .get("base")
+            .ok_or_else(|| FanError::InvalidUrl("Base URL not found".to_string()))?;
+
+        Ok(base_url.replace("{topic}", subreddit))
+    }
+
+    // Override fetch_body to wait out HTTP 429 responses
+    async fn fetch_body(&self, url: &str) -> Result<String> {
+        debug!("Fetching {} feed from URL: {}", self.name(), url);
+
+        let mut attempt = 0;
+        loop {
+            let response = self.http_client().send(HttpRequest::get(url)).await?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS.as_u16() {
+                return check_status(url, response).await?.text().await;
+            }
+
+            if attempt >= self.max_retries {
+                return Err(FanError::RateLimited(format!(
+                    "{} still rate limited after {} retries",
+                    url, self.max_retries
+                )));
+            }
+            attempt += 1;
+
+            let wait = rate_limit_wait(&response)
+                .unwrap_or(self.retry_delay)
+                .min(MAX_RATE_LIMIT_WAIT);
+            warn!(
+                "Reddit rate limited, retrying in {:?} (attempt {}/{})",
+                wait, attempt, self.max_retries
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    // Override fetch_body_conditional to keep the rate-limit handling of fetch_body;
+    // the listing API does not send validators
+    async fn fetch_body_conditional(
+        &self,
+        url: &str,
+        _cached: Option<&CachedBody>,
+    ) -> Result<Option<CachedBody>> {
+        let content = self.fetch_body(url).await?;
+        Ok(Some(CachedBody::new(url, content, None, None)))
+    }
+
+    // Override parse_body: Reddit listings are JSON
+    fn parse_body(&self, body: &str) -> Result<Vec<NewsArticle>> {
+        let listing: Listing = serde_json::from_str(body)?;
+
+        Ok(listing
+            .data
+            .children
+            .into_iter()
+            .map(|child| child.data)
+            .filter(|post| !post.stickied)
+            .filter(|post| self.min_score.is_none_or(|min| post.score >= min))
+            .map(Self::post_to_article)
+            .collect())
+    }
+
+    // Override next_page_url: listings are paged with the `after` cursor
+    fn next_page_url(&self, url: &str, body: &str) -> Option<String> {
+        let after = serde_json::from_str::<Listing>(body).ok()?.data.after?;
+        with_query_param(url, "after", &after)
+    }
+
+    // Override streams_body: JSON bodies are parsed in one piece
+    fn streams_body(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+
+    const SAMPLE: &str = r#"{
+        "kind": "Listing",
+        "data": {
+            "children": [
+                {"kind": "t3", "data": {
+                    "id": "abc123", "title": "Daily Discussion Thread", "selftext": "",
+                    "url": "https://www.reddit.com/r/stocks/comments/abc123/daily/",
+                    "permalink": "/r/stocks/comments/abc123/daily/", "author": "AutoModerator",
+                    "created_utc": 1714572000.0, "score": 40, "link_flair_text": null,
+                    "subreddit": "stocks", "num_comments": 900, "stickied": true
+                }},
+                {"kind": "t3", "data": {
+                    "id": "def456", "title": "NVDA earnings beat", "selftext": "Thoughts?",
+                    "url": "https://www.reddit.com/r/stocks/comments/def456/nvda/",
+                    "permalink": "/r/stocks/comments/def456/nvda/", "author": "trader1",
+                    "created_utc": 1714572000.0, "score": 1200, "link_flair_text": "Company News",
+                    "subreddit": "stocks", "num_comments": 310
+                }},
+                {"kind": "t3", "data": {
+                    "id": "ghi789", "title": "Is it too late to buy?", "selftext": "",
+                    "url": "https://www.reddit.com/r/stocks/comments/ghi789/late/",
+                    "permalink": "/r/stocks/comments/ghi789/late/", "author": "newbie",
+                    "created_utc": 1714572060.0, "score": 3, "link_flair_text": null,
+                    "subreddit": "stocks", "num_comments": 12
+                }}
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_listing() {
+        let reddit = Reddit::new(Client::new());
+        let articles = reddit.parse_body(SAMPLE).unwrap();
+
+        // Stickied posts are skipped
+        assert_eq!(articles.len(), 2);
+        let first = &articles[0];
+        assert_eq!(first.title.as_deref(), Some("NVDA earnings beat"));
+        assert_eq!(first.description.as_deref(), Some("Thoughts?"));
+        assert_eq!(
+            first.pub_date.as_deref(),
+            Some("Wed, 1 May 2024 14:00:00 +0000")
+        );
+        assert_eq!(first.extra_fields.get("score").unwrap(), "1200");
+        assert_eq!(first.extra_fields.get("flair").unwrap(), "Company News");
+        assert!(!articles[1].extra_fields.contains_key("flair"));
+    }
+
+    #[test]
+    fn test_min_score_filter() {
+        let reddit = Reddit::new(Client::new()).with_min_score(100);
+        let articles = reddit.parse_body(SAMPLE).unwrap();
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].guid.as_deref(), Some("def456"));
+    }
+
+    #[test]
+    fn test_rate_limit_wait() {
+        let wait = |value: &str| {
+            rate_limit_wait(&HttpResponse::new(429, "").with_header("Retry-After", value))
+        };
+        assert_eq!(wait("2.5"), Some(Duration::from_millis(2500)));
+        assert_eq!(wait("-1"), Some(Duration::ZERO));
+        assert_eq!(wait("NaN"), Some(Duration::ZERO));
+        for huge in ["inf", "1e30", "18446744073709551616"] {
+            assert_eq!(wait(huge), Some(MAX_RATE_LIMIT_WAIT));
+        }
+        assert_eq!(wait("soon"), None);
+    }
+
+    #[test]
+    fn test_build_topic_url() {
+        let reddit = Reddit::new(Client::new());
+        assert_eq!(
+            reddit.build_topic_url("r/investing").unwrap(),
+            "https://www.reddit.com/r/investing/.json"
+        );
+        assert!(reddit.build_topic_url("../api").is_err());
+    }
+}
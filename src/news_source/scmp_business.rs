@@ -0,0 +1,149 @@
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Language of the South China Morning Post edition
+const LANGUAGE: &str = "en";
+
+/// South China Morning Post business news client
+///
+/// Provides access to SCMP business RSS feeds covering Hong Kong and mainland China
+/// companies, markets, and the economy. Articles are tagged with
+/// `extra_fields["language"]`.
+pub struct SCMPBusiness {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+    topic_categories: HashMap<&'static str, u32>,
+}
+
+impl SCMPBusiness {
+    /// Create a new SCMP business client
+    ///
+    /// Initializes the client with the SCMP RSS feed URL pattern and section ID mappings.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://www.scmp.com/rss/{topic_id}/feed".to_string(),
+        );
+
+        let mut topic_categories = HashMap::new();
+        // RSS section IDs for SCMP business topics
+        topic_categories.insert("business", 92);
+        topic_categories.insert("companies", 10);
+        topic_categories.insert("banking_finance", 91);
+        topic_categories.insert("markets", 95);
+        topic_categories.insert("property", 96);
+        topic_categories.insert("china_economy", 318421);
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::SCMPBusiness),
+            topic_categories,
+        }
+    }
+
+    /// Get business news
+    pub async fn business(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("business").await
+    }
+
+    /// Get companies news
+    pub async fn companies(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("companies").await
+    }
+
+    /// Get markets news
+    pub async fn markets(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("markets").await
+    }
+}
+
+#[async_trait]
+impl NewsSource for SCMPBusiness {
+    fn name(&self) -> &'static str {
+        "SCMP Business"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        self.topic_categories.keys().copied().collect()
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Markets => vec!["markets"],
+            TopicGroup::Macro => vec!["china_economy"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for SCMPBusiness {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url to map topic names to numeric section IDs
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let topic_id = self.topic_categories.get(topic).ok_or_else(|| {
+            crate::error::FanError::InvalidUrl(format!("Invalid topic: {}", topic))
+        })?;
+
+        let base_url = self
+            .url_map()
+            .get("base")
+            .ok_or_else(|| crate::error::FanError::InvalidUrl("Base URL not found".to_string()))?;
+
+        Ok(base_url.replace("{topic_id}", &topic_id.to_string()))
+    }
+
+    // Tag articles with the edition language
+    fn annotate_article(&self, article: &mut NewsArticle) {
+        article
+            .extra_fields
+            .entry("language".to_string())
+            .or_insert_with(|| LANGUAGE.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+
+    #[test]
+    fn test_articles_tagged_with_language() {
+        let scmp = SCMPBusiness::new(Client::new());
+        let rss = r#"<rss><channel><item><title>Hang Seng rallies</title></item></channel></rss>"#;
+
+        let mut articles = scmp.parse_body(rss).unwrap();
+        scmp.annotate_article(&mut articles[0]);
+        assert_eq!(articles[0].extra_fields.get("language").unwrap(), "en");
+    }
+}
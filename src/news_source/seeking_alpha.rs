@@ -1,10 +1,15 @@
 use crate::error::Result;
-use crate::news_source::NewsSource;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
 use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::symbols::Symbol;
+use crate::topic_group::TopicGroup;
 use crate::types::NewsArticle;
 use async_trait::async_trait;
-use reqwest::Client;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Seeking Alpha news client
 ///
@@ -12,7 +17,7 @@ use std::collections::HashMap;
 /// stock ideas, IPO analysis, earnings transcripts, and more.
 pub struct SeekingAlpha {
     url_map: HashMap<String, String>,
-    client: Client,
+    client: Arc<dyn HttpClient>,
     parser: NewsParser,
 }
 
@@ -20,7 +25,7 @@ impl SeekingAlpha {
     /// Create a new Seeking Alpha client
     ///
     /// Initializes the client with Seeking Alpha RSS feed URL.
-    pub fn new(client: Client) -> Self {
+    pub fn new(client: impl HttpClient + 'static) -> Self {
         let mut url_map = HashMap::new();
         url_map.insert(
             "base".to_string(),
@@ -29,8 +34,8 @@ impl SeekingAlpha {
 
         Self {
             url_map,
-            client,
-            parser: NewsParser::new("seeking_alpha"),
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::SeekingAlpha),
         }
     }
 
@@ -114,9 +119,11 @@ impl SeekingAlpha {
     /// Get stocks by ticker symbol
     ///
     /// # Arguments
-    /// * `ticker` - Stock ticker symbol (e.g., "AAPL", "GOOGL", "MSFT")
+    /// * `ticker` - Stock ticker symbol (e.g., "AAPL", "GOOGL", "MSFT"), validated
+    ///   with `Symbol::parse()`
     pub async fn stocks(&self, ticker: &str) -> Result<Vec<NewsArticle>> {
-        self.fetch_topic(&format!("stocks-{}", ticker)).await
+        let symbol = Symbol::parse(ticker)?;
+        self.fetch_topic(&format!("stocks-{}", symbol)).await
     }
 }
 
@@ -126,12 +133,69 @@ impl NewsSource for SeekingAlpha {
         "Seeking Alpha"
     }
 
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec![
+            "latest-articles",
+            "all-news",
+            "market-news",
+            "long-ideas",
+            "short-ideas",
+            "ipo-analysis",
+            "transcripts",
+            "wall-street-breakfast",
+            "most-popular-articles",
+            "forex",
+            "editors-picks",
+            "etfs",
+            "sectors-technology",
+            "sectors-healthcare",
+            "sectors-financial",
+            "sectors-energy",
+            "sectors-real-estate",
+            "sectors-basic-materials",
+            "sectors-consumer",
+            "sectors-industrials",
+        ]
+    }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Markets => vec!["market-news"],
+            TopicGroup::Earnings => vec!["transcripts"],
+            _ => vec![],
+        }
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["sectors-technology"],
+            Sector::Healthcare => vec!["sectors-healthcare"],
+            Sector::Financials => vec!["sectors-financial"],
+            Sector::Energy => vec!["sectors-energy"],
+            Sector::RealEstate => vec!["sectors-real-estate"],
+            Sector::Materials => vec!["sectors-basic-materials"],
+            Sector::Consumer => vec!["sectors-consumer"],
+            Sector::Industrials => vec!["sectors-industrials"],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for SeekingAlpha {
     fn url_map(&self) -> &HashMap<String, String> {
         &self.url_map
     }
 
-    fn client(&self) -> &Client {
-        &self.client
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
     }
 
     fn parser(&self) -> &NewsParser {
@@ -147,23 +211,4 @@ impl NewsSource for SeekingAlpha {
 
         Ok(format!("{}?category={}", base_url, topic))
     }
-
-    // Uses default fetch_topic implementation
-
-    fn available_topics(&self) -> Vec<&'static str> {
-        vec![
-            "latest-articles",
-            "all-news",
-            "market-news",
-            "long-ideas",
-            "short-ideas",
-            "ipo-analysis",
-            "transcripts",
-            "wall-street-breakfast",
-            "most-popular-articles",
-            "forex",
-            "editors-picks",
-            "etfs",
-        ]
-    }
 }
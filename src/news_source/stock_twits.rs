@@ -0,0 +1,301 @@
+use crate::error::{FanError, Result};
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
+use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::symbols;
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Maximum number of characters of a message body used as the article title
+const TITLE_MAX_CHARS: usize = 120;
+
+/// StockTwits social stream client
+///
+/// Provides access to the public StockTwits JSON API for trending messages and
+/// per-symbol message streams. Each message is converted into a `NewsArticle` with
+/// the author, body, timestamp, mentioned symbols (`extra_fields["symbols"]`), and
+/// the author's sentiment tag when present (`extra_fields["sentiment"]`).
+pub struct StockTwits {
+    url_map: HashMap<String, String>,
+    client: Arc<dyn HttpClient>,
+    parser: NewsParser,
+}
+
+/// Envelope returned by the StockTwits streams API
+#[derive(Debug, Deserialize)]
+struct StreamResponse {
+    #[serde(default)]
+    messages: Vec<Message>,
+    #[serde(default)]
+    errors: Vec<ApiError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Message {
+    id: u64,
+    body: String,
+    created_at: Option<String>,
+    user: Option<User>,
+    #[serde(default)]
+    symbols: Vec<Symbol>,
+    entities: Option<Entities>,
+}
+
+#[derive(Debug, Deserialize)]
+struct User {
+    username: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Symbol {
+    symbol: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Entities {
+    sentiment: Option<Sentiment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Sentiment {
+    basic: String,
+}
+
+impl StockTwits {
+    /// Create a new StockTwits client
+    ///
+    /// Initializes the client with the StockTwits trending and symbol stream endpoints.
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "trending".to_string(),
+            "https://api.stocktwits.com/api/2/streams/trending.json".to_string(),
+        );
+        url_map.insert(
+            "symbol".to_string(),
+            "https://api.stocktwits.com/api/2/streams/symbol/{symbol}.json".to_string(),
+        );
+
+        Self {
+            url_map,
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::StockTwits),
+        }
+    }
+
+    /// Get trending messages across all symbols
+    pub async fn trending(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("trending").await
+    }
+
+    /// Get the message stream for a single symbol
+    ///
+    /// # Arguments
+    /// * `symbol` - Stock ticker symbol (e.g., "AAPL", "TSLA"), validated with
+    ///   `Symbol::parse()`
+    pub async fn symbol(&self, symbol: &str) -> Result<Vec<NewsArticle>> {
+        let symbol = symbols::Symbol::parse(symbol)?;
+        let url = self
+            .url_map
+            .get("symbol")
+            .ok_or_else(|| FanError::InvalidUrl("Symbol URL not found".to_string()))?
+            .replace("{symbol}", &symbol.to_string());
+
+        self.fetch_feed_by_url(&url).await
+    }
+
+    /// Convert a StockTwits message into a `NewsArticle`
+    fn message_to_article(message: Message) -> NewsArticle {
+        let mut article = NewsArticle::new();
+
+        article.title = Some(truncate_title(&message.body));
+        article.description = Some(message.body);
+        article.pub_date = message.created_at;
+        article.guid = Some(message.id.to_string());
+
+        if let Some(user) = message.user {
+            article.link = Some(format!(
+                "https://stocktwits.com/{}/message/{}",
+                user.username, message.id
+            ));
+            article.author = Some(user.username);
+        }
+
+        if !message.symbols.is_empty() {
+            let symbols: Vec<String> = message.symbols.into_iter().map(|s| s.symbol).collect();
+            article
+                .extra_fields
+                .insert("symbols".to_string(), symbols.join(",").into());
+        }
+
+        if let Some(sentiment) = message.entities.and_then(|e| e.sentiment) {
+            article
+                .extra_fields
+                .insert("sentiment".to_string(), sentiment.basic.into());
+        }
+
+        article
+    }
+}
+
+/// Shorten a message body to a single-line title
+fn truncate_title(body: &str) -> String {
+    let line = body.lines().next().unwrap_or_default().trim();
+    if line.chars().count() <= TITLE_MAX_CHARS {
+        line.to_string()
+    } else {
+        let truncated: String = line.chars().take(TITLE_MAX_CHARS).collect();
+        format!("{}…", truncated.trim_end())
+    }
+}
+
+#[async_trait]
+impl NewsSource for StockTwits {
+    fn name(&self) -> &'static str {
+        "StockTwits"
+    }
+
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec!["trending"]
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for StockTwits {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url: topics are named endpoints rather than a URL pattern
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        self.url_map()
+            .get(topic)
+            .filter(|_| topic != "symbol")
+            .cloned()
+            .ok_or_else(|| FanError::InvalidUrl(format!("Invalid topic: {}", topic)))
+    }
+
+    // Override parse_body: StockTwits returns JSON instead of RSS
+    fn parse_body(&self, body: &str) -> Result<Vec<NewsArticle>> {
+        let response: StreamResponse = serde_json::from_str(body)?;
+
+        if let Some(error) = response.errors.first() {
+            return Err(FanError::FeedParsing(format!(
+                "StockTwits API error: {}",
+                error.message
+            )));
+        }
+
+        Ok(response
+            .messages
+            .into_iter()
+            .map(Self::message_to_article)
+            .collect())
+    }
+
+    // Override streams_body: JSON bodies are parsed in one piece
+    fn streams_body(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+
+    const SAMPLE: &str = r#"{
+        "response": {"status": 200},
+        "messages": [
+            {
+                "id": 571234,
+                "body": "$AAPL breaking out above the 50 day, $MSFT following",
+                "created_at": "2024-05-01T14:30:00Z",
+                "user": {"id": 1, "username": "chartwatcher"},
+                "symbols": [{"symbol": "AAPL"}, {"symbol": "MSFT"}],
+                "entities": {"sentiment": {"basic": "Bullish"}}
+            },
+            {
+                "id": 571235,
+                "body": "quiet tape today",
+                "created_at": "2024-05-01T14:31:00Z",
+                "user": {"id": 2, "username": "daytrader"},
+                "symbols": [],
+                "entities": {"sentiment": null}
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_parse_stream_messages() {
+        let source = StockTwits::new(Client::new());
+        let articles = source.parse_body(SAMPLE).unwrap();
+
+        assert_eq!(articles.len(), 2);
+        let first = &articles[0];
+        assert_eq!(first.author.as_deref(), Some("chartwatcher"));
+        assert_eq!(first.guid.as_deref(), Some("571234"));
+        assert_eq!(first.pub_date.as_deref(), Some("2024-05-01T14:30:00Z"));
+        assert_eq!(
+            first.link.as_deref(),
+            Some("https://stocktwits.com/chartwatcher/message/571234")
+        );
+        assert_eq!(first.extra_fields.get("symbols").unwrap(), "AAPL,MSFT");
+        assert_eq!(first.extra_fields.get("sentiment").unwrap(), "Bullish");
+
+        let second = &articles[1];
+        assert!(!second.extra_fields.contains_key("symbols"));
+        assert!(!second.extra_fields.contains_key("sentiment"));
+    }
+
+    #[test]
+    fn test_parse_api_error() {
+        let source = StockTwits::new(Client::new());
+        let body = r#"{"response": {"status": 404}, "errors": [{"message": "Symbol not found"}]}"#;
+        assert!(matches!(
+            source.parse_body(body),
+            Err(FanError::FeedParsing(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_symbol_is_not_fetched() {
+        let source = StockTwits::new(Client::new());
+        assert!(matches!(
+            source.symbol("AAPL.json?x=").await,
+            Err(FanError::InvalidSymbol(_))
+        ));
+    }
+
+    #[test]
+    fn test_truncate_title() {
+        let long = "x".repeat(200);
+        assert_eq!(truncate_title(&long).chars().count(), TITLE_MAX_CHARS + 1);
+        assert_eq!(truncate_title("short\nsecond line"), "short");
+    }
+}
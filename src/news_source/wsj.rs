@@ -1,18 +1,26 @@
-use crate::error::Result;
-use crate::news_source::NewsSource;
+use crate::error::{FanError, Result};
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
 use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::sector::Sector;
+use crate::topic_group::TopicGroup;
 use crate::types::{NewsArticle, SourceConfig};
 use async_trait::async_trait;
-use reqwest::Client;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Podcast feeds, served from a different host than the news feeds
+const PODCAST_TOPICS: [&str; 3] = ["whats-news", "the-journal", "your-money-briefing"];
 
 /// Wall Street Journal news client
 ///
 /// Provides access to Wall Street Journal RSS feeds including opinions, world news,
-/// business, markets, technology, and lifestyle content.
+/// business, markets, technology, and lifestyle content, plus its podcasts, whose
+/// episodes are available through `NewsArticle::audio()`.
 pub struct WallStreetJournal {
     url_map: HashMap<String, String>,
-    client: Client,
+    client: Arc<dyn HttpClient>,
     parser: NewsParser,
 }
 
@@ -20,7 +28,7 @@ impl WallStreetJournal {
     /// Create a new Wall Street Journal client
     ///
     /// Initializes the client with WSJ RSS feed URL patterns.
-    pub fn new(client: Client) -> Self {
+    pub fn new(client: impl HttpClient + 'static) -> Self {
         Self::with_config(
             client,
             SourceConfig::new("https://feeds.a.dj.com/rss/{topic}.xml"),
@@ -32,14 +40,18 @@ impl WallStreetJournal {
     /// # Arguments
     /// * `client` - HTTP client for making requests
     /// * `config` - Source configuration (only base_url is used)
-    pub fn with_config(client: Client, config: SourceConfig) -> Self {
+    pub fn with_config(client: impl HttpClient + 'static, config: SourceConfig) -> Self {
         let mut url_map = HashMap::new();
         url_map.insert("base".to_string(), config.base_url.clone());
+        url_map.insert(
+            "podcast".to_string(),
+            "https://video-api.wsj.com/podcast/rss/wsj/{topic}".to_string(),
+        );
 
         Self {
             url_map,
-            client,
-            parser: NewsParser::new("wsj"),
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::WallStreetJournal),
         }
     }
 
@@ -72,28 +84,29 @@ impl WallStreetJournal {
     pub async fn lifestyle(&self) -> Result<Vec<NewsArticle>> {
         self.fetch_topic("RSSLifestyle").await
     }
-}
 
-#[async_trait]
-impl NewsSource for WallStreetJournal {
-    fn name(&self) -> &'static str {
-        "Wall Street Journal"
+    /// Get What's News podcast episodes
+    pub async fn whats_news_podcast(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("whats-news").await
     }
 
-    fn url_map(&self) -> &HashMap<String, String> {
-        &self.url_map
+    /// Get The Journal podcast episodes
+    pub async fn the_journal_podcast(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("the-journal").await
     }
 
-    fn client(&self) -> &Client {
-        &self.client
+    /// Get Your Money Briefing podcast episodes
+    pub async fn your_money_briefing_podcast(&self) -> Result<Vec<NewsArticle>> {
+        self.fetch_topic("your-money-briefing").await
     }
+}
 
-    fn parser(&self) -> &NewsParser {
-        &self.parser
+#[async_trait]
+impl NewsSource for WallStreetJournal {
+    fn name(&self) -> &'static str {
+        "Wall Street Journal"
     }
 
-    // Uses default fetch_topic implementation (simple pattern substitution)
-
     fn available_topics(&self) -> Vec<&'static str> {
         vec![
             "RSSOpinion",
@@ -102,14 +115,70 @@ impl NewsSource for WallStreetJournal {
             "RSSMarketsMain",
             "RSSWSJD",
             "RSSLifestyle",
+            "whats-news",
+            "the-journal",
+            "your-money-briefing",
         ]
     }
+
+    fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+        match group {
+            TopicGroup::Markets => vec!["RSSMarketsMain"],
+            TopicGroup::Tech => vec!["RSSWSJD"],
+            TopicGroup::Podcasts => vec!["whats-news", "your-money-briefing"],
+            _ => vec![],
+        }
+    }
+
+    fn sector_topics(&self, sector: Sector) -> Vec<&'static str> {
+        match sector {
+            Sector::Technology => vec!["RSSWSJD"],
+            _ => vec![],
+        }
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for WallStreetJournal {
+    fn url_map(&self) -> &HashMap<String, String> {
+        &self.url_map
+    }
+
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    fn parser(&self) -> &NewsParser {
+        &self.parser
+    }
+
+    // Override build_topic_url: podcasts use their own URL pattern
+    fn build_topic_url(&self, topic: &str) -> Result<String> {
+        let key = if PODCAST_TOPICS.contains(&topic) {
+            "podcast"
+        } else {
+            "base"
+        };
+        let url = self
+            .url_map
+            .get(key)
+            .ok_or_else(|| FanError::InvalidUrl(format!("{} URL not found", key)))?;
+        Ok(url.replace("{topic}", topic))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    // Test imports
+    use reqwest::Client;
 
     #[tokio::test]
     async fn test_wsj_opinions() {
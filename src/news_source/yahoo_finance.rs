@@ -1,17 +1,20 @@
 use crate::error::Result;
-use crate::news_source::NewsSource;
+use crate::http::HttpClient;
+use crate::news_source::{FeedSource, NewsSource};
 use crate::parser::NewsParser;
+use crate::quirks::Source;
+use crate::symbols::Symbol;
 use crate::types::NewsArticle;
 use async_trait::async_trait;
-use reqwest::Client;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Yahoo Finance news client
 ///
 /// Provides access to Yahoo Finance RSS feeds for financial news and market updates.
 pub struct YahooFinance {
     url_map: HashMap<String, String>,
-    client: Client,
+    client: Arc<dyn HttpClient>,
     parser: NewsParser,
 }
 
@@ -20,7 +23,7 @@ impl YahooFinance {
     ///
     /// Initializes the client with Yahoo Finance RSS feed URLs.
     /// Note: The old feeds.finance.yahoo.com/rss/2.0 endpoint is no longer available.
-    pub fn new(client: Client) -> Self {
+    pub fn new(client: impl HttpClient + 'static) -> Self {
         let mut url_map = HashMap::new();
         url_map.insert(
             "base".to_string(),
@@ -29,8 +32,8 @@ impl YahooFinance {
 
         Self {
             url_map,
-            client,
-            parser: NewsParser::new("yahoo"),
+            client: Arc::new(client),
+            parser: NewsParser::for_source(Source::YahooFinance),
         }
     }
 
@@ -45,14 +48,19 @@ impl YahooFinance {
     /// * `symbols` - Array of stock symbols (e.g., ["AAPL", "GOOGL", "MSFT"])
     ///
     /// # Returns
-    /// News articles related to the specified symbols
+    /// News articles related to the specified symbols, or `FanError::InvalidSymbol`
+    /// for a symbol that is not a ticker
     pub async fn headline(&self, symbols: &[&str]) -> Result<Vec<NewsArticle>> {
         let base_url = self
             .url_map
             .get("base")
             .ok_or_else(|| crate::error::FanError::InvalidUrl("Base URL not found".to_string()))?;
 
-        let symbols_str = symbols.join(",");
+        let symbols_str = symbols
+            .iter()
+            .map(|symbol| Ok(Symbol::parse(symbol)?.yahoo()))
+            .collect::<Result<Vec<_>>>()?
+            .join(",");
         let url = format!("{}/headline?s={}", base_url, symbols_str);
 
         self.fetch_feed_by_url(&url).await
@@ -70,12 +78,27 @@ impl NewsSource for YahooFinance {
         "Yahoo Finance"
     }
 
+    fn available_topics(&self) -> Vec<&'static str> {
+        vec!["topstories", "headlines"]
+    }
+
+    async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+        self.fetch_feed_topic(topic).await
+    }
+
+    fn feed(&self) -> Option<&dyn FeedSource> {
+        Some(self)
+    }
+}
+
+#[async_trait]
+impl FeedSource for YahooFinance {
     fn url_map(&self) -> &HashMap<String, String> {
         &self.url_map
     }
 
-    fn client(&self) -> &Client {
-        &self.client
+    fn http_client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
     }
 
     fn parser(&self) -> &NewsParser {
@@ -91,10 +114,4 @@ impl NewsSource for YahooFinance {
 
         Ok(format!("{}/{}", base_url, topic))
     }
-
-    // Uses default fetch_topic implementation
-
-    fn available_topics(&self) -> Vec<&'static str> {
-        vec!["topstories", "headlines"]
-    }
 }
@@ -0,0 +1,393 @@
+//! Batches of new articles in object storage (feature "object-store")
+//!
+//! [`ObjectStoreExporter`] is an `ArticleSink` collecting new articles and writing
+//! them in batches to S3, GCS, Azure Blob Storage, or any other `object_store`
+//! backend, as NDJSON or, with the "parquet" feature, Parquet. Objects are laid out
+//! in Hive-style partitions by write date and source, which Athena, BigQuery, Spark,
+//! and DuckDB read as columns:
+//!
+//! ```text
+//! news/dt=2024-06-01/source=wall-street-journal/143015123-0001.ndjson
+//! news/dt=2024-06-01/source=cnbc/143015123-0002.ndjson
+//! ```
+//!
+//! A batch is written once the oldest buffered article has waited the batch interval,
+//! checked whenever articles arrive, or once the buffer holds the maximum number of
+//! articles, and whatever is left when the poller shuts down.
+
+#[cfg(feature = "parquet")]
+use crate::columnar::write_parquet_to;
+use crate::error::Result;
+use crate::poller::{ArticleSink, NewArticles};
+use crate::types::NewsArticle;
+use async_trait::async_trait;
+use chrono::Utc;
+use object_store::path::Path;
+use object_store::{ObjectStore, PutPayload};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::time::Instant;
+
+pub use object_store;
+
+/// Longest an article is buffered unless set with `ObjectStoreExporter::with_interval()`
+pub const DEFAULT_BATCH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Articles buffered before a batch is written, unless set with
+/// `ObjectStoreExporter::with_max_articles()`
+pub const DEFAULT_MAX_BATCH_ARTICLES: usize = 10_000;
+
+/// Encoding of the batch objects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchFormat {
+    /// One JSON article per line
+    #[default]
+    Ndjson,
+    /// Parquet with the columns of `columnar::schema()`
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+impl BatchFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            BatchFormat::Ndjson => "ndjson",
+            #[cfg(feature = "parquet")]
+            BatchFormat::Parquet => "parquet",
+        }
+    }
+
+    fn encode(self, articles: &[NewsArticle]) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        match self {
+            BatchFormat::Ndjson => {
+                for article in articles {
+                    serde_json::to_writer(&mut bytes, article)?;
+                    bytes.push(b'\n');
+                }
+            }
+            #[cfg(feature = "parquet")]
+            BatchFormat::Parquet => {
+                write_parquet_to(articles, &mut bytes)?;
+            }
+        }
+        Ok(bytes)
+    }
+}
+
+/// Articles waiting for the next batch
+#[derive(Default)]
+struct Buffer {
+    /// Source and article, in arrival order
+    articles: Vec<(String, NewsArticle)>,
+    /// When the oldest buffered article arrived
+    since: Option<Instant>,
+}
+
+/// Sink writing new articles to object storage in partitioned batches
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use finance_news_aggregator_rs::object_export::{BatchFormat, ObjectStoreExporter};
+/// use finance_news_aggregator_rs::object_export::object_store::aws::AmazonS3Builder;
+/// use std::time::Duration;
+///
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let s3 = AmazonS3Builder::from_env().with_bucket_name("market-data").build()?;
+/// let exporter = ObjectStoreExporter::new(s3)
+///     .with_prefix("news")
+///     .with_format(BatchFormat::Ndjson)
+///     .with_interval(Duration::from_secs(600));
+/// // Poller::new(..).with_sink(exporter)
+/// # Ok(())
+/// # }
+/// ```
+pub struct ObjectStoreExporter {
+    store: Box<dyn ObjectStore>,
+    prefix: String,
+    format: BatchFormat,
+    interval: Duration,
+    max_articles: usize,
+    buffer: Mutex<Buffer>,
+    /// Numbers the objects written, so names within a millisecond differ
+    sequence: AtomicU64,
+}
+
+impl ObjectStoreExporter {
+    /// Write batches to the root of `store`
+    pub fn new(store: impl ObjectStore) -> Self {
+        Self {
+            store: Box::new(store),
+            prefix: String::new(),
+            format: BatchFormat::default(),
+            interval: DEFAULT_BATCH_INTERVAL,
+            max_articles: DEFAULT_MAX_BATCH_ARTICLES,
+            buffer: Mutex::new(Buffer::default()),
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    /// Write batches under a path within the store, such as `raw/news`
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into().trim_matches('/').to_string();
+        self
+    }
+
+    pub fn with_format(mut self, format: BatchFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Write a batch once its oldest article has waited this long
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Write a batch once this many articles are buffered (at least one)
+    pub fn with_max_articles(mut self, max_articles: usize) -> Self {
+        self.max_articles = max_articles.max(1);
+        self
+    }
+
+    /// Number of articles waiting for the next batch
+    pub fn buffered(&self) -> usize {
+        self.buffer.lock().unwrap().articles.len()
+    }
+
+    /// Write the buffered articles now, one object per source
+    ///
+    /// Articles of objects that fail to upload stay buffered for the next batch.
+    ///
+    /// # Returns
+    /// Paths of the objects written
+    pub async fn write_batch(&self) -> Result<Vec<Path>> {
+        let articles = {
+            let mut buffer = self.buffer.lock().unwrap();
+            buffer.since = None;
+            std::mem::take(&mut buffer.articles)
+        };
+        let mut by_source: BTreeMap<String, Vec<NewsArticle>> = BTreeMap::new();
+        for (source, article) in articles {
+            by_source.entry(source).or_default().push(article);
+        }
+
+        let now = Utc::now();
+        let mut paths = Vec::new();
+        let mut groups = by_source.into_iter();
+        while let Some((source, articles)) = groups.next() {
+            let sequence = self.sequence.fetch_add(1, Ordering::Relaxed) + 1;
+            let name = format!(
+                "dt={}/source={}/{}-{:04}.{}",
+                now.format("%Y-%m-%d"),
+                partition_value(&source),
+                now.format("%H%M%S%3f"),
+                sequence,
+                self.format.extension()
+            );
+            let path = match self.prefix.as_str() {
+                "" => Path::from(name),
+                prefix => Path::from(format!("{}/{}", prefix, name)),
+            };
+            let written = match self.format.encode(&articles) {
+                Ok(bytes) => self
+                    .store
+                    .put(&path, PutPayload::from(bytes))
+                    .await
+                    .map_err(Into::into),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = written {
+                let unwritten = std::iter::once((source, articles)).chain(groups);
+                self.requeue(unwritten);
+                return Err(e);
+            }
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Put articles back at the front of the buffer
+    fn requeue(&self, unwritten: impl Iterator<Item = (String, Vec<NewsArticle>)>) {
+        let mut articles: Vec<(String, NewsArticle)> = unwritten
+            .flat_map(|(source, articles)| {
+                articles
+                    .into_iter()
+                    .map(move |article| (source.clone(), article))
+            })
+            .collect();
+        let mut buffer = self.buffer.lock().unwrap();
+        articles.append(&mut buffer.articles);
+        buffer.articles = articles;
+        buffer.since = Some(Instant::now());
+    }
+}
+
+#[async_trait]
+impl ArticleSink for ObjectStoreExporter {
+    async fn deliver(&self, batch: &NewArticles) -> Result<()> {
+        let due = {
+            let mut buffer = self.buffer.lock().unwrap();
+            buffer.articles.extend(
+                batch
+                    .articles
+                    .iter()
+                    .map(|article| (batch.source.clone(), article.clone())),
+            );
+            let since = *buffer.since.get_or_insert_with(Instant::now);
+            !buffer.articles.is_empty()
+                && (buffer.articles.len() >= self.max_articles || since.elapsed() >= self.interval)
+        };
+        if due {
+            self.write_batch().await?;
+        }
+        Ok(())
+    }
+
+    async fn flush(&self) -> Result<()> {
+        if self.buffered() > 0 {
+            self.write_batch().await?;
+        }
+        Ok(())
+    }
+}
+
+/// A source name as a partition value: lowercase, with runs of other characters
+/// than letters and digits as `-`
+fn partition_value(source: &str) -> String {
+    let value = source
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if value.is_empty() {
+        "unknown".to_string()
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use object_store::memory::InMemory;
+    use std::sync::Arc;
+
+    fn batch(source: &str, titles: &[&str]) -> NewArticles {
+        let articles = titles
+            .iter()
+            .map(|title| {
+                let mut article = NewsArticle::new();
+                article.title = Some(title.to_string());
+                article
+            })
+            .collect();
+        NewArticles {
+            source: source.to_string(),
+            topic: "news".to_string(),
+            articles,
+        }
+    }
+
+    #[test]
+    fn test_partition_value() {
+        assert_eq!(
+            partition_value("Wall Street Journal"),
+            "wall-street-journal"
+        );
+        assert_eq!(partition_value("S&P Global / Platts"), "s-p-global-platts");
+        assert_eq!(partition_value("  "), "unknown");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_batches() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let exporter = ObjectStoreExporter::new(Arc::clone(&store))
+            .with_prefix("/news/")
+            .with_max_articles(3)
+            .with_interval(Duration::from_secs(60));
+
+        exporter
+            .deliver(&batch("Wall Street Journal", &["Fed holds"]))
+            .await
+            .unwrap();
+        assert_eq!(exporter.buffered(), 1);
+        exporter
+            .deliver(&batch("CNBC", &["Nvidia beats", "Oil slips"]))
+            .await
+            .unwrap();
+        assert_eq!(exporter.buffered(), 0);
+
+        let mut paths: Vec<String> = store
+            .list(None)
+            .map_ok(|meta| meta.location.to_string())
+            .try_collect()
+            .await
+            .unwrap();
+        paths.sort();
+        let dt = format!("news/dt={}/", Utc::now().format("%Y-%m-%d"));
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].starts_with(&format!("{}source=cnbc/", dt)));
+        assert!(paths[0].ends_with("-0001.ndjson"));
+        assert!(paths[1].starts_with(&format!("{}source=wall-street-journal/", dt)));
+
+        let cnbc = store.get(&Path::from(paths[0].as_str())).await.unwrap();
+        let cnbc = cnbc.bytes().await.unwrap();
+        let titles: Vec<String> = std::str::from_utf8(&cnbc)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let article: NewsArticle = serde_json::from_str(line).unwrap();
+                article.title.unwrap()
+            })
+            .collect();
+        assert_eq!(titles, vec!["Nvidia beats", "Oil slips"]);
+
+        // An article waiting out the interval is written with the next delivery
+        exporter
+            .deliver(&batch("CNBC", &["Apple sets date"]))
+            .await
+            .unwrap();
+        tokio::time::advance(Duration::from_secs(61)).await;
+        exporter.deliver(&batch("CNBC", &[])).await.unwrap();
+        assert_eq!(exporter.buffered(), 0);
+
+        exporter
+            .deliver(&batch("CNBC", &["Oil rebounds"]))
+            .await
+            .unwrap();
+        exporter.flush().await.unwrap();
+        assert_eq!(
+            store
+                .list(None)
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap()
+                .len(),
+            4
+        );
+    }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn test_parquet_batches() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let exporter =
+            ObjectStoreExporter::new(Arc::clone(&store)).with_format(BatchFormat::Parquet);
+        exporter
+            .deliver(&batch("CNBC", &["Nvidia beats"]))
+            .await
+            .unwrap();
+
+        let paths = exporter.write_batch().await.unwrap();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].extension(), Some("parquet"));
+        let object = store.get(&paths[0]).await.unwrap().bytes().await.unwrap();
+        assert!(object.starts_with(b"PAR1"));
+    }
+}
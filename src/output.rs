@@ -0,0 +1,511 @@
+//! JSON and text output shapes for articles
+//!
+//! `NewsArticle` serializes with its Rust field names, writes `null` for missing
+//! fields, and nests source-specific fields under `extra_fields`. `JsonOptions`
+//! reshapes that output to match a downstream schema without a post-processing pass,
+//! and `ArticleTemplate` renders articles as lines of text for piping into other tools.
+
+use crate::error::Result;
+use crate::types::NewsArticle;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+
+/// Name of the `NewsArticle` field holding source-specific fields
+const EXTRA_FIELDS: &str = "extra_fields";
+/// Name of the field added by `JsonOptions::with_source_info()`
+const SOURCE_INFO: &str = "source_info";
+
+/// Options for the JSON shape of exported articles
+///
+/// The default keeps `NewsArticle`'s own serialization. With any option set, keys are
+/// written in alphabetical order.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::NewsArticle;
+/// use finance_news_aggregator_rs::output::JsonOptions;
+///
+/// let mut article = NewsArticle::new();
+/// article.title = Some("Fed holds rates".to_string());
+/// article.pub_date = Some("Wed, 01 May 2024 18:00:00 GMT".to_string());
+/// article
+///     .extra_fields
+///     .insert("sentiment_score".to_string(), "0.25".into());
+///
+/// let options = JsonOptions::new()
+///     .with_camel_case(true)
+///     .with_skip_nulls(true)
+///     .with_flatten_extras(true);
+/// let json = options.to_value(&article).unwrap();
+/// assert_eq!(json["pubDate"], "Wed, 01 May 2024 18:00:00 GMT");
+/// assert_eq!(json["sentimentScore"], "0.25");
+/// assert!(json.get("link").is_none());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JsonOptions {
+    camel_case: bool,
+    skip_nulls: bool,
+    flatten_extras: bool,
+    source_info: bool,
+}
+
+impl JsonOptions {
+    /// Create options producing `NewsArticle`'s own serialization
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write keys in camelCase ("pubDate", "extraFields") instead of snake_case
+    pub fn with_camel_case(mut self, camel_case: bool) -> Self {
+        self.camel_case = camel_case;
+        self
+    }
+
+    /// Omit fields that are `null` instead of writing them out
+    pub fn with_skip_nulls(mut self, skip_nulls: bool) -> Self {
+        self.skip_nulls = skip_nulls;
+        self
+    }
+
+    /// Move `extra_fields` entries to the top level of each article
+    ///
+    /// An extra field never replaces a standard field of the same name; such entries
+    /// stay under `extra_fields`, which is otherwise left out.
+    pub fn with_flatten_extras(mut self, flatten_extras: bool) -> Self {
+        self.flatten_extras = flatten_extras;
+        self
+    }
+
+    /// Add the outlet metadata of `NewsArticle::source_info()` as a `source_info`
+    /// object, for articles of built-in sources
+    pub fn with_source_info(mut self, source_info: bool) -> Self {
+        self.source_info = source_info;
+        self
+    }
+
+    pub fn camel_case(&self) -> bool {
+        self.camel_case
+    }
+
+    pub fn skip_nulls(&self) -> bool {
+        self.skip_nulls
+    }
+
+    pub fn flatten_extras(&self) -> bool {
+        self.flatten_extras
+    }
+
+    pub fn source_info(&self) -> bool {
+        self.source_info
+    }
+
+    /// Wrap an article so that serializing it produces this shape
+    pub fn shape<'a>(&self, article: &'a NewsArticle) -> Shaped<'a> {
+        Shaped {
+            article,
+            options: *self,
+        }
+    }
+
+    /// Wrap articles so that serializing them produces a JSON array in this shape
+    pub fn shape_all<'a>(&self, articles: &'a [NewsArticle]) -> Vec<Shaped<'a>> {
+        articles.iter().map(|article| self.shape(article)).collect()
+    }
+
+    /// Serialize an article into a JSON value in this shape
+    pub fn to_value(&self, article: &NewsArticle) -> Result<Value> {
+        match serde_json::to_value(article)? {
+            Value::Object(mut fields) => {
+                if let Some(info) = article.source_info().filter(|_| self.source_info) {
+                    fields.insert(SOURCE_INFO.to_string(), serde_json::to_value(info)?);
+                }
+                Ok(Value::Object(self.reshape_article(fields)))
+            }
+            value => Ok(value),
+        }
+    }
+
+    /// Serialize articles into a pretty-printed JSON array in this shape
+    pub fn to_string_pretty(&self, articles: &[NewsArticle]) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.shape_all(articles))?)
+    }
+
+    /// Apply the options to the fields of a serialized article
+    fn reshape_article(&self, fields: Map<String, Value>) -> Map<String, Value> {
+        let mut extras = Map::new();
+        let mut shaped = Map::new();
+        for (key, value) in fields {
+            if key == EXTRA_FIELDS {
+                if let Value::Object(map) = value {
+                    extras = map;
+                }
+            } else if !(self.skip_nulls && value.is_null()) {
+                shaped.insert(self.key(key), self.reshape(value));
+            }
+        }
+
+        if self.flatten_extras {
+            let mut clashing = Map::new();
+            for (key, value) in extras {
+                let flat_key = self.key(key.clone());
+                if shaped.contains_key(&flat_key) {
+                    clashing.insert(key, value);
+                } else {
+                    shaped.insert(flat_key, value);
+                }
+            }
+            extras = clashing;
+        }
+        // Extra field names are data rather than schema, so they are kept as they are
+        if !self.flatten_extras || !extras.is_empty() {
+            shaped.insert(self.key(EXTRA_FIELDS.to_string()), Value::Object(extras));
+        }
+        shaped
+    }
+
+    /// Apply key casing and null skipping to a nested value, such as an enclosure
+    fn reshape(&self, value: Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .filter(|(_, value)| !(self.skip_nulls && value.is_null()))
+                    .map(|(key, value)| (self.key(key), self.reshape(value)))
+                    .collect(),
+            ),
+            Value::Array(values) => Value::Array(
+                values
+                    .into_iter()
+                    .map(|value| self.reshape(value))
+                    .collect(),
+            ),
+            value => value,
+        }
+    }
+
+    fn key(&self, key: String) -> String {
+        if self.camel_case && key.contains('_') {
+            to_camel_case(&key)
+        } else {
+            key
+        }
+    }
+}
+
+/// An article paired with the options it serializes with
+///
+/// Created by `JsonOptions::shape()`, for embedding articles in a larger structure
+/// or writing them with any serde serializer.
+#[derive(Debug, Clone, Copy)]
+pub struct Shaped<'a> {
+    article: &'a NewsArticle,
+    options: JsonOptions,
+}
+
+impl Serialize for Shaped<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        if self.options == JsonOptions::default() {
+            return self.article.serialize(serializer);
+        }
+        self.options
+            .to_value(self.article)
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+/// Renders an article as text, replacing `{field}` placeholders with its fields
+///
+/// A field is a path into the article's JSON serialization, with `.` between keys
+/// and array indices: `{title}`, `{enclosures.0.url}`, `{extra_fields.media:credit}`.
+/// A bare name that is not a standard field reads the extra field of that name, so
+/// `{sentiment_score}` works too. Two fields are computed rather than serialized:
+/// `{published}`, the publish date in RFC 3339 UTC, and `{age}`, such as "23m ago".
+/// Missing fields render empty; `{{` and `}}` write literal braces.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::NewsArticle;
+/// use finance_news_aggregator_rs::output::ArticleTemplate;
+///
+/// let mut article = NewsArticle::new();
+/// article.source = Some("CNBC".to_string());
+/// article.title = Some("Fed holds rates".to_string());
+/// article.pub_date = Some("Wed, 01 May 2024 18:00:00 GMT".to_string());
+///
+/// let template = ArticleTemplate::new("{source} | {published} | {title} | {link}");
+/// assert_eq!(
+///     template.render(&article),
+///     "CNBC | 2024-05-01T18:00:00+00:00 | Fed holds rates | "
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArticleTemplate {
+    parts: Vec<TemplatePart>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplatePart {
+    Text(String),
+    Field(String),
+}
+
+impl ArticleTemplate {
+    /// Parse a template; a `{` without a closing `}` is written as it is
+    pub fn new(template: &str) -> Self {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut rest = template;
+        while let Some(c) = rest.chars().next() {
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                text.push(c);
+                rest = &rest[2..];
+            } else if let Some(end) = rest.strip_prefix('{').and_then(|field| field.find('}')) {
+                if !text.is_empty() {
+                    parts.push(TemplatePart::Text(std::mem::take(&mut text)));
+                }
+                parts.push(TemplatePart::Field(rest[1..=end].trim().to_string()));
+                rest = &rest[end + 2..];
+            } else {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        if !text.is_empty() {
+            parts.push(TemplatePart::Text(text));
+        }
+        Self { parts }
+    }
+
+    /// Fields the template reads, in order
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|part| match part {
+            TemplatePart::Field(field) => Some(field.as_str()),
+            TemplatePart::Text(_) => None,
+        })
+    }
+
+    /// Render an article
+    pub fn render(&self, article: &NewsArticle) -> String {
+        let json = serde_json::to_value(article).unwrap_or_default();
+        self.parts
+            .iter()
+            .map(|part| match part {
+                TemplatePart::Text(text) => text.clone(),
+                TemplatePart::Field(field) => field_text(article, &json, field).unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+/// Text of one field of an article, as an `ArticleTemplate` placeholder reads it
+///
+/// Returns `None` when the article does not have the field.
+pub fn select_field(article: &NewsArticle, path: &str) -> Option<String> {
+    let json = serde_json::to_value(article).ok()?;
+    field_text(article, &json, path)
+}
+
+/// Names a topic template expands to for an article of a feed, as pub/sub sinks use
+///
+/// `{source}` and `{topic}` are the feed's. A template with `{ticker}` expands once
+/// per ticker the article is about, and to nothing for articles without one. `escape`
+/// makes each value safe to place in a name.
+#[cfg(any(feature = "mqtt", feature = "nats"))]
+pub(crate) fn expand_topics(
+    templates: &[String],
+    source: &str,
+    topic: &str,
+    article: &NewsArticle,
+    escape: fn(&str) -> String,
+) -> Vec<String> {
+    let tickers = crate::export::tickers(article);
+    let mut names = Vec::new();
+    for template in templates {
+        let template = template
+            .replace("{source}", &escape(source))
+            .replace("{topic}", &escape(topic));
+        if template.contains("{ticker}") {
+            names.extend(
+                tickers
+                    .iter()
+                    .map(|ticker| template.replace("{ticker}", &escape(ticker))),
+            );
+        } else {
+            names.push(template);
+        }
+    }
+    names
+}
+
+fn field_text(article: &NewsArticle, json: &Value, path: &str) -> Option<String> {
+    match path {
+        "published" => {
+            return article
+                .published_at()
+                .map(|published| published.to_rfc3339())
+                .or_else(|| article.pub_date.clone());
+        }
+        "age" => return article.relative_time(),
+        _ => {}
+    }
+    let value = path
+        .split('.')
+        .try_fold(json, |value, key| match value {
+            Value::Array(values) => values.get(key.parse::<usize>().ok()?),
+            value => value.get(key),
+        })
+        .or_else(|| json.get(EXTRA_FIELDS)?.get(path))?;
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text.clone()),
+        Value::Array(values) if values.iter().all(Value::is_string) => Some(
+            values
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        value => Some(value.to_string()),
+    }
+}
+
+/// Convert a snake_case key to camelCase
+fn to_camel_case(key: &str) -> String {
+    let mut camel = String::with_capacity(key.len());
+    let mut upper = false;
+    for c in key.chars() {
+        if c == '_' {
+            upper = !camel.is_empty();
+        } else if upper {
+            camel.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Enclosure;
+    use serde_json::json;
+
+    fn article() -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some("Apple beats estimates".to_string());
+        article.pub_date = Some("2024-05-01T20:30:00Z".to_string());
+        article
+            .enclosures
+            .push(Enclosure::new("https://example.com/a.mp3"));
+        article
+            .extra_fields
+            .insert("sentiment_score".to_string(), "0.4".into());
+        article
+            .extra_fields
+            .insert("title".to_string(), "Apple Q2".into());
+        article
+    }
+
+    #[test]
+    fn test_default_options_match_plain_serialization() {
+        let article = article();
+        assert_eq!(
+            serde_json::to_string(&JsonOptions::new().shape(&article)).unwrap(),
+            serde_json::to_string(&article).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_camel_case_and_skip_nulls() {
+        let options = JsonOptions::new()
+            .with_camel_case(true)
+            .with_skip_nulls(true);
+        let json = options.to_value(&article()).unwrap();
+
+        assert_eq!(json["pubDate"], "2024-05-01T20:30:00Z");
+        assert!(json.get("link").is_none());
+        assert!(json.get("pub_date").is_none());
+        assert_eq!(
+            json["enclosures"],
+            json!([{"url": "https://example.com/a.mp3"}])
+        );
+        // Extra field names are left alone unless flattened
+        assert_eq!(json["extraFields"]["sentiment_score"], "0.4");
+    }
+
+    #[test]
+    fn test_flatten_extras_keeps_standard_fields() {
+        let options = JsonOptions::new()
+            .with_camel_case(true)
+            .with_flatten_extras(true);
+        let json = options.to_value(&article()).unwrap();
+
+        assert_eq!(json["sentimentScore"], "0.4");
+        assert_eq!(json["title"], "Apple beats estimates");
+        assert_eq!(json["extraFields"], json!({"title": "Apple Q2"}));
+        assert_eq!(json["link"], Value::Null);
+    }
+
+    #[test]
+    fn test_source_info() {
+        let mut article = article();
+        let options = JsonOptions::new().with_source_info(true);
+        assert!(
+            options
+                .to_value(&article)
+                .unwrap()
+                .get("source_info")
+                .is_none()
+        );
+
+        article.source = Some("MarketWatch".to_string());
+        let json = options.to_value(&article).unwrap();
+        assert_eq!(
+            json["source_info"]["home_url"],
+            "https://www.marketwatch.com"
+        );
+        assert_eq!(json["source_info"]["paywall"], "metered");
+        let json = options.with_camel_case(true).to_value(&article).unwrap();
+        assert_eq!(json["sourceInfo"]["displayName"], "MarketWatch");
+    }
+
+    #[test]
+    fn test_template_fields() {
+        let template = ArticleTemplate::new(
+            "{title}\t{published}\t{enclosures.0.url}\t{sentiment_score}\t{extra_fields.title}",
+        );
+        assert_eq!(
+            template.render(&article()),
+            "Apple beats estimates\t2024-05-01T20:30:00+00:00\thttps://example.com/a.mp3\t0.4\tApple Q2"
+        );
+        assert_eq!(
+            template.fields().collect::<Vec<_>>(),
+            [
+                "title",
+                "published",
+                "enclosures.0.url",
+                "sentiment_score",
+                "extra_fields.title"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_template_braces_and_missing_fields() {
+        let template = ArticleTemplate::new("{{{link}}} {author} {unclosed");
+        assert_eq!(template.render(&article()), "{}  {unclosed");
+        assert_eq!(select_field(&article(), "link"), None);
+        assert_eq!(select_field(&article(), "stale").as_deref(), Some("false"));
+    }
+
+    #[test]
+    fn test_to_camel_case() {
+        assert_eq!(to_camel_case("duration_seconds"), "durationSeconds");
+        assert_eq!(to_camel_case("media:credit"), "media:credit");
+        assert_eq!(to_camel_case("_private"), "private");
+    }
+}
@@ -1,8 +1,370 @@
+//! RSS and Atom parsing into `NewsArticle`s
+//!
+//! [`NewsParser`] reads a feed from a `&str` and does no I/O, so it builds without
+//! default features, with no reqwest or tokio. `NewsParser::parse_stream()`, which
+//! parses a body as it downloads, needs the `fetch` feature.
+
 use crate::error::{FanError, Result};
-use crate::types::NewsArticle;
+use crate::quirks::{Quirks, Source};
+use crate::types::{Enclosure, ExtraValue, NewsArticle};
+#[cfg(feature = "fetch")]
+use bytes::Bytes;
+#[cfg(feature = "fetch")]
+use futures::stream::{self, BoxStream, Stream, StreamExt};
 use quick_xml::Reader;
-use quick_xml::events::Event;
+use quick_xml::encoding::Decoder;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesRef, BytesStart, Event};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::time::Duration;
+#[cfg(feature = "fetch")]
+use tokio_util::io::StreamReader;
+
+/// Typographic quote entities replaced before parsing, with their ASCII replacements
+const QUOTE_ENTITIES: [(&str, &str); 8] = [
+    ("&#x2018;", "'"),  // Left single quotation mark
+    ("&#x2019;", "'"),  // Right single quotation mark
+    ("&#x201C;", "\""), // Left double quotation mark
+    ("&#x201D;", "\""), // Right double quotation mark
+    ("&#8216;", "'"),   // Left single quotation mark (decimal)
+    ("&#8217;", "'"),   // Right single quotation mark (decimal)
+    ("&#8220;", "\""),  // Left double quotation mark (decimal)
+    ("&#8221;", "\""),  // Right double quotation mark (decimal)
+];
+
+/// Tag names mapped onto `NewsArticle` fields, matched case-insensitively
+const ARTICLE_FIELDS: [(&str, Field); 8] = [
+    ("title", Field::Title),
+    ("link", Field::Link),
+    ("description", Field::Description),
+    ("pubdate", Field::PubDate),
+    ("guid", Field::Guid),
+    ("category", Field::Category),
+    ("author", Field::Author),
+    ("creator", Field::Author),
+];
+
+/// HTML named entities resolved for sources with `Quirks::html_entities`
+const HTML_ENTITIES: [(&str, &str); 16] = [
+    ("nbsp", " "),
+    ("lsquo", "'"),
+    ("rsquo", "'"),
+    ("ldquo", "\""),
+    ("rdquo", "\""),
+    ("ndash", "\u{2013}"),
+    ("mdash", "\u{2014}"),
+    ("hellip", "\u{2026}"),
+    ("bull", "\u{2022}"),
+    ("middot", "\u{b7}"),
+    ("copy", "\u{a9}"),
+    ("reg", "\u{ae}"),
+    ("trade", "\u{2122}"),
+    ("euro", "\u{20ac}"),
+    ("pound", "\u{a3}"),
+    ("yen", "\u{a5}"),
+];
+
+/// Length of the longest entry in `QUOTE_ENTITIES`
+#[cfg(feature = "fetch")]
+const MAX_QUOTE_ENTITY_LEN: usize = 8;
+
+/// Parsing state carried across XML events
+struct ItemState {
+    current_article: NewsArticle,
+    current_tag: String,
+    /// `extra_fields` key of the current element, its tag name with its prefix
+    current_key: String,
+    in_item: bool,
+    /// Whether the current element already produced text
+    in_text: bool,
+    guard: LimitGuard,
+}
+
+impl ItemState {
+    fn new(limits: ParserLimits) -> Self {
+        Self {
+            current_article: NewsArticle::new(),
+            current_tag: String::new(),
+            current_key: String::new(),
+            in_item: false,
+            in_text: false,
+            guard: LimitGuard::new(limits),
+        }
+    }
+}
+
+/// Replace typographic quote entities with their ASCII equivalents
+///
+/// Borrows the input unchanged when it contains no numeric character references.
+fn replace_quote_entities(content: &[u8]) -> Cow<'_, [u8]> {
+    if !content.windows(2).any(|w| w == b"&#") {
+        return Cow::Borrowed(content);
+    }
+
+    let mut result = Vec::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(pos) = rest.windows(2).position(|w| w == b"&#") {
+        result.extend_from_slice(&rest[..pos]);
+        let tail = &rest[pos..];
+        match QUOTE_ENTITIES
+            .iter()
+            .find(|(entity, _)| tail.starts_with(entity.as_bytes()))
+        {
+            Some((entity, replacement)) => {
+                result.extend_from_slice(replacement.as_bytes());
+                rest = &tail[entity.len()..];
+            }
+            None => {
+                result.extend_from_slice(b"&#");
+                rest = &tail[2..];
+            }
+        }
+    }
+    result.extend_from_slice(rest);
+
+    Cow::Owned(result)
+}
+
+/// Apply quote entity replacement to a chunked body
+///
+/// An entity split across two chunks is held back until the next chunk arrives, so
+/// the output matches replacing entities in the whole body at once.
+#[cfg(feature = "fetch")]
+fn filter_quote_entities<S>(chunks: S) -> impl Stream<Item = std::io::Result<Bytes>>
+where
+    S: Stream<Item = std::io::Result<Bytes>> + Unpin,
+{
+    stream::unfold(Some((chunks, Vec::new())), |state| async move {
+        let (mut chunks, mut pending) = state?;
+        match chunks.next().await {
+            Some(Ok(chunk)) => {
+                pending.extend_from_slice(&chunk);
+                // Hold back a trailing '&' that could start an incomplete entity
+                let tail_start = pending.len().saturating_sub(MAX_QUOTE_ENTITY_LEN - 1);
+                let split = pending[tail_start..]
+                    .iter()
+                    .rposition(|&b| b == b'&')
+                    .map(|i| tail_start + i)
+                    .filter(|&i| !pending[i..].contains(&b';'))
+                    .unwrap_or(pending.len());
+                let held = pending.split_off(split);
+                let ready = Bytes::from(replace_quote_entities(&pending).into_owned());
+                Some((Ok(ready), Some((chunks, held))))
+            }
+            Some(Err(e)) => Some((Err(e), None)),
+            None if pending.is_empty() => None,
+            None => {
+                let ready = Bytes::from(replace_quote_entities(&pending).into_owned());
+                Some((Ok(ready), None))
+            }
+        }
+    })
+}
+
+/// `NewsArticle` fields a feed tag can be mapped onto
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Title,
+    Link,
+    Description,
+    PubDate,
+    Guid,
+    Category,
+    Author,
+}
+
+/// Adjustments for feeds that do not follow RSS conventions
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::parser::{Field, NewsParser, ParserOptions};
+///
+/// let options = ParserOptions::new()
+///     .map_tag("url", Field::Link)
+///     .map_tag("published", Field::PubDate);
+/// let parser = NewsParser::new("generic").with_options(options);
+///
+/// let rss = "<rss><channel><item><title>Update</title>\
+///     <url>https://example.com/a</url></item></channel></rss>";
+/// let articles = parser.parse_response(rss).unwrap();
+/// assert_eq!(articles[0].link.as_deref(), Some("https://example.com/a"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParserOptions {
+    tag_map: HashMap<String, Field>,
+}
+
+impl ParserOptions {
+    /// Create options that keep the standard RSS mapping
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the text of `tag` into `field`
+    ///
+    /// `tag` is matched case-insensitively against the tag name without its namespace
+    /// prefix (`published` matches `<atom:published>`). Mappings take precedence over
+    /// the standard ones, so a tag can also be redirected, e.g. `guid` to `Field::Link`.
+    pub fn map_tag(mut self, tag: &str, field: Field) -> Self {
+        self.tag_map.insert(tag.to_ascii_lowercase(), field);
+        self
+    }
+
+    /// The field a tag is mapped onto, if it has a custom mapping
+    fn field_for(&self, tag: &str) -> Option<Field> {
+        if self.tag_map.is_empty() {
+            return None;
+        }
+        self.tag_map.get(&tag.to_ascii_lowercase()).copied()
+    }
+}
+
+/// Bounds on the structure of a feed document
+///
+/// Long-running aggregators read feeds they do not control, so a hostile or corrupted
+/// payload must fail fast instead of exhausting memory or the stack. Documents that
+/// exceed a limit are rejected with `FanError::FeedParsing`. The defaults are far
+/// above what real news feeds need.
+///
+/// Entities declared in a DTD are never expanded, so billion-laughs style payloads
+/// only ever cost one reference each; `max_entity_refs` bounds how many of those a
+/// document may contain.
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::parser::{NewsParser, ParserLimits};
+///
+/// let limits = ParserLimits::new().with_max_depth(4);
+/// let parser = NewsParser::new("generic").with_limits(limits);
+///
+/// let nested = "<rss><channel><item><a><b><c/></b></a></item></channel></rss>";
+/// assert!(parser.parse_response(nested).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserLimits {
+    max_depth: usize,
+    max_entity_refs: usize,
+    max_attribute_len: usize,
+}
+
+impl Default for ParserLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 64,
+            max_entity_refs: 100_000,
+            max_attribute_len: 16 * 1024,
+        }
+    }
+}
+
+impl ParserLimits {
+    /// Create the default limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum element nesting depth (default 64)
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Maximum number of entity and character references per document (default 100,000)
+    pub fn with_max_entity_refs(mut self, max_entity_refs: usize) -> Self {
+        self.max_entity_refs = max_entity_refs;
+        self
+    }
+
+    /// Maximum length of one attribute value in bytes (default 16 KiB)
+    pub fn with_max_attribute_len(mut self, max_attribute_len: usize) -> Self {
+        self.max_attribute_len = max_attribute_len;
+        self
+    }
+
+    /// Maximum element nesting depth
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Maximum number of entity and character references per document
+    pub fn max_entity_refs(&self) -> usize {
+        self.max_entity_refs
+    }
+
+    /// Maximum length of one attribute value in bytes
+    pub fn max_attribute_len(&self) -> usize {
+        self.max_attribute_len
+    }
+}
+
+/// Enforces `ParserLimits` over the events of one document
+pub(crate) struct LimitGuard {
+    limits: ParserLimits,
+    depth: usize,
+    entity_refs: usize,
+}
+
+impl LimitGuard {
+    pub(crate) fn new(limits: ParserLimits) -> Self {
+        Self {
+            limits,
+            depth: 0,
+            entity_refs: 0,
+        }
+    }
+
+    /// Account for one event, failing once the document exceeds a limit
+    pub(crate) fn check(&mut self, event: &Event<'_>) -> Result<()> {
+        match event {
+            Event::Start(e) => {
+                self.depth += 1;
+                if self.depth > self.limits.max_depth {
+                    return Err(FanError::FeedParsing(format!(
+                        "Elements nested deeper than {} levels",
+                        self.limits.max_depth
+                    )));
+                }
+                self.check_attributes(e)
+            }
+            Event::Empty(e) => self.check_attributes(e),
+            Event::End(_) => {
+                self.depth = self.depth.saturating_sub(1);
+                Ok(())
+            }
+            Event::GeneralRef(_) => {
+                self.entity_refs += 1;
+                if self.entity_refs > self.limits.max_entity_refs {
+                    return Err(FanError::FeedParsing(format!(
+                        "More than {} entity references",
+                        self.limits.max_entity_refs
+                    )));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_attributes(&self, element: &BytesStart<'_>) -> Result<()> {
+        // No attribute can be longer than the whole tag, so most tags need no scan
+        if element.len() <= self.limits.max_attribute_len {
+            return Ok(());
+        }
+        for attr in element.attributes().flatten() {
+            if attr.value.len() > self.limits.max_attribute_len {
+                return Err(FanError::FeedParsing(format!(
+                    "Attribute {} longer than {} bytes",
+                    String::from_utf8_lossy(attr.key.as_ref()),
+                    self.limits.max_attribute_len
+                )));
+            }
+        }
+        Ok(())
+    }
+}
 
 /// RSS/XML parser for news feeds with namespace support
 ///
@@ -32,9 +394,13 @@ use std::collections::HashMap;
 /// assert_eq!(articles.len(), 1);
 /// assert_eq!(articles[0].title.as_ref().unwrap(), "Market Update");
 /// ```
+#[derive(Clone)]
 pub struct NewsParser {
     client_type: String,
     namespaces: HashMap<String, Vec<String>>,
+    options: ParserOptions,
+    limits: ParserLimits,
+    quirks: Quirks,
 }
 
 impl NewsParser {
@@ -98,12 +464,64 @@ impl NewsParser {
             vec!["http://search.yahoo.com/mrss/".to_string()],
         );
 
+        namespaces.insert(
+            "nytimes".to_string(),
+            vec![
+                "http://search.yahoo.com/mrss/".to_string(),
+                "http://purl.org/dc/elements/1.1/".to_string(),
+                "http://www.w3.org/2005/Atom".to_string(),
+            ],
+        );
+
         Self {
             client_type: client_type.to_string(),
             namespaces,
+            options: ParserOptions::default(),
+            limits: ParserLimits::default(),
+            quirks: Quirks::NONE,
         }
     }
 
+    /// Create a parser for a built-in source, with that source's quirks applied
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finance_news_aggregator_rs::parser::NewsParser;
+    /// use finance_news_aggregator_rs::quirks::Source;
+    ///
+    /// let parser = NewsParser::for_source(Source::MarketWatch);
+    /// let rss = "<rss><channel><item><title>Dow &amp; S&amp;P&nbsp;rise</title></item></channel></rss>";
+    /// let articles = parser.parse_response(rss).unwrap();
+    /// assert_eq!(articles[0].title.as_deref(), Some("Dow & S&P rise"));
+    /// ```
+    pub fn for_source(source: Source) -> Self {
+        Self::new(source.client_type()).with_quirks(source.quirks())
+    }
+
+    /// Apply source-specific parsing quirks
+    pub fn with_quirks(mut self, quirks: Quirks) -> Self {
+        self.quirks = quirks;
+        self
+    }
+
+    /// Use custom tag mappings for nonstandard feeds
+    pub fn with_options(mut self, options: ParserOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Use custom bounds on document structure instead of the defaults
+    pub fn with_limits(mut self, limits: ParserLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// The bounds documents are parsed under
+    pub fn limits(&self) -> ParserLimits {
+        self.limits
+    }
+
     /// Parse RSS/XML content into NewsArticle structs
     ///
     /// Processes RSS feed content and extracts article information, handling
@@ -142,138 +560,413 @@ impl NewsParser {
         // Pre-process the content to handle Unicode entities before XML parsing
         let preprocessed_content = self.preprocess_unicode_entities(content);
 
+        // Reading from a string lets events borrow directly from the input
         let mut reader = Reader::from_str(&preprocessed_content);
-        reader.config_mut().trim_text(true);
+        reader.config_mut().trim_text(!self.quirks.html_entities);
 
         let mut articles = Vec::new();
-        let mut current_article = NewsArticle::new();
-        let mut current_tag = String::new();
-        let mut in_item = false;
-        let mut buf = Vec::new();
+        let mut state = ItemState::new(self.limits);
+
+        loop {
+            match reader.read_event() {
+                Ok(Event::Eof) => break,
+                Ok(event) => {
+                    if let Some(article) = self.handle_event(&mut state, event, reader.decoder())? {
+                        articles.push(article);
+                    }
+                }
+                Err(e) => return Err(FanError::XmlParsing(e)),
+            }
+        }
+
+        Ok(articles)
+    }
+
+    /// Parse RSS/XML incrementally from a stream of body chunks
+    ///
+    /// Each article is yielded as soon as its closing `</item>` tag has been read, so
+    /// the full body is never held in memory and the first articles are available
+    /// before the download finishes. Produces the same articles as `parse_response()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunks` - Body chunks in order, e.g. from `reqwest::Response::bytes_stream()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finance_news_aggregator_rs::parser::NewsParser;
+    /// use futures::{StreamExt, stream};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let parser = NewsParser::new("wsj");
+    /// let chunks = stream::iter(vec![
+    ///     Ok(bytes::Bytes::from("<rss><channel><item><title>Fed hol")),
+    ///     Ok(bytes::Bytes::from("ds rates</title></item></channel></rss>")),
+    /// ]);
+    ///
+    /// let articles: Vec<_> = parser.parse_stream(chunks).collect().await;
+    /// assert_eq!(articles[0].as_ref().unwrap().title.as_deref(), Some("Fed holds rates"));
+    /// # });
+    /// ```
+    #[cfg(feature = "fetch")]
+    pub fn parse_stream<S>(&self, chunks: S) -> BoxStream<'static, Result<NewsArticle>>
+    where
+        S: Stream<Item = std::io::Result<Bytes>> + Send + Unpin + 'static,
+    {
+        let mut reader =
+            Reader::from_reader(StreamReader::new(Box::pin(filter_quote_entities(chunks))));
+        reader.config_mut().trim_text(!self.quirks.html_entities);
+
+        let initial = (
+            self.clone(),
+            reader,
+            Vec::new(),
+            ItemState::new(self.limits),
+        );
+        stream::unfold(Some(initial), |state| async move {
+            let (parser, mut reader, mut buf, mut items) = state?;
+            loop {
+                buf.clear();
+                match reader.read_event_into_async(&mut buf).await {
+                    Ok(Event::Eof) => return None,
+                    Ok(event) => {
+                        let decoder = reader.decoder();
+                        match parser.handle_event(&mut items, event, decoder) {
+                            Ok(Some(article)) => {
+                                return Some((Ok(article), Some((parser, reader, buf, items))));
+                            }
+                            Ok(None) => {}
+                            Err(e) => return Some((Err(e), None)),
+                        }
+                    }
+                    Err(e) => return Some((Err(FanError::XmlParsing(e)), None)),
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Read the channel-level `<ttl>` of an RSS feed
+    ///
+    /// RSS publishers use `<ttl>` to say how many minutes a feed may be cached before
+    /// it is refreshed. Only the channel metadata ahead of the first `<item>` is
+    /// scanned.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Raw RSS/XML content as a string
+    ///
+    /// # Returns
+    ///
+    /// The advertised TTL, or `None` when the feed has no valid `<ttl>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finance_news_aggregator_rs::parser::NewsParser;
+    /// use std::time::Duration;
+    ///
+    /// let parser = NewsParser::new("wsj");
+    /// let rss = "<rss><channel><ttl>15</ttl><item><title>News</title></item></channel></rss>";
+    /// assert_eq!(parser.parse_channel_ttl(rss), Some(Duration::from_secs(15 * 60)));
+    /// ```
+    pub fn parse_channel_ttl(&self, content: &str) -> Option<Duration> {
+        let mut reader = Reader::from_str(content);
+        reader.config_mut().trim_text(true);
 
+        let mut in_ttl = false;
         loop {
-            match reader.read_event_into(&mut buf) {
+            match reader.read_event() {
                 Ok(Event::Start(ref e)) => {
                     let tag_name = e.name();
-                    let tag_str = match std::str::from_utf8(tag_name.as_ref()) {
-                        Ok(s) => s,
-                        Err(_) => {
-                            log::warn!("Invalid UTF-8 in tag name");
-                            continue;
+                    let tag = self.clean_tag_name(std::str::from_utf8(tag_name.as_ref()).ok()?);
+                    if tag == "item" || tag == "entry" {
+                        return None;
+                    }
+                    in_ttl = tag == "ttl";
+                }
+                Ok(Event::Text(e)) if in_ttl => {
+                    let minutes: u64 = std::str::from_utf8(&e).ok()?.trim().parse().ok()?;
+                    return Some(Duration::from_secs(minutes.checked_mul(60)?));
+                }
+                Ok(Event::End(_)) => in_ttl = false,
+                Ok(Event::Eof) | Err(_) => return None,
+                _ => {}
+            }
+        }
+    }
+
+    /// Read the feed-level link to the next page of a paginated feed
+    ///
+    /// Looks for an `<atom:link rel="next">` in an RSS channel or a `<link rel="next">`
+    /// in an Atom feed; links inside items and entries are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Raw RSS/Atom content as a string
+    ///
+    /// # Returns
+    ///
+    /// The `href` of the link as written, which may be relative to the feed URL
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finance_news_aggregator_rs::parser::NewsParser;
+    ///
+    /// let parser = NewsParser::new("generic");
+    /// let rss = r#"<rss xmlns:atom="http://www.w3.org/2005/Atom"><channel>
+    ///     <atom:link rel="next" href="https://example.com/feed?page=2"/>
+    ///     <item><title>News</title></item>
+    /// </channel></rss>"#;
+    /// assert_eq!(
+    ///     parser.parse_next_link(rss).as_deref(),
+    ///     Some("https://example.com/feed?page=2")
+    /// );
+    /// ```
+    pub fn parse_next_link(&self, content: &str) -> Option<String> {
+        let mut reader = Reader::from_str(content);
+        let mut guard = LimitGuard::new(self.limits);
+        let mut item_depth = 0usize;
+
+        loop {
+            let event = reader.read_event().ok()?;
+            guard.check(&event).ok()?;
+            match event {
+                Event::Start(ref e) | Event::Empty(ref e) => {
+                    let name = e.local_name();
+                    match name.as_ref() {
+                        b"item" | b"entry" if matches!(event, Event::Start(_)) => {
+                            item_depth += 1;
                         }
-                    };
-                    current_tag = self.clean_tag_name(tag_str);
+                        b"link" if item_depth == 0 => {
+                            let rel = e.try_get_attribute("rel").ok().flatten();
+                            if rel.is_some_and(|rel| rel.value.as_ref() == b"next") {
+                                let href = e.try_get_attribute("href").ok().flatten()?;
+                                return href.unescape_value().ok().map(Cow::into_owned);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Event::End(ref e) if matches!(e.local_name().as_ref(), b"item" | b"entry") => {
+                    item_depth = item_depth.saturating_sub(1);
+                }
+                Event::Eof => return None,
+                _ => {}
+            }
+        }
+    }
 
-                    if current_tag == "item" {
-                        in_item = true;
-                        current_article = NewsArticle::new();
+    /// Apply one XML event to the parsing state
+    ///
+    /// Returns the article completed by this event, if any, or an error once the
+    /// document exceeds the parser's limits.
+    fn handle_event(
+        &self,
+        state: &mut ItemState,
+        event: Event<'_>,
+        decoder: Decoder,
+    ) -> Result<Option<NewsArticle>> {
+        state.guard.check(&event)?;
+
+        match event {
+            Event::Start(ref e) => {
+                let tag_name = e.name();
+                let tag_str = match std::str::from_utf8(tag_name.as_ref()) {
+                    Ok(s) => s,
+                    Err(_) => {
+                        log::warn!("Invalid UTF-8 in tag name");
+                        return Ok(None);
                     }
+                };
+                state.current_tag.clear();
+                state.current_tag.push_str(&self.clean_tag_name(tag_str));
+                state.current_key.clear();
+                if let Some((prefix, _)) = tag_str.rsplit_once(':') {
+                    state.current_key.push_str(prefix);
+                    state.current_key.push(':');
+                }
+                state.current_key.push_str(&state.current_tag);
+                state.in_text = false;
+
+                if state.current_tag == "item" {
+                    state.in_item = true;
+                    state.current_article = NewsArticle::new();
+                } else if state.in_item {
+                    self.capture_media(&mut state.current_article, &state.current_tag, e, decoder);
                 }
-                Ok(Event::Text(e)) => {
-                    if in_item && !current_tag.is_empty() {
-                        // Use the reader to decode entities properly
-                        let mut text = match reader.decoder().decode(&e) {
-                            Ok(cow_str) => cow_str.into_owned(),
-                            Err(err) => {
-                                log::warn!("Failed to decode text: {}", err);
-                                // Fallback to raw UTF-8 conversion
-                                match std::str::from_utf8(&e) {
-                                    Ok(s) => s.to_string(),
-                                    Err(_) => {
-                                        log::warn!("Invalid UTF-8 in text content");
-                                        continue;
-                                    }
+            }
+            Event::Empty(ref e) if state.in_item => {
+                let tag_name = e.name();
+                let tag_str = match std::str::from_utf8(tag_name.as_ref()) {
+                    Ok(s) => s,
+                    Err(_) => {
+                        log::warn!("Invalid UTF-8 in tag name");
+                        return Ok(None);
+                    }
+                };
+                let clean_tag = self.clean_tag_name(tag_str);
+                self.capture_media(&mut state.current_article, &clean_tag, e, decoder);
+            }
+            Event::Text(e) => {
+                if state.in_item && !state.current_tag.is_empty() {
+                    // Use the reader to decode entities properly
+                    let text = match decoder.decode(&e) {
+                        Ok(text) => text,
+                        Err(err) => {
+                            log::warn!("Failed to decode text: {}", err);
+                            // Fallback to raw UTF-8 conversion
+                            match std::str::from_utf8(&e) {
+                                Ok(s) => Cow::Borrowed(s),
+                                Err(_) => {
+                                    log::warn!("Invalid UTF-8 in text content");
+                                    return Ok(None);
                                 }
                             }
-                        };
+                        }
+                    };
 
-                        // Handle Unicode entities that the decoder might miss
-                        text = self.decode_unicode_entities(&text);
+                    // Handle Unicode entities that the decoder might miss
+                    let text = match self.decode_unicode_entities(&text) {
+                        Cow::Borrowed(_) => text,
+                        Cow::Owned(decoded) => Cow::Owned(decoded),
+                    };
 
-                        self.set_article_field(&mut current_article, &current_tag, text);
-                    }
+                    self.set_article_field(state, text);
                 }
-                Ok(Event::CData(e)) => {
-                    if in_item && !current_tag.is_empty() {
-                        // Handle CDATA sections
-                        let text = match std::str::from_utf8(&e) {
-                            Ok(s) => s.to_string(),
-                            Err(_) => {
-                                log::warn!("Invalid UTF-8 in CDATA section");
-                                continue;
-                            }
-                        };
-                        self.set_article_field(&mut current_article, &current_tag, text);
-                    }
+            }
+            Event::GeneralRef(e) => {
+                if state.in_item && !state.current_tag.is_empty() {
+                    let text = self.resolve_reference(&e);
+                    self.set_article_field(state, text);
                 }
-                Ok(Event::End(ref e)) => {
-                    let tag_name = e.name();
-                    let tag_str = match std::str::from_utf8(tag_name.as_ref()) {
+            }
+            Event::CData(e) => {
+                if state.in_item && !state.current_tag.is_empty() {
+                    // Handle CDATA sections
+                    let text = match std::str::from_utf8(&e) {
+                        Ok(s) if self.quirks.padded_cdata => s.trim(),
                         Ok(s) => s,
                         Err(_) => {
-                            log::warn!("Invalid UTF-8 in end tag name");
-                            continue;
+                            log::warn!("Invalid UTF-8 in CDATA section");
+                            return Ok(None);
                         }
                     };
-                    let clean_tag = self.clean_tag_name(tag_str);
-
-                    if clean_tag == "item" && in_item {
-                        articles.push(current_article.clone());
-                        in_item = false;
+                    self.set_article_field(state, Cow::Borrowed(text));
+                }
+            }
+            Event::End(ref e) => {
+                let tag_name = e.name();
+                let tag_str = match std::str::from_utf8(tag_name.as_ref()) {
+                    Ok(s) => s,
+                    Err(_) => {
+                        log::warn!("Invalid UTF-8 in end tag name");
+                        return Ok(None);
                     }
-                    current_tag.clear();
+                };
+
+                state.current_tag.clear();
+                state.in_text = false;
+                if state.in_item && self.clean_tag_name(tag_str) == "item" {
+                    state.in_item = false;
+                    self.finish_item(&mut state.current_article);
+                    return Ok(Some(std::mem::take(&mut state.current_article)));
                 }
-                Ok(Event::Eof) => break,
-                Err(e) => return Err(FanError::XmlParsing(e)),
-                _ => {}
             }
-            buf.clear();
+            _ => {}
         }
 
-        Ok(articles)
+        Ok(None)
+    }
+
+    /// Resolve an entity or character reference in item text
+    ///
+    /// Character references and the five XML entities are always resolved; HTML named
+    /// entities only for sources with `Quirks::html_entities`. Anything else is kept
+    /// as written.
+    fn resolve_reference<'a>(&self, reference: &BytesRef<'a>) -> Cow<'a, str> {
+        match reference.resolve_char_ref() {
+            Ok(Some(ch)) => return Cow::Owned(ch.to_string()),
+            Ok(None) => {}
+            Err(err) => log::warn!("Invalid character reference: {}", err),
+        }
+
+        let name = String::from_utf8_lossy(reference);
+        let resolved = resolve_predefined_entity(&name).or_else(|| {
+            if !self.quirks.html_entities {
+                return None;
+            }
+            HTML_ENTITIES
+                .iter()
+                .find(|(entity, _)| *entity == name)
+                .map(|(_, text)| *text)
+        });
+        match resolved {
+            Some(text) => Cow::Borrowed(text),
+            None => Cow::Owned(format!("&{};", name)),
+        }
     }
 
     /// Clean tag names by removing namespaces and prefixes
     ///
     /// Removes source-specific XML namespaces and namespace prefixes to normalize
-    /// tag names across different news sources.
-    fn clean_tag_name(&self, tag: &str) -> String {
-        let mut clean_tag = tag.to_string();
+    /// tag names across different news sources. Borrows from `tag` unless a
+    /// namespace has to be stripped.
+    fn clean_tag_name<'a>(&self, tag: &'a str) -> Cow<'a, str> {
+        let namespaces = self
+            .namespaces
+            .get(&self.client_type)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
 
-        if let Some(namespaces) = self.namespaces.get(&self.client_type) {
+        let clean_tag = if namespaces
+            .iter()
+            .any(|namespace| tag.contains(namespace.as_str()))
+        {
+            let mut stripped = tag.to_string();
             for namespace in namespaces {
-                clean_tag = clean_tag.replace(namespace, "");
+                stripped = stripped.replace(namespace, "");
             }
-        }
+            Cow::Owned(stripped)
+        } else {
+            Cow::Borrowed(tag)
+        };
 
         // Remove any remaining namespace prefixes
-        if let Some(colon_pos) = clean_tag.rfind(':') {
-            clean_tag = clean_tag[colon_pos + 1..].to_string();
+        match clean_tag.rfind(':') {
+            Some(colon_pos) => match clean_tag {
+                Cow::Borrowed(tag) => Cow::Borrowed(&tag[colon_pos + 1..]),
+                Cow::Owned(tag) => Cow::Owned(tag[colon_pos + 1..].to_string()),
+            },
+            None => clean_tag,
         }
-
-        clean_tag
     }
 
     /// Preprocess the entire XML content to handle Unicode entities before parsing
     ///
     /// This ensures that Unicode quotation marks are converted to regular apostrophes
-    /// before the XML parser splits them into separate text nodes
-    fn preprocess_unicode_entities(&self, content: &str) -> String {
-        content
-            .replace("&#x2018;", "'") // Left single quotation mark
-            .replace("&#x2019;", "'") // Right single quotation mark
-            .replace("&#x201C;", "\"") // Left double quotation mark
-            .replace("&#x201D;", "\"") // Right double quotation mark
-            .replace("&#8216;", "'") // Left single quotation mark (decimal)
-            .replace("&#8217;", "'") // Right single quotation mark (decimal)
-            .replace("&#8220;", "\"") // Left double quotation mark (decimal)
-            .replace("&#8221;", "\"") // Right double quotation mark (decimal)
+    /// before the XML parser splits them into separate text nodes. Content without
+    /// numeric character references is returned as-is without copying.
+    fn preprocess_unicode_entities<'a>(&self, content: &'a str) -> Cow<'a, str> {
+        match replace_quote_entities(content.as_bytes()) {
+            Cow::Borrowed(_) => Cow::Borrowed(content),
+            // Entities are ASCII and are replaced with ASCII, so the result stays UTF-8
+            Cow::Owned(bytes) => Cow::Owned(
+                String::from_utf8(bytes).expect("quote entity replacement preserves UTF-8"),
+            ),
+        }
     }
 
     /// Decode Unicode entities that might not be handled by the XML decoder
     ///
     /// Handles numeric character references like &#x2018; and &#x2019; for proper apostrophes
-    fn decode_unicode_entities(&self, text: &str) -> String {
+    fn decode_unicode_entities<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !text.contains("&#") {
+            return Cow::Borrowed(text);
+        }
+
         let mut result = text.to_string();
 
         // Handle hexadecimal numeric character references
@@ -327,52 +1020,469 @@ impl NewsParser {
             }
         }
 
-        result
+        Cow::Owned(result)
     }
 
-    /// Set the appropriate field in NewsArticle based on tag name
-    ///
-    /// Maps XML tag names to NewsArticle fields. Standard RSS tags like "title",
-    /// "link", "description" are mapped to their corresponding fields, while
-    /// unknown tags are stored in the `extra_fields` HashMap.
+    /// Capture media attached to an item
     ///
-    /// This method handles text accumulation for cases where XML content spans multiple text nodes.
-    fn set_article_field(&self, article: &mut NewsArticle, tag: &str, value: String) {
-        match tag.to_lowercase().as_str() {
-            "title" => {
-                if let Some(existing) = &article.title {
-                    article.title = Some(format!("{}{}", existing, value));
-                } else {
-                    article.title = Some(value);
+    /// `<enclosure>`, `<media:content>`, and `<media:thumbnail>` carry their payload in
+    /// attributes rather than text, so they are handled separately from
+    /// `set_article_field`. Enclosures and audio or video `<media:content>` are kept in
+    /// `NewsArticle::enclosures`; the first image URL found is stored as
+    /// `extra_fields["thumbnail"]`.
+    fn capture_media(
+        &self,
+        article: &mut NewsArticle,
+        tag: &str,
+        element: &BytesStart,
+        decoder: Decoder,
+    ) {
+        if tag != "content" && tag != "thumbnail" && tag != "enclosure" {
+            return;
+        }
+
+        let mut url = None;
+        let mut medium = None;
+        let mut mime_type = None;
+        let mut length = None;
+        let mut duration = None;
+        for attr in element.attributes().flatten() {
+            let value = match attr.decode_and_unescape_value(decoder) {
+                Ok(value) => value.into_owned(),
+                Err(err) => {
+                    log::warn!("Failed to decode attribute: {}", err);
+                    continue;
                 }
+            };
+            match attr.key.local_name().as_ref() {
+                b"url" => url = Some(value),
+                b"medium" => medium = Some(value),
+                b"type" => mime_type = Some(value),
+                b"length" | b"fileSize" => length = value.trim().parse().ok(),
+                b"duration" => duration = parse_duration(&value),
+                _ => {}
             }
-            "link" => {
-                if let Some(existing) = &article.link {
-                    article.link = Some(format!("{}{}", existing, value));
-                } else {
-                    article.link = Some(value);
-                }
+        }
+        let Some(url) = url else {
+            return;
+        };
+
+        let is_image = tag == "thumbnail"
+            || match (medium.as_deref(), mime_type.as_deref()) {
+                (Some(medium), _) => medium == "image",
+                (None, Some(mime_type)) => mime_type.starts_with("image/"),
+                // Untyped media:content is usually an image, untyped enclosures are not
+                (None, None) => tag == "content",
+            };
+
+        if is_image {
+            if !article.extra_fields.contains_key("thumbnail") {
+                article
+                    .extra_fields
+                    .insert("thumbnail".to_string(), url.into());
             }
-            "description" => {
-                if let Some(existing) = &article.description {
-                    article.description = Some(format!("{}{}", existing, value));
-                } else {
-                    article.description = Some(value);
+        } else if article
+            .enclosures
+            .iter()
+            .all(|enclosure| enclosure.url != url)
+        {
+            article.enclosures.push(Enclosure {
+                url,
+                mime_type,
+                length,
+                duration_seconds: duration,
+            });
+        }
+    }
+
+    /// Finish an item once its closing tag has been read
+    ///
+    /// Podcast feeds give the episode length as `<itunes:duration>` on the item rather
+    /// than on the enclosure.
+    fn finish_item(&self, article: &mut NewsArticle) {
+        // Untrimmed text keeps the whitespace around references until the item is done
+        if self.quirks.html_entities {
+            for field in [
+                &mut article.title,
+                &mut article.link,
+                &mut article.description,
+                &mut article.pub_date,
+                &mut article.guid,
+                &mut article.category,
+                &mut article.author,
+            ] {
+                *field = field
+                    .take()
+                    .map(|text| text.trim().to_string())
+                    .filter(|text| !text.is_empty());
+            }
+            article.extra_fields.retain(|_, value| {
+                match value {
+                    ExtraValue::Text(text) => *text = text.trim().to_string(),
+                    ExtraValue::List(values) => {
+                        for text in values.iter_mut() {
+                            *text = text.trim().to_string();
+                        }
+                        values.retain(|text| !text.is_empty());
+                    }
                 }
+                !value.values().iter().all(String::is_empty)
+            });
+        }
+
+        if let Some(duration) = article
+            .extra_fields
+            .get("itunes:duration")
+            .and_then(|d| parse_duration(d.as_str()))
+        {
+            for enclosure in &mut article.enclosures {
+                enclosure.duration_seconds.get_or_insert(duration);
             }
-            "pubdate" => article.pub_date = Some(value),
-            "guid" => article.guid = Some(value),
-            "category" => article.category = Some(value),
-            "author" | "creator" => article.author = Some(value),
-            _ => {
-                if let Some(existing) = article.extra_fields.get(tag) {
+        }
+    }
+
+    /// Set the appropriate field in NewsArticle based on tag name
+    ///
+    /// Maps XML tag names to NewsArticle fields. Tags mapped with `ParserOptions` come
+    /// first, then standard RSS tags like "title", "link", "description" are mapped to
+    /// their corresponding fields, while unknown tags are stored in the `extra_fields`
+    /// HashMap under their prefixed name. A tag repeated within an item is kept as a
+    /// list.
+    ///
+    /// This method handles text accumulation for cases where XML content spans multiple
+    /// text nodes, such as text interrupted by entity references.
+    fn set_article_field(&self, state: &mut ItemState, value: Cow<'_, str>) {
+        let continued = std::mem::replace(&mut state.in_text, true);
+        let article = &mut state.current_article;
+        let tag = state.current_tag.as_str();
+        let field = self.options.field_for(tag).or_else(|| {
+            ARTICLE_FIELDS
+                .iter()
+                .find(|(name, _)| tag.eq_ignore_ascii_case(name))
+                .map(|(_, field)| *field)
+        });
+
+        match field {
+            Some(Field::Title) => append_text(&mut article.title, value),
+            Some(Field::Link) => append_text(&mut article.link, value),
+            Some(Field::Description) => append_text(&mut article.description, value),
+            Some(Field::PubDate) => replace_text(&mut article.pub_date, value, continued),
+            Some(Field::Guid) => replace_text(&mut article.guid, value, continued),
+            Some(Field::Category) => replace_text(&mut article.category, value, continued),
+            Some(Field::Author) => replace_text(&mut article.author, value, continued),
+            None => match article.extra_fields.get_mut(state.current_key.as_str()) {
+                Some(ExtraValue::Text(text)) if continued => text.push_str(&value),
+                Some(ExtraValue::List(values)) if continued => {
+                    if let Some(text) = values.last_mut() {
+                        text.push_str(&value);
+                    }
+                }
+                Some(existing) => existing.push(value.into_owned()),
+                None => {
                     article
                         .extra_fields
-                        .insert(tag.to_string(), format!("{}{}", existing, value));
-                } else {
-                    article.extra_fields.insert(tag.to_string(), value);
+                        .insert(state.current_key.clone(), value.into_owned().into());
                 }
-            }
+            },
+        }
+    }
+}
+
+/// Parse a media duration in seconds
+///
+/// Accepts plain seconds ("1843", "1843.5") and clock times ("30:43", "1:02:03").
+pub(crate) fn parse_duration(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if !value.contains(':') {
+        return value
+            .parse::<f64>()
+            .ok()
+            .filter(|s| *s >= 0.0)
+            .map(|s| s as u64);
+    }
+
+    let parts: Vec<&str> = value.split(':').collect();
+    if parts.len() > 3 {
+        return None;
+    }
+    parts.iter().try_fold(0u64, |total, part| {
+        let part = part.split('.').next()?.parse::<u64>().ok()?;
+        total.checked_mul(60)?.checked_add(part)
+    })
+}
+
+/// Append a text node to a field that may span several nodes
+fn append_text(field: &mut Option<String>, value: Cow<'_, str>) {
+    match field {
+        Some(existing) => existing.push_str(&value),
+        None => *field = Some(value.into_owned()),
+    }
+}
+
+/// Replace a field with the text of a new element, or extend it within the same one
+///
+/// A repeated tag (e.g. a second `<category>`) replaces the earlier value.
+fn replace_text(field: &mut Option<String>, value: Cow<'_, str>, continued: bool) {
+    if continued {
+        append_text(field, value);
+    } else {
+        *field = Some(value.into_owned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accumulates_split_text_and_resets_items() {
+        let parser = NewsParser::new("nytimes");
+        let rss = r#"<rss><channel>
+            <item>
+                <title>Fed&#x2019;s &#8220;pause&#8221;</title>
+                <description><![CDATA[<p>First</p>]]> and more</description>
+                <dc:creator>Jane Reporter</dc:creator>
+                <nyt:kicker>Markets</nyt:kicker>
+            </item>
+            <item><title>Second</title></item>
+        </channel></rss>"#;
+
+        let articles = parser.parse_response(rss).unwrap();
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0].title.as_deref(), Some("Fed's \"pause\""));
+        assert_eq!(
+            articles[0].description.as_deref(),
+            Some("<p>First</p>and more")
+        );
+        assert_eq!(articles[0].author.as_deref(), Some("Jane Reporter"));
+        assert_eq!(articles[0].extra("nyt", "kicker").unwrap(), "Markets");
+        assert_eq!(articles[1].title.as_deref(), Some("Second"));
+        assert!(articles[1].author.is_none());
+        assert!(articles[1].extra_fields.is_empty());
+    }
+
+    #[test]
+    fn test_extra_fields_keep_prefixes_and_repeated_tags() {
+        let parser = NewsParser::new("wsj");
+        let rss = r#"<rss><channel><item>
+            <title>Earnings</title>
+            <wsj:articletype>Markets</wsj:articletype>
+            <media:credit>Reuters</media:credit>
+            <media:credit>AP&amp;Getty</media:credit>
+            <sa:credit>Staff</sa:credit>
+            <score>42</score>
+        </item></channel></rss>"#;
+
+        let articles = parser.parse_response(rss).unwrap();
+        let article = &articles[0];
+        assert_eq!(article.extra("wsj", "articletype").unwrap(), "Markets");
+        assert_eq!(
+            article.extra("media", "credit").unwrap().values(),
+            ["Reuters", "AP&Getty"]
+        );
+        assert_eq!(article.extra("sa", "credit").unwrap(), "Staff");
+        assert_eq!(article.extra("", "score").unwrap().parse::<u32>(), Some(42));
+        assert!(article.extra("", "credit").is_none());
+    }
+
+    #[cfg(feature = "fetch")]
+    #[tokio::test]
+    async fn test_parse_stream_matches_parse_response() {
+        let parser = NewsParser::new("nytimes");
+        let rss = r#"<rss xmlns:media="http://search.yahoo.com/mrss/"><channel>
+            <item>
+                <title>Fed&#x2019;s &#8220;pause&#8221; lifts caf&#xE9; stocks</title>
+                <description><![CDATA[<p>First</p>]]> and more</description>
+                <media:content url="https://example.com/a.jpg" medium="image"/>
+            </item>
+            <item><title>Second &#8212; story</title></item>
+        </channel></rss>"#;
+        let expected = serde_json::to_value(parser.parse_response(rss).unwrap()).unwrap();
+
+        // Split at every chunk size so entities and UTF-8 sequences straddle chunks
+        for size in 1..=16 {
+            let chunks: Vec<std::io::Result<Bytes>> = rss
+                .as_bytes()
+                .chunks(size)
+                .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+                .collect();
+            let articles: Vec<NewsArticle> = parser
+                .parse_stream(stream::iter(chunks))
+                .map(|article| article.unwrap())
+                .collect()
+                .await;
+            assert_eq!(
+                serde_json::to_value(articles).unwrap(),
+                expected,
+                "chunk size {}",
+                size
+            );
         }
     }
+
+    #[cfg(feature = "fetch")]
+    #[tokio::test]
+    async fn test_parse_stream_reports_transport_errors() {
+        let parser = NewsParser::new("wsj");
+        let chunks = stream::iter(vec![
+            Ok(Bytes::from_static(
+                b"<rss><channel><item><title>One</title></item>",
+            )),
+            Err(std::io::Error::other("connection reset")),
+        ]);
+
+        let results: Vec<_> = parser.parse_stream(chunks).collect().await;
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(FanError::XmlParsing(_))));
+    }
+
+    #[test]
+    fn test_parse_channel_ttl() {
+        let parser = NewsParser::new("wsj");
+        let rss = r#"<rss><channel>
+            <title>Markets</title>
+            <ttl> 30 </ttl>
+            <item><title>One</title></item>
+        </channel></rss>"#;
+        assert_eq!(
+            parser.parse_channel_ttl(rss),
+            Some(Duration::from_secs(30 * 60))
+        );
+
+        // A ttl inside an item is not channel metadata
+        let item_ttl = "<rss><channel><item><ttl>5</ttl></item></channel></rss>";
+        assert_eq!(parser.parse_channel_ttl(item_ttl), None);
+        assert_eq!(
+            parser.parse_channel_ttl("<rss><channel><ttl>soon</ttl></channel></rss>"),
+            None
+        );
+        assert_eq!(parser.parse_channel_ttl(r#"{"messages": []}"#), None);
+        // Too many minutes to count in seconds
+        assert_eq!(
+            parser.parse_channel_ttl("<rss><channel><ttl>400000000000000000</ttl></channel></rss>"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_custom_tag_mapping() {
+        let options = ParserOptions::new()
+            .map_tag("URL", Field::Link)
+            .map_tag("published", Field::PubDate)
+            .map_tag("link", Field::Guid);
+        let parser = NewsParser::new("generic").with_options(options);
+        let rss = r#"<rss><channel><item>
+            <title>Custom feed</title>
+            <url>https://example.com/story</url>
+            <link>story-42</link>
+            <atom:published>2024-05-01T14:30:00Z</atom:published>
+        </item></channel></rss>"#;
+
+        let articles = parser.parse_response(rss).unwrap();
+        assert_eq!(
+            articles[0].link.as_deref(),
+            Some("https://example.com/story")
+        );
+        assert_eq!(articles[0].guid.as_deref(), Some("story-42"));
+        assert_eq!(
+            articles[0].pub_date.as_deref(),
+            Some("2024-05-01T14:30:00Z")
+        );
+        assert!(articles[0].extra_fields.is_empty());
+    }
+
+    #[test]
+    fn test_parse_podcast_enclosures() {
+        let parser = NewsParser::new("wsj");
+        let rss = r#"<rss xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"
+                xmlns:media="http://search.yahoo.com/mrss/"><channel>
+            <item>
+                <title>What's News</title>
+                <enclosure url="https://example.com/ep1.mp3" length="12345" type="audio/mpeg"/>
+                <itunes:duration>12:34</itunes:duration>
+                <media:content url="https://example.com/ep1.jpg" medium="image"/>
+            </item>
+            <item>
+                <title>Video</title>
+                <media:content url="https://example.com/v.mp4" type="video/mp4" duration="90"/>
+            </item>
+        </channel></rss>"#;
+
+        let articles = parser.parse_response(rss).unwrap();
+        let audio = articles[0].audio().unwrap();
+        assert_eq!(audio.url, "https://example.com/ep1.mp3");
+        assert_eq!(audio.mime_type.as_deref(), Some("audio/mpeg"));
+        assert_eq!(audio.length, Some(12345));
+        assert_eq!(audio.duration_seconds, Some(754));
+        assert_eq!(
+            articles[0].extra_fields["thumbnail"],
+            "https://example.com/ep1.jpg"
+        );
+
+        assert!(articles[1].audio().is_none());
+        assert_eq!(articles[1].enclosures[0].duration_seconds, Some(90));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("1843"), Some(1843));
+        assert_eq!(parse_duration("30:43"), Some(1843));
+        assert_eq!(parse_duration("1:02:03.5"), Some(3723));
+        assert_eq!(parse_duration("soon"), None);
+        assert_eq!(parse_duration("18446744073709551615:59:59"), None);
+    }
+
+    #[cfg(feature = "fetch")]
+    #[tokio::test]
+    async fn test_limits_reject_hostile_documents() {
+        let limits = ParserLimits::new()
+            .with_max_depth(5)
+            .with_max_entity_refs(3)
+            .with_max_attribute_len(32);
+        let parser = NewsParser::new("generic").with_limits(limits);
+
+        let deep = format!(
+            "<rss><channel><item><title>ok</title>{}{}</item></channel></rss>",
+            "<x>".repeat(10),
+            "</x>".repeat(10)
+        );
+        assert!(matches!(
+            parser.parse_response(&deep),
+            Err(FanError::FeedParsing(_))
+        ));
+        let chunks = stream::iter(vec![Ok(Bytes::from(deep))]);
+        let results: Vec<_> = parser.parse_stream(chunks).collect().await;
+        assert!(matches!(
+            results.last(),
+            Some(Err(FanError::FeedParsing(_)))
+        ));
+
+        let refs = "<rss><channel><item><title>&amp;&amp;&amp;&amp;</title></item></channel></rss>";
+        assert!(parser.parse_response(refs).is_err());
+
+        let long_attr = format!(
+            "<rss><channel><item><enclosure url=\"{}\"/></item></channel></rss>",
+            "a".repeat(64)
+        );
+        assert!(parser.parse_response(&long_attr).is_err());
+
+        // Ordinary feeds stay well inside the limits
+        let rss = "<rss><channel><item><title>A &amp; B</title>\
+            <enclosure url=\"https://e.com/a.mp3\"/></item></channel></rss>";
+        assert_eq!(parser.parse_response(rss).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_clean_tag_name_borrows_plain_tags() {
+        let parser = NewsParser::new("wsj");
+        assert!(matches!(
+            parser.clean_tag_name("title"),
+            Cow::Borrowed("title")
+        ));
+        assert!(matches!(
+            parser.clean_tag_name("media:content"),
+            Cow::Borrowed("content")
+        ));
+    }
 }
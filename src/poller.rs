@@ -0,0 +1,2176 @@
+//! Periodic polling of feeds for new articles
+//!
+//! A [`Poller`] fetches a set of source topics on intervals and hands the
+//! articles it has not seen before, and those whose feed changed their title or
+//! description, to its sinks. `Poller::start()` runs it in a
+//! background task and returns a [`PollerHandle`], whose `shutdown()` stops it in
+//! order: the poll in progress completes, every sink is flushed, and the
+//! [`Watermarks`] are saved to their [`WatermarkStore`] so a restarted poller does
+//! not deliver the same articles again.
+
+use crate::aggregation::highlight_keywords;
+use crate::alerts::{AlertRouter, AlertRule, Notifier};
+use crate::audit::{AuditEntry, AuditLog, PollAudit};
+use crate::breaking::BreakingDetector;
+use crate::content_kind::{ContentClassifier, RuleClassifier};
+use crate::error::{FanError, Result};
+use crate::events::{self, Event, EventBus};
+use crate::health::{DegradationPolicy, FeedHealth};
+use crate::macro_events::MacroCalendar;
+use crate::market_hours::PollingProfile;
+use crate::news_client::{FeedResolver, TopicFetcher};
+#[cfg(feature = "sqlite")]
+use crate::store::{SearchFilter, SqliteStore};
+use crate::topic_group::TopicGroup;
+use crate::types::NewsArticle;
+use crate::volume::{FeedVolume, VolumePolicy};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use futures::stream::{self, Stream};
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fs;
+use std::io::ErrorKind;
+#[cfg(feature = "sqlite")]
+use std::ops::{Bound, RangeBounds};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{Notify, mpsc, watch};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, sleep_until};
+
+/// Interval between polls unless set with `Poller::with_interval()`
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Shortest interval adaptive polling shortens to, unless set in `AdaptivePolling`
+pub const DEFAULT_MIN_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Longest interval adaptive polling backs off to, unless set in `AdaptivePolling`
+pub const DEFAULT_MAX_POLL_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Topic of the batches `Poller::replay()` delivers, since the store does not keep
+/// the topic an article was polled from
+pub const REPLAY_TOPIC: &str = "replay";
+
+/// Interval between prunes of the disk cache under `SourceConfig::with_retention()`
+const PRUNE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Article IDs remembered per feed; the oldest are forgotten first
+const MAX_SEEN_PER_FEED: usize = 1000;
+
+/// Articles of one feed that earlier polls have not delivered
+///
+/// Includes articles delivered before whose title or description has changed since,
+/// with `NewsArticle::updated` set.
+#[derive(Debug, Clone)]
+pub struct NewArticles {
+    pub source: String,
+    pub topic: String,
+    pub articles: Vec<NewsArticle>,
+}
+
+/// Destination of the new articles a `Poller` finds
+#[async_trait]
+pub trait ArticleSink: Send + Sync {
+    /// Receive the new articles of one feed
+    async fn deliver(&self, batch: &NewArticles) -> Result<()>;
+
+    /// Write out anything buffered; called once when the poller shuts down
+    async fn flush(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// What a bounded `ChannelSink` does with a batch when its channel is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Wait for the receiver to make room, which holds up the poller, including its
+    /// shutdown, until the receiver catches up or is dropped
+    #[default]
+    Block,
+    /// Drop the oldest queued batch to make room
+    DropOldest,
+    /// Merge the articles into a queued batch of the same source topic, or drop the
+    /// oldest batch when there is none
+    Coalesce,
+}
+
+/// State shared by a `ChannelSink` and its `ArticleReceiver`
+struct Channel {
+    queue: Mutex<VecDeque<NewArticles>>,
+    capacity: Option<usize>,
+    overflow: Overflow,
+    /// Signalled when a batch is queued or the sink is dropped
+    readable: Notify,
+    /// Signalled when a batch is taken or the receiver is dropped
+    writable: Notify,
+    sink_alive: AtomicBool,
+    receiver_alive: AtomicBool,
+    /// Articles lost to `Overflow::DropOldest` or `Overflow::Coalesce`
+    dropped: AtomicU64,
+}
+
+/// Sink forwarding new articles to a channel
+///
+/// `ChannelSink::new()` creates an unbounded channel. With a slow consumer its queue
+/// grows without limit; `ChannelSink::bounded()` caps the number of queued batches
+/// and applies an [`Overflow`] policy instead.
+pub struct ChannelSink {
+    channel: Arc<Channel>,
+}
+
+impl ChannelSink {
+    /// Create a sink with an unbounded channel and the receiver its batches arrive on
+    pub fn new() -> (Self, ArticleReceiver) {
+        Self::with_capacity(None, Overflow::Block)
+    }
+
+    /// Create a sink whose channel holds at most `capacity` batches (at least one)
+    pub fn bounded(capacity: usize, overflow: Overflow) -> (Self, ArticleReceiver) {
+        Self::with_capacity(Some(capacity.max(1)), overflow)
+    }
+
+    fn with_capacity(capacity: Option<usize>, overflow: Overflow) -> (Self, ArticleReceiver) {
+        let channel = Arc::new(Channel {
+            queue: Mutex::new(VecDeque::new()),
+            capacity,
+            overflow,
+            readable: Notify::new(),
+            writable: Notify::new(),
+            sink_alive: AtomicBool::new(true),
+            receiver_alive: AtomicBool::new(true),
+            dropped: AtomicU64::new(0),
+        });
+        let receiver = ArticleReceiver {
+            channel: Arc::clone(&channel),
+        };
+        (Self { channel }, receiver)
+    }
+
+    /// Queue a batch unless the channel is full and the policy is to wait
+    ///
+    /// Returns `false` when the caller has to wait for room.
+    fn try_queue(&self, batch: &NewArticles) -> bool {
+        let channel = &self.channel;
+        let mut queue = channel.queue.lock().unwrap();
+        let full = channel
+            .capacity
+            .is_some_and(|capacity| queue.len() >= capacity);
+        if full {
+            match channel.overflow {
+                Overflow::Block => return false,
+                Overflow::Coalesce => {
+                    if let Some(queued) = queue
+                        .iter_mut()
+                        .rev()
+                        .find(|queued| queued.source == batch.source && queued.topic == batch.topic)
+                    {
+                        queued.articles.extend(batch.articles.iter().cloned());
+                        return true;
+                    }
+                    channel.drop_oldest(&mut queue);
+                }
+                Overflow::DropOldest => channel.drop_oldest(&mut queue),
+            }
+        }
+        queue.push_back(batch.clone());
+        true
+    }
+}
+
+impl Channel {
+    fn drop_oldest(&self, queue: &mut VecDeque<NewArticles>) {
+        if let Some(oldest) = queue.pop_front() {
+            warn!(
+                "Channel full, dropping {} new articles from {} topic '{}'",
+                oldest.articles.len(),
+                oldest.source,
+                oldest.topic
+            );
+            self.dropped
+                .fetch_add(oldest.articles.len() as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Drop for ChannelSink {
+    fn drop(&mut self) {
+        self.channel.sink_alive.store(false, Ordering::Release);
+        self.channel.readable.notify_one();
+    }
+}
+
+#[async_trait]
+impl ArticleSink for ChannelSink {
+    async fn deliver(&self, batch: &NewArticles) -> Result<()> {
+        loop {
+            // A dropped receiver is not an error; the other sinks still get the batch
+            if !self.channel.receiver_alive.load(Ordering::Acquire) {
+                debug!(
+                    "Receiver gone, dropping {} new articles",
+                    batch.articles.len()
+                );
+                return Ok(());
+            }
+            if self.try_queue(batch) {
+                self.channel.readable.notify_one();
+                return Ok(());
+            }
+            self.channel.writable.notified().await;
+        }
+    }
+}
+
+/// Receiving end of a `ChannelSink`
+pub struct ArticleReceiver {
+    channel: Arc<Channel>,
+}
+
+impl ArticleReceiver {
+    /// Wait for the next batch
+    ///
+    /// Returns `None` once the sink has been dropped, i.e. the poller has shut down,
+    /// and every queued batch has been received.
+    pub async fn recv(&mut self) -> Option<NewArticles> {
+        loop {
+            if let Some(batch) = self.try_recv() {
+                return Some(batch);
+            }
+            if !self.channel.sink_alive.load(Ordering::Acquire) {
+                // The sink may have queued a last batch before it was dropped
+                return self.try_recv();
+            }
+            self.channel.readable.notified().await;
+        }
+    }
+
+    /// Take the next batch if one is queued
+    pub fn try_recv(&mut self) -> Option<NewArticles> {
+        let batch = self.channel.queue.lock().unwrap().pop_front()?;
+        self.channel.writable.notify_one();
+        Some(batch)
+    }
+
+    /// Number of queued batches
+    pub fn len(&self) -> usize {
+        self.channel.queue.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of articles dropped so far because the channel was full
+    pub fn dropped(&self) -> u64 {
+        self.channel.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Receive batches as a stream, which ends when the poller shuts down
+    pub fn into_stream(self) -> impl Stream<Item = NewArticles> {
+        stream::unfold(self, |mut receiver| async move {
+            let batch = receiver.recv().await?;
+            Some((batch, receiver))
+        })
+    }
+}
+
+impl Drop for ArticleReceiver {
+    fn drop(&mut self) {
+        self.channel.receiver_alive.store(false, Ordering::Release);
+        self.channel.writable.notify_one();
+    }
+}
+
+/// Where a `Poller` keeps its watermarks between runs
+///
+/// `FileWatermarkStore` writes them to a JSON file; implement the trait to keep them
+/// in a database or a shared cache instead.
+#[async_trait]
+pub trait WatermarkStore: Send + Sync {
+    /// Read the saved watermarks, or empty ones if none were saved yet
+    async fn load(&self) -> Result<Watermarks>;
+
+    /// Replace the saved watermarks
+    async fn save(&self, watermarks: &Watermarks) -> Result<()>;
+}
+
+/// Keeps watermarks in a JSON file, as written by `Watermarks::save()`
+#[derive(Debug, Clone)]
+pub struct FileWatermarkStore {
+    path: PathBuf,
+}
+
+impl FileWatermarkStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl WatermarkStore for FileWatermarkStore {
+    async fn load(&self) -> Result<Watermarks> {
+        Watermarks::load(&self.path)
+    }
+
+    async fn save(&self, watermarks: &Watermarks) -> Result<()> {
+        watermarks.save(&self.path)
+    }
+}
+
+/// Articles already delivered, per source and topic
+///
+/// Articles are identified by their GUID, link, or title, in that order of
+/// preference. An article seen again with a different title or description counts as
+/// updated. Saved as JSON with `save()` and read back with `load()`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Watermarks {
+    feeds: BTreeMap<String, BTreeMap<String, FeedWatermark>>,
+}
+
+/// What has been seen of one feed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FeedWatermark {
+    /// IDs of recently seen articles, oldest first
+    pub seen: Vec<String>,
+    /// When the feed was last polled successfully
+    pub last_polled: Option<DateTime<Utc>>,
+    /// Latest version of each article in `seen`
+    #[serde(default)]
+    pub versions: BTreeMap<String, ArticleVersion>,
+    /// Version each updated article had before its latest update
+    #[serde(default)]
+    pub previous: BTreeMap<String, ArticleVersion>,
+}
+
+/// The parts of an article whose change makes it updated
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArticleVersion {
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+impl ArticleVersion {
+    pub fn of(article: &NewsArticle) -> Self {
+        Self {
+            title: article.title.clone(),
+            description: article.description.clone(),
+        }
+    }
+}
+
+impl Watermarks {
+    /// Create empty watermarks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The watermark of a feed, if it has been polled
+    pub fn get(&self, source: &str, topic: &str) -> Option<&FeedWatermark> {
+        self.feeds.get(source)?.get(topic)
+    }
+
+    /// Every feed with its watermark, as `(source, topic, watermark)`
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str, &FeedWatermark)> {
+        self.feeds.iter().flat_map(|(source, topics)| {
+            topics
+                .iter()
+                .map(move |(topic, watermark)| (source.as_str(), topic.as_str(), watermark))
+        })
+    }
+
+    /// Forget a feed, so its next poll delivers every article again
+    pub fn remove(&mut self, source: &str, topic: &str) -> Option<FeedWatermark> {
+        let topics = self.feeds.get_mut(source)?;
+        let watermark = topics.remove(topic);
+        if topics.is_empty() {
+            self.feeds.remove(source);
+        }
+        watermark
+    }
+
+    /// Keep the articles of a feed not seen before or updated since, and remember them
+    ///
+    /// Updated articles have `NewsArticle::updated` set. Articles without a GUID,
+    /// link, or title are dropped.
+    pub fn observe(
+        &mut self,
+        source: &str,
+        topic: &str,
+        articles: Vec<NewsArticle>,
+        now: DateTime<Utc>,
+    ) -> Vec<NewsArticle> {
+        let watermark = self
+            .feeds
+            .entry(source.to_string())
+            .or_default()
+            .entry(topic.to_string())
+            .or_default();
+
+        let mut fresh = Vec::new();
+        for mut article in articles {
+            let Some(id) = article_id(&article) else {
+                continue;
+            };
+            let version = ArticleVersion::of(&article);
+            if !watermark.seen.contains(&id) {
+                watermark.seen.push(id.clone());
+                watermark.versions.insert(id, version);
+                fresh.push(article);
+                continue;
+            }
+            // Watermarks saved before versions were kept have none to compare with
+            match watermark.versions.insert(id.clone(), version.clone()) {
+                Some(previous) if previous != version => {
+                    watermark.previous.insert(id, previous);
+                    article.updated = true;
+                    fresh.push(article);
+                }
+                _ => {}
+            }
+        }
+
+        let excess = watermark.seen.len().saturating_sub(MAX_SEEN_PER_FEED);
+        for id in watermark.seen.drain(..excess) {
+            watermark.versions.remove(&id);
+            watermark.previous.remove(&id);
+        }
+        watermark.last_polled = Some(now);
+        fresh
+    }
+
+    /// Title and description an updated article had before its latest update
+    pub fn previous_version(
+        &self,
+        source: &str,
+        topic: &str,
+        article: &NewsArticle,
+    ) -> Option<&ArticleVersion> {
+        self.get(source, topic)?.previous.get(&article_id(article)?)
+    }
+
+    /// Read watermarks saved with `save()`
+    ///
+    /// A missing file gives empty watermarks.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Write the watermarks to `path` as JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write to a temporary file first so a crash never leaves a partial file
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec(self)?)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
+
+/// Identity of an article across polls: its GUID, link, or title
+pub(crate) fn article_id(article: &NewsArticle) -> Option<String> {
+    article
+        .guid
+        .as_ref()
+        .or(article.link.as_ref())
+        .or(article.title.as_ref())
+        .cloned()
+}
+
+/// Settings of a `Poller` that can change while it runs
+///
+/// Read from a JSON file with `from_file()`:
+///
+/// ```json
+/// {
+///   "feeds": [
+///     { "source": "CNBC", "topic": "investing" },
+///     { "source": "Wall Street Journal", "topic": "RSSMarketsMain" }
+///   ],
+///   "interval_seconds": 120,
+///   "keywords": ["fed", "earnings"]
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PollerConfig {
+    pub feeds: Vec<FeedConfig>,
+    /// Seconds between polls of feeds without their own interval
+    #[serde(default = "default_interval_seconds")]
+    pub interval_seconds: u64,
+    /// Deliver only articles mentioning one of these keywords, or every article when
+    /// empty; matched as in `aggregation::mentions_any()`
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Adapt each feed's interval to how often it has new articles
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive: Option<AdaptivePolling>,
+    /// Follow market sessions instead of `interval_seconds`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<PollingProfile>,
+    /// Market session profiles for the feeds of topic groups, overriding `profile`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_profiles: Vec<GroupProfile>,
+    /// When a feed is reported degraded
+    #[serde(default)]
+    pub degradation: DegradationPolicy,
+    /// Report jumps and droughts in the number of new articles of each feed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume: Option<VolumePolicy>,
+    /// Rules sending matching new articles to the notifiers registered with
+    /// `Poller::with_notifier()`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alerts: Vec<AlertRule>,
+    /// Tag new articles about scheduled macro events using the built-in
+    /// `MacroCalendar`, so alert rules can use `event:` terms
+    #[serde(default)]
+    pub macro_events: bool,
+    /// Label new articles with their `ContentKind` using the built-in
+    /// `RuleClassifier`, so alert rules can use `kind:` terms
+    #[serde(default)]
+    pub classify_content: bool,
+    /// Log what would go to sinks and notifiers instead of sending it
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Set the priority of new articles using the built-in `BreakingDetector`, so
+    /// alert rules can use `priority:` terms
+    #[serde(default)]
+    pub detect_breaking: bool,
+}
+
+impl Default for PollerConfig {
+    fn default() -> Self {
+        Self {
+            feeds: Vec::new(),
+            interval_seconds: default_interval_seconds(),
+            keywords: Vec::new(),
+            adaptive: None,
+            profile: None,
+            group_profiles: Vec::new(),
+            degradation: DegradationPolicy::default(),
+            volume: None,
+            alerts: Vec::new(),
+            macro_events: false,
+            classify_content: false,
+            dry_run: false,
+            detect_breaking: false,
+        }
+    }
+}
+
+/// Market session profile of the feeds in a topic group
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupProfile {
+    pub group: TopicGroup,
+    #[serde(default)]
+    pub profile: PollingProfile,
+}
+
+/// One polled topic in a `PollerConfig`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedConfig {
+    /// Source name as returned by `NewsSource::name()`
+    pub source: String,
+    pub topic: String,
+    /// Seconds between polls of this feed, instead of the poller's interval
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval_seconds: Option<u64>,
+}
+
+/// Bounds within which adaptive polling moves each feed's interval
+///
+/// A feed starts at its configured interval, clamped to the bounds. A poll that finds
+/// new articles halves the interval, down to `min_interval_seconds`; a poll that
+/// finds none lengthens it by half, up to `max_interval_seconds`. Fast-moving
+/// headline feeds thus settle near the minimum, which should respect the sources'
+/// rate limits, while feeds that rarely change back off to the maximum. A failed poll
+/// leaves the interval as it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdaptivePolling {
+    #[serde(default = "default_min_interval_seconds")]
+    pub min_interval_seconds: u64,
+    #[serde(default = "default_max_interval_seconds")]
+    pub max_interval_seconds: u64,
+}
+
+fn default_min_interval_seconds() -> u64 {
+    DEFAULT_MIN_POLL_INTERVAL.as_secs()
+}
+
+fn default_max_interval_seconds() -> u64 {
+    DEFAULT_MAX_POLL_INTERVAL.as_secs()
+}
+
+impl Default for AdaptivePolling {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_POLL_INTERVAL, DEFAULT_MAX_POLL_INTERVAL)
+    }
+}
+
+impl AdaptivePolling {
+    /// Adapt intervals between `min_interval` and `max_interval`, in whole seconds
+    pub fn new(min_interval: Duration, max_interval: Duration) -> Self {
+        Self {
+            min_interval_seconds: min_interval.as_secs(),
+            max_interval_seconds: max_interval.as_secs(),
+        }
+    }
+
+    pub fn min_interval(&self) -> Duration {
+        Duration::from_secs(self.min_interval_seconds.max(1))
+    }
+
+    pub fn max_interval(&self) -> Duration {
+        Duration::from_secs(self.max_interval_seconds).max(self.min_interval())
+    }
+
+    /// Clamp an interval to the bounds
+    fn clamp(&self, interval: Duration) -> Duration {
+        interval.clamp(self.min_interval(), self.max_interval())
+    }
+
+    /// Interval following a successful poll that found `new_articles`
+    fn next_interval(&self, interval: Duration, new_articles: usize) -> Duration {
+        if new_articles > 0 {
+            self.clamp(interval / 2)
+        } else {
+            self.clamp(interval * 3 / 2)
+        }
+    }
+}
+
+fn default_interval_seconds() -> u64 {
+    DEFAULT_POLL_INTERVAL.as_secs()
+}
+
+impl PollerConfig {
+    /// Read a config from a JSON file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+}
+
+/// Polls source topics on an interval and delivers new articles to sinks
+///
+/// Created with [`NewsClient::poller()`](crate::NewsClient::poller). Each topic is
+/// polled on its own schedule, and topics that are due together are fetched
+/// concurrently; a failing topic is logged and retried at its next poll.
+pub struct Poller {
+    resolver: FeedResolver,
+    fetchers: Vec<TopicFetcher>,
+    interval: Duration,
+    feed_intervals: HashMap<(String, String), Duration>,
+    adaptive: Option<AdaptivePolling>,
+    profile: Option<PollingProfile>,
+    group_profiles: Vec<(TopicGroup, PollingProfile)>,
+    keywords: Vec<String>,
+    event_bus: Option<EventBus>,
+    degradation: DegradationPolicy,
+    volume: Option<VolumePolicy>,
+    sinks: Vec<Arc<dyn ArticleSink>>,
+    alerts: AlertRouter,
+    macro_calendar: Option<MacroCalendar>,
+    classifier: Option<Arc<dyn ContentClassifier>>,
+    breaking: Option<BreakingDetector>,
+    watermark_store: Option<Arc<dyn WatermarkStore>>,
+    audit_log: Option<AuditLog>,
+    dry_run: bool,
+    config_path: Option<PathBuf>,
+}
+
+impl Poller {
+    pub(crate) fn new(resolver: FeedResolver, fetchers: Vec<TopicFetcher>) -> Self {
+        Self {
+            resolver,
+            fetchers,
+            interval: DEFAULT_POLL_INTERVAL,
+            feed_intervals: HashMap::new(),
+            adaptive: None,
+            profile: None,
+            group_profiles: Vec::new(),
+            keywords: Vec::new(),
+            event_bus: None,
+            degradation: DegradationPolicy::default(),
+            volume: None,
+            sinks: Vec::new(),
+            alerts: AlertRouter::default(),
+            macro_calendar: None,
+            classifier: None,
+            breaking: None,
+            watermark_store: None,
+            audit_log: None,
+            dry_run: false,
+            config_path: None,
+        }
+    }
+
+    /// Set the interval between polls of feeds without their own interval
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Poll one feed on its own interval, such as a real-time headline feed more often
+    /// than the rest
+    pub fn with_feed_interval(mut self, source: &str, topic: &str, interval: Duration) -> Self {
+        self.feed_intervals
+            .insert((source.to_string(), topic.to_string()), interval);
+        self
+    }
+
+    /// Adapt each feed's interval to how often it has new articles
+    pub fn with_adaptive(mut self, adaptive: AdaptivePolling) -> Self {
+        self.adaptive = Some(adaptive);
+        self
+    }
+
+    /// Poll on the intervals of a market session profile instead of a fixed interval
+    ///
+    /// Feeds with their own interval from `with_feed_interval()` keep it, and feeds
+    /// following a profile are not adapted to their churn.
+    pub fn with_profile(mut self, profile: PollingProfile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Poll the feeds of a topic group on a market session profile
+    ///
+    /// Overrides `with_profile()`. A feed in several groups follows the profile added
+    /// first.
+    pub fn with_group_profile(mut self, group: TopicGroup, profile: PollingProfile) -> Self {
+        self.group_profiles.push((group, profile));
+        self
+    }
+
+    /// Deliver only articles mentioning one of these keywords
+    ///
+    /// Where each keyword was found is recorded in `NewsArticle::matches`.
+    pub fn with_keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Replace the polled feeds, interval, keywords, alert rules, macro event
+    /// tagging, and content classification with those of `config`
+    ///
+    /// Fails, leaving the poller unchanged, when the config names an unknown source.
+    pub fn with_config(mut self, config: &PollerConfig) -> Result<Self> {
+        self.apply(config)?;
+        Ok(self)
+    }
+
+    /// Take the polled feeds, interval, keywords, and alert rules from a JSON config
+    /// file
+    ///
+    /// With the `hot-reload` feature, the poller also watches the file once started
+    /// and applies every valid change without restarting; see `PollerHandle::reload()`.
+    pub fn with_config_file(self, path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let mut poller = self.with_config(&PollerConfig::from_file(&path)?)?;
+        poller.config_path = Some(path);
+        Ok(poller)
+    }
+
+    /// Add a sink for new articles
+    pub fn with_sink(mut self, sink: impl ArticleSink + 'static) -> Self {
+        self.sinks.push(Arc::new(sink));
+        self
+    }
+
+    /// Check new articles against alert rules, replacing any from the config
+    ///
+    /// Rules see the articles that pass `with_keywords()`.
+    pub fn with_alert_rules(mut self, rules: Vec<AlertRule>) -> Self {
+        self.alerts.set_rules(rules);
+        self
+    }
+
+    /// Register the notifier that alert rules name `name`
+    pub fn with_notifier(
+        mut self,
+        name: impl Into<String>,
+        notifier: impl Notifier + 'static,
+    ) -> Self {
+        self.alerts.add_notifier(name.into(), Arc::new(notifier));
+        self
+    }
+
+    /// Tag new articles with the macro events of `calendar` they cover, replacing
+    /// `macro_events` of the config
+    ///
+    /// Articles are tagged before keyword filtering, so sinks and alert rules see the
+    /// tags.
+    pub fn with_macro_calendar(mut self, calendar: MacroCalendar) -> Self {
+        self.macro_calendar = Some(calendar);
+        self
+    }
+
+    /// Label new articles with their kind using `classifier`, replacing
+    /// `classify_content` of the config
+    ///
+    /// Like macro event tags, labels are set before keyword filtering.
+    pub fn with_classifier(mut self, classifier: impl ContentClassifier + 'static) -> Self {
+        self.classifier = Some(Arc::new(classifier));
+        self
+    }
+
+    /// Set the priority of new articles using `detector`, replacing
+    /// `detect_breaking` of the config
+    ///
+    /// Like content labels, priorities are set before keyword filtering, so
+    /// coverage counts articles the keywords leave out.
+    pub fn with_breaking_detector(mut self, detector: BreakingDetector) -> Self {
+        self.breaking = Some(detector);
+        self
+    }
+
+    /// Publish new articles and feed degradation and recovery on an event bus
+    pub fn with_event_bus(mut self, bus: EventBus) -> Self {
+        self.event_bus = Some(bus);
+        self
+    }
+
+    /// Set when a feed is reported degraded; see `DegradationPolicy`
+    pub fn with_degradation_policy(mut self, policy: DegradationPolicy) -> Self {
+        self.degradation = policy;
+        self
+    }
+
+    /// Publish a `VolumeAnomaly` event when the number of new articles of a feed
+    /// spikes or dries up, replacing `volume` of the config; see `volume`
+    pub fn with_volume_policy(mut self, policy: VolumePolicy) -> Self {
+        self.volume = Some(policy);
+        self
+    }
+
+    /// Keep watermarks in a JSON file; see `with_watermark_store()`
+    pub fn with_watermarks_file(self, path: impl Into<PathBuf>) -> Self {
+        self.with_watermark_store(FileWatermarkStore::new(path))
+    }
+
+    /// Load watermarks from `store` on start, and save them there after every poll
+    /// that finds new articles and on shutdown
+    ///
+    /// Without a store, a restarted poller delivers every article of its first poll
+    /// again.
+    pub fn with_watermark_store(mut self, store: impl WatermarkStore + 'static) -> Self {
+        self.watermark_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Record each poll of a feed in `log`: the articles it returned, those already
+    /// delivered, and those that passed the keyword filter
+    pub fn with_audit_log(mut self, log: AuditLog) -> Self {
+        self.audit_log = Some(log);
+        self
+    }
+
+    /// Log each batch and alert that would go to the sinks and notifiers instead of
+    /// sending it, to try out alert rules against live feeds
+    ///
+    /// Watermarks, the event bus, and the audit log still see every new article, so
+    /// a dry run marks the articles it finds as delivered. `dry_run` in a config
+    /// replaces this setting when the config is applied.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self.alerts.set_dry_run(dry_run);
+        self
+    }
+
+    /// Run stored articles published within `range` through the stages new articles
+    /// go through: macro event tags, content labels, priorities, keywords, sinks,
+    /// alert rules, and the event bus
+    ///
+    /// For trying new alert rules on yesterday's news, or for catching a notifier up
+    /// after an outage. Nothing is fetched and the watermarks are left alone, so the
+    /// poller can replay before it starts. Articles are delivered oldest first, in one
+    /// batch per source with the topic `REPLAY_TOPIC`; articles without a parseable
+    /// publication date are skipped.
+    ///
+    /// # Returns
+    /// The number of articles that passed the keyword filter
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use chrono::{Duration, Utc};
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::alerts::AlertRule;
+    /// use finance_news_aggregator_rs::filter::Query;
+    /// use finance_news_aggregator_rs::store::SqliteStore;
+    ///
+    /// # async fn run(notifier: impl finance_news_aggregator_rs::alerts::Notifier + 'static)
+    /// # -> finance_news_aggregator_rs::Result<()> {
+    /// let store = SqliteStore::open("news.db")?;
+    /// let rule = AlertRule::new("fed", Query::parse(r#""rate cut" OR FOMC"#)?, "slack");
+    /// let poller = NewsClient::new()
+    ///     .poller(&[("CNBC", "economy")])?
+    ///     .with_alert_rules(vec![rule])
+    ///     .with_notifier("slack", notifier);
+    ///
+    /// let yesterday = Utc::now() - Duration::days(1);
+    /// let replayed = poller.replay(&store, yesterday..).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sqlite")]
+    pub async fn replay(
+        &self,
+        store: &SqliteStore,
+        range: impl RangeBounds<DateTime<Utc>>,
+    ) -> Result<usize> {
+        // Publication dates are stored in whole seconds
+        let second = chrono::Duration::seconds(1);
+        let mut filter = SearchFilter::new().with_limit(usize::MAX);
+        match range.start_bound() {
+            Bound::Included(start) => filter = filter.with_since(*start),
+            Bound::Excluded(start) => filter = filter.with_since(*start + second),
+            Bound::Unbounded => {}
+        }
+        match range.end_bound() {
+            Bound::Included(end) => filter = filter.with_until(*end + second),
+            Bound::Excluded(end) => filter = filter.with_until(*end),
+            Bound::Unbounded => {}
+        }
+        // Stored articles come newest first
+        let mut by_source: BTreeMap<String, Vec<NewsArticle>> = BTreeMap::new();
+        for article in store.search("", &filter)?.into_iter().rev() {
+            let source = article.source.clone().unwrap_or_default();
+            by_source.entry(source).or_default().push(article);
+        }
+
+        let mut replayed = 0;
+        for (source, articles) in by_source {
+            debug!("Replaying {} stored {} articles", articles.len(), source);
+            replayed += self.dispatch(&source, None, articles, None).await;
+        }
+        Ok(replayed)
+    }
+
+    /// Start polling in a background task
+    ///
+    /// The first poll runs at once. Must be called from within a Tokio runtime.
+    /// Dropping the returned handle also stops the poller, without waiting for it.
+    pub fn start(self) -> PollerHandle {
+        let (shutdown, signal) = watch::channel(false);
+        let (reload, updates) = mpsc::unbounded_channel();
+
+        #[cfg(feature = "hot-reload")]
+        let watcher = self.config_path.as_deref().and_then(|path| {
+            watch_config_file(path, reload.clone())
+                .inspect_err(|e| warn!("Not watching {:?} for changes: {}", path, e))
+                .ok()
+        });
+        let task = tokio::spawn(async move {
+            #[cfg(feature = "hot-reload")]
+            let _watcher = watcher;
+            self.run(signal, updates).await
+        });
+
+        PollerHandle {
+            shutdown,
+            reload,
+            task,
+        }
+    }
+
+    /// Apply a config, resolving every feed before changing anything
+    fn apply(&mut self, config: &PollerConfig) -> Result<()> {
+        let fetchers = config
+            .feeds
+            .iter()
+            .map(|feed| self.resolver.resolve(&feed.source, &feed.topic))
+            .collect::<Result<Vec<_>>>()?;
+        self.fetchers = fetchers;
+        self.interval = Duration::from_secs(config.interval_seconds.max(1));
+        self.feed_intervals = config
+            .feeds
+            .iter()
+            .filter_map(|feed| {
+                let interval = Duration::from_secs(feed.interval_seconds?.max(1));
+                Some(((feed.source.clone(), feed.topic.clone()), interval))
+            })
+            .collect();
+        self.adaptive = config.adaptive;
+        self.profile = config.profile;
+        self.group_profiles = config
+            .group_profiles
+            .iter()
+            .map(|group| (group.group, group.profile))
+            .collect();
+        self.degradation = config.degradation;
+        self.volume = config.volume;
+        self.keywords = config.keywords.clone();
+        self.alerts.set_rules(config.alerts.clone());
+        self.macro_calendar = config.macro_events.then(MacroCalendar::builtin);
+        self.classifier = config
+            .classify_content
+            .then(|| Arc::new(RuleClassifier::new()) as Arc<dyn ContentClassifier>);
+        self.dry_run = config.dry_run;
+        self.alerts.set_dry_run(config.dry_run);
+        self.breaking = config.detect_breaking.then(BreakingDetector::new);
+        Ok(())
+    }
+
+    /// Schedule every feed, keeping the state of feeds already in `previous`
+    ///
+    /// New feeds are due at once. A feed whose configured interval or profile changed
+    /// is next polled one new interval from now.
+    fn schedule(&self, previous: Vec<ScheduledFeed>, now: Instant) -> Vec<ScheduledFeed> {
+        let mut previous: HashMap<(String, String), ScheduledFeed> = previous
+            .into_iter()
+            .map(|feed| (feed.key(), feed))
+            .collect();
+        self.fetchers
+            .iter()
+            .map(|fetcher| {
+                let key = (
+                    fetcher.source_name().to_string(),
+                    fetcher.topic().to_string(),
+                );
+                let own_interval = self.feed_intervals.get(&key).copied();
+                let mut base = own_interval.unwrap_or(self.interval);
+                if let Some(adaptive) = &self.adaptive {
+                    base = adaptive.clamp(base);
+                }
+                let profile = match own_interval {
+                    Some(_) => None,
+                    None => self
+                        .group_profiles
+                        .iter()
+                        .find(|(group, _)| fetcher.in_group(*group))
+                        .map(|(_, profile)| *profile)
+                        .or(self.profile),
+                };
+                match previous.remove(&key) {
+                    Some(feed) if feed.base == base && feed.profile == profile => ScheduledFeed {
+                        fetcher: fetcher.clone(),
+                        ..feed
+                    },
+                    Some(previous) => {
+                        let feed = ScheduledFeed::new(fetcher.clone(), base, profile, now);
+                        ScheduledFeed {
+                            next_poll: now + feed.delay(),
+                            health: previous.health,
+                            volume: previous.volume,
+                            ..feed
+                        }
+                    }
+                    None => ScheduledFeed::new(fetcher.clone(), base, profile, now),
+                }
+            })
+            .collect()
+    }
+
+    async fn run(
+        mut self,
+        mut signal: watch::Receiver<bool>,
+        mut updates: mpsc::UnboundedReceiver<PollerConfig>,
+    ) -> Result<()> {
+        let mut watermarks = match &self.watermark_store {
+            Some(store) => store.load().await.unwrap_or_else(|e| {
+                warn!("Ignoring unreadable watermarks: {}", e);
+                Watermarks::new()
+            }),
+            None => Watermarks::new(),
+        };
+
+        let mut feeds = self.schedule(Vec::new(), Instant::now());
+        let mut pruned_at: Option<Instant> = None;
+        loop {
+            let next_poll = feeds.iter().map(|feed| feed.next_poll).min();
+            tokio::select! {
+                // Only checked between polls, so a poll in progress always completes
+                _ = signal.changed() => break,
+                Some(config) = updates.recv() => {
+                    match self.apply(&config) {
+                        Ok(()) => info!("Poller config reloaded: {} feeds", self.fetchers.len()),
+                        Err(e) => warn!("Keeping the previous poller config: {}", e),
+                    }
+                    feeds = self.schedule(feeds, Instant::now());
+                }
+                _ = sleep_until(next_poll.unwrap_or_else(Instant::now)), if next_poll.is_some() => {
+                    let found = self.poll_due(&mut feeds, &mut watermarks).await;
+                    // Saved as it goes, so a crash does not redeliver what was delivered
+                    if found
+                        && let Some(store) = &self.watermark_store
+                        && let Err(e) = store.save(&watermarks).await
+                    {
+                        warn!("Failed to save watermarks: {}", e);
+                    }
+                    if pruned_at.is_none_or(|at| at.elapsed() >= PRUNE_INTERVAL) {
+                        if let Err(e) = self.resolver.prune() {
+                            warn!("Failed to prune the disk cache: {}", e);
+                        }
+                        pruned_at = Some(Instant::now());
+                    }
+                }
+            }
+        }
+
+        debug!("Poller stopping, flushing {} sinks", self.sinks.len());
+        let mut result = Ok(());
+        for sink in &self.sinks {
+            if let Err(e) = sink.flush().await {
+                warn!("Failed to flush sink: {}", e);
+                result = result.and(Err(e));
+            }
+        }
+        if let Some(store) = &self.watermark_store
+            && let Err(e) = store.save(&watermarks).await
+        {
+            warn!("Failed to save watermarks: {}", e);
+            result = result.and(Err(e));
+        }
+        result
+    }
+
+    /// Poll the feeds that are due and schedule their next polls
+    ///
+    /// Returns whether any feed had new or updated articles.
+    async fn poll_due(&self, feeds: &mut [ScheduledFeed], watermarks: &mut Watermarks) -> bool {
+        let now = Instant::now();
+        let mut due: Vec<&mut ScheduledFeed> = feeds
+            .iter_mut()
+            .filter(|feed| feed.next_poll <= now)
+            .collect();
+        let churn = self.poll(&mut due, watermarks).await;
+        let found = churn
+            .iter()
+            .any(|new_articles| new_articles.unwrap_or(0) > 0);
+
+        let now = Instant::now();
+        for (feed, result) in due.iter_mut().zip(churn) {
+            if let (Some(adaptive), None, Some(new_articles)) =
+                (&self.adaptive, &feed.profile, result)
+            {
+                let interval = adaptive.next_interval(feed.interval, new_articles);
+                if interval != feed.interval {
+                    debug!(
+                        "Polling {} topic '{}' every {:?}",
+                        feed.fetcher.source_name(),
+                        feed.fetcher.topic(),
+                        interval
+                    );
+                    feed.interval = interval;
+                }
+            }
+            feed.next_poll = now + feed.delay();
+        }
+        found
+    }
+
+    /// Record a poll of a feed in the audit log, if there is one
+    fn audit(
+        &self,
+        fetcher: &TopicFetcher,
+        articles: usize,
+        duplicates: usize,
+        matched: usize,
+        error: Option<String>,
+    ) {
+        if let Some(log) = &self.audit_log {
+            log.record(&AuditEntry::Poll(PollAudit {
+                time: Utc::now(),
+                source: fetcher.source_name().to_string(),
+                topic: fetcher.topic().to_string(),
+                articles,
+                duplicates,
+                matched,
+                error,
+            }));
+        }
+    }
+
+    /// Fetch feeds once, record their health, and deliver the new articles
+    ///
+    /// Returns the number of new and updated articles of each feed, before keyword
+    /// filtering, or `None` for a feed that failed.
+    async fn poll(
+        &self,
+        feeds: &mut [&mut ScheduledFeed],
+        watermarks: &mut Watermarks,
+    ) -> Vec<Option<usize>> {
+        let results = join_all(feeds.iter().map(|feed| feed.fetcher.fetch())).await;
+        let mut churn = Vec::with_capacity(feeds.len());
+        for (feed, result) in feeds.iter_mut().zip(results) {
+            let fetcher = &feed.fetcher;
+            let event = feed.health.record(
+                fetcher.source_name(),
+                fetcher.topic(),
+                result.as_ref().err(),
+                &self.degradation,
+            );
+            if let (Some(bus), Some(event)) = (&self.event_bus, event) {
+                bus.publish(event);
+            }
+            let articles = match result {
+                Ok(articles) => articles,
+                Err(e) => {
+                    warn!(
+                        "Polling {} topic '{}' failed: {}",
+                        fetcher.source_name(),
+                        fetcher.topic(),
+                        e
+                    );
+                    self.audit(fetcher, 0, 0, 0, Some(e.to_string()));
+                    churn.push(None);
+                    continue;
+                }
+            };
+
+            // Filtered-out articles still count as seen
+            let fetched = articles.len();
+            let articles =
+                watermarks.observe(fetcher.source_name(), fetcher.topic(), articles, Utc::now());
+            churn.push(Some(articles.len()));
+            let duplicates = fetched - articles.len();
+            if let Some(policy) = &self.volume {
+                let event = feed.volume.record(
+                    fetcher.source_name(),
+                    fetcher.topic(),
+                    articles.len(),
+                    policy,
+                );
+                if let (Some(bus), Some(event)) = (&self.event_bus, event) {
+                    bus.publish(event);
+                }
+            }
+            let matched = self
+                .dispatch(
+                    fetcher.source_name(),
+                    Some(fetcher.topic()),
+                    articles,
+                    Some(watermarks),
+                )
+                .await;
+            self.audit(fetcher, fetched, duplicates, matched, None);
+        }
+        churn
+    }
+
+    /// Tag and filter new articles of a source, then hand them to the sinks, alert
+    /// rules, and event bus
+    ///
+    /// Articles without a topic, such as replayed ones, are batched under
+    /// `REPLAY_TOPIC`. `watermarks` give the previous versions of updated articles.
+    ///
+    /// # Returns
+    /// The number of articles that passed the keyword filter
+    async fn dispatch(
+        &self,
+        source: &str,
+        topic: Option<&str>,
+        mut articles: Vec<NewsArticle>,
+        watermarks: Option<&Watermarks>,
+    ) -> usize {
+        if let Some(calendar) = &self.macro_calendar {
+            calendar.tag_all(&mut articles);
+        }
+        if let Some(classifier) = &self.classifier {
+            classifier.label_all(&mut articles, topic);
+        }
+        if let Some(detector) = &self.breaking {
+            detector.prioritize_all(source, topic, &mut articles);
+        }
+        if !self.keywords.is_empty() {
+            let keywords: Vec<&str> = self.keywords.iter().map(String::as_str).collect();
+            articles.retain_mut(|article| highlight_keywords(article, &keywords));
+        }
+        if articles.is_empty() {
+            return 0;
+        }
+        let batch = NewArticles {
+            source: source.to_string(),
+            topic: topic.unwrap_or(REPLAY_TOPIC).to_string(),
+            articles,
+        };
+        if self.dry_run {
+            info!(
+                "Dry run: would deliver {} articles of {} topic '{}' to {} sinks",
+                batch.articles.len(),
+                batch.source,
+                batch.topic,
+                self.sinks.len()
+            );
+        } else {
+            for sink in &self.sinks {
+                if let Err(e) = sink.deliver(&batch).await {
+                    warn!("Failed to deliver {} articles: {}", batch.articles.len(), e);
+                }
+            }
+        }
+        // Failed notifications are logged by the router
+        let _ = self.alerts.route(&batch).await;
+        if let Some(bus) = &self.event_bus {
+            for article in &batch.articles {
+                let previous = watermarks
+                    .filter(|_| article.updated)
+                    .and_then(|watermarks| {
+                        watermarks.previous_version(&batch.source, &batch.topic, article)
+                    });
+                let event = match previous {
+                    Some(previous) => Event::ArticleUpdated(Box::new(events::ArticleUpdated {
+                        source: batch.source.clone(),
+                        topic: batch.topic.clone(),
+                        article: article.clone(),
+                        previous: previous.clone(),
+                    })),
+                    None => Event::NewArticle(Box::new(events::NewArticle {
+                        source: batch.source.clone(),
+                        topic: batch.topic.clone(),
+                        article: article.clone(),
+                    })),
+                };
+                bus.publish(event);
+            }
+        }
+        batch.articles.len()
+    }
+}
+
+/// A polled feed with its schedule
+struct ScheduledFeed {
+    fetcher: TopicFetcher,
+    /// Configured interval, clamped to the adaptive polling bounds
+    base: Duration,
+    /// Current interval, which adaptive polling moves away from `base`
+    interval: Duration,
+    /// Market session profile, which replaces the interval
+    profile: Option<PollingProfile>,
+    next_poll: Instant,
+    health: FeedHealth,
+    volume: FeedVolume,
+}
+
+impl ScheduledFeed {
+    fn new(
+        fetcher: TopicFetcher,
+        interval: Duration,
+        profile: Option<PollingProfile>,
+        next_poll: Instant,
+    ) -> Self {
+        Self {
+            fetcher,
+            base: interval,
+            interval,
+            profile,
+            next_poll,
+            health: FeedHealth::default(),
+            volume: FeedVolume::default(),
+        }
+    }
+
+    /// Delay from now until the next poll
+    fn delay(&self) -> Duration {
+        match &self.profile {
+            Some(profile) => profile.next_poll_delay(Utc::now()),
+            None => self.interval,
+        }
+    }
+
+    fn key(&self) -> (String, String) {
+        (
+            self.fetcher.source_name().to_string(),
+            self.fetcher.topic().to_string(),
+        )
+    }
+}
+
+/// Send the config in `path` to a running poller whenever the file changes
+///
+/// The directory is watched rather than the file, since editors often save by
+/// replacing the file. Unreadable or invalid versions of the file are logged and
+/// skipped.
+#[cfg(feature = "hot-reload")]
+fn watch_config_file(
+    path: &Path,
+    reload: mpsc::UnboundedSender<PollerConfig>,
+) -> Result<notify::RecommendedWatcher> {
+    use notify::{RecursiveMode, Watcher};
+
+    let file = path.to_path_buf();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let event = match event {
+            Ok(event) => event,
+            Err(e) => {
+                warn!("Error watching {:?}: {}", file, e);
+                return;
+            }
+        };
+        let touches_file = event
+            .paths
+            .iter()
+            .any(|changed| changed.file_name() == file.file_name());
+        if !touches_file || !(event.kind.is_create() || event.kind.is_modify()) {
+            return;
+        }
+        match PollerConfig::from_file(&file) {
+            Ok(config) => {
+                // The poller may have stopped
+                let _ = reload.send(config);
+            }
+            Err(e) => warn!("Ignoring invalid poller config in {:?}: {}", file, e),
+        }
+    })
+    .map_err(|e| FanError::Unknown(format!("Cannot watch {:?}: {}", path, e)))?;
+
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    watcher
+        .watch(dir, RecursiveMode::NonRecursive)
+        .map_err(|e| FanError::Unknown(format!("Cannot watch {:?}: {}", path, e)))?;
+    Ok(watcher)
+}
+
+/// Handle to a running [`Poller`]
+pub struct PollerHandle {
+    shutdown: watch::Sender<bool>,
+    reload: mpsc::UnboundedSender<PollerConfig>,
+    task: JoinHandle<Result<()>>,
+}
+
+impl PollerHandle {
+    /// Whether the poller is still running
+    pub fn is_running(&self) -> bool {
+        !self.task.is_finished()
+    }
+
+    /// Apply a new config to the running poller
+    ///
+    /// Takes effect between polls: added feeds are polled from the next poll on,
+    /// removed ones are no longer fetched, and a changed interval restarts the
+    /// interval timer. A config naming an unknown source is logged and ignored, and
+    /// the poller keeps its previous settings.
+    pub fn reload(&self, config: PollerConfig) {
+        // Fails only when the poller has already stopped
+        let _ = self.reload.send(config);
+    }
+
+    /// Stop the poller and wait until it has shut down
+    ///
+    /// A poll in progress completes and delivers its articles first. Then every sink
+    /// is flushed and the watermarks are saved, when a watermark store is set.
+    /// Returns the first error from flushing or saving.
+    pub async fn shutdown(self) -> Result<()> {
+        // Fails only when the poller has already stopped
+        let _ = self.shutdown.send(true);
+        self.task
+            .await
+            .map_err(|e| FanError::Unknown(format!("Poller task failed: {}", e)))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::HttpClient;
+    use crate::news_source::{FeedSource, NewsSource};
+    use crate::parser::NewsParser;
+    use crate::types::SourceConfig;
+    use reqwest::Client;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Source whose feed gains one story per fetch
+    struct GrowingSource {
+        url_map: HashMap<String, String>,
+        client: Client,
+        parser: NewsParser,
+        fetches: AtomicUsize,
+        failing: AtomicBool,
+    }
+
+    fn growing_source() -> Arc<GrowingSource> {
+        let mut url_map = HashMap::new();
+        url_map.insert(
+            "base".to_string(),
+            "https://example.com/{topic}".to_string(),
+        );
+        Arc::new(GrowingSource {
+            url_map,
+            client: Client::new(),
+            parser: NewsParser::new("test"),
+            fetches: AtomicUsize::new(0),
+            failing: AtomicBool::new(false),
+        })
+    }
+
+    #[async_trait]
+    impl NewsSource for GrowingSource {
+        fn name(&self) -> &'static str {
+            "Test"
+        }
+
+        fn available_topics(&self) -> Vec<&'static str> {
+            vec!["news"]
+        }
+
+        fn group_topics(&self, group: TopicGroup) -> Vec<&'static str> {
+            match group {
+                TopicGroup::Markets => vec!["markets"],
+                _ => vec![],
+            }
+        }
+
+        async fn fetch_topic(&self, topic: &str) -> Result<Vec<NewsArticle>> {
+            self.fetch_feed_topic(topic).await
+        }
+
+        fn feed(&self) -> Option<&dyn FeedSource> {
+            Some(self)
+        }
+    }
+
+    #[async_trait]
+    impl FeedSource for GrowingSource {
+        fn url_map(&self) -> &HashMap<String, String> {
+            &self.url_map
+        }
+
+        fn http_client(&self) -> &dyn HttpClient {
+            &self.client
+        }
+
+        fn parser(&self) -> &NewsParser {
+            &self.parser
+        }
+
+        async fn fetch_body(&self, _url: &str) -> Result<String> {
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(FanError::Timeout("test".to_string()));
+            }
+            let count = self.fetches.fetch_add(1, Ordering::SeqCst) + 1;
+            let items: String = (1..=count)
+                .map(|n| format!("<item><title>Story {}</title></item>", n))
+                .collect();
+            Ok(format!("<rss><channel>{}</channel></rss>", items))
+        }
+    }
+
+    /// Sink counting its flushes
+    struct FlushCounter(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl ArticleSink for FlushCounter {
+        async fn deliver(&self, _batch: &NewArticles) -> Result<()> {
+            Ok(())
+        }
+
+        async fn flush(&self) -> Result<()> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_watermarks_observe() {
+        let mut watermarks = Watermarks::new();
+        let article = |title: &str| {
+            let mut article = NewsArticle::new();
+            article.title = Some(title.to_string());
+            article
+        };
+
+        let fresh = watermarks.observe("CNBC", "markets", vec![article("A")], Utc::now());
+        assert_eq!(fresh.len(), 1);
+        let fresh = watermarks.observe(
+            "CNBC",
+            "markets",
+            vec![article("A"), article("B"), NewsArticle::new()],
+            Utc::now(),
+        );
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].title.as_deref(), Some("B"));
+        // Topics are tracked separately
+        let fresh = watermarks.observe("CNBC", "earnings", vec![article("A")], Utc::now());
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(watermarks.get("CNBC", "markets").unwrap().seen, ["A", "B"]);
+    }
+
+    #[test]
+    fn test_watermarks_detect_updates() {
+        let mut watermarks = Watermarks::new();
+        let article = |title: &str| {
+            let mut article = NewsArticle::new();
+            article.guid = Some("story-1".to_string());
+            article.title = Some(title.to_string());
+            article
+        };
+
+        let fresh = watermarks.observe("CNBC", "markets", vec![article("Fed holds")], Utc::now());
+        assert!(!fresh[0].updated);
+        let fresh = watermarks.observe("CNBC", "markets", vec![article("Fed holds")], Utc::now());
+        assert!(fresh.is_empty());
+
+        // A corrected headline under the same GUID is delivered again
+        let corrected = article("Fed holds rates steady");
+        let fresh = watermarks.observe("CNBC", "markets", vec![corrected.clone()], Utc::now());
+        assert_eq!(fresh.len(), 1);
+        assert!(fresh[0].updated);
+        let previous = watermarks
+            .previous_version("CNBC", "markets", &corrected)
+            .unwrap();
+        assert_eq!(previous.title.as_deref(), Some("Fed holds"));
+
+        // Watermarks from before versions were kept do not report updates
+        let mut old = watermarks.clone();
+        old.feeds
+            .get_mut("CNBC")
+            .unwrap()
+            .get_mut("markets")
+            .unwrap()
+            .versions
+            .clear();
+        assert!(
+            old.observe("CNBC", "markets", vec![article("Fed cuts")], Utc::now())
+                .is_empty()
+        );
+    }
+
+    fn batch(topic: &str, titles: &[&str]) -> NewArticles {
+        let articles = titles
+            .iter()
+            .map(|title| {
+                let mut article = NewsArticle::new();
+                article.title = Some(title.to_string());
+                article
+            })
+            .collect();
+        NewArticles {
+            source: "CNBC".to_string(),
+            topic: topic.to_string(),
+            articles,
+        }
+    }
+
+    fn titles(batch: &NewArticles) -> Vec<&str> {
+        batch
+            .articles
+            .iter()
+            .filter_map(|article| article.title.as_deref())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_bounded_channel_drop_oldest() {
+        let (sink, mut receiver) = ChannelSink::bounded(2, Overflow::DropOldest);
+        for title in ["A", "B", "C"] {
+            sink.deliver(&batch("markets", &[title])).await.unwrap();
+        }
+
+        assert_eq!(receiver.len(), 2);
+        assert_eq!(receiver.dropped(), 1);
+        assert_eq!(titles(&receiver.recv().await.unwrap()), ["B"]);
+        assert_eq!(titles(&receiver.recv().await.unwrap()), ["C"]);
+        // Ends once the sink is gone and the queue is drained
+        drop(sink);
+        assert!(receiver.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_bounded_channel_coalesce() {
+        let (sink, mut receiver) = ChannelSink::bounded(1, Overflow::Coalesce);
+        sink.deliver(&batch("markets", &["A"])).await.unwrap();
+        sink.deliver(&batch("markets", &["B", "C"])).await.unwrap();
+        assert_eq!(receiver.len(), 1);
+        assert_eq!(receiver.dropped(), 0);
+        assert_eq!(titles(&receiver.recv().await.unwrap()), ["A", "B", "C"]);
+
+        // A batch of another topic has nothing to merge into
+        sink.deliver(&batch("markets", &["D"])).await.unwrap();
+        sink.deliver(&batch("earnings", &["E"])).await.unwrap();
+        assert_eq!(receiver.dropped(), 1);
+        assert_eq!(receiver.try_recv().unwrap().topic, "earnings");
+    }
+
+    #[tokio::test]
+    async fn test_bounded_channel_blocks_until_received() {
+        let (sink, mut receiver) = ChannelSink::bounded(1, Overflow::Block);
+        let sink = Arc::new(sink);
+        sink.deliver(&batch("markets", &["A"])).await.unwrap();
+
+        let blocked = tokio::spawn({
+            let sink = Arc::clone(&sink);
+            async move { sink.deliver(&batch("markets", &["B"])).await }
+        });
+        tokio::task::yield_now().await;
+        assert!(!blocked.is_finished());
+
+        assert_eq!(titles(&receiver.recv().await.unwrap()), ["A"]);
+        blocked.await.unwrap().unwrap();
+        assert_eq!(titles(&receiver.recv().await.unwrap()), ["B"]);
+        assert_eq!(receiver.dropped(), 0);
+
+        // A dropped receiver never leaves the poller waiting
+        sink.deliver(&batch("markets", &["C"])).await.unwrap();
+        drop(receiver);
+        sink.deliver(&batch("markets", &["D"])).await.unwrap();
+    }
+
+    /// Watermark store in memory, counting saves
+    #[derive(Default)]
+    struct MemoryStore {
+        watermarks: Mutex<Watermarks>,
+        saves: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl WatermarkStore for Arc<MemoryStore> {
+        async fn load(&self) -> Result<Watermarks> {
+            Ok(self.watermarks.lock().unwrap().clone())
+        }
+
+        async fn save(&self, watermarks: &Watermarks) -> Result<()> {
+            *self.watermarks.lock().unwrap() = watermarks.clone();
+            self.saves.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watermark_store_saved_after_each_finding_poll() {
+        let source = growing_source();
+        let resolver = FeedResolver::new(vec![source], SourceConfig::default());
+        let fetcher = resolver.resolve("Test", "news").unwrap();
+        let store = Arc::new(MemoryStore::default());
+        // Story 1 was delivered before the restart
+        store.watermarks.lock().unwrap().observe(
+            "Test",
+            "news",
+            vec![NewsArticle {
+                title: Some("Story 1".to_string()),
+                ..NewsArticle::new()
+            }],
+            Utc::now(),
+        );
+
+        let (sink, mut receiver) = ChannelSink::new();
+        let handle = Poller::new(resolver, vec![fetcher])
+            .with_interval(Duration::from_secs(60))
+            .with_sink(sink)
+            .with_watermark_store(Arc::clone(&store))
+            .start();
+
+        // The first poll finds only Story 1, so nothing is delivered or saved
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert!(receiver.try_recv().is_none());
+        assert_eq!(store.saves.load(Ordering::SeqCst), 0);
+
+        let batch = receiver.recv().await.unwrap();
+        assert_eq!(batch.articles[0].title.as_deref(), Some("Story 2"));
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(store.saves.load(Ordering::SeqCst), 1);
+        let saved = store.watermarks.lock().unwrap().clone();
+        assert_eq!(
+            saved.get("Test", "news").unwrap().seen,
+            ["Story 1", "Story 2"]
+        );
+
+        handle.shutdown().await.unwrap();
+        assert_eq!(store.saves.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            saved
+                .iter()
+                .map(|(source, topic, _)| (source, topic))
+                .collect::<Vec<_>>(),
+            [("Test", "news")]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_poller_delivers_new_articles_and_shuts_down() {
+        let source = growing_source();
+        let resolver = FeedResolver::new(vec![source], SourceConfig::default());
+        let fetcher = resolver.resolve("Test", "news").unwrap();
+        let path = std::env::temp_dir()
+            .join(format!("fan-poller-{}", std::process::id()))
+            .join("watermarks.json");
+        let _ = fs::remove_file(&path);
+
+        let (sink, mut receiver) = ChannelSink::new();
+        let flushes = Arc::new(AtomicUsize::new(0));
+        let handle = Poller::new(resolver, vec![fetcher])
+            .with_interval(Duration::from_secs(60))
+            .with_sink(sink)
+            .with_sink(FlushCounter(Arc::clone(&flushes)))
+            .with_watermarks_file(&path)
+            .start();
+
+        let first = receiver.recv().await.unwrap();
+        assert_eq!(first.source, "Test");
+        assert_eq!(first.articles.len(), 1);
+        let second = receiver.recv().await.unwrap();
+        assert_eq!(second.articles.len(), 1);
+        assert_eq!(second.articles[0].title.as_deref(), Some("Story 2"));
+
+        assert!(handle.is_running());
+        handle.shutdown().await.unwrap();
+        assert_eq!(flushes.load(Ordering::SeqCst), 1);
+
+        let watermarks = Watermarks::load(&path).unwrap();
+        assert_eq!(
+            watermarks.get("Test", "news").unwrap().seen,
+            ["Story 1", "Story 2"]
+        );
+        let _ = fs::remove_file(&path);
+    }
+    #[tokio::test(start_paused = true)]
+    async fn test_reload_changes_feeds_and_keywords() {
+        let source = growing_source();
+        let resolver = FeedResolver::new(vec![source], SourceConfig::default());
+
+        let (sink, mut receiver) = ChannelSink::new();
+        let handle = Poller::new(resolver, Vec::new())
+            .with_config(&PollerConfig {
+                feeds: vec![feed("Test", "news")],
+                interval_seconds: 60,
+                ..PollerConfig::default()
+            })
+            .unwrap()
+            .with_sink(sink)
+            .start();
+        assert_eq!(receiver.recv().await.unwrap().topic, "news");
+
+        // An unknown source leaves the running config in place
+        handle.reload(PollerConfig {
+            feeds: vec![feed("Missing", "news")],
+            interval_seconds: 60,
+            ..PollerConfig::default()
+        });
+        assert_eq!(receiver.recv().await.unwrap().topic, "news");
+
+        handle.reload(PollerConfig {
+            feeds: vec![feed("Test", "markets")],
+            interval_seconds: 60,
+            keywords: vec!["story".to_string()],
+            ..PollerConfig::default()
+        });
+        let batch = receiver.recv().await.unwrap();
+        assert_eq!(batch.topic, "markets");
+        assert!(!batch.articles.is_empty());
+
+        handle.reload(PollerConfig {
+            feeds: vec![feed("Test", "markets")],
+            interval_seconds: 60,
+            keywords: vec!["bitcoin".to_string()],
+            ..PollerConfig::default()
+        });
+        tokio::time::sleep(Duration::from_secs(300)).await;
+        assert!(receiver.try_recv().is_none());
+        handle.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_alert_rules_route_to_notifiers() {
+        let source = growing_source();
+        let resolver = FeedResolver::new(vec![source], SourceConfig::default());
+        let config: PollerConfig = serde_json::from_str(
+            r#"{
+                "feeds": [{"source": "Test", "topic": "news"}],
+                "interval_seconds": 60,
+                "alerts": [
+                    {"name": "second", "query": "\"story 2\"", "notifier": "first-channel"},
+                    {"name": "others", "query": "story", "sources": ["CNBC"], "notifier": "second-channel"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let (first_tx, mut first_rx) = mpsc::unbounded_channel();
+        let (second_tx, mut second_rx) = mpsc::unbounded_channel();
+        let handle = Poller::new(resolver, Vec::new())
+            .with_config(&config)
+            .unwrap()
+            .with_notifier("first-channel", first_tx)
+            .with_notifier("second-channel", second_tx)
+            .start();
+
+        let alert = first_rx.recv().await.unwrap();
+        assert_eq!(alert.rule, "second");
+        assert_eq!(alert.articles.len(), 1);
+        assert_eq!(alert.articles[0].title.as_deref(), Some("Story 2"));
+        handle.shutdown().await.unwrap();
+        assert!(second_rx.try_recv().is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_dry_run_sends_nothing() {
+        let source = growing_source();
+        let resolver = FeedResolver::new(vec![source], SourceConfig::default());
+        let config: PollerConfig = serde_json::from_str(
+            r#"{
+                "feeds": [{"source": "Test", "topic": "news"}],
+                "interval_seconds": 60,
+                "alerts": [{"name": "all", "query": "story", "notifier": "channel"}],
+                "dry_run": true
+            }"#,
+        )
+        .unwrap();
+
+        let bus = EventBus::new();
+        let mut events = bus.subscribe();
+        let (sink, mut receiver) = ChannelSink::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let handle = Poller::new(resolver, Vec::new())
+            .with_config(&config)
+            .unwrap()
+            .with_sink(sink)
+            .with_notifier("channel", tx)
+            .with_event_bus(bus)
+            .start();
+
+        // The articles are found, but go nowhere
+        assert!(matches!(events.recv().await.unwrap(), Event::NewArticle(_)));
+        handle.shutdown().await.unwrap();
+        assert!(receiver.try_recv().is_none());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_replay() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let stored = [
+            ("CNBC", "Fed signals rate cut", "2024-05-01T10:00:00Z"),
+            ("CNBC", "Fed holds, rate cut later", "2024-05-01T09:00:00Z"),
+            ("WSJ", "Rate cut odds rise", "2024-05-01T11:00:00Z"),
+            ("CNBC", "Oil slips", "2024-05-01T12:00:00Z"),
+            ("CNBC", "Rate cut priced in", "2024-05-02T10:00:00Z"),
+        ];
+        let articles: Vec<NewsArticle> = stored
+            .iter()
+            .map(|(source, title, published)| {
+                let mut article = NewsArticle::new();
+                article.source = Some(source.to_string());
+                article.title = Some(title.to_string());
+                article.pub_date = Some(published.to_string());
+                article
+            })
+            .collect();
+        store.insert(&articles).unwrap();
+
+        let (notifier, mut alerts) = mpsc::unbounded_channel();
+        let (sink, mut batches) = ChannelSink::new();
+        let poller = Poller::new(
+            FeedResolver::new(Vec::new(), SourceConfig::default()),
+            Vec::new(),
+        )
+        .with_keywords(["rate cut"])
+        .with_sink(sink)
+        .with_alert_rules(vec![AlertRule::new(
+            "cnbc-fed",
+            crate::filter::Query::parse("fed").unwrap(),
+            "channel",
+        )])
+        .with_notifier("channel", notifier);
+
+        let day = |day: &str| {
+            DateTime::parse_from_rfc3339(day)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        let replayed = poller
+            .replay(
+                &store,
+                day("2024-05-01T00:00:00Z")..day("2024-05-02T00:00:00Z"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(replayed, 3);
+
+        let titles = |batch: &NewArticles| -> Vec<String> {
+            batch
+                .articles
+                .iter()
+                .map(|article| article.title.clone().unwrap())
+                .collect()
+        };
+        let cnbc = batches.try_recv().unwrap();
+        assert_eq!(
+            (cnbc.source.as_str(), cnbc.topic.as_str()),
+            ("CNBC", REPLAY_TOPIC)
+        );
+        assert_eq!(
+            titles(&cnbc),
+            ["Fed holds, rate cut later", "Fed signals rate cut"]
+        );
+        assert_eq!(titles(&batches.try_recv().unwrap()), ["Rate cut odds rise"]);
+        assert!(batches.try_recv().is_none());
+
+        let alert = alerts.try_recv().unwrap();
+        assert_eq!(alert.source, "CNBC");
+        assert_eq!(alert.articles.len(), 2);
+        assert!(alerts.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_poller_config_from_file() {
+        let path =
+            std::env::temp_dir().join(format!("fan-poller-config-{}.json", std::process::id()));
+        fs::write(
+            &path,
+            r#"{"feeds": [{"source": "CNBC", "topic": "investing"}], "keywords": ["fed"]}"#,
+        )
+        .unwrap();
+        let config = PollerConfig::from_file(&path).unwrap();
+        assert_eq!(config.feeds, [feed("CNBC", "investing")]);
+        assert_eq!(config.interval_seconds, DEFAULT_POLL_INTERVAL.as_secs());
+        assert_eq!(config.keywords, ["fed"]);
+
+        assert!(config.adaptive.is_none());
+
+        fs::write(
+            &path,
+            r#"{
+                "feeds": [{"source": "CNBC", "topic": "top_news", "interval_seconds": 30}],
+                "adaptive": {"max_interval_seconds": 900}
+            }"#,
+        )
+        .unwrap();
+        let config = PollerConfig::from_file(&path).unwrap();
+        assert_eq!(config.feeds[0].interval_seconds, Some(30));
+        assert_eq!(
+            config.adaptive,
+            Some(AdaptivePolling::new(
+                DEFAULT_MIN_POLL_INTERVAL,
+                Duration::from_secs(900)
+            ))
+        );
+
+        fs::write(&path, r#"{"interval_seconds": 60}"#).unwrap();
+        assert!(PollerConfig::from_file(&path).is_err());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_adaptive_intervals_follow_churn() {
+        let adaptive = AdaptivePolling::new(Duration::from_secs(60), Duration::from_secs(600));
+        let interval = Duration::from_secs(300);
+        assert_eq!(
+            adaptive.next_interval(interval, 3),
+            Duration::from_secs(150)
+        );
+        assert_eq!(
+            adaptive.next_interval(interval, 0),
+            Duration::from_secs(450)
+        );
+        assert_eq!(
+            adaptive.next_interval(Duration::from_secs(100), 1),
+            Duration::from_secs(60)
+        );
+        assert_eq!(
+            adaptive.next_interval(Duration::from_secs(500), 0),
+            Duration::from_secs(600)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_feeds_keep_their_own_schedules() {
+        let source = growing_source();
+        let resolver = FeedResolver::new(vec![source.clone()], SourceConfig::default());
+        let news = resolver.resolve("Test", "news").unwrap();
+        let markets = resolver.resolve("Test", "markets").unwrap();
+
+        let handle = Poller::new(resolver, vec![news, markets])
+            .with_interval(Duration::from_secs(60))
+            .with_feed_interval("Test", "news", Duration::from_secs(40))
+            .with_adaptive(AdaptivePolling::new(
+                Duration::from_secs(10),
+                Duration::from_secs(120),
+            ))
+            .start();
+
+        // Every poll finds a new story, so both feeds speed up: "news" polls at 0,
+        // 20, 30, 40, and 50s, "markets" at 0, 30, and 45s
+        tokio::time::sleep(Duration::from_secs(55)).await;
+        assert_eq!(source.fetches.load(Ordering::SeqCst), 8);
+        handle.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_group_profiles_override_the_interval() {
+        let source = growing_source();
+        let resolver = FeedResolver::new(vec![source.clone()], SourceConfig::default());
+
+        let config = PollerConfig {
+            feeds: vec![feed("Test", "news"), feed("Test", "markets")],
+            interval_seconds: 60,
+            group_profiles: vec![GroupProfile {
+                group: TopicGroup::Markets,
+                profile: PollingProfile::constant(Duration::from_secs(10)),
+            }],
+            ..PollerConfig::default()
+        };
+        let handle = Poller::new(resolver, Vec::new())
+            .with_config(&config)
+            .unwrap()
+            .start();
+
+        // "news" polls at 0s, "markets" every 10s
+        tokio::time::sleep(Duration::from_secs(55)).await;
+        assert_eq!(source.fetches.load(Ordering::SeqCst), 7);
+        handle.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_event_bus_reports_articles_and_feed_health() {
+        let source = growing_source();
+        source.failing.store(true, Ordering::SeqCst);
+        let resolver = FeedResolver::new(vec![source.clone()], SourceConfig::default());
+        let fetcher = resolver.resolve("Test", "news").unwrap();
+        let bus = EventBus::new();
+        let mut events = bus.subscribe();
+        let handle = Poller::new(resolver, vec![fetcher])
+            .with_interval(Duration::from_secs(60))
+            .with_event_bus(bus.clone())
+            .start();
+
+        match events.recv().await.unwrap() {
+            Event::FeedDegraded(event) => {
+                assert_eq!(event.consecutive_failures, 3);
+                assert_eq!(event.topic, "news");
+            }
+            event => panic!("unexpected {:?}", event),
+        }
+
+        source.failing.store(false, Ordering::SeqCst);
+        match events.recv().await.unwrap() {
+            Event::FeedRecovered(event) => assert!(event.success_rate < 1.0),
+            event => panic!("unexpected {:?}", event),
+        }
+        match events.recv().await.unwrap() {
+            Event::NewArticle(event) => {
+                assert_eq!(event.article.title.as_deref(), Some("Story 1"))
+            }
+            event => panic!("unexpected {:?}", event),
+        }
+        handle.shutdown().await.unwrap();
+    }
+
+    fn feed(source: &str, topic: &str) -> FeedConfig {
+        FeedConfig {
+            source: source.to_string(),
+            topic: topic.to_string(),
+            interval_seconds: None,
+        }
+    }
+}
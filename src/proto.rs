@@ -0,0 +1,316 @@
+//! Protobuf encoding of articles
+//!
+//! The message types mirror `proto/news_article.proto` and convert to and from
+//! `NewsArticle`. Messages are a fraction of the size of the JSON encoding, which
+//! matters when publishing every article of every feed.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use finance_news_aggregator_rs::NewsArticle;
+//! use finance_news_aggregator_rs::proto;
+//!
+//! let mut article = NewsArticle::new();
+//! article.title = Some("Fed holds rates".to_string());
+//!
+//! let bytes = proto::encode_article(&article);
+//! let decoded = proto::decode_article(&bytes).unwrap();
+//! assert_eq!(decoded.title, article.title);
+//! ```
+
+use crate::breaking;
+use crate::error::Result;
+use crate::types;
+use prost::Message;
+use std::collections::HashMap;
+
+/// `finance_news.NewsArticle`
+#[derive(Clone, PartialEq, Message)]
+pub struct NewsArticle {
+    #[prost(string, optional, tag = "1")]
+    pub title: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub link: Option<String>,
+    #[prost(string, optional, tag = "3")]
+    pub description: Option<String>,
+    #[prost(string, optional, tag = "4")]
+    pub pub_date: Option<String>,
+    #[prost(string, optional, tag = "5")]
+    pub guid: Option<String>,
+    #[prost(string, optional, tag = "6")]
+    pub category: Option<String>,
+    #[prost(string, optional, tag = "7")]
+    pub author: Option<String>,
+    #[prost(string, optional, tag = "8")]
+    pub source: Option<String>,
+    #[prost(map = "string, message", tag = "9")]
+    pub extra_fields: HashMap<String, ExtraValue>,
+    #[prost(bool, tag = "10")]
+    pub stale: bool,
+    #[prost(message, repeated, tag = "11")]
+    pub enclosures: Vec<Enclosure>,
+    /// `pub_date` normalized to UTC, in milliseconds since the Unix epoch
+    #[prost(int64, optional, tag = "12")]
+    pub published_at_ms: Option<i64>,
+    #[prost(bool, tag = "13")]
+    pub updated: bool,
+    #[prost(message, repeated, tag = "14")]
+    pub matches: Vec<TermMatch>,
+    #[prost(enumeration = "Priority", tag = "15")]
+    pub priority: i32,
+}
+
+/// `finance_news.ExtraValue`
+#[derive(Clone, PartialEq, Message)]
+pub struct ExtraValue {
+    #[prost(string, repeated, tag = "1")]
+    pub values: Vec<String>,
+}
+
+/// `finance_news.Enclosure`
+#[derive(Clone, PartialEq, Message)]
+pub struct Enclosure {
+    #[prost(string, tag = "1")]
+    pub url: String,
+    #[prost(string, optional, tag = "2")]
+    pub mime_type: Option<String>,
+    #[prost(uint64, optional, tag = "3")]
+    pub length: Option<u64>,
+    #[prost(uint64, optional, tag = "4")]
+    pub duration_seconds: Option<u64>,
+}
+
+/// `finance_news.TermMatch`
+#[derive(Clone, PartialEq, Message)]
+pub struct TermMatch {
+    #[prost(string, tag = "1")]
+    pub term: String,
+    #[prost(enumeration = "MatchField", tag = "2")]
+    pub field: i32,
+    #[prost(uint64, tag = "3")]
+    pub start: u64,
+    #[prost(uint64, tag = "4")]
+    pub end: u64,
+}
+
+/// `finance_news.MatchField`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum MatchField {
+    Title = 0,
+    Description = 1,
+}
+
+/// `finance_news.Priority`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum Priority {
+    Routine = 0,
+    High = 1,
+    Breaking = 2,
+}
+
+impl From<breaking::Priority> for Priority {
+    fn from(priority: breaking::Priority) -> Self {
+        match priority {
+            breaking::Priority::Routine => Priority::Routine,
+            breaking::Priority::High => Priority::High,
+            breaking::Priority::Breaking => Priority::Breaking,
+        }
+    }
+}
+
+impl From<Priority> for breaking::Priority {
+    fn from(priority: Priority) -> Self {
+        match priority {
+            Priority::Routine => breaking::Priority::Routine,
+            Priority::High => breaking::Priority::High,
+            Priority::Breaking => breaking::Priority::Breaking,
+        }
+    }
+}
+
+impl From<&types::NewsArticle> for NewsArticle {
+    fn from(article: &types::NewsArticle) -> Self {
+        Self {
+            title: article.title.clone(),
+            link: article.link.clone(),
+            description: article.description.clone(),
+            pub_date: article.pub_date.clone(),
+            guid: article.guid.clone(),
+            category: article.category.clone(),
+            author: article.author.clone(),
+            source: article.source.clone(),
+            extra_fields: article
+                .extra_fields
+                .iter()
+                .map(|(key, value)| {
+                    let values = value.values().to_vec();
+                    (key.clone(), ExtraValue { values })
+                })
+                .collect(),
+            stale: article.stale,
+            enclosures: article.enclosures.iter().map(Enclosure::from).collect(),
+            published_at_ms: article.published_at().map(|date| date.timestamp_millis()),
+            updated: article.updated,
+            matches: article.matches.iter().map(TermMatch::from).collect(),
+            priority: Priority::from(article.priority).into(),
+        }
+    }
+}
+
+impl From<NewsArticle> for types::NewsArticle {
+    /// A single-element list among the extra fields comes back as text, and an
+    /// unknown priority as routine
+    fn from(message: NewsArticle) -> Self {
+        let priority = message.priority().into();
+        Self {
+            title: message.title,
+            link: message.link,
+            description: message.description,
+            pub_date: message.pub_date,
+            guid: message.guid,
+            category: message.category,
+            author: message.author,
+            source: message.source,
+            extra_fields: message
+                .extra_fields
+                .into_iter()
+                .map(|(key, mut value)| {
+                    let value = match value.values.len() {
+                        1 => types::ExtraValue::Text(value.values.remove(0)),
+                        _ => types::ExtraValue::List(value.values),
+                    };
+                    (key, value)
+                })
+                .collect(),
+            stale: message.stale,
+            updated: message.updated,
+            enclosures: message
+                .enclosures
+                .into_iter()
+                .map(types::Enclosure::from)
+                .collect(),
+            matches: message
+                .matches
+                .into_iter()
+                .map(types::TermMatch::from)
+                .collect(),
+            priority,
+            // Protobuf fields evolve by tag, so a decoded message is current
+            schema_version: crate::schema::SCHEMA_VERSION,
+        }
+    }
+}
+
+impl From<&types::Enclosure> for Enclosure {
+    fn from(enclosure: &types::Enclosure) -> Self {
+        Self {
+            url: enclosure.url.clone(),
+            mime_type: enclosure.mime_type.clone(),
+            length: enclosure.length,
+            duration_seconds: enclosure.duration_seconds,
+        }
+    }
+}
+
+impl From<Enclosure> for types::Enclosure {
+    fn from(message: Enclosure) -> Self {
+        Self {
+            url: message.url,
+            mime_type: message.mime_type,
+            length: message.length,
+            duration_seconds: message.duration_seconds,
+        }
+    }
+}
+
+impl From<&types::TermMatch> for TermMatch {
+    fn from(term_match: &types::TermMatch) -> Self {
+        let field = match term_match.field {
+            types::MatchField::Title => MatchField::Title,
+            types::MatchField::Description => MatchField::Description,
+        };
+        Self {
+            term: term_match.term.clone(),
+            field: field.into(),
+            start: term_match.start as u64,
+            end: term_match.end as u64,
+        }
+    }
+}
+
+impl From<TermMatch> for types::TermMatch {
+    /// An unknown field comes back as the title
+    fn from(message: TermMatch) -> Self {
+        let field = match message.field() {
+            MatchField::Title => types::MatchField::Title,
+            MatchField::Description => types::MatchField::Description,
+        };
+        Self {
+            term: message.term,
+            field,
+            start: message.start as usize,
+            end: message.end as usize,
+        }
+    }
+}
+
+/// Encode an article as a `finance_news.NewsArticle` message
+pub fn encode_article(article: &types::NewsArticle) -> Vec<u8> {
+    NewsArticle::from(article).encode_to_vec()
+}
+
+/// Decode a `finance_news.NewsArticle` message
+pub fn decode_article(bytes: &[u8]) -> Result<types::NewsArticle> {
+    Ok(NewsArticle::decode(bytes)?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut article = types::NewsArticle::new();
+        article.title = Some("Apple beats estimates".to_string());
+        article.pub_date = Some("Wed, 01 May 2024 16:30:00 EDT".to_string());
+        article.stale = true;
+        article.priority = breaking::Priority::High;
+        article
+            .extra_fields
+            .insert("symbols".to_string(), "AAPL".into());
+        article.extra_fields.insert(
+            "media:credit".to_string(),
+            vec!["Reuters".to_string(), "AP".to_string()].into(),
+        );
+        let mut enclosure = types::Enclosure::new("https://example.com/call.mp3");
+        enclosure.duration_seconds = Some(1843);
+        article.enclosures.push(enclosure);
+        article.matches.push(types::TermMatch {
+            term: "AAPL".to_string(),
+            field: types::MatchField::Description,
+            start: 4,
+            end: 8,
+        });
+
+        let message = NewsArticle::from(&article);
+        assert_eq!(message.published_at_ms, Some(1_714_595_400_000));
+
+        let decoded = decode_article(&encode_article(&article)).unwrap();
+        assert_eq!(decoded.title, article.title);
+        assert_eq!(decoded.pub_date, article.pub_date);
+        assert!(decoded.stale);
+        assert_eq!(decoded.extra_fields, article.extra_fields);
+        assert_eq!(decoded.enclosures, article.enclosures);
+        assert_eq!(decoded.matches, article.matches);
+        assert_eq!(decoded.priority, breaking::Priority::High);
+        // Missing fields stay missing rather than becoming empty strings
+        assert!(decoded.link.is_none());
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage() {
+        assert!(decode_article(&[0xff, 0xff, 0xff]).is_err());
+    }
+}
@@ -0,0 +1,159 @@
+//! Per-source parsing quirks
+//!
+//! Most feeds parse correctly under the default RSS rules, but a few built-in sources
+//! format their items in ways that need special handling. Each `Source` maps to the
+//! parser profile it uses, and `NewsParser::for_source()` builds a parser with the
+//! matching quirks applied. The fixtures under `tests/fixtures` hold a representative
+//! feed for every source and are checked by `tests/test_parser_conformance.rs`.
+
+/// Built-in news sources, for selecting a parser profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Source {
+    AlphaVantage,
+    BusinessWire,
+    CNBC,
+    CoinDesk,
+    CoinTelegraph,
+    FinancialPost,
+    FinnhubNews,
+    Gdelt,
+    GlobeAndMail,
+    GlobeNewswire,
+    MarketWatch,
+    NASDAQ,
+    NewsApiOrg,
+    NikkeiAsia,
+    NYTimes,
+    Polygon,
+    PRNewswire,
+    Reddit,
+    SCMPBusiness,
+    SeekingAlpha,
+    StockTwits,
+    WallStreetJournal,
+    YahooFinance,
+}
+
+/// Source-specific deviations from the default RSS parsing rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Quirks {
+    /// Item text is wrapped in CDATA sections padded with whitespace and newlines,
+    /// which is trimmed from each section
+    pub padded_cdata: bool,
+    /// Item text uses HTML named entities (`&nbsp;`, `&rsquo;`, `&mdash;`) and relies
+    /// on the whitespace around entity references, which is kept while reading and
+    /// trimmed once the item is complete
+    pub html_entities: bool,
+}
+
+impl Quirks {
+    /// The default RSS rules
+    pub const NONE: Self = Self {
+        padded_cdata: false,
+        html_entities: false,
+    };
+}
+
+/// Sources whose feeds need more than the default rules
+const SOURCE_QUIRKS: [(Source, Quirks); 2] = [
+    // CNBC wraps every title and description in CDATA with leading newlines
+    (
+        Source::CNBC,
+        Quirks {
+            padded_cdata: true,
+            ..Quirks::NONE
+        },
+    ),
+    // MarketWatch headlines are dense with `&amp;`, `&nbsp;`, and typographic entities
+    (
+        Source::MarketWatch,
+        Quirks {
+            html_entities: true,
+            ..Quirks::NONE
+        },
+    ),
+];
+
+impl Source {
+    /// All built-in sources
+    pub fn all() -> [Source; 23] {
+        [
+            Source::AlphaVantage,
+            Source::BusinessWire,
+            Source::CNBC,
+            Source::CoinDesk,
+            Source::CoinTelegraph,
+            Source::FinancialPost,
+            Source::FinnhubNews,
+            Source::Gdelt,
+            Source::GlobeAndMail,
+            Source::GlobeNewswire,
+            Source::MarketWatch,
+            Source::NASDAQ,
+            Source::NewsApiOrg,
+            Source::NikkeiAsia,
+            Source::NYTimes,
+            Source::Polygon,
+            Source::PRNewswire,
+            Source::Reddit,
+            Source::SCMPBusiness,
+            Source::SeekingAlpha,
+            Source::StockTwits,
+            Source::WallStreetJournal,
+            Source::YahooFinance,
+        ]
+    }
+
+    /// Parser identifier of the source, as passed to `NewsParser::new()`
+    ///
+    /// Also the file stem of the source's fixture under `tests/fixtures`.
+    pub fn client_type(&self) -> &'static str {
+        match self {
+            Source::AlphaVantage => "alpha_vantage",
+            Source::BusinessWire => "business_wire",
+            Source::CNBC => "cnbc",
+            Source::CoinDesk => "coin_desk",
+            Source::CoinTelegraph => "coin_telegraph",
+            Source::FinancialPost => "financial_post",
+            Source::FinnhubNews => "finnhub_news",
+            Source::Gdelt => "gdelt",
+            Source::GlobeAndMail => "globe_and_mail",
+            Source::GlobeNewswire => "globe_newswire",
+            Source::MarketWatch => "market_watch",
+            Source::NASDAQ => "nasdaq",
+            Source::NewsApiOrg => "news_api_org",
+            Source::NikkeiAsia => "nikkei_asia",
+            Source::NYTimes => "nytimes",
+            Source::Polygon => "polygon",
+            Source::PRNewswire => "pr_newswire",
+            Source::Reddit => "reddit",
+            Source::SCMPBusiness => "scmp",
+            Source::SeekingAlpha => "seeking_alpha",
+            Source::StockTwits => "stock_twits",
+            Source::WallStreetJournal => "wsj",
+            Source::YahooFinance => "yahoo",
+        }
+    }
+
+    /// Whether the source serves JSON instead of a feed document
+    pub fn is_json(&self) -> bool {
+        matches!(
+            self,
+            Source::AlphaVantage
+                | Source::FinnhubNews
+                | Source::Gdelt
+                | Source::NewsApiOrg
+                | Source::Polygon
+                | Source::Reddit
+                | Source::StockTwits
+        )
+    }
+
+    /// The quirks the source's feeds need
+    pub fn quirks(&self) -> Quirks {
+        SOURCE_QUIRKS
+            .iter()
+            .find(|(source, _)| source == self)
+            .map_or(Quirks::NONE, |(_, quirks)| *quirks)
+    }
+}
@@ -0,0 +1,158 @@
+//! Versioning and migration of serialized articles
+//!
+//! Articles carry the [`SCHEMA_VERSION`] of the release that wrote them in
+//! `NewsArticle::schema_version`, 0 for articles written before versioning.
+//! [`from_str()`] and [`from_value()`] read an article of any earlier version by
+//! upgrading its JSON one version at a time before deserializing, so stores and
+//! exports outlive changes to `NewsArticle`. `store::SqliteStore` reads its
+//! articles this way.
+//!
+//! A change to the serialized form of `NewsArticle` bumps [`SCHEMA_VERSION`] and
+//! adds the step upgrading the previous version to `MIGRATIONS`.
+//!
+//! | Version | Changes |
+//! |---------|---------|
+//! | 0 | Written before versioning; `extra_fields` may be missing or hold non-text values |
+//! | 1 | `schema_version` added |
+
+use crate::error::{FanError, Result};
+use crate::types::NewsArticle;
+use serde_json::{Map, Value};
+
+/// Version of the serialized form of `NewsArticle` this release writes
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Field of the version in serialized articles
+pub const SCHEMA_VERSION_FIELD: &str = "schema_version";
+
+/// Steps upgrading an article object by one version; the step at index `i` upgrades
+/// version `i` to `i + 1`
+const MIGRATIONS: &[fn(&mut Map<String, Value>)] = &[fill_extra_fields];
+
+/// Read an article serialized by this or an earlier release
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::schema::{self, SCHEMA_VERSION};
+///
+/// // Written before versioning, without extra fields
+/// let article = schema::from_str(r#"{"title": "Fed holds rates", "link": null}"#).unwrap();
+/// assert_eq!(article.title.as_deref(), Some("Fed holds rates"));
+/// assert_eq!(article.schema_version, SCHEMA_VERSION);
+/// ```
+pub fn from_str(json: &str) -> Result<NewsArticle> {
+    from_value(serde_json::from_str(json)?)
+}
+
+/// Read an article from JSON serialized by this or an earlier release
+pub fn from_value(mut value: Value) -> Result<NewsArticle> {
+    migrate(&mut value)?;
+    Ok(serde_json::from_value(value)?)
+}
+
+/// The version of a serialized article, 0 when it has none
+pub fn version_of(value: &Value) -> u32 {
+    value
+        .get(SCHEMA_VERSION_FIELD)
+        .and_then(Value::as_u64)
+        .map_or(0, |version| version.min(u32::MAX as u64) as u32)
+}
+
+/// Upgrade a serialized article to [`SCHEMA_VERSION`] in place
+///
+/// Fails on an article of a later version, written by a newer release. A value that
+/// is not an object is left for deserializing to reject.
+pub fn migrate(value: &mut Value) -> Result<()> {
+    let version = version_of(value);
+    if version > SCHEMA_VERSION {
+        return Err(FanError::UnsupportedSchema {
+            found: version,
+            supported: SCHEMA_VERSION,
+        });
+    }
+    let Value::Object(article) = value else {
+        return Ok(());
+    };
+    for step in &MIGRATIONS[version as usize..] {
+        step(article);
+    }
+    article.insert(SCHEMA_VERSION_FIELD.to_string(), SCHEMA_VERSION.into());
+    Ok(())
+}
+
+/// 0 to 1: give articles written by hand or by other tools the `extra_fields` map
+/// they left out, and turn numbers and other values in it into text
+fn fill_extra_fields(article: &mut Map<String, Value>) {
+    let extra = article
+        .entry("extra_fields")
+        .or_insert_with(|| Value::Object(Map::new()));
+    if !extra.is_object() {
+        *extra = Value::Object(Map::new());
+    }
+    let Value::Object(extra) = extra else {
+        return;
+    };
+    extra.retain(|_, value| !value.is_null());
+    for value in extra.values_mut() {
+        match value {
+            Value::String(_) => {}
+            Value::Array(items) => {
+                for item in items.iter_mut().filter(|item| !item.is_string()) {
+                    *item = Value::String(item.to_string());
+                }
+            }
+            other => *other = Value::String(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_round_trip() {
+        let mut article = NewsArticle::new();
+        article.title = Some("Nvidia beats".to_string());
+        article
+            .extra_fields
+            .insert("symbols".to_string(), "NVDA".into());
+        let json = serde_json::to_string(&article).unwrap();
+        assert!(json.contains("\"schema_version\":1"));
+
+        let read = from_str(&json).unwrap();
+        assert_eq!(read.title, article.title);
+        assert_eq!(read.extra_fields, article.extra_fields);
+    }
+
+    #[test]
+    fn test_migrate_unversioned() {
+        let mut value = json!({
+            "title": "Fed holds rates",
+            "extra_fields": { "score": 0.8, "tags": ["fed", 2], "empty": null },
+        });
+        migrate(&mut value).unwrap();
+        assert_eq!(version_of(&value), SCHEMA_VERSION);
+        assert_eq!(
+            value["extra_fields"],
+            json!({ "score": "0.8", "tags": ["fed", "2"] })
+        );
+
+        // Serde alone rejects an article without extra fields
+        let bare = json!({ "title": "Oil slips" });
+        assert!(serde_json::from_value::<NewsArticle>(bare.clone()).is_err());
+        assert!(from_value(bare).unwrap().extra_fields.is_empty());
+    }
+
+    #[test]
+    fn test_later_version() {
+        let value = json!({ "title": "From the future", "schema_version": SCHEMA_VERSION + 1 });
+        assert!(matches!(
+            from_value(value),
+            Err(FanError::UnsupportedSchema { found, .. }) if found == SCHEMA_VERSION + 1
+        ));
+        assert!(from_value(json!(["not", "an", "article"])).is_err());
+    }
+}
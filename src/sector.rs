@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+/// Market sectors, for composite feeds across sources
+///
+/// Like a [`TopicGroup`](crate::TopicGroup), a `Sector` names an industry once and
+/// lets every source declare which of its native topics cover it via
+/// [`NewsSource::sector_topics`](crate::news_source::NewsSource::sector_topics):
+/// Seeking Alpha's `sectors-technology`, NASDAQ's `technology`, WSJ's `RSSWSJD`, ...
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Sector {
+    /// Software, semiconductors, and hardware
+    Technology,
+    /// Pharma, biotech, and health care providers
+    Healthcare,
+    /// Banks, insurers, and financial services
+    Financials,
+    /// Oil, gas, and power
+    Energy,
+    /// Property and REITs
+    RealEstate,
+    /// Mining, chemicals, and other basic materials
+    Materials,
+    /// Retail, autos, and consumer goods
+    Consumer,
+    /// Manufacturing and industrial goods
+    Industrials,
+}
+
+impl Sector {
+    /// All sectors
+    pub fn all() -> Vec<Sector> {
+        vec![
+            Sector::Technology,
+            Sector::Healthcare,
+            Sector::Financials,
+            Sector::Energy,
+            Sector::RealEstate,
+            Sector::Materials,
+            Sector::Consumer,
+            Sector::Industrials,
+        ]
+    }
+
+    /// Stable identifier for the sector
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Sector::Technology => "technology",
+            Sector::Healthcare => "healthcare",
+            Sector::Financials => "financials",
+            Sector::Energy => "energy",
+            Sector::RealEstate => "real_estate",
+            Sector::Materials => "materials",
+            Sector::Consumer => "consumer",
+            Sector::Industrials => "industrials",
+        }
+    }
+}
+
+impl std::fmt::Display for Sector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Sector {
+    type Err = crate::error::FanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Sector::all()
+            .into_iter()
+            .find(|sector| sector.as_str() == s)
+            .ok_or_else(|| crate::error::FanError::Unknown(format!("Unknown sector: {}", s)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_str() {
+        for sector in Sector::all() {
+            assert_eq!(sector.as_str().parse::<Sector>().unwrap(), sector);
+        }
+        assert!("utilities".parse::<Sector>().is_err());
+    }
+}
@@ -0,0 +1,369 @@
+//! HTTP server mode streaming articles to web clients (feature "server")
+//!
+//! [`NewsServer`] follows an `EventBus` that a `Poller` publishes on and pushes each
+//! new article to the clients connected to it:
+//!
+//! | Route | Protocol |
+//! |-------|----------|
+//! | `GET /stream` | Server-sent events, one `article` event per new article |
+//! | `GET /ws` | WebSocket, one JSON text frame per new article |
+//!
+//! Clients narrow the stream with URL parameters: `query` takes a filter query as
+//! parsed by `filter::Query::parse()`, and `topics` a comma-separated list of topics.
+//! A browser subscribes with `EventSource`:
+//!
+//! ```javascript
+//! const stream = new EventSource("/stream?query=ticker:NVDA&topics=earnings");
+//! stream.addEventListener("article", (e) => console.log(JSON.parse(e.data).article.title));
+//! ```
+//!
+//! Each event's data is the `events::NewArticle` as JSON: the `source`, `topic`, and
+//! `article`. Its id is the article's GUID, link, or title.
+//!
+//! WebSocket frames are `Event`s as JSON, `{"type": "new_article", "source": ..}`.
+//! A client changes its subscription at any time by sending the same parameters as
+//! a JSON object, and the server answers with a `subscribed` or an `error` frame:
+//!
+//! ```javascript
+//! const socket = new WebSocket("ws://localhost:8080/ws?topics=earnings");
+//! socket.onopen = () => socket.send(JSON.stringify({ query: "ticker:NVDA OR ticker:AMD" }));
+//! socket.onmessage = (e) => console.log(JSON.parse(e.data));
+//! ```
+
+use crate::error::Result;
+use crate::events::{Event, EventBus, EventSubscriber, NewArticle};
+use crate::filter::Query;
+use crate::poller::article_id;
+use axum::Router;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query as Params, State};
+use axum::http::StatusCode;
+use axum::response::sse::{self, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use futures::{Stream, StreamExt};
+use serde::Deserialize;
+use serde_json::json;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+/// Interval of keep-alive comments on idle streams unless set with
+/// `NewsServer::with_keep_alive()`
+pub const DEFAULT_KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// Server pushing new articles to web clients
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use finance_news_aggregator_rs::events::EventBus;
+/// use finance_news_aggregator_rs::server::NewsServer;
+/// use tokio::net::TcpListener;
+///
+/// # async fn run() -> finance_news_aggregator_rs::Result<()> {
+/// let bus = EventBus::new();
+/// // Poller::new(..).with_event_bus(bus.clone()).start();
+/// let listener = TcpListener::bind("0.0.0.0:8080").await?;
+/// NewsServer::new(bus).serve(listener).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NewsServer {
+    events: EventBus,
+    keep_alive: Duration,
+}
+
+impl NewsServer {
+    /// Serve the new articles published on `events`
+    pub fn new(events: EventBus) -> Self {
+        Self {
+            events,
+            keep_alive: DEFAULT_KEEP_ALIVE,
+        }
+    }
+
+    /// Send a keep-alive comment on streams idle for this long, so proxies keep them open
+    pub fn with_keep_alive(mut self, interval: Duration) -> Self {
+        self.keep_alive = interval;
+        self
+    }
+
+    /// The server's routes, to serve or to merge into an existing axum app
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/stream", get(stream))
+            .route("/ws", get(ws))
+            .with_state(Arc::new(self))
+    }
+
+    /// Serve connections from `listener` until the task is dropped or accepting fails
+    pub async fn serve(self, listener: TcpListener) -> Result<()> {
+        axum::serve(listener, self.router()).await?;
+        Ok(())
+    }
+}
+
+/// URL parameters narrowing a stream; WebSocket clients also send them as JSON
+#[derive(Debug, Default, Deserialize)]
+struct StreamParams {
+    /// Filter query
+    query: Option<String>,
+    /// Comma-separated topics
+    topics: Option<String>,
+}
+
+/// Which new articles a client receives
+#[derive(Debug, Default)]
+struct Subscription {
+    query: Option<Query>,
+    topics: Vec<String>,
+}
+
+impl Subscription {
+    fn from_params(params: StreamParams) -> Result<Self> {
+        let query = params
+            .query
+            .filter(|query| !query.trim().is_empty())
+            .map(|query| Query::parse(&query))
+            .transpose()?;
+        let topics = params
+            .topics
+            .iter()
+            .flat_map(|topics| topics.split(','))
+            .map(str::trim)
+            .filter(|topic| !topic.is_empty())
+            .map(str::to_string)
+            .collect();
+        Ok(Self { query, topics })
+    }
+
+    /// Frame confirming the subscription to a WebSocket client
+    fn subscribed(&self) -> String {
+        let query = self.query.as_ref().map(Query::to_string);
+        json!({"type": "subscribed", "query": query, "topics": self.topics}).to_string()
+    }
+
+    fn matches(&self, event: &NewArticle) -> bool {
+        (self.topics.is_empty() || self.topics.contains(&event.topic))
+            && self
+                .query
+                .as_ref()
+                .is_none_or(|query| query.matches(&event.article))
+    }
+
+    /// The new articles on `events` this subscription receives
+    fn articles(self, events: &EventBus) -> impl Stream<Item = Box<NewArticle>> + use<> {
+        events.subscribe().into_stream().filter_map(move |event| {
+            let article = match event {
+                Event::NewArticle(event) if self.matches(&event) => Some(event),
+                _ => None,
+            };
+            futures::future::ready(article)
+        })
+    }
+}
+
+/// `GET /stream`
+async fn stream(
+    State(server): State<Arc<NewsServer>>,
+    Params(params): Params<StreamParams>,
+) -> Response {
+    let subscription = match Subscription::from_params(params) {
+        Ok(subscription) => subscription,
+        Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    };
+    let events = subscription.articles(&server.events).map(|event| {
+        let mut sse_event = sse::Event::default().event("article");
+        if let Some(id) = article_id(&event.article) {
+            // Ids cannot span lines
+            sse_event = sse_event.id(id.replace(['\n', '\r'], " "));
+        }
+        // A new article always serializes
+        let data = serde_json::to_string(&event).unwrap_or_default();
+        Ok::<_, Infallible>(sse_event.data(data))
+    });
+    Sse::new(events)
+        .keep_alive(KeepAlive::new().interval(server.keep_alive))
+        .into_response()
+}
+
+/// `GET /ws`
+async fn ws(
+    State(server): State<Arc<NewsServer>>,
+    Params(params): Params<StreamParams>,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let subscription = match Subscription::from_params(params) {
+        Ok(subscription) => subscription,
+        Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    };
+    // Subscribe before upgrading so no article is missed while the socket opens
+    let events = server.events.subscribe();
+    upgrade.on_upgrade(move |socket| push(socket, events, subscription))
+}
+
+/// Push new articles to a WebSocket client until either side closes the connection
+async fn push(mut socket: WebSocket, mut events: EventSubscriber, mut subscription: Subscription) {
+    loop {
+        let frame = tokio::select! {
+            event = events.recv() => match event {
+                Some(Event::NewArticle(event)) if subscription.matches(&event) => {
+                    // An event always serializes
+                    serde_json::to_string(&Event::NewArticle(event)).unwrap_or_default()
+                }
+                Some(_) => continue,
+                None => break,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    match serde_json::from_str::<StreamParams>(&text)
+                        .map_err(Into::into)
+                        .and_then(Subscription::from_params)
+                    {
+                        Ok(updated) => {
+                            subscription = updated;
+                            subscription.subscribed()
+                        }
+                        Err(e) => json!({"type": "error", "message": e.to_string()}).to_string(),
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Pings are answered by the socket itself
+                Some(Ok(_)) => continue,
+            },
+        };
+        if socket.send(Message::Text(frame.into())).await.is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::NewsArticle;
+
+    fn new_article(topic: &str, title: &str) -> Event {
+        let mut article = NewsArticle::new();
+        article.title = Some(title.to_string());
+        article.link = Some(format!("https://example.com/{}", topic));
+        Event::NewArticle(Box::new(NewArticle {
+            source: "CNBC".to_string(),
+            topic: topic.to_string(),
+            article,
+        }))
+    }
+
+    /// Serve `server` on a local port, returning its base URL
+    async fn spawn(server: NewsServer) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(server.serve(listener));
+        url
+    }
+
+    #[test]
+    fn test_subscription() {
+        let params = StreamParams {
+            query: Some("nvidia".to_string()),
+            topics: Some("earnings, markets,".to_string()),
+        };
+        let subscription = Subscription::from_params(params).unwrap();
+        assert_eq!(subscription.topics, vec!["earnings", "markets"]);
+
+        let matches = |event: Event| match event {
+            Event::NewArticle(event) => subscription.matches(&event),
+            _ => unreachable!(),
+        };
+        assert!(matches(new_article("earnings", "Nvidia beats")));
+        assert!(!matches(new_article("energy", "Nvidia beats")));
+        assert!(!matches(new_article("markets", "Oil slips")));
+
+        let everything = Subscription::from_params(StreamParams::default()).unwrap();
+        assert!(everything.query.is_none() && everything.topics.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let bus = EventBus::new();
+        let url = spawn(NewsServer::new(bus.clone())).await;
+
+        let response = reqwest::get(format!("{}/stream?query=nvidia%20OR%20oil", url))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.headers()["content-type"].to_str().unwrap(),
+            "text/event-stream"
+        );
+        bus.publish(new_article("markets", "Apple sets event date"));
+        bus.publish(new_article("earnings", "Nvidia guidance beats"));
+
+        let mut body = response.bytes_stream();
+        let mut text = String::new();
+        while !text.ends_with("\n\n") {
+            let chunk = body.next().await.unwrap().unwrap();
+            text.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+        assert!(text.starts_with("event: article\n"));
+        assert!(text.contains("id: https://example.com/earnings\n"));
+        assert!(text.contains(r#""title":"Nvidia guidance beats""#));
+        assert!(text.contains(r#""topic":"earnings""#));
+
+        let invalid = reqwest::get(format!("{}/stream?query=%28unbalanced", url))
+            .await
+            .unwrap();
+        assert_eq!(invalid.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_ws() {
+        use futures::SinkExt;
+        use tokio_tungstenite::tungstenite::{self, Message};
+
+        async fn next_frame(
+            socket: &mut (impl Stream<Item = tungstenite::Result<Message>> + Unpin),
+        ) -> serde_json::Value {
+            match socket.next().await.unwrap().unwrap() {
+                Message::Text(text) => serde_json::from_str(&text).unwrap(),
+                message => panic!("unexpected {:?}", message),
+            }
+        }
+
+        let bus = EventBus::new();
+        let url = spawn(NewsServer::new(bus.clone())).await;
+        let ws_url = format!("{}/ws?topics=earnings", url.replace("http", "ws"));
+        let (mut socket, _) = tokio_tungstenite::connect_async(ws_url).await.unwrap();
+        bus.publish(new_article("markets", "Oil slips"));
+        bus.publish(new_article("earnings", "Apple sets event date"));
+        let frame = next_frame(&mut socket).await;
+        assert_eq!(frame["type"], "new_article");
+        assert_eq!(frame["article"]["title"], "Apple sets event date");
+
+        socket
+            .send(Message::text(
+                r#"{"query": "nvidia", "topics": "earnings,markets"}"#,
+            ))
+            .await
+            .unwrap();
+        let frame = next_frame(&mut socket).await;
+        assert_eq!(frame["type"], "subscribed");
+        assert_eq!(frame["query"], "nvidia");
+        assert_eq!(frame["topics"], json!(["earnings", "markets"]));
+        bus.publish(new_article("earnings", "Apple sets event date"));
+        bus.publish(new_article("markets", "Nvidia slides"));
+        assert_eq!(
+            next_frame(&mut socket).await["article"]["title"],
+            "Nvidia slides"
+        );
+
+        socket
+            .send(Message::text(r#"{"query": "(unbalanced"}"#))
+            .await
+            .unwrap();
+        assert_eq!(next_frame(&mut socket).await["type"], "error");
+    }
+}
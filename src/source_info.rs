@@ -0,0 +1,407 @@
+//! Outlet metadata for the built-in sources
+//!
+//! [`source_info()`] looks up an outlet's display name, home page, favicon, country,
+//! language, and paywall policy by source name, so UIs don't hardcode them.
+//! `JsonOptions::with_source_info()` attaches the same metadata to exported articles,
+//! and `SourceInfo::fetch_icon()` downloads the favicon.
+
+#[cfg(feature = "client")]
+use crate::NewsClient;
+#[cfg(feature = "client")]
+use crate::error::{FanError, Result};
+#[cfg(feature = "client")]
+use crate::http::HttpRequest;
+use bytes::Bytes;
+use serde::Serialize;
+
+/// How much of an outlet's content can be read without paying
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Paywall {
+    /// Everything is free to read
+    Free,
+    /// Free, but the feed or API needs an account or key
+    Registration,
+    /// A number of free articles before a subscription is needed
+    Metered,
+    /// Most articles need a subscription
+    Subscription,
+}
+
+/// Metadata of a news outlet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SourceInfo {
+    /// Source name as returned by `NewsSource::name()`
+    pub name: &'static str,
+    /// Name of the outlet as it presents itself
+    pub display_name: &'static str,
+    pub home_url: &'static str,
+    pub favicon_url: &'static str,
+    /// ISO 3166-1 alpha-2 country code, or `None` for international outlets
+    pub country: Option<&'static str>,
+    /// ISO 639-1 language code, or `None` for outlets in many languages
+    pub language: Option<&'static str>,
+    pub paywall: Paywall,
+}
+
+const fn info(
+    name: &'static str,
+    display_name: &'static str,
+    home_url: &'static str,
+    favicon_url: &'static str,
+    country: Option<&'static str>,
+    paywall: Paywall,
+) -> SourceInfo {
+    SourceInfo {
+        name,
+        display_name,
+        home_url,
+        favicon_url,
+        country,
+        language: Some("en"),
+        paywall,
+    }
+}
+
+/// Metadata of every built-in source
+const SOURCES: &[SourceInfo] = &[
+    info(
+        "AlphaVantage",
+        "Alpha Vantage",
+        "https://www.alphavantage.co",
+        "https://www.alphavantage.co/favicon.ico",
+        Some("US"),
+        Paywall::Registration,
+    ),
+    info(
+        "Business Wire",
+        "Business Wire",
+        "https://www.businesswire.com",
+        "https://www.businesswire.com/favicon.ico",
+        Some("US"),
+        Paywall::Free,
+    ),
+    info(
+        "CNBC",
+        "CNBC",
+        "https://www.cnbc.com",
+        "https://www.cnbc.com/favicon.ico",
+        Some("US"),
+        Paywall::Free,
+    ),
+    info(
+        "CoinDesk",
+        "CoinDesk",
+        "https://www.coindesk.com",
+        "https://www.coindesk.com/favicon.ico",
+        Some("US"),
+        Paywall::Free,
+    ),
+    info(
+        "CoinTelegraph",
+        "Cointelegraph",
+        "https://cointelegraph.com",
+        "https://cointelegraph.com/favicon.ico",
+        None,
+        Paywall::Free,
+    ),
+    info(
+        "Financial Post",
+        "Financial Post",
+        "https://financialpost.com",
+        "https://financialpost.com/favicon.ico",
+        Some("CA"),
+        Paywall::Metered,
+    ),
+    info(
+        "Finnhub",
+        "Finnhub",
+        "https://finnhub.io",
+        "https://finnhub.io/favicon.ico",
+        None,
+        Paywall::Registration,
+    ),
+    SourceInfo {
+        language: None,
+        ..info(
+            "GDELT",
+            "GDELT Project",
+            "https://www.gdeltproject.org",
+            "https://www.gdeltproject.org/favicon.ico",
+            None,
+            Paywall::Free,
+        )
+    },
+    info(
+        "Globe and Mail",
+        "The Globe and Mail",
+        "https://www.theglobeandmail.com",
+        "https://www.theglobeandmail.com/favicon.ico",
+        Some("CA"),
+        Paywall::Subscription,
+    ),
+    info(
+        "GlobeNewswire",
+        "GlobeNewswire",
+        "https://www.globenewswire.com",
+        "https://www.globenewswire.com/favicon.ico",
+        Some("US"),
+        Paywall::Free,
+    ),
+    info(
+        "MarketWatch",
+        "MarketWatch",
+        "https://www.marketwatch.com",
+        "https://www.marketwatch.com/favicon.ico",
+        Some("US"),
+        Paywall::Metered,
+    ),
+    info(
+        "NASDAQ",
+        "Nasdaq",
+        "https://www.nasdaq.com",
+        "https://www.nasdaq.com/favicon.ico",
+        Some("US"),
+        Paywall::Free,
+    ),
+    SourceInfo {
+        language: None,
+        ..info(
+            "NewsAPI",
+            "NewsAPI.org",
+            "https://newsapi.org",
+            "https://newsapi.org/favicon.ico",
+            None,
+            Paywall::Registration,
+        )
+    },
+    info(
+        "New York Times",
+        "The New York Times",
+        "https://www.nytimes.com",
+        "https://www.nytimes.com/favicon.ico",
+        Some("US"),
+        Paywall::Metered,
+    ),
+    info(
+        "Nikkei Asia",
+        "Nikkei Asia",
+        "https://asia.nikkei.com",
+        "https://asia.nikkei.com/favicon.ico",
+        Some("JP"),
+        Paywall::Subscription,
+    ),
+    info(
+        "Polygon",
+        "Polygon.io",
+        "https://polygon.io",
+        "https://polygon.io/favicon.ico",
+        Some("US"),
+        Paywall::Registration,
+    ),
+    info(
+        "PR Newswire",
+        "PR Newswire",
+        "https://www.prnewswire.com",
+        "https://www.prnewswire.com/favicon.ico",
+        Some("US"),
+        Paywall::Free,
+    ),
+    info(
+        "Reddit",
+        "Reddit",
+        "https://www.reddit.com",
+        "https://www.reddit.com/favicon.ico",
+        None,
+        Paywall::Free,
+    ),
+    info(
+        "SCMP Business",
+        "South China Morning Post",
+        "https://www.scmp.com",
+        "https://www.scmp.com/favicon.ico",
+        Some("HK"),
+        Paywall::Metered,
+    ),
+    info(
+        "Seeking Alpha",
+        "Seeking Alpha",
+        "https://seekingalpha.com",
+        "https://seekingalpha.com/favicon.ico",
+        Some("US"),
+        Paywall::Metered,
+    ),
+    info(
+        "StockTwits",
+        "Stocktwits",
+        "https://stocktwits.com",
+        "https://stocktwits.com/favicon.ico",
+        Some("US"),
+        Paywall::Free,
+    ),
+    info(
+        "Wall Street Journal",
+        "The Wall Street Journal",
+        "https://www.wsj.com",
+        "https://www.wsj.com/favicon.ico",
+        Some("US"),
+        Paywall::Subscription,
+    ),
+    info(
+        "Yahoo Finance",
+        "Yahoo Finance",
+        "https://finance.yahoo.com",
+        "https://finance.yahoo.com/favicon.ico",
+        Some("US"),
+        Paywall::Free,
+    ),
+];
+
+/// Look up an outlet by source name or display name, ignoring case
+///
+/// # Examples
+///
+/// ```rust
+/// use finance_news_aggregator_rs::source_info::{Paywall, source_info};
+///
+/// let info = source_info("MarketWatch").unwrap();
+/// assert_eq!(info.home_url, "https://www.marketwatch.com");
+/// assert_eq!(info.paywall, Paywall::Metered);
+/// assert_eq!(source_info("the wall street journal").unwrap().name, "Wall Street Journal");
+/// ```
+pub fn source_info(name: &str) -> Option<&'static SourceInfo> {
+    SOURCES.iter().find(|info| {
+        info.name.eq_ignore_ascii_case(name) || info.display_name.eq_ignore_ascii_case(name)
+    })
+}
+
+impl SourceInfo {
+    /// Metadata of every built-in source
+    pub fn all() -> &'static [SourceInfo] {
+        SOURCES
+    }
+
+    /// Download the outlet's favicon through `client`
+    ///
+    /// Icons are cached by the client, so only the first call for an outlet makes
+    /// a request; failures are not cached.
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::NewsClient;
+    /// use finance_news_aggregator_rs::source_info::source_info;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = NewsClient::new();
+    ///     let icon = source_info("CNBC").unwrap().fetch_icon(&client).await?;
+    ///     println!("{} bytes of {:?}", icon.bytes.len(), icon.content_type);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "client")]
+    pub async fn fetch_icon(&self, client: &NewsClient) -> Result<Icon> {
+        if let Some(icon) = client.icons().lock().unwrap().get(self.favicon_url) {
+            return Ok(icon.clone());
+        }
+        let response = client
+            .http_client()
+            .send(HttpRequest::get(self.favicon_url))
+            .await?;
+        if !response.is_success() {
+            return Err(FanError::HttpStatus {
+                status: response.status(),
+                url: self.favicon_url.to_string(),
+                snippet: String::new(),
+            });
+        }
+        let icon = Icon {
+            content_type: response.header("Content-Type").map(str::to_string),
+            bytes: response.bytes().await?,
+        };
+        client
+            .icons()
+            .lock()
+            .unwrap()
+            .insert(self.favicon_url.to_string(), icon.clone());
+        Ok(icon)
+    }
+}
+
+/// A downloaded favicon
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Icon {
+    pub bytes: Bytes,
+    /// The `Content-Type` the icon was served with, such as "image/x-icon"
+    pub content_type: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "client")]
+    use crate::http::{HttpClient, HttpResponse};
+    use crate::types::NewsArticle;
+    #[cfg(feature = "client")]
+    use async_trait::async_trait;
+    #[cfg(feature = "client")]
+    use std::sync::Arc;
+    #[cfg(feature = "client")]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Serves an icon for CNBC and 404 for anything else, counting requests
+    #[cfg(feature = "client")]
+    #[derive(Default)]
+    struct Icons {
+        requests: AtomicUsize,
+    }
+
+    #[cfg(feature = "client")]
+    #[async_trait]
+    impl HttpClient for Icons {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            if request.url == "https://www.cnbc.com/favicon.ico" {
+                Ok(HttpResponse::new(200, &b"\x00\x00\x01\x00"[..])
+                    .with_header("content-type", "image/x-icon"))
+            } else {
+                Ok(HttpResponse::new(404, "Not Found"))
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(source_info("cnbc").unwrap().display_name, "CNBC");
+        assert_eq!(source_info("Nasdaq").unwrap().name, "NASDAQ");
+        assert!(source_info("Generic").is_none());
+
+        let mut article = NewsArticle::new();
+        assert!(article.source_info().is_none());
+        article.source = Some("Globe and Mail".to_string());
+        assert_eq!(article.source_info().unwrap().country, Some("CA"));
+    }
+
+    #[cfg(feature = "client")]
+    #[tokio::test]
+    async fn test_fetch_icon_is_cached() {
+        let icons = Arc::new(Icons::default());
+        let client = NewsClient::new().with_http_client(Arc::clone(&icons));
+        let cnbc = source_info("CNBC").unwrap();
+
+        let icon = cnbc.fetch_icon(&client).await.unwrap();
+        assert_eq!(icon.bytes.as_ref(), b"\x00\x00\x01\x00");
+        assert_eq!(icon.content_type.as_deref(), Some("image/x-icon"));
+        assert_eq!(cnbc.fetch_icon(&client).await.unwrap(), icon);
+        assert_eq!(icons.requests.load(Ordering::SeqCst), 1);
+
+        // Failures are retried
+        let wsj = source_info("Wall Street Journal").unwrap();
+        assert!(matches!(
+            wsj.fetch_icon(&client).await,
+            Err(FanError::HttpStatus { status: 404, .. })
+        ));
+        assert!(wsj.fetch_icon(&client).await.is_err());
+        assert_eq!(icons.requests.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,546 @@
+//! SQLite article store with full-text search (feature "sqlite")
+//!
+//! [`SqliteStore`] keeps articles in a single SQLite file, one row per source and
+//! article identity (GUID, link, or title), and indexes titles and descriptions in
+//! an FTS5 table. [`SqliteStore::search()`] ranks matches with BM25 and narrows them
+//! with a [`SearchFilter`]:
+//!
+//! ```rust
+//! use finance_news_aggregator_rs::NewsArticle;
+//! use finance_news_aggregator_rs::store::{SearchFilter, SqliteStore};
+//!
+//! let store = SqliteStore::open_in_memory()?;
+//! let mut article = NewsArticle::new();
+//! article.title = Some("Nvidia earnings beat as data center sales soar".to_string());
+//! article.source = Some("CNBC".to_string());
+//! store.insert(&[article])?;
+//!
+//! let hits = store.search("nvidia earnings", &SearchFilter::new().with_source("CNBC"))?;
+//! assert_eq!(hits.len(), 1);
+//! # Ok::<(), finance_news_aggregator_rs::error::FanError>(())
+//! ```
+//!
+//! Queries use the FTS5 syntax: words, `"quoted phrases"`, `prefix*`, and `AND`,
+//! `OR`, `NOT`. Words with punctuation, such as `S&P` or `non-farm`, are searched as
+//! phrases.
+
+use crate::disk_cache::RetentionPolicy;
+use crate::error::{FanError, Result};
+use crate::filter::Query;
+use crate::poller::article_id;
+use crate::schema;
+use crate::types::NewsArticle;
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, OptionalExtension, params, params_from_iter};
+use std::path::Path;
+use std::sync::Mutex;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS articles (
+        id INTEGER PRIMARY KEY,
+        source TEXT NOT NULL,
+        article_id TEXT NOT NULL,
+        title TEXT,
+        description TEXT,
+        published_at INTEGER,
+        stored_at INTEGER NOT NULL,
+        article TEXT NOT NULL,
+        UNIQUE (source, article_id)
+    );
+    CREATE INDEX IF NOT EXISTS articles_published_at ON articles (published_at);
+    CREATE VIRTUAL TABLE IF NOT EXISTS articles_fts USING fts5 (
+        title, description, content = 'articles', content_rowid = 'id'
+    );
+    CREATE TRIGGER IF NOT EXISTS articles_fts_insert AFTER INSERT ON articles BEGIN
+        INSERT INTO articles_fts (rowid, title, description)
+        VALUES (new.id, new.title, new.description);
+    END;
+    CREATE TRIGGER IF NOT EXISTS articles_fts_delete AFTER DELETE ON articles BEGIN
+        INSERT INTO articles_fts (articles_fts, rowid, title, description)
+        VALUES ('delete', old.id, old.title, old.description);
+    END;
+    CREATE TRIGGER IF NOT EXISTS articles_fts_update AFTER UPDATE ON articles BEGIN
+        INSERT INTO articles_fts (articles_fts, rowid, title, description)
+        VALUES ('delete', old.id, old.title, old.description);
+        INSERT INTO articles_fts (rowid, title, description)
+        VALUES (new.id, new.title, new.description);
+    END;
+";
+
+const DEFAULT_LIMIT: usize = 50;
+
+/// Narrows the results of [`SqliteStore::search()`]
+///
+/// Articles without a parseable publication date are left out when a date bound is
+/// set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchFilter {
+    source: Option<String>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    ticker: Option<String>,
+    limit: usize,
+}
+
+impl Default for SearchFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchFilter {
+    /// Any article, at most 50
+    pub fn new() -> Self {
+        Self {
+            source: None,
+            since: None,
+            until: None,
+            ticker: None,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// Only articles of a source, by `NewsSource::name()`
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.source = Some(source.to_string());
+        self
+    }
+
+    /// Only articles published at or after `since`
+    pub fn with_since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Only articles published before `until`
+    pub fn with_until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Only articles mentioning a ticker, as the query term `ticker:NVDA` matches
+    ///
+    /// Tickers are not indexed, so the articles matching the other conditions are
+    /// read and checked one by one.
+    pub fn with_ticker(mut self, ticker: &str) -> Self {
+        self.ticker = Some(ticker.to_string());
+        self
+    }
+
+    /// Return at most this many articles
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+/// Articles kept in a SQLite database, searchable by full text
+pub struct SqliteStore {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteStore {
+    /// Open a store in the database file at `path`, creating it if needed
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Open a store that lives in memory, for tests and short-lived tools
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> Result<Self> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// Store articles, replacing earlier versions of the same articles
+    ///
+    /// Articles without a GUID, link, or title are skipped.
+    ///
+    /// # Returns
+    /// The number of articles that were not stored before
+    pub fn insert(&self, articles: &[NewsArticle]) -> Result<usize> {
+        let mut connection = self.connection.lock().unwrap();
+        let transaction = connection.transaction()?;
+        let mut added = 0;
+        {
+            let mut exists = transaction
+                .prepare_cached("SELECT 1 FROM articles WHERE source = ?1 AND article_id = ?2")?;
+            let mut upsert = transaction.prepare_cached(
+                "INSERT INTO articles
+                     (source, article_id, title, description, published_at, stored_at, article)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT (source, article_id) DO UPDATE SET
+                     title = excluded.title,
+                     description = excluded.description,
+                     published_at = excluded.published_at,
+                     stored_at = excluded.stored_at,
+                     article = excluded.article",
+            )?;
+            let now = Utc::now().timestamp();
+            for article in articles {
+                let Some(id) = article_id(article) else {
+                    continue;
+                };
+                let source = article.source.as_deref().unwrap_or_default();
+                if !exists.exists(params![source, id])? {
+                    added += 1;
+                }
+                upsert.execute(params![
+                    source,
+                    id,
+                    article.title,
+                    article.description,
+                    article
+                        .published_at()
+                        .map(|published| published.timestamp()),
+                    now,
+                    serde_json::to_string(article)?,
+                ])?;
+            }
+        }
+        transaction.commit()?;
+        Ok(added)
+    }
+
+    /// Number of stored articles
+    pub fn len(&self) -> Result<usize> {
+        let connection = self.connection.lock().unwrap();
+        let count: i64 =
+            connection.query_row("SELECT COUNT(*) FROM articles", [], |row| row.get(0))?;
+        Ok(count as usize)
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Look up a stored article by source and GUID, link, or title
+    pub fn get(&self, source: &str, id: &str) -> Result<Option<NewsArticle>> {
+        let connection = self.connection.lock().unwrap();
+        let json: Option<String> = connection
+            .query_row(
+                "SELECT article FROM articles WHERE source = ?1 AND article_id = ?2",
+                params![source, id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        json.map(|json| schema::from_str(&json)).transpose()
+    }
+
+    /// Remove the articles that fall outside `policy`
+    ///
+    /// Ages and per-source limits go by when an article was last stored, not by its
+    /// publication date. Nothing schedules this; long-running aggregators should
+    /// call it periodically so the database does not grow without bound.
+    ///
+    /// # Returns
+    /// The number of articles removed
+    pub fn prune(&self, policy: &RetentionPolicy) -> Result<usize> {
+        let connection = self.connection.lock().unwrap();
+        let mut removed = 0;
+        if let Some(max_age) = policy.max_age {
+            let cutoff =
+                Utc::now().timestamp() - i64::try_from(max_age.as_secs()).unwrap_or(i64::MAX);
+            removed += connection.execute("DELETE FROM articles WHERE stored_at < ?1", [cutoff])?;
+        }
+        if let Some(max_entries) = policy.max_entries_per_source {
+            removed += connection.execute(
+                "DELETE FROM articles WHERE id IN (
+                     SELECT id FROM (
+                         SELECT id, ROW_NUMBER() OVER (
+                             PARTITION BY source ORDER BY stored_at DESC, id DESC
+                         ) AS rank
+                         FROM articles
+                     )
+                     WHERE rank > ?1
+                 )",
+                [i64::try_from(max_entries).unwrap_or(i64::MAX)],
+            )?;
+        }
+        Ok(removed)
+    }
+
+    /// Articles matching a full-text query and a filter, best matches first
+    ///
+    /// An empty query matches every article, newest first. A query FTS5 cannot
+    /// parse, such as one with unbalanced parentheses, fails with
+    /// `FanError::InvalidQuery`.
+    pub fn search(&self, query: &str, filter: &SearchFilter) -> Result<Vec<NewsArticle>> {
+        let query = fts_query(query);
+        let mut sql = String::from("SELECT articles.article FROM articles");
+        let mut conditions = Vec::new();
+        let mut values: Vec<rusqlite::types::Value> = Vec::new();
+        if !query.is_empty() {
+            sql.push_str(" JOIN articles_fts ON articles_fts.rowid = articles.id");
+            conditions.push("articles_fts MATCH ?");
+            values.push(query.clone().into());
+        }
+        if let Some(source) = &filter.source {
+            conditions.push("articles.source = ?");
+            values.push(source.clone().into());
+        }
+        if let Some(since) = filter.since {
+            conditions.push("articles.published_at >= ?");
+            values.push(since.timestamp().into());
+        }
+        if let Some(until) = filter.until {
+            conditions.push("articles.published_at < ?");
+            values.push(until.timestamp().into());
+        }
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+        sql.push_str(if query.is_empty() {
+            " ORDER BY articles.published_at DESC, articles.id DESC"
+        } else {
+            " ORDER BY articles_fts.rank, articles.published_at DESC"
+        });
+        if filter.ticker.is_none() {
+            sql.push_str(" LIMIT ?");
+            values.push((filter.limit.min(i64::MAX as usize) as i64).into());
+        }
+
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection.prepare(&sql)?;
+        let rows = statement
+            .query_map(params_from_iter(values), |row| row.get::<_, String>(0))
+            .and_then(|rows| rows.collect::<rusqlite::Result<Vec<String>>>())
+            .map_err(|e| match e {
+                rusqlite::Error::SqliteFailure(_, Some(message))
+                    if message.starts_with("fts5:") || message.contains("syntax error") =>
+                {
+                    FanError::InvalidQuery(format!("{}: {}", query, message))
+                }
+                e => FanError::from(e),
+            })?;
+        let ticker = filter.ticker.clone().map(Query::Ticker);
+        let mut articles = Vec::new();
+        for json in &rows {
+            if articles.len() == filter.limit {
+                break;
+            }
+            let article = schema::from_str(json)?;
+            if ticker
+                .as_ref()
+                .is_none_or(|ticker| ticker.matches(&article))
+            {
+                articles.push(article);
+            }
+        }
+        Ok(articles)
+    }
+}
+
+impl std::fmt::Debug for SqliteStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqliteStore").finish_non_exhaustive()
+    }
+}
+
+/// A query in FTS5 syntax, with bare words that contain punctuation quoted
+fn fts_query(query: &str) -> String {
+    let mut fts = String::new();
+    let mut rest = query.trim();
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() || c == '(' || c == ')' {
+            fts.push(c);
+            rest = &rest[c.len_utf8()..];
+        } else if c == '"' {
+            // Quoted phrases are copied as they are, closing quote included
+            let end = rest[1..].find('"').map_or(rest.len(), |i| i + 2);
+            fts.push_str(&rest[..end]);
+            rest = &rest[end..];
+        } else {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || c == '(' || c == ')' || c == '"')
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+            let stem = word.strip_suffix('*').unwrap_or(word);
+            if !stem.is_empty() && stem.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                fts.push_str(word);
+            } else {
+                fts.push('"');
+                fts.push_str(&word.replace('"', "\"\""));
+                fts.push('"');
+            }
+            rest = &rest[end..];
+        }
+    }
+    fts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(source: &str, title: &str, pub_date: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.source = Some(source.to_string());
+        article.title = Some(title.to_string());
+        article.link = Some(format!("https://example.com/{}", title.replace(' ', "-")));
+        article.pub_date = Some(pub_date.to_string());
+        article
+    }
+
+    fn titles(articles: &[NewsArticle]) -> Vec<&str> {
+        articles
+            .iter()
+            .map(|article| article.title.as_deref().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_replaces_updated_articles() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let mut fed = article("CNBC", "Fed holds rates", "Wed, 01 May 2024 18:00:00 GMT");
+        assert_eq!(store.insert(&[fed.clone()]).unwrap(), 1);
+
+        fed.description = Some("Powell signals patience on cuts".to_string());
+        let other = article("WSJ", "Fed holds rates", "Wed, 01 May 2024 18:05:00 GMT");
+        assert_eq!(store.insert(&[fed.clone(), other]).unwrap(), 1);
+        assert_eq!(store.len().unwrap(), 2);
+
+        let stored = store
+            .get("CNBC", fed.link.as_deref().unwrap())
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.description, fed.description);
+        assert_eq!(
+            titles(&store.search("powell", &SearchFilter::new()).unwrap()),
+            vec!["Fed holds rates"]
+        );
+    }
+
+    #[test]
+    fn test_prune() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let date = "Wed, 22 May 2024 21:00:00 GMT";
+        store
+            .insert(&[
+                article("CNBC", "Nvidia earnings beat", date),
+                article("CNBC", "Oil slips", date),
+                article("CNBC", "Gold rallies", date),
+                article("WSJ", "Fed holds rates", date),
+            ])
+            .unwrap();
+        // Stored an hour apart, the WSJ article two days ago
+        store
+            .connection
+            .lock()
+            .unwrap()
+            .execute_batch(
+                "UPDATE articles SET stored_at = stored_at - 3600 * id;
+                 UPDATE articles SET stored_at = stored_at - 48 * 3600 WHERE source = 'WSJ';",
+            )
+            .unwrap();
+
+        let policy = RetentionPolicy::new()
+            .with_max_age(std::time::Duration::from_secs(24 * 3600))
+            .with_max_entries_per_source(2);
+        assert_eq!(store.prune(&policy).unwrap(), 2);
+        let kept = store.search("", &SearchFilter::new()).unwrap();
+        let mut kept = titles(&kept);
+        kept.sort();
+        assert_eq!(kept, vec!["Nvidia earnings beat", "Oil slips"]);
+        assert!(
+            store
+                .search("gold", &SearchFilter::new())
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(store.prune(&RetentionPolicy::new()).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_search() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        store
+            .insert(&[
+                article(
+                    "CNBC",
+                    "Nvidia earnings beat",
+                    "Wed, 22 May 2024 21:00:00 GMT",
+                ),
+                article("WSJ", "Nvidia stock split", "Thu, 23 May 2024 12:00:00 GMT"),
+                article(
+                    "CNBC",
+                    "S&P 500 hits record",
+                    "Fri, 24 May 2024 20:00:00 GMT",
+                ),
+                article("CNBC", "Oil slips", "not a date"),
+            ])
+            .unwrap();
+
+        let all = SearchFilter::new();
+        assert_eq!(store.search("nvidia", &all).unwrap().len(), 2);
+        assert_eq!(
+            titles(
+                &store
+                    .search("nvid*", &all.clone().with_source("WSJ"))
+                    .unwrap()
+            ),
+            vec!["Nvidia stock split"]
+        );
+        assert_eq!(
+            titles(&store.search("S&P", &all).unwrap()),
+            vec!["S&P 500 hits record"]
+        );
+        assert_eq!(
+            titles(&store.search("nvidia NOT split", &all).unwrap()),
+            vec!["Nvidia earnings beat"]
+        );
+
+        let since = DateTime::parse_from_rfc3339("2024-05-23T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            titles(&store.search("", &all.clone().with_since(since)).unwrap()),
+            vec!["S&P 500 hits record", "Nvidia stock split"]
+        );
+        assert_eq!(
+            store.search("", &all.clone().with_limit(1)).unwrap().len(),
+            1
+        );
+
+        let mut apple = article(
+            "WSJ",
+            "Apple and $NVDA rally",
+            "Sat, 25 May 2024 12:00:00 GMT",
+        );
+        apple
+            .extra_fields
+            .insert("symbols".to_string(), "AAPL".into());
+        store.insert(&[apple]).unwrap();
+        assert_eq!(
+            titles(&store.search("", &all.clone().with_ticker("NVDA")).unwrap()),
+            vec!["Apple and $NVDA rally"]
+        );
+        assert_eq!(
+            titles(
+                &store
+                    .search("rally", &all.clone().with_ticker("aapl").with_limit(1))
+                    .unwrap()
+            ),
+            vec!["Apple and $NVDA rally"]
+        );
+        assert!(matches!(
+            store.search("(nvidia", &all),
+            Err(FanError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_fts_query() {
+        assert_eq!(fts_query("nvidia AND earn*"), "nvidia AND earn*");
+        assert_eq!(
+            fts_query("S&P (non-farm OR \"jobs report\")"),
+            "\"S&P\" (\"non-farm\" OR \"jobs report\")"
+        );
+    }
+}
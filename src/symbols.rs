@@ -0,0 +1,195 @@
+//! Ticker symbol validation and normalization
+//!
+//! [`Symbol::parse()`] accepts tickers as people write them and returns one
+//! canonical form, so symbol inputs can be interpolated into feed URLs safely:
+//!
+//! | Input | Symbol | Yahoo form |
+//! |-------|--------|------------|
+//! | `aapl`, `$AAPL` | `AAPL` | `AAPL` |
+//! | `BRK.B`, `brk-b`, `BRK/B`, `BRK B` | `BRK.B` | `BRK-B` |
+//! | `shop.to` | `SHOP.TO` | `SHOP.TO` |
+//! | `RCI-B.TO` | `RCI.B.TO` | `RCI-B.TO` |
+//! | `^gspc` | `^GSPC` | `^GSPC` |
+//!
+//! A symbol is a root of up to 6 letters and digits (an index root starts with `^`),
+//! optionally followed by a share class of 1 or 2 letters and an exchange suffix
+//! such as `.TO`, `.L`, or `.HK`. Anything else is rejected with
+//! `FanError::InvalidSymbol`.
+
+use crate::error::{FanError, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// Yahoo-style exchange suffixes recognized after a `.`
+const EXCHANGE_SUFFIXES: &[&str] = &[
+    "AS", "AX", "BK", "BO", "BR", "CN", "CO", "DE", "F", "HE", "HK", "IR", "JK", "JO", "KL", "KQ",
+    "KS", "L", "LS", "MC", "MI", "MX", "NE", "NS", "NZ", "OL", "PA", "SA", "SI", "SS", "ST", "SW",
+    "SZ", "T", "TO", "TW", "V", "VI", "WA",
+];
+
+/// Longest symbol root
+const MAX_ROOT_LEN: usize = 6;
+
+/// A validated ticker symbol
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    root: String,
+    class: Option<String>,
+    exchange: Option<String>,
+}
+
+impl Symbol {
+    /// Validate and normalize a ticker symbol
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finance_news_aggregator_rs::symbols::Symbol;
+    ///
+    /// let symbol = Symbol::parse("brk-b").unwrap();
+    /// assert_eq!(symbol.to_string(), "BRK.B");
+    /// assert_eq!(symbol.yahoo(), "BRK-B");
+    /// assert!(Symbol::parse("AAPL&s=MSFT").is_err());
+    /// ```
+    pub fn parse(input: &str) -> Result<Self> {
+        let invalid = || FanError::InvalidSymbol(input.to_string());
+        let text = input.trim().trim_start_matches('$').to_uppercase();
+        let (root, mut rest) = match text.find(['.', '-', '/', ' ']) {
+            Some(split) => text.split_at(split),
+            None => (text.as_str(), ""),
+        };
+        let digits = root.strip_prefix('^').unwrap_or(root);
+        if digits.is_empty()
+            || digits.len() > MAX_ROOT_LEN
+            || !digits.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(invalid());
+        }
+
+        let mut symbol = Self {
+            root: root.to_string(),
+            class: None,
+            exchange: None,
+        };
+        while let Some(separator) = rest.chars().next() {
+            let segment = &rest[1..];
+            let end = segment.find(['.', '-', '/', ' ']).unwrap_or(segment.len());
+            let (part, remaining) = segment.split_at(end);
+            if separator == '.' && remaining.is_empty() && EXCHANGE_SUFFIXES.contains(&part) {
+                symbol.exchange = Some(part.to_string());
+            } else if symbol.class.is_none()
+                && (1..=2).contains(&part.len())
+                && part.chars().all(|c| c.is_ascii_alphabetic())
+            {
+                symbol.class = Some(part.to_string());
+            } else {
+                return Err(invalid());
+            }
+            rest = remaining;
+        }
+        Ok(symbol)
+    }
+
+    /// The symbol without share class or exchange, such as `BRK`
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    /// The share class, such as `B` in `BRK.B`
+    pub fn class(&self) -> Option<&str> {
+        self.class.as_deref()
+    }
+
+    /// The exchange suffix, such as `TO` in `SHOP.TO`
+    pub fn exchange(&self) -> Option<&str> {
+        self.exchange.as_deref()
+    }
+
+    /// The symbol as Yahoo Finance writes it, with a `-` before the share class
+    pub fn yahoo(&self) -> String {
+        self.format('-')
+    }
+
+    fn format(&self, class_separator: char) -> String {
+        let mut text = self.root.clone();
+        if let Some(class) = &self.class {
+            text.push(class_separator);
+            text.push_str(class);
+        }
+        if let Some(exchange) = &self.exchange {
+            text.push('.');
+            text.push_str(exchange);
+        }
+        text
+    }
+}
+
+/// The canonical form, with a `.` before the share class
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format('.'))
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = FanError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        for (input, canonical, yahoo) in [
+            ("aapl", "AAPL", "AAPL"),
+            (" $nvda ", "NVDA", "NVDA"),
+            ("BRK.B", "BRK.B", "BRK-B"),
+            ("brk-b", "BRK.B", "BRK-B"),
+            ("BRK/B", "BRK.B", "BRK-B"),
+            ("BRK B", "BRK.B", "BRK-B"),
+            ("shop.to", "SHOP.TO", "SHOP.TO"),
+            ("RCI-B.TO", "RCI.B.TO", "RCI-B.TO"),
+            ("0700.HK", "0700.HK", "0700.HK"),
+            ("^gspc", "^GSPC", "^GSPC"),
+        ] {
+            let symbol = Symbol::parse(input).unwrap();
+            assert_eq!(symbol.to_string(), canonical, "{}", input);
+            assert_eq!(symbol.yahoo(), yahoo, "{}", input);
+        }
+
+        let symbol: Symbol = "rci-b.to".parse().unwrap();
+        assert_eq!(symbol.root(), "RCI");
+        assert_eq!(symbol.class(), Some("B"));
+        assert_eq!(symbol.exchange(), Some("TO"));
+        // A suffix that is not an exchange is a share class
+        assert_eq!(Symbol::parse("ABC.X").unwrap().class(), Some("X"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        for input in [
+            "",
+            "$",
+            "^",
+            "AAPL MSFT",
+            "AAPL&s=MSFT",
+            "../feed",
+            "TOOLONGX",
+            "BRK.B.C",
+            "SHOP.TO.B",
+            "BRK.",
+            "Cash & Cash Investments",
+        ] {
+            assert!(
+                matches!(Symbol::parse(input), Err(FanError::InvalidSymbol(_))),
+                "{:?} should be invalid",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+/// Cross-source topic groups
+///
+/// Each source has its own topic vocabulary (`"cryptocurrency"` on NASDAQ,
+/// `"RSSMarketsMain"` on WSJ, `"personal_finance"` on CNBC, ...). A `TopicGroup`
+/// names a subject area once and lets every source declare which of its native
+/// topics belong to it via
+/// [`NewsSource::group_topics`](crate::news_source::NewsSource::group_topics).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TopicGroup {
+    /// Stock market and trading news
+    Markets,
+    /// Earnings reports, releases, and call transcripts
+    Earnings,
+    /// Cryptocurrency and digital asset news
+    Crypto,
+    /// Macroeconomics, central banks, and economic data
+    Macro,
+    /// Technology sector news
+    Tech,
+    /// Oil, gas, and commodities news
+    Energy,
+    /// Personal finance, wealth, and retirement
+    PersonalFinance,
+    /// Audio briefings and podcast episodes
+    Podcasts,
+}
+
+impl TopicGroup {
+    /// All topic groups
+    pub fn all() -> Vec<TopicGroup> {
+        vec![
+            TopicGroup::Markets,
+            TopicGroup::Earnings,
+            TopicGroup::Crypto,
+            TopicGroup::Macro,
+            TopicGroup::Tech,
+            TopicGroup::Energy,
+            TopicGroup::PersonalFinance,
+            TopicGroup::Podcasts,
+        ]
+    }
+
+    /// Stable identifier for the group
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TopicGroup::Markets => "markets",
+            TopicGroup::Earnings => "earnings",
+            TopicGroup::Crypto => "crypto",
+            TopicGroup::Macro => "macro",
+            TopicGroup::Tech => "tech",
+            TopicGroup::Energy => "energy",
+            TopicGroup::PersonalFinance => "personal_finance",
+            TopicGroup::Podcasts => "podcasts",
+        }
+    }
+}
+
+impl std::fmt::Display for TopicGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for TopicGroup {
+    type Err = crate::error::FanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TopicGroup::all()
+            .into_iter()
+            .find(|group| group.as_str() == s)
+            .ok_or_else(|| crate::error::FanError::Unknown(format!("Unknown topic group: {}", s)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_str() {
+        for group in TopicGroup::all() {
+            assert_eq!(group.as_str().parse::<TopicGroup>().unwrap(), group);
+        }
+        assert!("sports".parse::<TopicGroup>().is_err());
+    }
+}
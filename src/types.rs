@@ -1,9 +1,24 @@
+use crate::aggregation::MergePolicy;
+use crate::breaking::Priority;
+use crate::cluster::DEFAULT_SYNDICATION_THRESHOLD;
+use crate::content_kind::{CONTENT_KIND_FIELD, ContentKind};
+use crate::credentials::Credentials;
+use crate::dates::parse_date;
+use crate::disk_cache::RetentionPolicy;
+use crate::schema::SCHEMA_VERSION;
+use crate::source_info::{SourceInfo, source_info};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "fetch")]
 use fake_user_agent::get_safari_rua;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
 
 /// Represents a news article from any source
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct NewsArticle {
     pub title: Option<String>,
     pub link: Option<String>,
@@ -14,7 +29,32 @@ pub struct NewsArticle {
     pub author: Option<String>,
     pub source: Option<String>,
     /// Additional fields that might be source-specific
-    pub extra_fields: HashMap<String, String>,
+    ///
+    /// Namespaced feed tags are keyed `{prefix}:{local}` as written in the feed (e.g.
+    /// "media:credit"); see `extra()`.
+    pub extra_fields: HashMap<String, ExtraValue>,
+    /// Served from the disk cache because the feed could not be fetched
+    #[serde(default)]
+    pub stale: bool,
+    /// Delivered again by a `Poller` because its feed changed the title or
+    /// description; see `Watermarks::previous_version()`
+    #[serde(default)]
+    pub updated: bool,
+    /// Media files attached to the article, such as podcast episodes
+    #[serde(default)]
+    pub enclosures: Vec<Enclosure>,
+    /// Keywords and tickers found by the filter that let the article through, for
+    /// highlighting
+    #[serde(default)]
+    pub matches: Vec<TermMatch>,
+    /// How urgently the article should reach readers, set by a
+    /// `breaking::BreakingDetector`
+    #[serde(default)]
+    pub priority: Priority,
+    /// Version of the serialized form the article was written in, 0 before
+    /// versioning; see `schema`
+    #[serde(default)]
+    pub schema_version: u32,
 }
 
 impl NewsArticle {
@@ -29,6 +69,247 @@ impl NewsArticle {
             author: None,
             source: None,
             extra_fields: HashMap::new(),
+            stale: false,
+            updated: false,
+            enclosures: Vec::new(),
+            matches: Vec::new(),
+            priority: Priority::Routine,
+            schema_version: SCHEMA_VERSION,
+        }
+    }
+
+    /// Look up an extra field by namespace prefix and local name
+    ///
+    /// `article.extra("media", "credit")` reads `<media:credit>`; an empty namespace
+    /// reads an unprefixed tag or a field set by a JSON source.
+    pub fn extra(&self, namespace: &str, name: &str) -> Option<&ExtraValue> {
+        if namespace.is_empty() {
+            self.extra_fields.get(name)
+        } else {
+            self.extra_fields.get(&format!("{}:{}", namespace, name))
+        }
+    }
+
+    /// Parse `pub_date` into a UTC timestamp
+    ///
+    /// Accepts RFC 2822 dates (RSS), RFC 3339 dates (Atom and JSON APIs), and the
+    /// zone names and zone-less formats feeds produce in practice; see
+    /// [`parse_date`](crate::dates::parse_date). `pub_date` itself keeps the date as
+    /// the feed wrote it. Returns `None` when the article has no date or it is in an
+    /// unknown format.
+    pub fn published_at(&self) -> Option<DateTime<Utc>> {
+        parse_date(self.pub_date.as_deref()?)
+    }
+
+    /// Time elapsed since the article was published
+    ///
+    /// Returns `None` when `published_at()` is. Dates in the future, usually from
+    /// publisher clock skew, count as just published.
+    pub fn age(&self) -> Option<std::time::Duration> {
+        self.age_at(Utc::now())
+    }
+
+    /// Time elapsed between publication and `now`
+    pub fn age_at(&self, now: DateTime<Utc>) -> Option<std::time::Duration> {
+        let elapsed = now.signed_duration_since(self.published_at()?);
+        Some(elapsed.to_std().unwrap_or_default())
+    }
+
+    /// Human-readable age, e.g. "23m ago"
+    ///
+    /// Uses minutes, hours, and days up to 30 days, then the publication date
+    /// ("2024-05-01"). Returns `None` for undated articles.
+    pub fn relative_time(&self) -> Option<String> {
+        self.relative_time_at(Utc::now())
+    }
+
+    /// Human-readable age relative to `now`
+    pub fn relative_time_at(&self, now: DateTime<Utc>) -> Option<String> {
+        let minutes = self.age_at(now)?.as_secs() / 60;
+        let text = match minutes {
+            0 => "just now".to_string(),
+            1..60 => format!("{}m ago", minutes),
+            60..1440 => format!("{}h ago", minutes / 60),
+            1440..43200 => format!("{}d ago", minutes / 1440),
+            _ => self.published_at()?.format("%Y-%m-%d").to_string(),
+        };
+        Some(text)
+    }
+
+    /// The kind of piece the article is, once labeled by a
+    /// `content_kind::ContentClassifier`
+    pub fn content_kind(&self) -> Option<ContentKind> {
+        self.extra_fields.get(CONTENT_KIND_FIELD)?.parse()
+    }
+
+    /// Metadata of the outlet the article came from, for a built-in source
+    pub fn source_info(&self) -> Option<&'static SourceInfo> {
+        source_info(self.source.as_deref()?)
+    }
+
+    /// The first audio enclosure, e.g. the episode of a podcast feed item
+    pub fn audio(&self) -> Option<&Enclosure> {
+        self.enclosures
+            .iter()
+            .find(|enclosure| enclosure.is_audio())
+    }
+}
+
+/// Value of an entry in `NewsArticle::extra_fields`
+///
+/// A tag that appears once in an item is kept as `Text`; a tag repeated within the
+/// same item, such as several `<media:credit>` elements, as a `List` in document
+/// order. Serializes as a plain string or an array of strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum ExtraValue {
+    Text(String),
+    List(Vec<String>),
+}
+
+impl ExtraValue {
+    /// The value, or the first value of a list
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Text(text) => text,
+            Self::List(values) => values.first().map(String::as_str).unwrap_or_default(),
+        }
+    }
+
+    /// All values, in document order
+    pub fn values(&self) -> &[String] {
+        match self {
+            Self::Text(text) => std::slice::from_ref(text),
+            Self::List(values) => values,
+        }
+    }
+
+    /// Parse the value, or the first value of a list, e.g. into a number
+    pub fn parse<T: FromStr>(&self) -> Option<T> {
+        self.as_str().trim().parse().ok()
+    }
+
+    /// Add a value from a repeated tag, turning `Text` into a `List`
+    pub fn push(&mut self, value: String) {
+        match self {
+            Self::Text(text) => *self = Self::List(vec![std::mem::take(text), value]),
+            Self::List(values) => values.push(value),
+        }
+    }
+}
+
+impl From<String> for ExtraValue {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for ExtraValue {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<Vec<String>> for ExtraValue {
+    fn from(values: Vec<String>) -> Self {
+        Self::List(values)
+    }
+}
+
+impl fmt::Display for ExtraValue {
+    /// Writes a list as its values separated by ", "
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.values().join(", "))
+    }
+}
+
+impl PartialEq<str> for ExtraValue {
+    fn eq(&self, other: &str) -> bool {
+        matches!(self, Self::Text(text) if text == other)
+    }
+}
+
+impl PartialEq<&str> for ExtraValue {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// Media file attached to an article, from an RSS `<enclosure>` or similar element
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Enclosure {
+    pub url: String,
+    /// MIME type, e.g. "audio/mpeg"
+    pub mime_type: Option<String>,
+    /// File size in bytes
+    pub length: Option<u64>,
+    /// Playing time in seconds
+    pub duration_seconds: Option<u64>,
+}
+
+impl Enclosure {
+    /// Create an enclosure with only its URL known
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            mime_type: None,
+            length: None,
+            duration_seconds: None,
+        }
+    }
+
+    /// Whether the enclosure is an audio file
+    ///
+    /// Decided by the MIME type, or by the file extension when there is none.
+    pub fn is_audio(&self) -> bool {
+        match &self.mime_type {
+            Some(mime_type) => mime_type.starts_with("audio/"),
+            None => {
+                let path = self.url.split(['?', '#']).next().unwrap_or_default();
+                [".mp3", ".m4a", ".aac", ".ogg", ".opus", ".wav"]
+                    .iter()
+                    .any(|extension| path.to_ascii_lowercase().ends_with(extension))
+            }
+        }
+    }
+
+    /// Playing time, when the feed gives one
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        self.duration_seconds.map(std::time::Duration::from_secs)
+    }
+}
+
+/// A filter term found in an article
+///
+/// `start..end` is the byte range of the match in the field's text, so
+/// `&article.title.unwrap()[start..end]` is the matched text as written.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TermMatch {
+    /// The keyword or ticker as given to the filter
+    pub term: String,
+    pub field: MatchField,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Article field a [`TermMatch`] was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MatchField {
+    Title,
+    Description,
+}
+
+impl MatchField {
+    /// The field's text in `article`
+    pub fn text(self, article: &NewsArticle) -> Option<&str> {
+        match self {
+            Self::Title => article.title.as_deref(),
+            Self::Description => article.description.as_deref(),
         }
     }
 }
@@ -39,6 +320,21 @@ impl Default for NewsArticle {
     }
 }
 
+/// HTTP protocol selection for the shared client
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HttpVersion {
+    /// Negotiate through TLS ALPN: HTTP/2 when the server offers it, HTTP/1.1 otherwise
+    #[default]
+    Auto,
+    /// Only speak HTTP/1.1, one request per connection at a time
+    Http1Only,
+    /// Speak HTTP/2 without negotiation; the server must support it
+    Http2PriorKnowledge,
+}
+
+/// Longest cache TTL taken from a feed's own `<ttl>`
+pub const MAX_FEED_TTL: std::time::Duration = std::time::Duration::from_secs(24 * 3600);
+
 /// Configuration for news sources
 #[derive(Debug, Clone)]
 pub struct SourceConfig {
@@ -47,6 +343,36 @@ pub struct SourceConfig {
     pub timeout_seconds: u64,
     pub max_retries: u32,
     pub retry_delay_ms: u64,
+    /// Per-source deadline for aggregated fetches, independent of `timeout_seconds`
+    pub source_deadline_ms: Option<u64>,
+    /// Alternate feed URLs keyed by (source name, topic), used for hedged fetches
+    pub mirrors: HashMap<(String, String), Vec<String>>,
+    /// How long idle pooled connections are kept open (client default when `None`)
+    pub pool_idle_timeout_seconds: Option<u64>,
+    /// Maximum idle pooled connections kept per host (client default when `None`)
+    pub pool_max_idle_per_host: Option<usize>,
+    /// TCP keep-alive interval for open connections (disabled when `None`)
+    pub tcp_keepalive_seconds: Option<u64>,
+    /// TTL of the in-process DNS cache (system resolver on every request when `None`)
+    pub dns_cache_ttl_seconds: Option<u64>,
+    /// HTTP protocol selection
+    pub http_version: HttpVersion,
+    /// Default TTL of `NewsClient`'s topic cache (caching disabled when `None`)
+    pub cache_ttl_seconds: Option<u64>,
+    /// Per-topic cache TTLs keyed by (source name, topic), overriding the feed's `<ttl>`
+    pub topic_ttls: HashMap<(String, String), u64>,
+    /// Directory of the persistent feed body cache (disabled when `None`)
+    pub disk_cache_dir: Option<PathBuf>,
+    /// Retention limits applied by `NewsClient::prune()`
+    pub retention: RetentionPolicy,
+    /// API keys for key-based sources, keyed by source name; `Debug` shows only
+    /// which sources have one
+    pub api_keys: Credentials,
+    /// Source weights and quotas applied to topic group results
+    pub merge_policy: MergePolicy,
+    /// Body similarity from which merged articles of different sources are collapsed
+    /// as syndicated copies (copies kept when `None`)
+    pub syndication_threshold: Option<f64>,
 }
 
 impl SourceConfig {
@@ -54,10 +380,24 @@ impl SourceConfig {
     pub fn new(base_url: &str) -> Self {
         Self {
             base_url: base_url.to_string(),
-            user_agent: get_safari_rua().to_string(),
+            user_agent: default_user_agent(),
             timeout_seconds: 30,
             max_retries: 3,
             retry_delay_ms: 1000,
+            source_deadline_ms: None,
+            mirrors: HashMap::new(),
+            pool_idle_timeout_seconds: None,
+            pool_max_idle_per_host: None,
+            tcp_keepalive_seconds: None,
+            dns_cache_ttl_seconds: None,
+            http_version: HttpVersion::Auto,
+            cache_ttl_seconds: None,
+            topic_ttls: HashMap::new(),
+            disk_cache_dir: None,
+            retention: RetentionPolicy::default(),
+            api_keys: Credentials::new(),
+            merge_policy: MergePolicy::default(),
+            syndication_threshold: Some(DEFAULT_SYNDICATION_THRESHOLD),
         }
     }
 
@@ -80,6 +420,172 @@ impl SourceConfig {
         self
     }
 
+    /// Set the per-source deadline used by aggregation APIs
+    ///
+    /// When a source takes longer than this to return a feed during
+    /// `NewsClient::fetch_group()` and similar calls, it is skipped rather than
+    /// delaying the merged result.
+    pub fn with_source_deadline(mut self, deadline_ms: u64) -> Self {
+        self.source_deadline_ms = Some(deadline_ms);
+        self
+    }
+
+    /// Register a mirror URL for a source topic
+    ///
+    /// Aggregation APIs request the topic's primary URL and all of its mirrors at
+    /// the same time and keep the first successful response.
+    ///
+    /// # Arguments
+    /// * `source` - Source name as returned by `NewsSource::name()` (e.g., "Wall Street Journal")
+    /// * `topic` - Topic identifier on that source
+    /// * `url` - Alternate URL serving the same feed
+    pub fn with_mirror(mut self, source: &str, topic: &str, url: &str) -> Self {
+        self.mirrors
+            .entry((source.to_string(), topic.to_string()))
+            .or_default()
+            .push(url.to_string());
+        self
+    }
+
+    /// Set connection pool limits
+    ///
+    /// Keeping connections to feed hosts open between polls avoids paying the TCP and
+    /// TLS handshake on every request.
+    ///
+    /// # Arguments
+    /// * `idle_timeout_seconds` - How long an idle connection stays in the pool
+    /// * `max_idle_per_host` - Maximum idle connections kept per host
+    pub fn with_pool(mut self, idle_timeout_seconds: u64, max_idle_per_host: usize) -> Self {
+        self.pool_idle_timeout_seconds = Some(idle_timeout_seconds);
+        self.pool_max_idle_per_host = Some(max_idle_per_host);
+        self
+    }
+
+    /// Set the TCP keep-alive interval in seconds
+    pub fn with_tcp_keepalive(mut self, keepalive_seconds: u64) -> Self {
+        self.tcp_keepalive_seconds = Some(keepalive_seconds);
+        self
+    }
+
+    /// Enable the in-process DNS cache with the given TTL in seconds
+    pub fn with_dns_cache(mut self, ttl_seconds: u64) -> Self {
+        self.dns_cache_ttl_seconds = Some(ttl_seconds);
+        self
+    }
+
+    /// Set the HTTP protocol selection
+    ///
+    /// With HTTP/2, concurrent fetches to the same host (for example several WSJ
+    /// topics in one `fetch_group()` call) are multiplexed over a single connection
+    /// instead of opening one connection and TLS session per request.
+    pub fn with_http_version(mut self, http_version: HttpVersion) -> Self {
+        self.http_version = http_version;
+        self
+    }
+
+    /// Enable `NewsClient`'s in-memory topic cache
+    ///
+    /// Fetches through `NewsClient` return the previously parsed articles for a
+    /// (source, topic) pair until its TTL runs out. The TTL is taken from
+    /// `with_topic_ttl()` when set, otherwise from the feed's channel `<ttl>`, and
+    /// falls back to `default_ttl_seconds`.
+    pub fn with_cache(mut self, default_ttl_seconds: u64) -> Self {
+        self.cache_ttl_seconds = Some(default_ttl_seconds);
+        self
+    }
+
+    /// Set the cache TTL in seconds for one source topic
+    ///
+    /// # Arguments
+    /// * `source` - Source name as returned by `NewsSource::name()` (e.g., "CNBC")
+    /// * `topic` - Topic identifier on that source
+    /// * `ttl_seconds` - How long fetched articles stay cached
+    pub fn with_topic_ttl(mut self, source: &str, topic: &str, ttl_seconds: u64) -> Self {
+        self.topic_ttls
+            .insert((source.to_string(), topic.to_string()), ttl_seconds);
+        self
+    }
+
+    /// Keep the last successful body of every feed in `dir`
+    ///
+    /// `NewsClient` then refreshes feeds with conditional GETs using the stored
+    /// `ETag`/`Last-Modified` validators, and when a feed fails or times out it serves
+    /// the last-known articles with `NewsArticle::stale` set instead of an error.
+    pub fn with_disk_cache(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.disk_cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Set the retention policy for the disk cache
+    ///
+    /// # Example
+    /// ```rust
+    /// use finance_news_aggregator_rs::disk_cache::RetentionPolicy;
+    /// use finance_news_aggregator_rs::types::SourceConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = SourceConfig::default()
+    ///     .with_disk_cache(".cache/feeds")
+    ///     .with_retention(
+    ///         RetentionPolicy::new()
+    ///             .with_max_age(Duration::from_secs(7 * 24 * 3600))
+    ///             .with_max_entries_per_source(50),
+    ///     );
+    /// ```
+    pub fn with_retention(mut self, retention: RetentionPolicy) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Set the API key for a key-based source
+    ///
+    /// Takes precedence over the source's environment variable (e.g. `NEWSAPI_KEY`).
+    ///
+    /// # Arguments
+    /// * `source` - Source name as returned by `NewsSource::name()` (e.g., "NewsAPI")
+    /// * `api_key` - The key issued by the provider
+    pub fn with_api_key(mut self, source: &str, api_key: &str) -> Self {
+        self.api_keys = self.api_keys.with_key(source, api_key);
+        self
+    }
+
+    /// Weight a source in topic group results
+    ///
+    /// A source of weight 2 gets two places in `NewsClient::fetch_group()` results for
+    /// every place of a source of the default weight 1. See [`MergePolicy`].
+    ///
+    /// # Arguments
+    /// * `source` - Source name as returned by `NewsSource::name()` (e.g., "WSJ")
+    /// * `weight` - Relative share of the merged list
+    pub fn with_source_weight(mut self, source: &str, weight: f64) -> Self {
+        self.merge_policy = self.merge_policy.with_weight(source, weight);
+        self
+    }
+
+    /// Keep at most `quota` articles of a source in topic group results
+    ///
+    /// # Arguments
+    /// * `source` - Source name as returned by `NewsSource::name()` (e.g., "Seeking Alpha")
+    /// * `quota` - Most articles kept from the source
+    pub fn with_source_quota(mut self, source: &str, quota: usize) -> Self {
+        self.merge_policy = self.merge_policy.with_quota(source, quota);
+        self
+    }
+
+    /// Set the body similarity from which merged results collapse syndicated copies
+    ///
+    /// Merged fetches such as `NewsClient::fetch_group()` keep one of the articles of
+    /// different sources whose descriptions are copies of one wire story, as
+    /// estimated by `cluster::SyndicationDedup`. On by default at
+    /// `DEFAULT_SYNDICATION_THRESHOLD`; `None` keeps every copy.
+    ///
+    /// # Arguments
+    /// * `threshold` - Estimated share of shingles in common, between 0 and 1
+    pub fn with_syndication_dedup(mut self, threshold: Option<f64>) -> Self {
+        self.syndication_threshold = threshold;
+        self
+    }
+
     /// Get timeout as Duration
     pub fn timeout_duration(&self) -> std::time::Duration {
         std::time::Duration::from_secs(self.timeout_seconds)
@@ -89,16 +595,144 @@ impl SourceConfig {
     pub fn retry_delay_duration(&self) -> std::time::Duration {
         std::time::Duration::from_millis(self.retry_delay_ms)
     }
+
+    /// Get the per-source deadline as Duration, if one is set
+    pub fn source_deadline_duration(&self) -> Option<std::time::Duration> {
+        self.source_deadline_ms
+            .map(std::time::Duration::from_millis)
+    }
+
+    /// Get how long a freshly fetched topic should be cached
+    ///
+    /// Returns `None` when caching is disabled. A TTL advertised by the feed is
+    /// capped at `MAX_FEED_TTL`, so a feed cannot pin itself in the cache.
+    ///
+    /// # Arguments
+    /// * `source` - Source name as returned by `NewsSource::name()`
+    /// * `topic` - Topic identifier on that source
+    /// * `feed_ttl` - TTL advertised by the feed itself, if any
+    pub fn cache_ttl_for(
+        &self,
+        source: &str,
+        topic: &str,
+        feed_ttl: Option<std::time::Duration>,
+    ) -> Option<std::time::Duration> {
+        let default_ttl = self.cache_ttl_seconds?;
+        let topic_ttl = self
+            .topic_ttls
+            .get(&(source.to_string(), topic.to_string()))
+            .map(|&ttl| std::time::Duration::from_secs(ttl));
+        Some(
+            topic_ttl
+                .or(feed_ttl.map(|ttl| ttl.min(MAX_FEED_TTL)))
+                .unwrap_or(std::time::Duration::from_secs(default_ttl)),
+        )
+    }
+
+    /// Get the mirror URLs registered for a source topic
+    pub fn mirrors_for(&self, source: &str, topic: &str) -> &[String] {
+        self.mirrors
+            .get(&(source.to_string(), topic.to_string()))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// A browser user agent, which feeds that turn away bots accept
+#[cfg(feature = "fetch")]
+fn default_user_agent() -> String {
+    get_safari_rua().to_string()
+}
+
+/// Without the HTTP stack nothing sends the user agent; name the crate
+#[cfg(not(feature = "fetch"))]
+fn default_user_agent() -> String {
+    concat!("finance-news-aggregator-rs/", env!("CARGO_PKG_VERSION")).to_string()
 }
 
 impl Default for SourceConfig {
     fn default() -> Self {
         Self {
             base_url: String::new(),
-            user_agent: get_safari_rua().to_string(),
+            user_agent: default_user_agent(),
             timeout_seconds: 30,
             max_retries: 3,
             retry_delay_ms: 1000,
+            source_deadline_ms: None,
+            mirrors: HashMap::new(),
+            pool_idle_timeout_seconds: None,
+            pool_max_idle_per_host: None,
+            tcp_keepalive_seconds: None,
+            dns_cache_ttl_seconds: None,
+            http_version: HttpVersion::Auto,
+            cache_ttl_seconds: None,
+            topic_ttls: HashMap::new(),
+            disk_cache_dir: None,
+            retention: RetentionPolicy::default(),
+            api_keys: Credentials::new(),
+            merge_policy: MergePolicy::default(),
+            syndication_threshold: Some(DEFAULT_SYNDICATION_THRESHOLD),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_age_and_relative_time() {
+        let now = DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let at = |pub_date: &str| {
+            let mut article = NewsArticle::new();
+            article.pub_date = Some(pub_date.to_string());
+            article.relative_time_at(now)
+        };
+
+        assert_eq!(at("2024-05-01T11:59:30Z").as_deref(), Some("just now"));
+        assert_eq!(
+            at("Wed, 01 May 2024 11:37:00 GMT").as_deref(),
+            Some("23m ago")
+        );
+        assert_eq!(at("2024-05-01T07:00:00+00:00").as_deref(), Some("5h ago"));
+        assert_eq!(at("2024-04-28T12:00:00Z").as_deref(), Some("3d ago"));
+        assert_eq!(at("2024-01-15T08:00:00Z").as_deref(), Some("2024-01-15"));
+        // Clock skew: a date slightly ahead of now is just published
+        assert_eq!(at("2024-05-01T12:05:00Z").as_deref(), Some("just now"));
+        assert_eq!(at("yesterday"), None);
+
+        let mut article = NewsArticle::new();
+        article.pub_date = Some("2024-05-01T10:00:00Z".to_string());
+        assert_eq!(
+            article.age_at(now),
+            Some(std::time::Duration::from_secs(2 * 3600))
+        );
+    }
+
+    #[test]
+    fn test_config_debug_hides_api_keys() {
+        let config = SourceConfig::default().with_api_key("Polygon", "poly-secret");
+        assert_eq!(config.api_keys.get("Polygon"), Some("poly-secret"));
+
+        let debug = format!("{:?}", config);
+        assert!(!debug.contains("poly-secret"));
+        assert!(debug.contains("Polygon"));
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_news_article_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(NewsArticle)).unwrap();
+
+        let properties = &schema["properties"];
+        assert_eq!(
+            properties["title"]["type"],
+            serde_json::json!(["string", "null"])
+        );
+        assert!(properties["enclosures"].is_object());
+        assert!(properties["extra_fields"]["additionalProperties"].is_object());
+        assert!(schema["$defs"]["ExtraValue"]["anyOf"].is_array());
+    }
+}
@@ -0,0 +1,461 @@
+//! Link previews from article pages
+//!
+//! Some feeds, such as Yahoo Finance headlines, carry little more than a title. An
+//! [`Unfurler`] fetches the article page and reads its OpenGraph and Twitter card
+//! `<meta>` tags to fill in a missing description and `extra_fields["thumbnail"]`.
+//!
+//! Requests to the same host are spaced out, one per second by default, and previews
+//! are cached by URL, so enriching the same articles again costs no requests.
+
+use crate::error::Result;
+use crate::http::{HttpClient, HttpRequest};
+use crate::news_source::{check_status, resolve_link};
+use crate::types::NewsArticle;
+use futures::future::join_all;
+use futures::stream::TryStreamExt;
+use log::debug;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::{Instant, sleep_until};
+
+/// Key of the preview image in `NewsArticle::extra_fields`, as set by the parser
+const THUMBNAIL_FIELD: &str = "thumbnail";
+
+/// Bytes of a page read looking for its `<head>` meta tags
+const MAX_PAGE_BYTES: usize = 256 * 1024;
+
+const DEFAULT_HOST_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_ENTRIES: usize = 1000;
+
+/// Metadata of a page, from its OpenGraph and Twitter card tags
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Preview {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// Absolute URL of the preview image
+    pub image: Option<String>,
+    pub site_name: Option<String>,
+}
+
+impl Preview {
+    /// Read the preview of an HTML page served from `url`
+    ///
+    /// `og:` properties are preferred over `twitter:` ones, and those over the plain
+    /// `description` meta tag.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finance_news_aggregator_rs::unfurl::Preview;
+    ///
+    /// let html = r#"<head>
+    ///     <meta property="og:description" content="Shares rose 5% &amp; more">
+    ///     <meta name="twitter:image" content="/img/aapl.jpg">
+    /// </head>"#;
+    /// let preview = Preview::from_html("https://example.com/news/aapl", html);
+    /// assert_eq!(preview.description.as_deref(), Some("Shares rose 5% & more"));
+    /// assert_eq!(preview.image.as_deref(), Some("https://example.com/img/aapl.jpg"));
+    /// ```
+    pub fn from_html(url: &str, html: &str) -> Self {
+        let tags = meta_tags(html);
+        let find = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| tags.get(*key).filter(|value| !value.is_empty()))
+                .cloned()
+        };
+        Self {
+            title: find(&["og:title", "twitter:title"]),
+            description: find(&["og:description", "twitter:description", "description"]),
+            image: find(&[
+                "og:image:secure_url",
+                "og:image",
+                "twitter:image",
+                "twitter:image:src",
+            ])
+            .and_then(|image| resolve_link(url, &image)),
+            site_name: find(&["og:site_name"]),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Fetches link previews, rate-limited per host and cached
+#[derive(Clone)]
+pub struct Unfurler {
+    client: Arc<dyn HttpClient>,
+    host_interval: Duration,
+    max_entries: usize,
+    state: Arc<Mutex<UnfurlState>>,
+}
+
+#[derive(Default)]
+struct UnfurlState {
+    previews: HashMap<String, Preview>,
+    /// Cached URLs, oldest first
+    order: VecDeque<String>,
+    /// When each host may next be requested
+    next_request: HashMap<String, Instant>,
+}
+
+impl Unfurler {
+    /// Fetch pages through `client`
+    pub fn new(client: impl HttpClient + 'static) -> Self {
+        Self {
+            client: Arc::new(client),
+            host_interval: DEFAULT_HOST_INTERVAL,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            state: Arc::new(Mutex::new(UnfurlState::default())),
+        }
+    }
+
+    /// Wait at least `interval` between requests to the same host
+    pub fn with_host_interval(mut self, interval: Duration) -> Self {
+        self.host_interval = interval;
+        self
+    }
+
+    /// Keep at most this many previews, dropping the oldest
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// The preview of a page, from the cache or by fetching it
+    ///
+    /// Pages without preview tags are cached as empty previews; failed fetches are
+    /// not cached.
+    pub async fn preview(&self, url: &str) -> Result<Preview> {
+        if let Some(preview) = self.state.lock().unwrap().previews.get(url) {
+            return Ok(preview.clone());
+        }
+
+        self.wait_for_host(url).await;
+        debug!("Unfurling {}", url);
+        let response = self
+            .client
+            .send(HttpRequest::get(url).with_header("Accept", "text/html"))
+            .await?;
+        let mut body = check_status(url, response).await?.bytes_stream();
+        let mut page = Vec::new();
+        while let Some(chunk) = body.try_next().await? {
+            page.extend_from_slice(&chunk);
+            if page.len() >= MAX_PAGE_BYTES || contains_head_end(&page) {
+                break;
+            }
+        }
+        let preview = Preview::from_html(url, &String::from_utf8_lossy(&page));
+
+        let mut state = self.state.lock().unwrap();
+        if self.max_entries > 0 && !state.previews.contains_key(url) {
+            while state.order.len() >= self.max_entries {
+                if let Some(oldest) = state.order.pop_front() {
+                    state.previews.remove(&oldest);
+                }
+            }
+            state.order.push_back(url.to_string());
+            state.previews.insert(url.to_string(), preview.clone());
+        }
+        Ok(preview)
+    }
+
+    /// Fill an article's missing description and thumbnail from its page
+    ///
+    /// Articles that have both, or no link, are left alone without a request.
+    /// Returns whether anything was filled in.
+    pub async fn enrich(&self, article: &mut NewsArticle) -> Result<bool> {
+        let needs_description = article
+            .description
+            .as_deref()
+            .is_none_or(|description| description.trim().is_empty());
+        let needs_image = !article.extra_fields.contains_key(THUMBNAIL_FIELD);
+        let Some(link) = article.link.clone() else {
+            return Ok(false);
+        };
+        if !needs_description && !needs_image {
+            return Ok(false);
+        }
+
+        let preview = self.preview(&link).await?;
+        let mut changed = false;
+        if let (true, Some(description)) = (needs_description, preview.description) {
+            article.description = Some(description);
+            changed = true;
+        }
+        if let (true, Some(image)) = (needs_image, preview.image) {
+            article
+                .extra_fields
+                .insert(THUMBNAIL_FIELD.to_string(), image.into());
+            changed = true;
+        }
+        Ok(changed)
+    }
+
+    /// Enrich every article, fetching pages of different hosts concurrently
+    ///
+    /// Failures are logged and leave their article as it was. Returns the number of
+    /// articles filled in.
+    pub async fn enrich_all(&self, articles: &mut [NewsArticle]) -> usize {
+        let results = join_all(articles.iter_mut().map(|article| async move {
+            let link = article.link.clone().unwrap_or_default();
+            self.enrich(article)
+                .await
+                .inspect_err(|e| debug!("Failed to unfurl {}: {}", link, e))
+        }))
+        .await;
+        results
+            .into_iter()
+            .filter(|result| matches!(result, Ok(true)))
+            .count()
+    }
+
+    /// Sleep until the host of `url` may be requested, and reserve the next slot
+    async fn wait_for_host(&self, url: &str) {
+        let host = Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_default();
+        let start = {
+            let mut state = self.state.lock().unwrap();
+            let now = Instant::now();
+            let start = state
+                .next_request
+                .get(&host)
+                .copied()
+                .filter(|next| *next > now)
+                .unwrap_or(now);
+            state.next_request.insert(host, start + self.host_interval);
+            start
+        };
+        sleep_until(start).await;
+    }
+}
+
+impl std::fmt::Debug for Unfurler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Unfurler")
+            .field("host_interval", &self.host_interval)
+            .field("max_entries", &self.max_entries)
+            .finish_non_exhaustive()
+    }
+}
+
+fn contains_head_end(page: &[u8]) -> bool {
+    page.windows(7)
+        .any(|window| window.eq_ignore_ascii_case(b"</head>"))
+}
+
+/// The `property` or `name` and `content` of each `<meta>` tag, keys lowercased
+///
+/// Earlier tags win over later ones with the same key.
+fn meta_tags(html: &str) -> HashMap<String, String> {
+    let lower = html.to_ascii_lowercase();
+    let end = lower.find("</head>").unwrap_or(html.len());
+    let mut tags = HashMap::new();
+    let mut from = 0;
+    // A tag can run past `</head>`, leaving `from` beyond `end`
+    while let Some(start) = lower
+        .get(from..end)
+        .and_then(|head| head.find("<meta"))
+        .map(|i| from + i)
+    {
+        let tag_end = html[start..].find('>').map_or(html.len(), |i| start + i);
+        let attributes = attributes(&html[start + 5..tag_end]);
+        let key = attributes
+            .get("property")
+            .or_else(|| attributes.get("name"));
+        if let (Some(key), Some(content)) = (key, attributes.get("content")) {
+            let content =
+                quick_xml::escape::unescape(content).unwrap_or(Cow::Borrowed(content.as_str()));
+            tags.entry(key.to_ascii_lowercase())
+                .or_insert_with(|| content.trim().to_string());
+        }
+        from = tag_end.max(start + 5);
+    }
+    tags
+}
+
+/// Attributes of a tag, names lowercased
+fn attributes(text: &str) -> HashMap<String, String> {
+    let mut attributes = HashMap::new();
+    let mut rest = text.trim_start_matches('/');
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        if name_end == 0 {
+            return attributes;
+        }
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+        let Some(after_equals) = rest.strip_prefix('=') else {
+            attributes.insert(name, String::new());
+            continue;
+        };
+        rest = after_equals.trim_start();
+        let value = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let value_end = rest[1..].find(quote).map_or(rest.len(), |i| i + 1);
+                let value = &rest[1..value_end];
+                rest = rest.get(value_end + 1..).unwrap_or_default();
+                value
+            }
+            _ => {
+                let value_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                let value = &rest[..value_end];
+                rest = &rest[value_end..];
+                value
+            }
+        };
+        attributes.insert(name, value.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::HttpResponse;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const PAGE: &str = r#"<!DOCTYPE html><html><head>
+        <META PROPERTY="og:title" CONTENT="Apple beats">
+        <meta name="description" content="Plain description">
+        <meta property='og:description' content='Shares of Apple rose 5% after &quot;record&quot; iPhone sales'/>
+        <meta property="og:image" content="https://cdn.example.com/aapl.jpg" />
+        <meta name=twitter:image content=/ignored.jpg>
+        <meta property="og:site_name" content="Example News">
+        </head><body><meta property="og:title" content="Not in head"></body></html>"#;
+
+    /// Serves `PAGE` for every URL on example.com, counting requests
+    #[derive(Default)]
+    struct Pages {
+        requests: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl HttpClient for Pages {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            if request.url.starts_with("https://example.com/") {
+                Ok(HttpResponse::new(200, PAGE))
+            } else {
+                Ok(HttpResponse::new(404, "Not Found"))
+            }
+        }
+    }
+
+    fn article(link: &str) -> NewsArticle {
+        let mut article = NewsArticle::new();
+        article.title = Some("Apple beats".to_string());
+        article.link = Some(link.to_string());
+        article
+    }
+
+    #[test]
+    fn test_preview_from_html() {
+        let preview = Preview::from_html("https://example.com/a", PAGE);
+        assert_eq!(
+            preview,
+            Preview {
+                title: Some("Apple beats".to_string()),
+                description: Some(
+                    "Shares of Apple rose 5% after \"record\" iPhone sales".to_string()
+                ),
+                image: Some("https://cdn.example.com/aapl.jpg".to_string()),
+                site_name: Some("Example News".to_string()),
+            }
+        );
+
+        let twitter = Preview::from_html(
+            "https://example.com/news/a",
+            r#"<meta name="twitter:image:src" content="../img/b.png"><meta name="description" content="Fallback">"#,
+        );
+        assert_eq!(
+            twitter.image.as_deref(),
+            Some("https://example.com/img/b.png")
+        );
+        assert_eq!(twitter.description.as_deref(), Some("Fallback"));
+        assert!(Preview::from_html("https://example.com", "<p>No tags</p>").is_empty());
+    }
+
+    #[test]
+    fn test_meta_tags_running_past_head() {
+        let tags = meta_tags(r#"<meta name="description" content="a </head> b"><p>body</p>"#);
+        // The tag is cut at the first `>`, but the scan does not panic
+        assert!(tags.contains_key("description"));
+
+        let unclosed = meta_tags(r#"<meta name="description" content="cut"</head><body>"#);
+        assert!(unclosed.len() <= 1);
+        assert!(meta_tags("<head><meta").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_enrich_fills_missing_fields() {
+        let pages = Arc::new(Pages::default());
+        let unfurler = Unfurler::new(Arc::clone(&pages)).with_host_interval(Duration::ZERO);
+
+        let mut terse = article("https://example.com/a");
+        assert!(unfurler.enrich(&mut terse).await.unwrap());
+        assert!(terse.description.unwrap().starts_with("Shares of Apple"));
+        assert_eq!(
+            terse.extra_fields[THUMBNAIL_FIELD],
+            "https://cdn.example.com/aapl.jpg"
+        );
+
+        // Complete articles and cached pages need no request
+        let mut complete = article("https://example.com/b");
+        complete.description = Some("From the feed".to_string());
+        complete.extra_fields.insert(
+            THUMBNAIL_FIELD.to_string(),
+            "https://example.com/c.jpg".into(),
+        );
+        assert!(!unfurler.enrich(&mut complete).await.unwrap());
+        let mut again = article("https://example.com/a");
+        assert!(unfurler.enrich(&mut again).await.unwrap());
+        assert_eq!(pages.requests.load(Ordering::SeqCst), 1);
+
+        let mut articles = vec![
+            article("https://example.com/d"),
+            article("https://other.com/e"),
+        ];
+        assert_eq!(unfurler.enrich_all(&mut articles).await, 1);
+        assert!(articles[1].description.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_requests_to_a_host_are_spaced() {
+        let unfurler = Unfurler::new(Pages::default()).with_host_interval(Duration::from_secs(2));
+        let start = Instant::now();
+        let mut articles = vec![
+            article("https://example.com/1"),
+            article("https://example.com/2"),
+            article("https://example.com/3"),
+        ];
+        assert_eq!(unfurler.enrich_all(&mut articles).await, 3);
+        assert_eq!(start.elapsed(), Duration::from_secs(4));
+    }
+
+    #[tokio::test]
+    async fn test_cache_is_bounded() {
+        let pages = Arc::new(Pages::default());
+        let unfurler = Unfurler::new(Arc::clone(&pages))
+            .with_host_interval(Duration::ZERO)
+            .with_max_entries(1);
+        for url in [
+            "https://example.com/1",
+            "https://example.com/2",
+            "https://example.com/1",
+        ] {
+            unfurler.preview(url).await.unwrap();
+        }
+        assert_eq!(pages.requests.load(Ordering::SeqCst), 3);
+    }
+}
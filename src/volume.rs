@@ -0,0 +1,321 @@
+//! Article volume anomaly detection
+//!
+//! With `Poller::with_volume_policy()`, a `Poller` counts the new articles of each
+//! feed per interval in a [`FeedVolume`] and compares every finished interval with
+//! the mean of the intervals before it. Two kinds of change raise a `VolumeAnomaly`
+//! event:
+//!
+//! - a **spike**, an interval with several times the usual number of articles,
+//!   which tends to mean breaking news;
+//! - a **silence**, several intervals in a row without new articles from a feed
+//!   that usually has some, which tends to mean the feed died while still
+//!   answering, something `FeedDegraded` does not catch.
+//!
+//! Each spike or silence raises one event, however long it lasts. A
+//! `health::WebhookNotifier` forwards the events like the feed health ones.
+
+use crate::events::{Event, VolumeAnomaly};
+use chrono::{DateTime, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Seconds in a counting interval, unless set in the policy
+pub const DEFAULT_VOLUME_INTERVAL_SECONDS: u64 = 900;
+
+/// Finished intervals the baseline is the mean of, unless set in the policy
+pub const DEFAULT_BASELINE_INTERVALS: usize = 24;
+
+/// Times the baseline an interval needs for a spike, unless set in the policy
+pub const DEFAULT_SPIKE_FACTOR: f64 = 3.0;
+
+/// Fewest articles in an interval that make a spike, unless set in the policy
+pub const DEFAULT_MIN_SPIKE_ARTICLES: usize = 5;
+
+/// Empty intervals in a row that make a silence, unless set in the policy
+pub const DEFAULT_SILENT_INTERVALS: usize = 4;
+
+/// Whether an interval stands out from the ones before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyKind {
+    Spike,
+    Silence,
+}
+
+/// Thresholds at which a feed's article volume is anomalous
+///
+/// An interval is a spike when it has at least `min_spike_articles` and
+/// `spike_factor` times the baseline, the mean of the last `baseline_intervals`.
+/// A feed is silent after `silent_intervals` empty intervals in a row, when the
+/// intervals before them average at least one article. Both need half the baseline
+/// intervals to compare with, so a new feed raises nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolumePolicy {
+    pub interval_seconds: u64,
+    pub baseline_intervals: usize,
+    pub spike_factor: f64,
+    pub min_spike_articles: usize,
+    pub silent_intervals: usize,
+}
+
+impl Default for VolumePolicy {
+    fn default() -> Self {
+        Self {
+            interval_seconds: DEFAULT_VOLUME_INTERVAL_SECONDS,
+            baseline_intervals: DEFAULT_BASELINE_INTERVALS,
+            spike_factor: DEFAULT_SPIKE_FACTOR,
+            min_spike_articles: DEFAULT_MIN_SPIKE_ARTICLES,
+            silent_intervals: DEFAULT_SILENT_INTERVALS,
+        }
+    }
+}
+
+impl VolumePolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count articles over intervals of this many seconds
+    pub fn with_interval_seconds(mut self, seconds: u64) -> Self {
+        self.interval_seconds = seconds.max(1);
+        self
+    }
+
+    /// Compare each interval with the mean of this many intervals before it
+    pub fn with_baseline_intervals(mut self, intervals: usize) -> Self {
+        self.baseline_intervals = intervals.max(1);
+        self
+    }
+
+    /// Report intervals with at least `factor` times the baseline and at least
+    /// `min_articles` articles as spikes
+    pub fn with_spike(mut self, factor: f64, min_articles: usize) -> Self {
+        self.spike_factor = factor.max(1.0);
+        self.min_spike_articles = min_articles.max(1);
+        self
+    }
+
+    /// Report a feed as silent after this many empty intervals in a row
+    pub fn with_silent_intervals(mut self, intervals: usize) -> Self {
+        self.silent_intervals = intervals.max(1);
+        self
+    }
+
+    fn interval(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.interval_seconds.max(1).min(i64::MAX as u64) as i64)
+    }
+}
+
+/// New articles per interval of one feed
+#[derive(Debug, Clone, Default)]
+pub struct FeedVolume {
+    /// Start of the interval being counted, or `None` before the first poll
+    started: Option<DateTime<Utc>>,
+    articles: usize,
+    /// Articles of finished intervals, oldest first
+    history: VecDeque<usize>,
+    /// Start of the run of empty intervals the latest finished interval ends
+    silent_since: Option<DateTime<Utc>>,
+    silent_intervals: usize,
+    /// Anomaly reported and still going on
+    reported: Option<AnomalyKind>,
+}
+
+impl FeedVolume {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mean articles of the finished intervals, or `None` before the first one
+    pub fn baseline(&self) -> Option<f64> {
+        if self.history.is_empty() {
+            return None;
+        }
+        Some(self.history.iter().sum::<usize>() as f64 / self.history.len() as f64)
+    }
+
+    /// Record the new articles of a successful poll, returning the event it causes
+    pub fn record(
+        &mut self,
+        source: &str,
+        topic: &str,
+        articles: usize,
+        policy: &VolumePolicy,
+    ) -> Option<Event> {
+        self.record_at(source, topic, articles, policy, Utc::now())
+    }
+
+    /// Record the new articles of a poll at `now`
+    ///
+    /// An interval finishes at the first poll after its end, whose articles count
+    /// toward the next interval; intervals without any poll are skipped rather than
+    /// counted as empty, so a stopped poller does not come back to a silence.
+    pub fn record_at(
+        &mut self,
+        source: &str,
+        topic: &str,
+        articles: usize,
+        policy: &VolumePolicy,
+        now: DateTime<Utc>,
+    ) -> Option<Event> {
+        let started = *self.started.get_or_insert(now);
+        let mut event = None;
+        if now - started >= policy.interval() {
+            event = self.finish(source, topic, started, policy);
+            self.started = Some(now);
+            self.articles = 0;
+        }
+        self.articles += articles;
+        event
+    }
+
+    /// Compare the interval that started at `started` with the baseline and add it
+    /// to the history
+    fn finish(
+        &mut self,
+        source: &str,
+        topic: &str,
+        started: DateTime<Utc>,
+        policy: &VolumePolicy,
+    ) -> Option<Event> {
+        let articles = self.articles;
+        let min_history = policy.baseline_intervals.div_ceil(2).max(1);
+        let warmed_up = self.history.len() >= min_history;
+        let baseline = self.baseline().unwrap_or(0.0);
+        // A silence is measured against the intervals before it
+        let before_silence = self.history.len().saturating_sub(self.silent_intervals);
+        let silence_baseline = if before_silence >= min_history {
+            self.history.iter().take(before_silence).sum::<usize>() as f64 / before_silence as f64
+        } else {
+            0.0
+        };
+
+        if self.history.len() >= policy.baseline_intervals.max(1) {
+            self.history.pop_front();
+        }
+        self.history.push_back(articles);
+        if articles == 0 {
+            self.silent_intervals += 1;
+            self.silent_since.get_or_insert(started);
+        } else {
+            self.silent_intervals = 0;
+            self.silent_since = None;
+        }
+
+        let kind = if articles >= policy.min_spike_articles.max(1)
+            && articles as f64 >= policy.spike_factor * baseline.max(1.0)
+        {
+            Some(AnomalyKind::Spike)
+        } else if self.silent_intervals >= policy.silent_intervals.max(1) && silence_baseline >= 1.0
+        {
+            Some(AnomalyKind::Silence)
+        } else {
+            None
+        };
+        let (kind, since, baseline) = match kind {
+            Some(AnomalyKind::Spike) => (AnomalyKind::Spike, started, baseline),
+            Some(AnomalyKind::Silence) => (
+                AnomalyKind::Silence,
+                self.silent_since.unwrap_or(started),
+                silence_baseline,
+            ),
+            None => {
+                // A silence lasts until articles come back
+                if self.silent_intervals == 0 || self.reported != Some(AnomalyKind::Silence) {
+                    self.reported = None;
+                }
+                return None;
+            }
+        };
+        if !warmed_up || self.reported == Some(kind) {
+            return None;
+        }
+        self.reported = Some(kind);
+        warn!(
+            "{} topic '{}' volume {:?}: {} articles in the last interval, {:.1} usual",
+            source, topic, kind, articles, baseline
+        );
+        Some(Event::VolumeAnomaly(VolumeAnomaly {
+            source: source.to_string(),
+            topic: topic.to_string(),
+            kind,
+            articles,
+            baseline,
+            since,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Record one poll a minute from minute `from` with these article counts,
+    /// returning the kinds of the events raised
+    fn run(volume: &mut FeedVolume, from: i64, counts: &[usize]) -> Vec<Option<AnomalyKind>> {
+        let policy = VolumePolicy::new()
+            .with_interval_seconds(60)
+            .with_baseline_intervals(4)
+            .with_silent_intervals(2);
+        let start = DateTime::parse_from_rfc3339("2024-05-01T14:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        counts
+            .iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let now = start + chrono::Duration::minutes(from + i as i64);
+                let event = volume.record_at("CNBC", "earnings", *count, &policy, now);
+                event.map(|event| match event {
+                    Event::VolumeAnomaly(anomaly) => anomaly.kind,
+                    event => panic!("unexpected {:?}", event),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_spike() {
+        let mut volume = FeedVolume::new();
+        // Each count is judged at the next poll; a new feed raises nothing
+        assert_eq!(run(&mut volume, 0, &[20, 2, 3, 2]), [None; 4]);
+        assert_eq!(volume.baseline(), Some(25.0 / 3.0));
+        let kinds = run(&mut volume, 4, &[30, 40, 3, 2]);
+        assert_eq!(kinds, [None, Some(AnomalyKind::Spike), None, None]);
+        // The spike ended, so the next one is reported
+        assert_eq!(
+            run(&mut volume, 8, &[60, 1]),
+            [None, Some(AnomalyKind::Spike)]
+        );
+    }
+
+    #[test]
+    fn test_silence() {
+        let mut volume = FeedVolume::new();
+        let kinds = run(&mut volume, 0, &[3, 2, 3, 0, 0, 0, 0]);
+        let silence = Some(AnomalyKind::Silence);
+        assert_eq!(kinds, [None, None, None, None, None, silence, None]);
+
+        // A quiet feed is not silent
+        let mut quiet = FeedVolume::new();
+        let kinds = run(&mut quiet, 0, &[0, 1, 0, 0, 0, 0, 0]);
+        assert!(kinds.iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn test_skipped_intervals_are_not_silent() {
+        let policy = VolumePolicy::new().with_interval_seconds(60);
+        let start = Utc::now();
+        let mut volume = FeedVolume::new();
+        volume.record_at("CNBC", "earnings", 3, &policy, start);
+        let later = start + chrono::Duration::hours(5);
+        assert!(
+            volume
+                .record_at("CNBC", "earnings", 0, &policy, later)
+                .is_none()
+        );
+        assert_eq!(volume.history, [3]);
+    }
+}
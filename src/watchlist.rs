@@ -0,0 +1,317 @@
+//! Ticker watchlists, loadable from portfolio CSV files
+//!
+//! A [`Watchlist`] is a list of symbols with weights. `Watchlist::from_csv()` reads
+//! a plain `symbol,weight` file, with or without a header row, or a positions
+//! export from a broker, recognizing the usual column names:
+//!
+//! | Column | Headers |
+//! |--------|---------|
+//! | Symbol | `Symbol`, `Ticker`, `Instrument`, `Security Symbol` |
+//! | Weight | `Weight`, `Allocation`, `% of Account`, `Percent of Account`, `% of Acct (% of Account)` |
+//! | Value | `Current Value`, `Market Value`, `Mkt Val (Market Value)`, `Total Value`, `Value` |
+//! | Quantity | `Quantity`, `Qty`, `Qty (Quantity)`, `Shares`, `Position` |
+//!
+//! A holding's weight comes from the first of the weight, value, and quantity
+//! columns the file has, and is 1 without any. Title lines before the header, and
+//! rows whose symbol is not a ticker (cash, account totals, disclaimers), are
+//! skipped; holdings listed in several accounts are added together.
+
+use crate::earnings::EarningsQuery;
+use crate::error::{FanError, Result};
+use crate::filter::Query;
+use crate::symbols::Symbol;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Header rows searched for before the first row is read as data
+const MAX_TITLE_LINES: usize = 10;
+
+const SYMBOL_HEADERS: &[&str] = &["symbol", "ticker", "instrument", "security symbol"];
+const WEIGHT_HEADERS: &[&str] = &[
+    "weight",
+    "allocation",
+    "% of account",
+    "percent of account",
+    "% of acct (% of account)",
+];
+const VALUE_HEADERS: &[&str] = &[
+    "current value",
+    "market value",
+    "mkt val (market value)",
+    "total value",
+    "value",
+];
+const QUANTITY_HEADERS: &[&str] = &["quantity", "qty", "qty (quantity)", "shares", "position"];
+
+/// A symbol of a watchlist
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Holding {
+    /// Uppercase ticker symbol
+    pub symbol: String,
+    /// Relative importance, such as a share of the portfolio
+    pub weight: f64,
+}
+
+/// Ticker symbols to follow, with weights
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Watchlist {
+    holdings: Vec<Holding>,
+}
+
+impl Watchlist {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a watchlist of symbols with weight 1
+    pub fn for_symbols<I, S>(symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        symbols.into_iter().fold(Self::new(), |list, symbol| {
+            list.with_symbol(symbol.as_ref(), 1.0)
+        })
+    }
+
+    /// Add a symbol, adding to its weight if already present
+    pub fn with_symbol(mut self, symbol: &str, weight: f64) -> Self {
+        self.add(symbol, weight);
+        self
+    }
+
+    /// Read holdings from a CSV file; see the [module docs](self) for the formats
+    pub fn from_csv(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_csv_reader(File::open(path)?)
+    }
+
+    /// Read holdings from CSV data
+    pub fn from_csv_reader(reader: impl Read) -> Result<Self> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let rows = reader
+            .records()
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let header = rows
+            .iter()
+            .take(MAX_TITLE_LINES)
+            .position(|row| row.iter().any(|cell| is_header(cell, SYMBOL_HEADERS)));
+        let (columns, data) = match header {
+            Some(index) => (Columns::find(&rows[index]), &rows[index + 1..]),
+            // Headerless `symbol,weight`
+            None => (
+                Columns {
+                    symbol: 0,
+                    weight: Some(1),
+                },
+                &rows[..],
+            ),
+        };
+
+        let mut list = Self::new();
+        for row in data {
+            let Some(symbol) = row.get(columns.symbol).and_then(normalize_symbol) else {
+                continue;
+            };
+            let weight = match columns.weight.and_then(|column| row.get(column)) {
+                Some(cell) if !cell.is_empty() => parse_number(cell).ok_or_else(|| {
+                    FanError::InvalidWatchlist(format!("invalid weight '{}' of {}", cell, symbol))
+                })?,
+                _ => 1.0,
+            };
+            list.add(&symbol, weight);
+        }
+        if list.is_empty() {
+            return Err(FanError::InvalidWatchlist("no holdings found".to_string()));
+        }
+        Ok(list)
+    }
+
+    pub fn holdings(&self) -> &[Holding] {
+        &self.holdings
+    }
+
+    /// The symbols, in the order they were added
+    pub fn symbols(&self) -> impl Iterator<Item = &str> {
+        self.holdings.iter().map(|holding| holding.symbol.as_str())
+    }
+
+    /// Weight of a symbol, matched ignoring case
+    pub fn weight(&self, symbol: &str) -> Option<f64> {
+        self.holdings
+            .iter()
+            .find(|holding| holding.symbol.eq_ignore_ascii_case(symbol))
+            .map(|holding| holding.weight)
+    }
+
+    pub fn len(&self) -> usize {
+        self.holdings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.holdings.is_empty()
+    }
+
+    /// A query matching articles that mention any of the symbols, or `None` when empty
+    ///
+    /// Usable with `Query::filter()` and as the query of an `alerts::AlertRule`.
+    pub fn query(&self) -> Option<Query> {
+        let mut tickers: Vec<Query> = self
+            .symbols()
+            .map(|symbol| Query::Ticker(symbol.to_string()))
+            .collect();
+        match tickers.len() {
+            0 => None,
+            1 => tickers.pop(),
+            _ => Some(Query::Or(tickers)),
+        }
+    }
+
+    /// An earnings query for the symbols, for `NewsClient::earnings()`
+    pub fn earnings_query(&self) -> EarningsQuery {
+        EarningsQuery::for_symbols(self.symbols())
+    }
+
+    fn add(&mut self, symbol: &str, weight: f64) {
+        let symbol = symbol.trim().trim_start_matches('$').to_uppercase();
+        match self.holdings.iter_mut().find(|h| h.symbol == symbol) {
+            Some(holding) => holding.weight += weight,
+            None => self.holdings.push(Holding { symbol, weight }),
+        }
+    }
+}
+
+/// Columns of a CSV file
+struct Columns {
+    symbol: usize,
+    weight: Option<usize>,
+}
+
+impl Columns {
+    fn find(header: &csv::StringRecord) -> Self {
+        let column = |names: &[&str]| header.iter().position(|cell| is_header(cell, names));
+        Self {
+            symbol: column(SYMBOL_HEADERS).unwrap_or(0),
+            weight: column(WEIGHT_HEADERS)
+                .or_else(|| column(VALUE_HEADERS))
+                .or_else(|| column(QUANTITY_HEADERS)),
+        }
+    }
+}
+
+fn is_header(cell: &str, names: &[&str]) -> bool {
+    names.iter().any(|name| cell.eq_ignore_ascii_case(name))
+}
+
+/// The ticker in a symbol cell, or `None` for cash lines, totals, and notes
+///
+/// Strips the `*` markers brokers put on money market funds.
+fn normalize_symbol(cell: &str) -> Option<String> {
+    Symbol::parse(cell.trim_end_matches('*'))
+        .ok()
+        .map(|symbol| symbol.to_string())
+}
+
+/// Parse a number such as `1,234.56`, `$98.10`, `12.5%`, or `(40.00)`
+fn parse_number(cell: &str) -> Option<f64> {
+    let negative = cell.starts_with('(') && cell.ends_with(')');
+    let digits: String = cell
+        .chars()
+        .filter(|c| !matches!(c, '$' | ',' | '%' | '(' | ')' | ' '))
+        .collect();
+    let number: f64 = digits.parse().ok()?;
+    Some(if negative { -number } else { number })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(csv: &str) -> Watchlist {
+        Watchlist::from_csv_reader(csv.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_simple_files() {
+        let list = parse("aapl,0.6\nMSFT,0.4\n");
+        assert_eq!(list.symbols().collect::<Vec<_>>(), ["AAPL", "MSFT"]);
+        assert_eq!(list.weight("aapl"), Some(0.6));
+
+        let list = parse("symbol,weight\nNVDA,2\nBRK.B,\n$SHOP.TO,1\nNVDA,1\n");
+        assert_eq!(
+            list.symbols().collect::<Vec<_>>(),
+            ["NVDA", "BRK.B", "SHOP.TO"]
+        );
+        assert_eq!(list.weight("NVDA"), Some(3.0));
+        assert_eq!(list.weight("BRK.B"), Some(1.0));
+
+        // One symbol per line
+        assert_eq!(parse("TSLA\nAMD\n").len(), 2);
+    }
+
+    #[test]
+    fn test_broker_exports() {
+        // Fidelity: one row per account, money market markers, trailing notes
+        let fidelity = "Account Number,Account Name,Symbol,Description,Quantity,Last Price,Current Value,Percent Of Account\n\
+            X1,Individual,SPAXX**,HELD IN MONEY MARKET,,,$1200.00,10.00%\n\
+            X1,Individual,AAPL,APPLE INC,10,$190.00,\"$1,900.00\",15.83%\n\
+            X2,Roth IRA,AAPL,APPLE INC,5,$190.00,$950.00,40.00%\n\
+            X2,Roth IRA,Pending Activity,,,,$-20.00,\n\
+            \n\
+            \"The data and information in this spreadsheet is provided to you solely for your use.\"\n";
+        let list = parse(fidelity);
+        assert_eq!(list.symbols().collect::<Vec<_>>(), ["SPAXX", "AAPL"]);
+        assert!((list.weight("AAPL").unwrap() - 55.83).abs() < 1e-9);
+
+        // Schwab: a title line before the header, and totals
+        let schwab = "\"Positions for account Individual ...123 as of 04:00 PM ET, 2024/05/01\"\n\
+            \n\
+            \"Symbol\",\"Description\",\"Qty (Quantity)\",\"Price\",\"Mkt Val (Market Value)\"\n\
+            \"MSFT\",\"MICROSOFT CORP\",\"20\",\"$400.00\",\"$8,000.00\"\n\
+            \"Cash & Cash Investments\",\"--\",\"--\",\"--\",\"$500.00\"\n\
+            \"Account Total\",\"--\",\"--\",\"--\",\"$8,500.00\"\n";
+        let list = parse(schwab);
+        assert_eq!(
+            list.holdings(),
+            [Holding {
+                symbol: "MSFT".to_string(),
+                weight: 8000.0
+            }]
+        );
+
+        // Quantity only
+        let list = parse("Ticker,Shares\nVTI,12\n");
+        assert_eq!(list.weight("VTI"), Some(12.0));
+    }
+
+    #[test]
+    fn test_invalid_files() {
+        for csv in ["", "Symbol,Quantity\n", "AAPL,lots\n"] {
+            assert!(matches!(
+                Watchlist::from_csv_reader(csv.as_bytes()),
+                Err(FanError::InvalidWatchlist(_))
+            ));
+        }
+        assert!(matches!(
+            Watchlist::from_csv("/nonexistent/holdings.csv"),
+            Err(FanError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_queries() {
+        let list = Watchlist::for_symbols(["AAPL", "NVDA"]);
+        assert_eq!(
+            list.query().unwrap().to_string(),
+            "ticker:AAPL OR ticker:NVDA"
+        );
+        assert_eq!(list.earnings_query().symbols, ["AAPL", "NVDA"]);
+        assert!(Watchlist::new().query().is_none());
+    }
+}
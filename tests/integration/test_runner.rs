@@ -13,8 +13,8 @@ use crate::integration::utils::{
 };
 
 use finance_news_aggregator_rs::news_source::{
-    NewsSource, cnbc::CNBC, market_watch::MarketWatch, nasdaq::NASDAQ,
-    seeking_alpha::SeekingAlpha, wsj::WallStreetJournal, yahoo_finance::YahooFinance,
+    NewsSource, cnbc::CNBC, market_watch::MarketWatch, nasdaq::NASDAQ, seeking_alpha::SeekingAlpha,
+    wsj::WallStreetJournal, yahoo_finance::YahooFinance,
 };
 
 /// Comprehensive test runner for all news sources
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use finance_news_aggregator_rs::error::FanError;
 use std::collections::HashMap;
 use std::fmt;
 
@@ -20,7 +21,12 @@ impl DeprecationTracker {
     }
 
     /// Record a failure for deprecation tracking
-    pub fn record_failure(&mut self, source: &str, function: &str, error: &dyn std::error::Error) {
+    pub fn record_failure(
+        &mut self,
+        source: &str,
+        function: &str,
+        error: &(dyn std::error::Error + 'static),
+    ) {
         let error_type = Self::classify_error(error);
         let failure = FailureRecord {
             source: source.to_string(),
@@ -49,7 +55,7 @@ impl DeprecationTracker {
         source: &str,
         function: &str,
         url: &str,
-        error: &dyn std::error::Error,
+        error: &(dyn std::error::Error + 'static),
     ) {
         let error_type = Self::classify_error(error);
         let failure = FailureRecord {
@@ -72,7 +78,25 @@ impl DeprecationTracker {
     }
 
     /// Classify error types for deprecation analysis
-    fn classify_error(error: &dyn std::error::Error) -> String {
+    fn classify_error(error: &(dyn std::error::Error + 'static)) -> String {
+        // Typed errors first; the message checks below cover everything else
+        match error.downcast_ref::<FanError>() {
+            Some(FanError::NotAFeed { .. }) => return "NOT_A_FEED".to_string(),
+            Some(FanError::HttpStatus { status: 404, .. }) => {
+                return "HTTP_404_NOT_FOUND".to_string();
+            }
+            Some(FanError::HttpStatus { status: 403, .. }) => {
+                return "HTTP_403_FORBIDDEN".to_string();
+            }
+            Some(FanError::HttpStatus { status: 429, .. }) => return "RATE_LIMITED".to_string(),
+            Some(FanError::HttpStatus {
+                status: 500..=599, ..
+            }) => {
+                return "SERVER_ERROR".to_string();
+            }
+            _ => {}
+        }
+
         let error_msg = error.to_string().to_lowercase();
 
         if error_msg.contains("404") || error_msg.contains("not found") {
@@ -107,11 +131,11 @@ impl DeprecationTracker {
         let mut deprecated_endpoints = Vec::new();
         let mut removal_candidates = Vec::new();
 
-        // Identify deprecated endpoints (404, 403, DNS errors)
+        // Identify deprecated endpoints (404, 403, DNS errors, pages served instead of feeds)
         for failure in &self.failures {
             if matches!(
                 failure.error_type.as_str(),
-                "HTTP_404_NOT_FOUND" | "HTTP_403_FORBIDDEN" | "DNS_ERROR"
+                "HTTP_404_NOT_FOUND" | "HTTP_403_FORBIDDEN" | "DNS_ERROR" | "NOT_A_FEED"
             ) {
                 deprecated_endpoints.push(DeprecatedEndpoint {
                     source: failure.source.clone(),
@@ -288,6 +312,26 @@ mod tests {
             DeprecationTracker::classify_error(&timeout_error),
             "NETWORK_TIMEOUT"
         );
+
+        // A retired feed redirected to a homepage is not mistaken for a parse error
+        let not_a_feed = FanError::NotAFeed {
+            url: "https://example.com/feed.xml".to_string(),
+            content_type: "text/html".to_string(),
+        };
+        assert_eq!(
+            DeprecationTracker::classify_error(&not_a_feed),
+            "NOT_A_FEED"
+        );
+
+        let server_error = FanError::HttpStatus {
+            status: 503,
+            url: "https://example.com/feed.xml".to_string(),
+            snippet: "Service Unavailable".to_string(),
+        };
+        assert_eq!(
+            DeprecationTracker::classify_error(&server_error),
+            "SERVER_ERROR"
+        );
     }
 
     #[test]
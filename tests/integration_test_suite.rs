@@ -1,3 +1,5 @@
+#![cfg(feature = "all-sources")]
+
 use std::env;
 
 // Import integration test utilities
@@ -0,0 +1,91 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::business_wire::BusinessWire;
+use finance_news_aggregator_rs::news_source::{FeedSource, NewsSource};
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_business_wire_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let business_wire = BusinessWire::new(client);
+
+    assert_eq!(business_wire.name(), "Business Wire");
+
+    let topics = business_wire.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"all"));
+    assert!(topics.contains(&"earnings"));
+    // Listed in a fixed order, each with a feed
+    assert_eq!(topics[..2], ["all", "earnings"]);
+    for topic in &topics {
+        assert!(
+            business_wire.build_topic_url(topic).is_ok(),
+            "no feed for {}",
+            topic
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_business_wire_all() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let business_wire = BusinessWire::new(client);
+
+    match business_wire.all().await {
+        Ok(articles) => {
+            println!("✓ all returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("Business Wire".to_string()));
+            }
+        }
+        Err(e) => println!("✗ all failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_business_wire_earnings() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let business_wire = BusinessWire::new(client);
+
+    match business_wire.earnings().await {
+        Ok(articles) => {
+            println!("✓ earnings returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ earnings failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_business_wire_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let business_wire = BusinessWire::new(client);
+
+    let topics = business_wire.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match business_wire.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nBusiness Wire Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one Business Wire feed should be accessible"
+    );
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::coin_desk::CoinDesk;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_coin_desk_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let coin_desk = CoinDesk::new(client);
+
+    assert_eq!(coin_desk.name(), "CoinDesk");
+
+    let topics = coin_desk.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"latest"));
+    assert!(topics.contains(&"markets"));
+}
+
+#[tokio::test]
+async fn test_coin_desk_latest() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let coin_desk = CoinDesk::new(client);
+
+    match coin_desk.latest().await {
+        Ok(articles) => {
+            println!("✓ latest returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("CoinDesk".to_string()));
+            }
+        }
+        Err(e) => println!("✗ latest failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_coin_desk_markets() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let coin_desk = CoinDesk::new(client);
+
+    match coin_desk.markets().await {
+        Ok(articles) => {
+            println!("✓ markets returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ markets failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_coin_desk_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let coin_desk = CoinDesk::new(client);
+
+    let topics = coin_desk.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match coin_desk.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nCoinDesk Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one CoinDesk feed should be accessible"
+    );
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::financial_post::FinancialPost;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_financial_post_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let financial_post = FinancialPost::new(client);
+
+    assert_eq!(financial_post.name(), "Financial Post");
+
+    let topics = financial_post.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"top_stories"));
+    assert!(topics.contains(&"economy"));
+}
+
+#[tokio::test]
+async fn test_financial_post_top_stories() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let financial_post = FinancialPost::new(client);
+
+    match financial_post.top_stories().await {
+        Ok(articles) => {
+            println!("✓ top_stories returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("Financial Post".to_string()));
+            }
+        }
+        Err(e) => println!("✗ top_stories failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_financial_post_investing() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let financial_post = FinancialPost::new(client);
+
+    match financial_post.investing().await {
+        Ok(articles) => {
+            println!("✓ investing returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ investing failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_financial_post_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let financial_post = FinancialPost::new(client);
+
+    let topics = financial_post.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match financial_post.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nFinancial Post Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one Financial Post feed should be accessible"
+    );
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::finnhub_news::FinnhubNews;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_finnhub_news_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let finnhub = FinnhubNews::new(client);
+
+    assert_eq!(finnhub.name(), "Finnhub");
+
+    let topics = finnhub.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"general"));
+    assert!(topics.contains(&"forex"));
+    assert!(topics.contains(&"crypto"));
+    assert!(topics.contains(&"merger"));
+}
+
+#[tokio::test]
+async fn test_finnhub_news_general() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let finnhub = FinnhubNews::new(client);
+
+    match finnhub.general().await {
+        Ok(articles) => {
+            println!("✓ general returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("Finnhub".to_string()));
+            }
+        }
+        Err(e) => println!("✗ general failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_finnhub_news_crypto() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let finnhub = FinnhubNews::new(client);
+
+    match finnhub.crypto().await {
+        Ok(articles) => {
+            println!("✓ crypto returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ crypto failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_finnhub_news_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let finnhub = FinnhubNews::new(client);
+
+    let topics = finnhub.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match finnhub.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nFinnhub Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one Finnhub feed should be accessible"
+    );
+}
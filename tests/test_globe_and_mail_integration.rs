@@ -0,0 +1,82 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::globe_and_mail::GlobeAndMail;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_globe_and_mail_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let globe_and_mail = GlobeAndMail::new(client);
+
+    assert_eq!(globe_and_mail.name(), "Globe and Mail");
+
+    let topics = globe_and_mail.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"business"));
+    assert!(topics.contains(&"markets"));
+}
+
+#[tokio::test]
+async fn test_globe_and_mail_business() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let globe_and_mail = GlobeAndMail::new(client);
+
+    match globe_and_mail.business().await {
+        Ok(articles) => {
+            println!("✓ business returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("Globe and Mail".to_string()));
+            }
+        }
+        Err(e) => println!("✗ business failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_globe_and_mail_markets() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let globe_and_mail = GlobeAndMail::new(client);
+
+    match globe_and_mail.markets().await {
+        Ok(articles) => {
+            println!("✓ markets returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ markets failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_globe_and_mail_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let globe_and_mail = GlobeAndMail::new(client);
+
+    let topics = globe_and_mail.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match globe_and_mail.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nGlobe and Mail Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one Globe and Mail feed should be accessible"
+    );
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::globe_newswire::GlobeNewswire;
+use finance_news_aggregator_rs::news_source::{FeedSource, NewsSource};
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_globe_newswire_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let globe_newswire = GlobeNewswire::new(client);
+
+    assert_eq!(globe_newswire.name(), "GlobeNewswire");
+
+    let topics = globe_newswire.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"public_companies"));
+    assert!(topics.contains(&"earnings"));
+    // Listed in a fixed order, each with a feed
+    assert_eq!(topics[..2], ["public_companies", "earnings"]);
+    for topic in &topics {
+        assert!(
+            globe_newswire.build_topic_url(topic).is_ok(),
+            "no feed for {}",
+            topic
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_globe_newswire_public_companies() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let globe_newswire = GlobeNewswire::new(client);
+
+    match globe_newswire.public_companies().await {
+        Ok(articles) => {
+            println!("✓ public_companies returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("GlobeNewswire".to_string()));
+            }
+        }
+        Err(e) => println!("✗ public_companies failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_globe_newswire_earnings() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let globe_newswire = GlobeNewswire::new(client);
+
+    match globe_newswire.earnings().await {
+        Ok(articles) => {
+            println!("✓ earnings returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ earnings failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_globe_newswire_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let globe_newswire = GlobeNewswire::new(client);
+
+    let topics = globe_newswire.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match globe_newswire.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nGlobeNewswire Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one GlobeNewswire feed should be accessible"
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::news_api_org::NewsApiOrg;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_news_api_org_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let news_api = NewsApiOrg::new(client);
+
+    assert_eq!(news_api.name(), "NewsAPI");
+
+    let topics = news_api.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"business"));
+    assert!(topics.contains(&"technology"));
+    assert!(topics.contains(&"general"));
+}
+
+#[tokio::test]
+async fn test_news_api_org_business() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let news_api = NewsApiOrg::new(client);
+
+    match news_api.business().await {
+        Ok(articles) => {
+            println!("✓ business returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("NewsAPI".to_string()));
+            }
+        }
+        Err(e) => println!("✗ business failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_news_api_org_technology() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let news_api = NewsApiOrg::new(client);
+
+    match news_api.technology().await {
+        Ok(articles) => {
+            println!("✓ technology returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ technology failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_news_api_org_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let news_api = NewsApiOrg::new(client);
+
+    let topics = news_api.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match news_api.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nNewsAPI Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one NewsAPI feed should be accessible"
+    );
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::nikkei_asia::NikkeiAsia;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_nikkei_asia_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let nikkei_asia = NikkeiAsia::new(client);
+
+    assert_eq!(nikkei_asia.name(), "Nikkei Asia");
+
+    let topics = nikkei_asia.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"latest"));
+}
+
+#[tokio::test]
+async fn test_nikkei_asia_latest() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let nikkei_asia = NikkeiAsia::new(client);
+
+    match nikkei_asia.latest().await {
+        Ok(articles) => {
+            println!("✓ latest returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("Nikkei Asia".to_string()));
+            }
+        }
+        Err(e) => println!("✗ latest failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_nikkei_asia_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let nikkei_asia = NikkeiAsia::new(client);
+
+    let topics = nikkei_asia.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match nikkei_asia.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nNikkei Asia Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one Nikkei Asia feed should be accessible"
+    );
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::nytimes::NYTimes;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_nytimes_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let nytimes = NYTimes::new(client);
+
+    assert_eq!(nytimes.name(), "New York Times");
+
+    let topics = nytimes.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"Business"));
+    assert!(topics.contains(&"Dealbook"));
+}
+
+#[tokio::test]
+async fn test_nytimes_business() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let nytimes = NYTimes::new(client);
+
+    match nytimes.business().await {
+        Ok(articles) => {
+            println!("✓ business returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("New York Times".to_string()));
+            }
+        }
+        Err(e) => println!("✗ business failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_nytimes_economy() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let nytimes = NYTimes::new(client);
+
+    match nytimes.economy().await {
+        Ok(articles) => {
+            println!("✓ economy returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ economy failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_nytimes_dealbook() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let nytimes = NYTimes::new(client);
+
+    match nytimes.dealbook().await {
+        Ok(articles) => {
+            println!("✓ dealbook returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ dealbook failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_nytimes_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let nytimes = NYTimes::new(client);
+
+    let topics = nytimes.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match nytimes.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nNew York Times Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one New York Times feed should be accessible"
+    );
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::*;
+use finance_news_aggregator_rs::parser::NewsParser;
+use finance_news_aggregator_rs::quirks::Source;
+use finance_news_aggregator_rs::types::NewsArticle;
+use reqwest::Client;
+use std::fs;
+use std::path::PathBuf;
+
+/// Read the fixture feed of a source from `tests/fixtures`
+fn fixture(source: Source) -> String {
+    let extension = if source.is_json() { "json" } else { "xml" };
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("{}.{}", source.client_type(), extension));
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read {:?}: {}", path, e))
+}
+
+/// Build the news source a `Source` stands for
+fn news_source(source: Source) -> Box<dyn FeedSource> {
+    let client = Client::new();
+    match source {
+        Source::AlphaVantage => Box::new(AlphaVantage::new(client)),
+        Source::BusinessWire => Box::new(BusinessWire::new(client)),
+        Source::CNBC => Box::new(CNBC::new(client)),
+        Source::CoinDesk => Box::new(CoinDesk::new(client)),
+        Source::CoinTelegraph => Box::new(CoinTelegraph::new(client)),
+        Source::FinancialPost => Box::new(FinancialPost::new(client)),
+        Source::FinnhubNews => Box::new(FinnhubNews::new(client)),
+        Source::Gdelt => Box::new(Gdelt::new(client)),
+        Source::GlobeAndMail => Box::new(GlobeAndMail::new(client)),
+        Source::GlobeNewswire => Box::new(GlobeNewswire::new(client)),
+        Source::MarketWatch => Box::new(MarketWatch::new(client)),
+        Source::NASDAQ => Box::new(NASDAQ::new(client)),
+        Source::NewsApiOrg => Box::new(NewsApiOrg::new(client)),
+        Source::NikkeiAsia => Box::new(NikkeiAsia::new(client)),
+        Source::NYTimes => Box::new(NYTimes::new(client)),
+        Source::Polygon => Box::new(Polygon::new(client)),
+        Source::PRNewswire => Box::new(PRNewswire::new(client)),
+        Source::Reddit => Box::new(Reddit::new(client)),
+        Source::SCMPBusiness => Box::new(SCMPBusiness::new(client)),
+        Source::SeekingAlpha => Box::new(SeekingAlpha::new(client)),
+        Source::StockTwits => Box::new(StockTwits::new(client)),
+        Source::WallStreetJournal => Box::new(WallStreetJournal::new(client)),
+        Source::YahooFinance => Box::new(YahooFinance::new(client)),
+    }
+}
+
+#[test]
+fn test_every_source_parses_its_fixture() {
+    for source in Source::all() {
+        let articles = news_source(source)
+            .parse_body(&fixture(source))
+            .unwrap_or_else(|e| panic!("{:?} fixture failed to parse: {}", source, e));
+
+        assert!(!articles.is_empty(), "{:?} fixture has no articles", source);
+        for article in &articles {
+            let title = article.title.as_deref().unwrap_or_default();
+            assert!(!title.is_empty(), "{:?} article without a title", source);
+            assert_eq!(title, title.trim(), "{:?} title is not trimmed", source);
+            assert!(
+                article.link.is_some(),
+                "{:?} article without a link",
+                source
+            );
+        }
+    }
+}
+
+#[test]
+fn test_cnbc_cdata_is_trimmed() {
+    let articles = NewsParser::for_source(Source::CNBC)
+        .parse_response(&fixture(Source::CNBC))
+        .unwrap();
+
+    assert_eq!(
+        articles[0].title.as_deref(),
+        Some("Fed holds rates steady, signals fewer cuts")
+    );
+    assert_eq!(
+        articles[1].description.as_deref(),
+        Some("<p>Check out the companies making headlines.</p>")
+    );
+    assert_eq!(
+        articles[0].extra("metadata", "type").unwrap(),
+        "cnbcnewsstory"
+    );
+}
+
+#[test]
+fn test_market_watch_entities_are_resolved() {
+    let articles = NewsParser::for_source(Source::MarketWatch)
+        .parse_response(&fixture(Source::MarketWatch))
+        .unwrap();
+
+    assert_eq!(
+        articles[0].title.as_deref(),
+        Some("Dow & S&P 500 close higher after Fed's decision")
+    );
+    assert_eq!(
+        articles[0].description.as_deref(),
+        Some("Stocks rose \u{2014} led by tech & energy \u{2026}")
+    );
+    assert_eq!(
+        articles[1].description.as_deref(),
+        Some("Berkshire's cash pile tops $180 billion.")
+    );
+    assert!(!articles[0].extra_fields.contains_key("item"));
+}
+
+#[test]
+fn test_default_profile_keeps_unknown_entities() {
+    let rss = "<rss><channel><item><title>Q&amp;A</title><guid>a?b=1&amp;c=2</guid>\
+        <description>50&nbsp;bps</description></item></channel></rss>";
+    let articles = NewsParser::new("generic").parse_response(rss).unwrap();
+
+    assert_eq!(articles[0].title.as_deref(), Some("Q&A"));
+    assert_eq!(articles[0].guid.as_deref(), Some("a?b=1&c=2"));
+    assert_eq!(articles[0].description.as_deref(), Some("50&nbsp;bps"));
+}
+
+#[test]
+fn test_pub_dates_normalize_to_utc() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/pub_dates.tsv");
+    let rows = fs::read_to_string(&path).unwrap();
+
+    for line in rows.lines().filter(|line| !line.starts_with('#')) {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let (source, raw, expected) = (fields[0], fields[1], fields[2]);
+
+        let mut article = NewsArticle::new();
+        article.source = Some(source.to_string());
+        article.pub_date = Some(raw.to_string());
+
+        let published = article
+            .published_at()
+            .unwrap_or_else(|| panic!("{}: failed to parse {:?}", source, raw));
+        assert_eq!(published.to_rfc3339(), expected, "{}: {:?}", source, raw);
+        // The original string is kept as served
+        assert_eq!(article.pub_date.as_deref(), Some(raw));
+    }
+}
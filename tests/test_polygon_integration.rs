@@ -0,0 +1,68 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::polygon::Polygon;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_polygon_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let polygon = Polygon::new(client);
+
+    assert_eq!(polygon.name(), "Polygon");
+
+    let topics = polygon.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"latest"));
+}
+
+#[tokio::test]
+async fn test_polygon_latest() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let polygon = Polygon::new(client);
+
+    match polygon.latest().await {
+        Ok(articles) => {
+            println!("✓ latest returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("Polygon".to_string()));
+            }
+        }
+        Err(e) => println!("✗ latest failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_polygon_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let polygon = Polygon::new(client);
+
+    let topics = polygon.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match polygon.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nPolygon Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one Polygon feed should be accessible"
+    );
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::pr_newswire::PRNewswire;
+use finance_news_aggregator_rs::news_source::{FeedSource, NewsSource};
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_pr_newswire_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let pr_newswire = PRNewswire::new(client);
+
+    assert_eq!(pr_newswire.name(), "PR Newswire");
+
+    let topics = pr_newswire.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"all"));
+    assert!(topics.contains(&"earnings"));
+    // Listed in a fixed order, each with a feed
+    assert_eq!(topics[..2], ["all", "financial_services"]);
+    for topic in &topics {
+        assert!(
+            pr_newswire.build_topic_url(topic).is_ok(),
+            "no feed for {}",
+            topic
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_pr_newswire_all() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let pr_newswire = PRNewswire::new(client);
+
+    match pr_newswire.all().await {
+        Ok(articles) => {
+            println!("✓ all returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("PR Newswire".to_string()));
+            }
+        }
+        Err(e) => println!("✗ all failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_pr_newswire_financial_services() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let pr_newswire = PRNewswire::new(client);
+
+    match pr_newswire.financial_services().await {
+        Ok(articles) => {
+            println!("✓ financial_services returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ financial_services failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_pr_newswire_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let pr_newswire = PRNewswire::new(client);
+
+    let topics = pr_newswire.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match pr_newswire.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nPR Newswire Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one PR Newswire feed should be accessible"
+    );
+}
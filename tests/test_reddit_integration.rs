@@ -0,0 +1,82 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::reddit::Reddit;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_reddit_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let reddit = Reddit::new(client);
+
+    assert_eq!(reddit.name(), "Reddit");
+
+    let topics = reddit.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"stocks"));
+    assert!(topics.contains(&"wallstreetbets"));
+}
+
+#[tokio::test]
+async fn test_reddit_stocks() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let reddit = Reddit::new(client);
+
+    match reddit.stocks().await {
+        Ok(articles) => {
+            println!("✓ stocks returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("Reddit".to_string()));
+            }
+        }
+        Err(e) => println!("✗ stocks failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_reddit_subreddit() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let reddit = Reddit::new(client);
+
+    match reddit.subreddit("SecurityAnalysis").await {
+        Ok(articles) => {
+            println!("✓ subreddit returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ subreddit failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_reddit_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let reddit = Reddit::new(client);
+
+    let topics = reddit.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match reddit.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nReddit Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one Reddit feed should be accessible"
+    );
+}
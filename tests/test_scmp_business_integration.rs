@@ -0,0 +1,82 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::scmp_business::SCMPBusiness;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_scmp_business_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let scmp = SCMPBusiness::new(client);
+
+    assert_eq!(scmp.name(), "SCMP Business");
+
+    let topics = scmp.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"business"));
+    assert!(topics.contains(&"markets"));
+}
+
+#[tokio::test]
+async fn test_scmp_business_business() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let scmp = SCMPBusiness::new(client);
+
+    match scmp.business().await {
+        Ok(articles) => {
+            println!("✓ business returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("SCMP Business".to_string()));
+            }
+        }
+        Err(e) => println!("✗ business failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_scmp_business_companies() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let scmp = SCMPBusiness::new(client);
+
+    match scmp.companies().await {
+        Ok(articles) => {
+            println!("✓ companies returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ companies failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_scmp_business_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let scmp = SCMPBusiness::new(client);
+
+    let topics = scmp.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match scmp.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nSCMP Business Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one SCMP Business feed should be accessible"
+    );
+}
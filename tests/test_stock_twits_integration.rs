@@ -0,0 +1,81 @@
+#![cfg(feature = "all-sources")]
+
+use finance_news_aggregator_rs::news_source::NewsSource;
+use finance_news_aggregator_rs::news_source::stock_twits::StockTwits;
+
+mod integration;
+use integration::utils::client_factory::ClientFactory;
+
+#[tokio::test]
+async fn test_stock_twits_basic_functionality() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let stock_twits = StockTwits::new(client);
+
+    assert_eq!(stock_twits.name(), "StockTwits");
+
+    let topics = stock_twits.available_topics();
+    assert!(!topics.is_empty());
+    assert!(topics.contains(&"trending"));
+}
+
+#[tokio::test]
+async fn test_stock_twits_trending() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let stock_twits = StockTwits::new(client);
+
+    match stock_twits.trending().await {
+        Ok(articles) => {
+            println!("✓ trending returned {} articles", articles.len());
+            for article in &articles {
+                assert_eq!(article.source, Some("StockTwits".to_string()));
+            }
+        }
+        Err(e) => println!("✗ trending failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_stock_twits_symbol() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let stock_twits = StockTwits::new(client);
+
+    match stock_twits.symbol("AAPL").await {
+        Ok(articles) => {
+            println!("✓ symbol returned {} articles", articles.len());
+        }
+        Err(e) => println!("✗ symbol failed: {}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_stock_twits_all_topics() {
+    let client = ClientFactory::create_test_client().expect("Failed to create test client");
+    let stock_twits = StockTwits::new(client);
+
+    let topics = stock_twits.available_topics();
+    let mut successful = 0;
+    let mut _failed = 0;
+
+    for &topic in &topics {
+        match stock_twits.fetch_topic(topic).await {
+            Ok(articles) => {
+                successful += 1;
+                println!("✓ {} returned {} articles", topic, articles.len());
+            }
+            Err(e) => {
+                _failed += 1;
+                println!("✗ {} failed: {}", topic, e);
+            }
+        }
+    }
+
+    println!(
+        "\nStockTwits Summary: {}/{} topics accessible",
+        successful,
+        topics.len()
+    );
+    assert!(
+        successful > 0,
+        "At least one StockTwits feed should be accessible"
+    );
+}
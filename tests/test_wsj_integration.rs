@@ -1,3 +1,5 @@
+#![cfg(feature = "all-sources")]
+
 use finance_news_aggregator_rs::news_source::NewsSource;
 use finance_news_aggregator_rs::news_source::wsj::WallStreetJournal;
 use tokio;